@@ -16,10 +16,10 @@ use e2e_tests::{
 };
 use ldk_node::lightning::ln::msgs::SocketAddress;
 use ldk_server_client::ldk_server_protos::api::{
-	Bolt11ReceiveRequest, Bolt12ReceiveRequest, OnchainReceiveRequest,
+	Bolt11ReceiveRequest, Bolt12ReceiveRequest, OnchainReceiveRequest, SubscribeEventsRequest,
 };
 use ldk_server_client::ldk_server_protos::types::{
-	bolt11_invoice_description, Bolt11InvoiceDescription,
+	bolt11_invoice_description, Bolt11InvoiceDescription, PaymentMetadata,
 };
 use ldk_server_protos::events::event_envelope::Event;
 
@@ -29,8 +29,8 @@ async fn test_cli_get_node_info() {
 	let server = LdkServerHandle::start(&bitcoind).await;
 
 	let output = run_cli(&server, &["get-node-info"]);
-	assert!(output.get("node_id").is_some());
-	assert_eq!(output["node_id"], server.node_id());
+	assert!(output.get("nodeId").is_some());
+	assert_eq!(output["nodeId"], server.node_id());
 }
 
 #[tokio::test]
@@ -49,9 +49,9 @@ async fn test_cli_get_balances() {
 	let server = LdkServerHandle::start(&bitcoind).await;
 
 	let output = run_cli(&server, &["get-balances"]);
-	assert_eq!(output["total_onchain_balance_sats"], 0);
-	assert_eq!(output["spendable_onchain_balance_sats"], 0);
-	assert_eq!(output["total_lightning_balance_sats"], 0);
+	assert_eq!(output["totalOnchainBalanceSats"], "0");
+	assert_eq!(output["spendableOnchainBalanceSats"], "0");
+	assert_eq!(output["totalLightningBalanceSats"], "0");
 }
 
 #[tokio::test]
@@ -72,6 +72,15 @@ async fn test_cli_list_payments_empty() {
 	assert!(output["list"].as_array().unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_cli_list_payments_updated_since_empty() {
+	let bitcoind = TestBitcoind::new();
+	let server = LdkServerHandle::start(&bitcoind).await;
+
+	let output = run_cli(&server, &["list-payments-updated-since", "0"]);
+	assert!(output["list"].as_array().unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_cli_list_forwarded_payments_empty() {
 	let bitcoind = TestBitcoind::new();
@@ -81,6 +90,17 @@ async fn test_cli_list_forwarded_payments_empty() {
 	assert!(output["list"].as_array().unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_cli_get_forwarding_stats_empty() {
+	let bitcoind = TestBitcoind::new();
+	let server = LdkServerHandle::start(&bitcoind).await;
+
+	let output = run_cli(&server, &["get-forwarding-stats"]);
+	assert_eq!(output["forwardedPaymentsCount"], "0");
+	assert!(output["channelPairs"].as_array().unwrap().is_empty());
+	assert!(output["days"].as_array().unwrap().is_empty());
+}
+
 #[tokio::test]
 async fn test_cli_sign_message() {
 	let bitcoind = TestBitcoind::new();
@@ -118,6 +138,7 @@ async fn test_cli_export_pathfinding_scores() {
 				kind: Some(bolt11_invoice_description::Kind::Direct("test".to_string())),
 			}),
 			expiry_secs: 3600,
+			metadata: None,
 		})
 		.await
 		.unwrap();
@@ -205,7 +226,7 @@ async fn test_cli_open_channel() {
 		&server_a,
 		&["open-channel", server_b.node_id(), &addr, "100000sat", "--announce-channel"],
 	);
-	assert!(!output["user_channel_id"].as_str().unwrap().is_empty());
+	assert!(!output["userChannelId"].as_str().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -218,7 +239,7 @@ async fn test_cli_list_channels() {
 	let output = run_cli(&server_a, &["list-channels"]);
 	let channels = output["channels"].as_array().unwrap();
 	assert!(!channels.is_empty());
-	assert_eq!(channels[0]["counterparty_node_id"], server_b.node_id());
+	assert_eq!(channels[0]["counterpartyNodeId"], server_b.node_id());
 }
 
 #[tokio::test]
@@ -262,13 +283,14 @@ async fn test_cli_bolt11_send() {
 				kind: Some(bolt11_invoice_description::Kind::Direct("test".to_string())),
 			}),
 			expiry_secs: 3600,
+			metadata: None,
 		})
 		.await
 		.unwrap();
 
 	// Pay via CLI from A
 	let output = run_cli(&server_a, &["bolt11-send", &invoice_resp.invoice]);
-	assert!(!output["payment_id"].as_str().unwrap().is_empty());
+	assert!(!output["paymentId"].as_str().unwrap().is_empty());
 
 	// Verify events
 	tokio::time::sleep(Duration::from_secs(5)).await;
@@ -301,13 +323,14 @@ async fn test_cli_bolt12_send() {
 			amount_msat: None,
 			expiry_secs: None,
 			quantity: None,
+			metadata: None,
 		})
 		.await
 		.unwrap();
 
 	// Send via CLI from A
 	let output = run_cli(&server_a, &["bolt12-send", &offer_resp.offer, "10000sat"]);
-	assert!(!output["payment_id"].as_str().unwrap().is_empty());
+	assert!(!output["paymentId"].as_str().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -322,7 +345,7 @@ async fn test_cli_spontaneous_send() {
 	setup_funded_channel(&bitcoind, &server_a, &server_b, 100_000).await;
 
 	let output = run_cli(&server_a, &["spontaneous-send", server_b.node_id(), "10000sat"]);
-	assert!(!output["payment_id"].as_str().unwrap().is_empty());
+	assert!(!output["paymentId"].as_str().unwrap().is_empty());
 
 	// Verify events
 	tokio::time::sleep(Duration::from_secs(5)).await;
@@ -340,6 +363,88 @@ async fn test_cli_spontaneous_send() {
 	);
 }
 
+#[tokio::test]
+async fn test_subscribe_events() {
+	let bitcoind = TestBitcoind::new();
+	let server_a = LdkServerHandle::start(&bitcoind).await;
+	let server_b = LdkServerHandle::start(&bitcoind).await;
+
+	setup_funded_channel(&bitcoind, &server_a, &server_b, 100_000).await;
+
+	let mut events_b = server_b.client().subscribe_events(SubscribeEventsRequest {}).await.unwrap();
+
+	let output = run_cli(&server_a, &["spontaneous-send", server_b.node_id(), "10000sat"]);
+	assert!(!output["paymentId"].as_str().unwrap().is_empty());
+
+	// Channel events of the channel setup may still be published after subscribing.
+	let event = tokio::time::timeout(Duration::from_secs(30), async {
+		loop {
+			let event =
+				events_b.next_event().await.unwrap().expect("Event stream closed unexpectedly");
+			if !matches!(&event.event, Some(Event::ChannelPending(_) | Event::ChannelReady(_))) {
+				return event;
+			}
+		}
+	})
+	.await
+	.expect("Timed out waiting for event");
+	assert!(
+		matches!(&event.event, Some(Event::PaymentReceived(_))),
+		"Expected PaymentReceived on receiver, got {:?}",
+		event.event
+	);
+	assert_eq!(event.node_id, server_b.node_id());
+	assert!(event.sequence_number > 0);
+	assert!(!event.event_id.is_empty());
+}
+
+#[tokio::test]
+async fn test_cli_list_events() {
+	let bitcoind = TestBitcoind::new();
+	let server_a = LdkServerHandle::start(&bitcoind).await;
+	let server_b = LdkServerHandle::start(&bitcoind).await;
+	setup_funded_channel(&bitcoind, &server_a, &server_b, 100_000).await;
+
+	run_cli(&server_a, &["spontaneous-send", server_b.node_id(), "10000sat"]);
+	tokio::time::sleep(Duration::from_secs(3)).await;
+
+	let output = run_cli(&server_b, &["list-events"]);
+	let events = output["list"].as_array().unwrap();
+	assert!(events.iter().any(|e| e["event"]["channelReady"].is_object()));
+	assert!(events.iter().any(|e| e["event"]["paymentReceived"].is_object()));
+
+	let output = run_cli(&server_b, &["list-events", "--event-type", "PaymentReceived"]);
+	let events = output["list"].as_array().unwrap();
+	assert_eq!(events.len(), 1);
+	let sequence_number = events[0]["sequenceNumber"].as_str().unwrap();
+
+	let output = run_cli(&server_b, &["list-events", "--since-sequence-number", sequence_number]);
+	assert!(output["list"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_event_file() {
+	let bitcoind = TestBitcoind::new();
+	let server_a = LdkServerHandle::start(&bitcoind).await;
+	let server_b = LdkServerHandle::start(&bitcoind).await;
+	setup_funded_channel(&bitcoind, &server_a, &server_b, 100_000).await;
+
+	run_cli(&server_a, &["spontaneous-send", server_b.node_id(), "10000sat"]);
+	tokio::time::sleep(Duration::from_secs(3)).await;
+
+	let events = server_b.read_event_file();
+	assert!(
+		events.iter().any(|e| matches!(&e.event, Some(Event::PaymentReceived(_)))),
+		"Expected PaymentReceived in event file, got events: {:?}",
+		events.iter().map(|e| &e.event).collect::<Vec<_>>()
+	);
+	assert!(events.iter().all(|e| e.node_id == server_b.node_id()));
+
+	// Events are appended in the order they were published.
+	let sequence_numbers = events.iter().map(|e| e.sequence_number).collect::<Vec<_>>();
+	assert!(sequence_numbers.windows(2).all(|w| w[0] <= w[1]));
+}
+
 #[tokio::test]
 async fn test_cli_get_payment_details() {
 	let bitcoind = TestBitcoind::new();
@@ -356,12 +461,13 @@ async fn test_cli_get_payment_details() {
 				kind: Some(bolt11_invoice_description::Kind::Direct("test".to_string())),
 			}),
 			expiry_secs: 3600,
+			metadata: None,
 		})
 		.await
 		.unwrap();
 
 	let send_output = run_cli(&server_a, &["bolt11-send", &invoice_resp.invoice]);
-	let payment_id = send_output["payment_id"].as_str().unwrap();
+	let payment_id = send_output["paymentId"].as_str().unwrap();
 
 	// Wait for payment to be recorded
 	tokio::time::sleep(Duration::from_secs(3)).await;
@@ -387,6 +493,7 @@ async fn test_cli_list_payments() {
 				kind: Some(bolt11_invoice_description::Kind::Direct("test".to_string())),
 			}),
 			expiry_secs: 3600,
+			metadata: None,
 		})
 		.await
 		.unwrap();
@@ -396,6 +503,82 @@ async fn test_cli_list_payments() {
 
 	let output = run_cli(&server_a, &["list-payments"]);
 	assert!(!output["list"].as_array().unwrap().is_empty());
+
+	let output = run_cli(
+		&server_a,
+		&["list-payments", "--direction", "outbound", "--status", "succeeded", "--kind", "bolt11"],
+	);
+	let payments = output["list"].as_array().unwrap();
+	assert_eq!(payments.len(), 1);
+	assert_eq!(payments[0]["direction"], "OUTBOUND");
+
+	let output =
+		run_cli(&server_a, &["list-payments", "--direction", "inbound", "--kind", "bolt11"]);
+	assert!(output["list"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_cli_payment_metadata() {
+	let bitcoind = TestBitcoind::new();
+	let server_a = LdkServerHandle::start(&bitcoind).await;
+	let server_b = LdkServerHandle::start(&bitcoind).await;
+	setup_funded_channel(&bitcoind, &server_a, &server_b, 100_000).await;
+
+	let invoice_resp = server_b
+		.client()
+		.bolt11_receive(Bolt11ReceiveRequest {
+			amount_msat: Some(10_000_000),
+			description: Some(Bolt11InvoiceDescription {
+				kind: Some(bolt11_invoice_description::Kind::Direct("test".to_string())),
+			}),
+			expiry_secs: 3600,
+			metadata: Some(PaymentMetadata {
+				order_id: Some("order-1".to_string()),
+				customer_reference: None,
+				labels: vec!["sales".to_string()],
+			}),
+		})
+		.await
+		.unwrap();
+
+	let send_output = run_cli(
+		&server_a,
+		&[
+			"bolt11-send",
+			&invoice_resp.invoice,
+			"--order-id",
+			"purchase-1",
+			"--label",
+			"supplies",
+			"--label",
+			"march",
+		],
+	);
+	let payment_id = send_output["paymentId"].as_str().unwrap();
+	tokio::time::sleep(Duration::from_secs(3)).await;
+
+	let output = run_cli(&server_a, &["get-payment-details", payment_id]);
+	assert_eq!(output["payment"]["metadata"]["orderId"], "purchase-1");
+	assert_eq!(output["payment"]["metadata"]["labels"], serde_json::json!(["supplies", "march"]));
+
+	let output = run_cli(&server_a, &["list-payments", "--direction", "outbound"]);
+	assert_eq!(output["list"][0]["metadata"]["orderId"], "purchase-1");
+
+	for label in ["supplies", "march"] {
+		let output = run_cli(&server_a, &["list-payments-by-label", label]);
+		let payments = output["list"].as_array().unwrap();
+		assert_eq!(payments.len(), 1);
+		assert_eq!(payments[0]["id"], payment_id);
+	}
+	let output = run_cli(&server_a, &["list-payments-by-label", "sales"]);
+	assert!(output["list"].as_array().unwrap().is_empty());
+
+	// The metadata supplied for the invoice is attached to the payment received for it.
+	let output = run_cli(&server_b, &["list-payments-by-label", "sales"]);
+	let payments = output["list"].as_array().unwrap();
+	assert_eq!(payments.len(), 1);
+	assert_eq!(payments[0]["direction"], "INBOUND");
+	assert_eq!(payments[0]["metadata"]["orderId"], "order-1");
 }
 
 #[tokio::test]
@@ -432,6 +615,34 @@ async fn test_cli_force_close_channel() {
 	assert!(channels_output["channels"].as_array().unwrap().is_empty());
 }
 
+#[tokio::test]
+async fn test_channel_closed_event() {
+	let bitcoind = TestBitcoind::new();
+	let server_a = LdkServerHandle::start(&bitcoind).await;
+	let server_b = LdkServerHandle::start(&bitcoind).await;
+	let user_channel_id = setup_funded_channel(&bitcoind, &server_a, &server_b, 100_000).await;
+
+	let mut events_b = server_b.client().subscribe_events(SubscribeEventsRequest {}).await.unwrap();
+
+	run_cli(&server_a, &["force-close-channel", &user_channel_id, server_b.node_id()]);
+	mine_and_sync(&bitcoind, &[&server_a, &server_b], 6).await;
+
+	let channel_closed = tokio::time::timeout(Duration::from_secs(30), async {
+		loop {
+			let event =
+				events_b.next_event().await.unwrap().expect("Event stream closed unexpectedly");
+			if let Some(Event::ChannelClosed(channel_closed)) = event.event {
+				return channel_closed;
+			}
+		}
+	})
+	.await
+	.expect("Timed out waiting for ChannelClosed event");
+	assert_eq!(channel_closed.counterparty_node_id.as_deref(), Some(server_a.node_id()));
+	assert!(channel_closed.force_closed);
+	assert!(channel_closed.reason.is_some());
+}
+
 #[tokio::test]
 async fn test_cli_splice_in() {
 	let bitcoind = TestBitcoind::new();
@@ -463,7 +674,7 @@ async fn test_cli_graph_list_channels_empty() {
 	let server = LdkServerHandle::start(&bitcoind).await;
 
 	let output = run_cli(&server, &["graph-list-channels"]);
-	assert!(output["short_channel_ids"].as_array().unwrap().is_empty());
+	assert!(output["shortChannelIds"].as_array().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -472,7 +683,7 @@ async fn test_cli_graph_list_nodes_empty() {
 	let server = LdkServerHandle::start(&bitcoind).await;
 
 	let output = run_cli(&server, &["graph-list-nodes"]);
-	assert!(output["node_ids"].as_array().unwrap().is_empty());
+	assert!(output["nodeIds"].as_array().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -487,9 +698,9 @@ async fn test_cli_graph_with_channel() {
 		let start = std::time::Instant::now();
 		loop {
 			let output = run_cli(&server_a, &["graph-list-channels"]);
-			let scids = output["short_channel_ids"].as_array().unwrap();
+			let scids = output["shortChannelIds"].as_array().unwrap();
 			if !scids.is_empty() {
-				break scids[0].as_u64().unwrap().to_string();
+				break scids[0].as_str().unwrap().to_string();
 			}
 			if start.elapsed() > Duration::from_secs(30) {
 				panic!("Timed out waiting for channel to appear in network graph");
@@ -501,8 +712,8 @@ async fn test_cli_graph_with_channel() {
 	// Test GraphGetChannel: should return channel info with both our nodes.
 	let output = run_cli(&server_a, &["graph-get-channel", &scid]);
 	let channel = &output["channel"];
-	let node_one = channel["node_one"].as_str().unwrap();
-	let node_two = channel["node_two"].as_str().unwrap();
+	let node_one = channel["nodeOne"].as_str().unwrap();
+	let node_two = channel["nodeTwo"].as_str().unwrap();
 	let nodes = [server_a.node_id(), server_b.node_id()];
 	assert!(nodes.contains(&node_one), "node_one {} not one of our nodes", node_one);
 	assert!(nodes.contains(&node_two), "node_two {} not one of our nodes", node_two);
@@ -510,7 +721,7 @@ async fn test_cli_graph_with_channel() {
 	// Test GraphListNodes: should contain both node IDs.
 	let output = run_cli(&server_a, &["graph-list-nodes"]);
 	let node_ids: Vec<&str> =
-		output["node_ids"].as_array().unwrap().iter().map(|n| n.as_str().unwrap()).collect();
+		output["nodeIds"].as_array().unwrap().iter().map(|n| n.as_str().unwrap()).collect();
 	assert!(node_ids.contains(&server_a.node_id()), "Expected server_a in graph nodes");
 	assert!(node_ids.contains(&server_b.node_id()), "Expected server_b in graph nodes");
 
@@ -609,5 +820,21 @@ async fn test_forwarded_payment_event() {
 		events_b.iter().map(|e| &e.event).collect::<Vec<_>>()
 	);
 
+	// Verify the forwarded payment can be filtered by the nodes it was forwarded between
+	let output =
+		run_cli(&server_b, &["list-forwarded-payments", "--prev-node-id", server_a.node_id()]);
+	assert_eq!(output["list"].as_array().unwrap().len(), 1);
+	let output =
+		run_cli(&server_b, &["list-forwarded-payments", "--next-node-id", server_a.node_id()]);
+	assert!(output["list"].as_array().unwrap().is_empty());
+
+	// Verify the forwarded payment is counted in the forwarding stats
+	let output =
+		run_cli(&server_b, &["get-forwarding-stats", "--prev-node-id", server_a.node_id()]);
+	assert_eq!(output["forwardedPaymentsCount"], "1");
+	assert_eq!(output["channelPairs"].as_array().unwrap().len(), 1);
+	assert_eq!(output["channelPairs"][0]["prevNodeId"], server_a.node_id());
+	assert_eq!(output["days"].as_array().unwrap().len(), 1);
+
 	node_c.stop().unwrap();
 }
@@ -62,6 +62,8 @@ pub struct Config {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TlsConfig {
 	pub cert_path: Option<String>,
+	pub client_cert_path: Option<String>,
+	pub client_key_path: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
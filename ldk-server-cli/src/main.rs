@@ -20,32 +20,40 @@ use ldk_server_client::client::LdkServerClient;
 use ldk_server_client::error::LdkServerError;
 use ldk_server_client::error::LdkServerErrorCode::{
 	AuthError, InternalError, InternalServerError, InvalidRequestError, LightningError,
+	SpendingLimitExceededError,
 };
 use ldk_server_client::ldk_server_protos::api::{
-	Bolt11ReceiveRequest, Bolt11ReceiveResponse, Bolt11SendRequest, Bolt11SendResponse,
-	Bolt12ReceiveRequest, Bolt12ReceiveResponse, Bolt12SendRequest, Bolt12SendResponse,
-	CloseChannelRequest, CloseChannelResponse, ConnectPeerRequest, ConnectPeerResponse,
+	ApprovePaymentRequest, ApprovePaymentResponse, Bolt11ReceiveRequest, Bolt11ReceiveResponse,
+	Bolt11SendRequest, Bolt11SendResponse, Bolt12ReceiveRequest, Bolt12ReceiveResponse,
+	Bolt12SendRequest, Bolt12SendResponse, CloseChannelRequest, CloseChannelResponse,
+	ConnectPeerRequest, ConnectPeerResponse, CreateApiKeyRequest, CreateApiKeyResponse,
 	DisconnectPeerRequest, DisconnectPeerResponse, ExportPathfindingScoresRequest,
 	ForceCloseChannelRequest, ForceCloseChannelResponse, GetBalancesRequest, GetBalancesResponse,
-	GetNodeInfoRequest, GetNodeInfoResponse, GetPaymentDetailsRequest, GetPaymentDetailsResponse,
-	GraphGetChannelRequest, GraphGetChannelResponse, GraphGetNodeRequest, GraphGetNodeResponse,
-	GraphListChannelsRequest, GraphListChannelsResponse, GraphListNodesRequest,
-	GraphListNodesResponse, ListChannelsRequest, ListChannelsResponse,
-	ListForwardedPaymentsRequest, ListPaymentsRequest, OnchainReceiveRequest,
-	OnchainReceiveResponse, OnchainSendRequest, OnchainSendResponse, OpenChannelRequest,
-	OpenChannelResponse, SignMessageRequest, SignMessageResponse, SpliceInRequest,
-	SpliceInResponse, SpliceOutRequest, SpliceOutResponse, SpontaneousSendRequest,
-	SpontaneousSendResponse, UpdateChannelConfigRequest, UpdateChannelConfigResponse,
-	VerifySignatureRequest, VerifySignatureResponse,
+	GetForwardingStatsRequest, GetForwardingStatsResponse, GetNodeInfoRequest, GetNodeInfoResponse,
+	GetPaymentDetailsRequest, GetPaymentDetailsResponse, GraphGetChannelRequest,
+	GraphGetChannelResponse, GraphGetNodeRequest, GraphGetNodeResponse, GraphListChannelsRequest,
+	GraphListChannelsResponse, GraphListNodesRequest, GraphListNodesResponse, ListApiKeysRequest,
+	ListApiKeysResponse, ListAuditLogRequest, ListChannelsRequest, ListChannelsResponse,
+	ListEventsRequest, ListForwardedPaymentsRequest, ListPaymentsByLabelRequest,
+	ListPaymentsRequest, ListPaymentsUpdatedSinceRequest, ListPendingApprovalsRequest,
+	ListPendingApprovalsResponse, OnchainReceiveRequest, OnchainReceiveResponse,
+	OnchainSendRequest, OnchainSendResponse, OpenChannelRequest, OpenChannelResponse,
+	RejectPaymentRequest, RejectPaymentResponse, RevokeApiKeyRequest, RevokeApiKeyResponse,
+	RotateApiKeyRequest, RotateApiKeyResponse, SignMessageRequest, SignMessageResponse,
+	SpliceInRequest, SpliceInResponse, SpliceOutRequest, SpliceOutResponse, SpontaneousSendRequest,
+	SpontaneousSendResponse, SubscribeEventsRequest, UpdateChannelConfigRequest,
+	UpdateChannelConfigResponse, VerifySignatureRequest, VerifySignatureResponse,
 };
 use ldk_server_client::ldk_server_protos::types::{
-	bolt11_invoice_description, Bolt11InvoiceDescription, ChannelConfig, PageToken,
+	bolt11_invoice_description, ApiKeyPermission, Bolt11InvoiceDescription, ChannelConfig,
+	PageToken, PaymentDirection, PaymentKindType, PaymentMetadata, PaymentStatus,
 	RouteParametersConfig,
 };
 use serde::Serialize;
 use serde_json::{json, Value};
 use types::{
-	Amount, CliListForwardedPaymentsResponse, CliListPaymentsResponse, CliPaginatedResponse,
+	Amount, CliListAuditLogResponse, CliListEventsResponse, CliListForwardedPaymentsResponse,
+	CliListPaymentsResponse, CliPaginatedResponse,
 };
 
 mod config;
@@ -84,6 +92,20 @@ struct Cli {
 	)]
 	tls_cert: Option<String>,
 
+	#[arg(
+		long,
+		requires = "tls_client_key",
+		help = "Path to the client's TLS certificate file (PEM format). Only required if the server requires client certificates"
+	)]
+	tls_client_cert: Option<String>,
+
+	#[arg(
+		long,
+		requires = "tls_client_cert",
+		help = "Path to the client's TLS private key file (PEM format)"
+	)]
+	tls_client_key: Option<String>,
+
 	#[arg(short, long, help = "Path to config file. Defaults to ~/.ldk-server/config.toml")]
 	config: Option<String>,
 
@@ -117,6 +139,15 @@ enum Commands {
 			help = "Fee rate in satoshis per virtual byte. If not set, a reasonable estimate will be used"
 		)]
 		fee_rate_sat_per_vb: Option<u64>,
+		#[arg(long, help = "Identifier of the order the payment is for")]
+		order_id: Option<String>,
+		#[arg(long, help = "Reference to the customer the payment is to")]
+		customer_reference: Option<String>,
+		#[arg(
+			long = "label",
+			help = "Label to attach to the payment, allowing it to be looked up via list-payments-by-label. Can be specified multiple times"
+		)]
+		labels: Vec<String>,
 	},
 	#[command(about = "Create a BOLT11 invoice to receive a payment")]
 	Bolt11Receive {
@@ -133,6 +164,15 @@ enum Commands {
 		description_hash: Option<String>,
 		#[arg(short, long, help = "Invoice expiry time in seconds (default: 86400)")]
 		expiry_secs: Option<u32>,
+		#[arg(long, help = "Identifier of the order the payment is for")]
+		order_id: Option<String>,
+		#[arg(long, help = "Reference to the customer the payment is from")]
+		customer_reference: Option<String>,
+		#[arg(
+			long = "label",
+			help = "Label to attach to the payment, allowing it to be looked up via list-payments-by-label. Can be specified multiple times"
+		)]
+		labels: Vec<String>,
 	},
 	#[command(about = "Pay a BOLT11 invoice")]
 	Bolt11Send {
@@ -159,6 +199,15 @@ enum Commands {
 			help = "Maximum share of a channel's total capacity to send over a channel, as a power of 1/2 (default: 2)"
 		)]
 		max_channel_saturation_power_of_half: Option<u32>,
+		#[arg(long, help = "Identifier of the order the payment is for")]
+		order_id: Option<String>,
+		#[arg(long, help = "Reference to the customer the payment is to")]
+		customer_reference: Option<String>,
+		#[arg(
+			long = "label",
+			help = "Label to attach to the payment, allowing it to be looked up via list-payments-by-label. Can be specified multiple times"
+		)]
+		labels: Vec<String>,
 	},
 	#[command(about = "Return a BOLT12 offer for receiving payments")]
 	Bolt12Receive {
@@ -172,6 +221,15 @@ enum Commands {
 		expiry_secs: Option<u32>,
 		#[arg(long, help = "Number of items requested. Can only be set for fixed-amount offers")]
 		quantity: Option<u64>,
+		#[arg(long, help = "Identifier of the order the payments are for")]
+		order_id: Option<String>,
+		#[arg(long, help = "Reference to the customer the payments are from")]
+		customer_reference: Option<String>,
+		#[arg(
+			long = "label",
+			help = "Label to attach to the payments received for the offer, allowing them to be looked up via list-payments-by-label. Can be specified multiple times"
+		)]
+		labels: Vec<String>,
 	},
 	#[command(about = "Send a payment for a BOLT12 offer")]
 	Bolt12Send {
@@ -206,6 +264,15 @@ enum Commands {
 			help = "Maximum share of a channel's total capacity to send over a channel, as a power of 1/2 (default: 2)"
 		)]
 		max_channel_saturation_power_of_half: Option<u32>,
+		#[arg(long, help = "Identifier of the order the payment is for")]
+		order_id: Option<String>,
+		#[arg(long, help = "Reference to the customer the payment is to")]
+		customer_reference: Option<String>,
+		#[arg(
+			long = "label",
+			help = "Label to attach to the payment, allowing it to be looked up via list-payments-by-label. Can be specified multiple times"
+		)]
+		labels: Vec<String>,
 	},
 	#[command(about = "Send a spontaneous payment (keysend) to a node")]
 	SpontaneousSend {
@@ -230,6 +297,15 @@ enum Commands {
 			help = "Maximum share of a channel's total capacity to send over a channel, as a power of 1/2 (default: 2)"
 		)]
 		max_channel_saturation_power_of_half: Option<u32>,
+		#[arg(long, help = "Identifier of the order the payment is for")]
+		order_id: Option<String>,
+		#[arg(long, help = "Reference to the customer the payment is to")]
+		customer_reference: Option<String>,
+		#[arg(
+			long = "label",
+			help = "Label to attach to the payment, allowing it to be looked up via list-payments-by-label. Can be specified multiple times"
+		)]
+		labels: Vec<String>,
 	},
 	#[command(about = "Cooperatively close the channel specified by the given channel ID")]
 	CloseChannel {
@@ -322,6 +398,68 @@ enum Commands {
 		#[arg(long)]
 		#[arg(help = "Page token to continue from a previous page (format: token:index)")]
 		page_token: Option<String>,
+		#[arg(
+			long,
+			help = "Only return payments with this status, one of pending, succeeded or failed"
+		)]
+		status: Option<String>,
+		#[arg(long, help = "Only return payments in this direction, one of inbound or outbound")]
+		direction: Option<String>,
+		#[arg(
+			long,
+			help = "Only return payments of this kind, one of onchain, bolt11, bolt11_jit, bolt12_offer, bolt12_refund or spontaneous"
+		)]
+		kind: Option<String>,
+		#[arg(
+			long,
+			help = "Only return payments created at or after this UNIX timestamp, in seconds"
+		)]
+		min_creation_timestamp: Option<u64>,
+		#[arg(
+			long,
+			help = "Only return payments created at or before this UNIX timestamp, in seconds"
+		)]
+		max_creation_timestamp: Option<u64>,
+		#[arg(
+			long,
+			help = "Only return payments last updated at or after this UNIX timestamp, in seconds"
+		)]
+		min_latest_update_timestamp: Option<u64>,
+		#[arg(
+			long,
+			help = "Only return payments last updated at or before this UNIX timestamp, in seconds"
+		)]
+		max_latest_update_timestamp: Option<u64>,
+	},
+	#[command(
+		about = "Retrieves list of all payments last updated at or after a given time, ordered by update time"
+	)]
+	ListPaymentsUpdatedSince {
+		#[arg(
+			help = "Only return payments last updated at or after this UNIX timestamp, in seconds"
+		)]
+		updated_since: u64,
+		#[arg(short, long)]
+		#[arg(
+			help = "Fetch at least this many payments by iterating through multiple pages. Returns combined results with the last page token. If not provided, returns only a single page."
+		)]
+		number_of_payments: Option<u64>,
+		#[arg(long)]
+		#[arg(help = "Page token to continue from a previous page (format: token:index)")]
+		page_token: Option<String>,
+	},
+	#[command(about = "Retrieves list of all payments whose metadata carries the given label")]
+	ListPaymentsByLabel {
+		#[arg(help = "The label to look up payments by")]
+		label: String,
+		#[arg(short, long)]
+		#[arg(
+			help = "Fetch at least this many payments by iterating through multiple pages. Returns combined results with the last page token. If not provided, returns only a single page."
+		)]
+		number_of_payments: Option<u64>,
+		#[arg(long)]
+		#[arg(help = "Page token to continue from a previous page (format: token:index)")]
+		page_token: Option<String>,
 	},
 	#[command(about = "Get details of a specific payment by its payment ID")]
 	GetPaymentDetails {
@@ -338,6 +476,59 @@ enum Commands {
 		number_of_payments: Option<u64>,
 		#[arg(long, help = "Page token to continue from a previous page (format: token:index)")]
 		page_token: Option<String>,
+		#[arg(long, help = "Only return payments forwarded from the channel with this channel ID")]
+		prev_channel_id: Option<String>,
+		#[arg(long, help = "Only return payments forwarded to the channel with this channel ID")]
+		next_channel_id: Option<String>,
+		#[arg(
+			long,
+			help = "Only return payments forwarded from the node with this hex-encoded node ID"
+		)]
+		prev_node_id: Option<String>,
+		#[arg(
+			long,
+			help = "Only return payments forwarded to the node with this hex-encoded node ID"
+		)]
+		next_node_id: Option<String>,
+		#[arg(
+			long,
+			help = "Only return payments forwarded at or after this UNIX timestamp, in seconds"
+		)]
+		min_timestamp: Option<u64>,
+		#[arg(
+			long,
+			help = "Only return payments forwarded at or before this UNIX timestamp, in seconds"
+		)]
+		max_timestamp: Option<u64>,
+	},
+	#[command(
+		about = "Get the number of payments forwarded, the amount forwarded and the fees earned, per channel pair and per day"
+	)]
+	GetForwardingStats {
+		#[arg(long, help = "Only count payments forwarded from the channel with this channel ID")]
+		prev_channel_id: Option<String>,
+		#[arg(long, help = "Only count payments forwarded to the channel with this channel ID")]
+		next_channel_id: Option<String>,
+		#[arg(
+			long,
+			help = "Only count payments forwarded from the node with this hex-encoded node ID"
+		)]
+		prev_node_id: Option<String>,
+		#[arg(
+			long,
+			help = "Only count payments forwarded to the node with this hex-encoded node ID"
+		)]
+		next_node_id: Option<String>,
+		#[arg(
+			long,
+			help = "Only count payments forwarded at or after this UNIX timestamp, in seconds. Defaults to 90 days before the max timestamp, which it must be within"
+		)]
+		min_timestamp: Option<u64>,
+		#[arg(
+			long,
+			help = "Only count payments forwarded at or before this UNIX timestamp, in seconds. Defaults to now"
+		)]
+		max_timestamp: Option<u64>,
 	},
 	UpdateChannelConfig {
 		#[arg(help = "The local user_channel_id of this channel")]
@@ -414,6 +605,95 @@ enum Commands {
 		#[arg(help = "The hex-encoded node ID to look up")]
 		node_id: String,
 	},
+	#[command(about = "Create a new API key with the given permissions")]
+	CreateApiKey {
+		#[arg(help = "The unique name of the API key")]
+		name: String,
+		#[arg(
+			short,
+			long = "permission",
+			required = true,
+			help = "Permission to grant to the API key, one of read, invoice, send or admin. Can be specified multiple times"
+		)]
+		permissions: Vec<String>,
+		#[arg(
+			long,
+			help = "Maximum amount of a single payment sent using the API key, e.g. 50sat or 50000msat"
+		)]
+		max_send_amount: Option<Amount>,
+		#[arg(
+			long,
+			help = "Maximum total amount of all payments sent using the API key within 24 hours, e.g. 500sat or 500000msat"
+		)]
+		daily_budget: Option<Amount>,
+		#[arg(
+			long,
+			help = "Only accept the API key on connections using a client certificate with this subject common name"
+		)]
+		client_identity: Option<String>,
+	},
+	#[command(about = "List all API keys known to the server")]
+	ListApiKeys,
+	#[command(about = "Revoke an API key, rejecting any further requests authenticated with it")]
+	RevokeApiKey {
+		#[arg(help = "The name of the API key to revoke")]
+		name: String,
+	},
+	#[command(about = "Replace the secret of an API key by a newly generated one")]
+	RotateApiKey {
+		#[arg(help = "The name of the API key to rotate")]
+		name: String,
+		#[arg(
+			long,
+			help = "Number of seconds the previous secret remains valid for (default: 3600)"
+		)]
+		grace_period_secs: Option<u64>,
+	},
+	#[command(about = "List all outgoing payments held until approved")]
+	ListPendingApprovals,
+	#[command(about = "Approve and send a payment held for approval")]
+	ApprovePayment {
+		#[arg(help = "The id of the pending approval")]
+		id: String,
+	},
+	#[command(about = "Reject a payment held for approval, such that it is never sent")]
+	RejectPayment {
+		#[arg(help = "The id of the pending approval")]
+		id: String,
+	},
+	#[command(about = "Retrieves the audit log of state-changing API calls, newest first")]
+	ListAuditLog {
+		#[arg(
+			short,
+			long,
+			help = "Fetch at least this many entries by iterating through multiple pages. Returns combined results with the last page token. If not provided, returns only a single page."
+		)]
+		number_of_entries: Option<u64>,
+		#[arg(long, help = "Page token to continue from a previous page (format: token:index)")]
+		page_token: Option<String>,
+	},
+	#[command(about = "Retrieves the history of events, oldest first")]
+	ListEvents {
+		#[arg(
+			short,
+			long,
+			help = "Fetch at least this many events by iterating through multiple pages. Returns combined results with the last page token. If not provided, returns only a single page."
+		)]
+		number_of_entries: Option<u64>,
+		#[arg(long, help = "Page token to continue from a previous page (format: token:index)")]
+		page_token: Option<String>,
+		#[arg(long, help = "Only return events with a sequence number greater than this one")]
+		since_sequence_number: Option<u64>,
+		#[arg(
+			long = "event-type",
+			help = "Only return events of this type, e.g. PaymentReceived or ChannelClosed. Can be specified multiple times"
+		)]
+		event_types: Vec<String>,
+	},
+	#[command(
+		about = "Stream events, e.g. on received payments, as they occur. Prints one JSON object per line until the server closes the stream"
+	)]
+	SubscribeEvents,
 	#[command(about = "Generate shell completions for the CLI")]
 	Completions {
 		#[arg(
@@ -488,10 +768,37 @@ async fn main() {
 		std::process::exit(1);
 	});
 
-	let client = LdkServerClient::new(base_url, api_key, &server_cert_pem).unwrap_or_else(|e| {
-		eprintln!("Failed to create client: {e}");
-		std::process::exit(1);
-	});
+	// Get the client certificate and key from arguments, then from config tls section.
+	let client_tls = config.as_ref().and_then(|c| c.tls.as_ref());
+	let client_identity_pem = match (
+		cli.tls_client_cert.or_else(|| client_tls.and_then(|t| t.client_cert_path.clone())),
+		cli.tls_client_key.or_else(|| client_tls.and_then(|t| t.client_key_path.clone())),
+	) {
+		(Some(cert_path), Some(key_path)) => {
+			let mut identity_pem = std::fs::read(&cert_path).unwrap_or_else(|e| {
+				eprintln!("Failed to read client certificate file '{cert_path}': {e}");
+				std::process::exit(1);
+			});
+			identity_pem.push(b'\n');
+			identity_pem.extend(std::fs::read(&key_path).unwrap_or_else(|e| {
+				eprintln!("Failed to read client key file '{key_path}': {e}");
+				std::process::exit(1);
+			}));
+			Some(identity_pem)
+		},
+		(None, None) => None,
+		_ => {
+			eprintln!("Both a client certificate and a client key are required for client certificate authentication");
+			std::process::exit(1);
+		},
+	};
+
+	let client =
+		LdkServerClient::new(base_url, api_key, &server_cert_pem, client_identity_pem.as_deref())
+			.unwrap_or_else(|e| {
+				eprintln!("Failed to create client: {e}");
+				std::process::exit(1);
+			});
 
 	match cli.command {
 		Commands::GetNodeInfo => {
@@ -509,7 +816,15 @@ async fn main() {
 				client.onchain_receive(OnchainReceiveRequest {}).await,
 			);
 		},
-		Commands::OnchainSend { address, amount, send_all, fee_rate_sat_per_vb } => {
+		Commands::OnchainSend {
+			address,
+			amount,
+			send_all,
+			fee_rate_sat_per_vb,
+			order_id,
+			customer_reference,
+			labels,
+		} => {
 			let amount_sats = amount.map(|a| a.to_sat().unwrap_or_else(|e| handle_error_msg(&e)));
 			handle_response_result::<_, OnchainSendResponse>(
 				client
@@ -518,11 +833,20 @@ async fn main() {
 						amount_sats,
 						send_all,
 						fee_rate_sat_per_vb,
+						metadata: build_payment_metadata(order_id, customer_reference, labels),
 					})
 					.await,
 			);
 		},
-		Commands::Bolt11Receive { description, description_hash, expiry_secs, amount } => {
+		Commands::Bolt11Receive {
+			description,
+			description_hash,
+			expiry_secs,
+			amount,
+			order_id,
+			customer_reference,
+			labels,
+		} => {
 			let amount_msat = amount.map(|a| a.to_msat());
 			let invoice_description = match (description, description_hash) {
 				(Some(desc), None) => Some(Bolt11InvoiceDescription {
@@ -541,8 +865,12 @@ async fn main() {
 			};
 
 			let expiry_secs = expiry_secs.unwrap_or(DEFAULT_EXPIRY_SECS);
-			let request =
-				Bolt11ReceiveRequest { description: invoice_description, expiry_secs, amount_msat };
+			let request = Bolt11ReceiveRequest {
+				description: invoice_description,
+				expiry_secs,
+				amount_msat,
+				metadata: build_payment_metadata(order_id, customer_reference, labels),
+			};
 
 			handle_response_result::<_, Bolt11ReceiveResponse>(
 				client.bolt11_receive(request).await,
@@ -555,6 +883,9 @@ async fn main() {
 			max_total_cltv_expiry_delta,
 			max_path_count,
 			max_channel_saturation_power_of_half,
+			order_id,
+			customer_reference,
+			labels,
 		} => {
 			let amount_msat = amount.map(|a| a.to_msat());
 			let max_total_routing_fee_msat = max_total_routing_fee.map(|a| a.to_msat());
@@ -572,11 +903,20 @@ async fn main() {
 						invoice,
 						amount_msat,
 						route_parameters: Some(route_parameters),
+						metadata: build_payment_metadata(order_id, customer_reference, labels),
 					})
 					.await,
 			);
 		},
-		Commands::Bolt12Receive { description, amount, expiry_secs, quantity } => {
+		Commands::Bolt12Receive {
+			description,
+			amount,
+			expiry_secs,
+			quantity,
+			order_id,
+			customer_reference,
+			labels,
+		} => {
 			let amount_msat = amount.map(|a| a.to_msat());
 			handle_response_result::<_, Bolt12ReceiveResponse>(
 				client
@@ -585,6 +925,7 @@ async fn main() {
 						amount_msat,
 						expiry_secs,
 						quantity,
+						metadata: build_payment_metadata(order_id, customer_reference, labels),
 					})
 					.await,
 			);
@@ -598,6 +939,9 @@ async fn main() {
 			max_total_cltv_expiry_delta,
 			max_path_count,
 			max_channel_saturation_power_of_half,
+			order_id,
+			customer_reference,
+			labels,
 		} => {
 			let amount_msat = amount.map(|a| a.to_msat());
 			let max_total_routing_fee_msat = max_total_routing_fee.map(|a| a.to_msat());
@@ -618,6 +962,7 @@ async fn main() {
 						quantity,
 						payer_note,
 						route_parameters: Some(route_parameters),
+						metadata: build_payment_metadata(order_id, customer_reference, labels),
 					})
 					.await,
 			);
@@ -629,6 +974,9 @@ async fn main() {
 			max_total_cltv_expiry_delta,
 			max_path_count,
 			max_channel_saturation_power_of_half,
+			order_id,
+			customer_reference,
+			labels,
 		} => {
 			let amount_msat = amount.to_msat();
 			let max_total_routing_fee_msat = max_total_routing_fee.map(|a| a.to_msat());
@@ -647,6 +995,7 @@ async fn main() {
 						amount_msat,
 						node_id,
 						route_parameters: Some(route_parameters),
+						metadata: build_payment_metadata(order_id, customer_reference, labels),
 					})
 					.await,
 			);
@@ -737,7 +1086,76 @@ async fn main() {
 				client.list_channels(ListChannelsRequest {}).await,
 			);
 		},
-		Commands::ListPayments { number_of_payments, page_token } => {
+		Commands::ListPayments {
+			number_of_payments,
+			page_token,
+			status,
+			direction,
+			kind,
+			min_creation_timestamp,
+			max_creation_timestamp,
+			min_latest_update_timestamp,
+			max_latest_update_timestamp,
+		} => {
+			let page_token = page_token
+				.map(|token_str| parse_page_token(&token_str).unwrap_or_else(|e| handle_error(e)));
+			let status =
+				status.map(|s| match PaymentStatus::from_str_name(&s.to_ascii_uppercase()) {
+					Some(status) => status as i32,
+					None => handle_error_msg(&format!("Invalid payment status: {s}")),
+				});
+			let direction =
+				direction.map(|d| match PaymentDirection::from_str_name(&d.to_ascii_uppercase()) {
+					Some(direction) => direction as i32,
+					None => handle_error_msg(&format!("Invalid payment direction: {d}")),
+				});
+			let kind =
+				kind.map(|k| match PaymentKindType::from_str_name(&k.to_ascii_uppercase()) {
+					Some(kind) => kind as i32,
+					None => handle_error_msg(&format!("Invalid payment kind: {k}")),
+				});
+
+			handle_response_result::<_, CliListPaymentsResponse>(
+				fetch_paginated(
+					number_of_payments,
+					page_token,
+					|pt| {
+						client.list_payments(ListPaymentsRequest {
+							page_token: pt,
+							status,
+							direction,
+							kind,
+							min_creation_timestamp,
+							max_creation_timestamp,
+							min_latest_update_timestamp,
+							max_latest_update_timestamp,
+						})
+					},
+					|r| (r.payments, r.next_page_token),
+				)
+				.await,
+			);
+		},
+		Commands::ListPaymentsUpdatedSince { updated_since, number_of_payments, page_token } => {
+			let page_token = page_token
+				.map(|token_str| parse_page_token(&token_str).unwrap_or_else(|e| handle_error(e)));
+
+			handle_response_result::<_, CliListPaymentsResponse>(
+				fetch_paginated(
+					number_of_payments,
+					page_token,
+					|pt| {
+						client.list_payments_updated_since(ListPaymentsUpdatedSinceRequest {
+							updated_since,
+							page_token: pt,
+						})
+					},
+					|r| (r.payments, r.next_page_token),
+				)
+				.await,
+			);
+		},
+		Commands::ListPaymentsByLabel { label, number_of_payments, page_token } => {
 			let page_token = page_token
 				.map(|token_str| parse_page_token(&token_str).unwrap_or_else(|e| handle_error(e)));
 
@@ -745,7 +1163,12 @@ async fn main() {
 				fetch_paginated(
 					number_of_payments,
 					page_token,
-					|pt| client.list_payments(ListPaymentsRequest { page_token: pt }),
+					|pt| {
+						client.list_payments_by_label(ListPaymentsByLabelRequest {
+							label: label.clone(),
+							page_token: pt,
+						})
+					},
 					|r| (r.payments, r.next_page_token),
 				)
 				.await,
@@ -756,7 +1179,16 @@ async fn main() {
 				client.get_payment_details(GetPaymentDetailsRequest { payment_id }).await,
 			);
 		},
-		Commands::ListForwardedPayments { number_of_payments, page_token } => {
+		Commands::ListForwardedPayments {
+			number_of_payments,
+			page_token,
+			prev_channel_id,
+			next_channel_id,
+			prev_node_id,
+			next_node_id,
+			min_timestamp,
+			max_timestamp,
+		} => {
 			let page_token = page_token
 				.map(|token_str| parse_page_token(&token_str).unwrap_or_else(|e| handle_error(e)));
 
@@ -767,6 +1199,12 @@ async fn main() {
 					|pt| {
 						client.list_forwarded_payments(ListForwardedPaymentsRequest {
 							page_token: pt,
+							prev_channel_id: prev_channel_id.clone(),
+							next_channel_id: next_channel_id.clone(),
+							prev_node_id: prev_node_id.clone(),
+							next_node_id: next_node_id.clone(),
+							min_timestamp,
+							max_timestamp,
 						})
 					},
 					|r| (r.forwarded_payments, r.next_page_token),
@@ -774,6 +1212,27 @@ async fn main() {
 				.await,
 			);
 		},
+		Commands::GetForwardingStats {
+			prev_channel_id,
+			next_channel_id,
+			prev_node_id,
+			next_node_id,
+			min_timestamp,
+			max_timestamp,
+		} => {
+			handle_response_result::<_, GetForwardingStatsResponse>(
+				client
+					.get_forwarding_stats(GetForwardingStatsRequest {
+						prev_channel_id,
+						next_channel_id,
+						prev_node_id,
+						next_node_id,
+						min_timestamp,
+						max_timestamp,
+					})
+					.await,
+			);
+		},
 		Commands::UpdateChannelConfig {
 			user_channel_id,
 			counterparty_node_id,
@@ -866,6 +1325,119 @@ async fn main() {
 				client.graph_get_node(GraphGetNodeRequest { node_id }).await,
 			);
 		},
+		Commands::CreateApiKey {
+			name,
+			permissions,
+			max_send_amount,
+			daily_budget,
+			client_identity,
+		} => {
+			let permissions = permissions
+				.iter()
+				.map(|p| match ApiKeyPermission::from_str_name(&p.to_ascii_uppercase()) {
+					Some(permission) => permission as i32,
+					None => handle_error_msg(&format!("Invalid permission: {p}")),
+				})
+				.collect();
+			handle_response_result::<_, CreateApiKeyResponse>(
+				client
+					.create_api_key(CreateApiKeyRequest {
+						name,
+						permissions,
+						max_send_amount_msat: max_send_amount.map(|a| a.to_msat()),
+						client_identity,
+						daily_budget_msat: daily_budget.map(|a| a.to_msat()),
+					})
+					.await,
+			);
+		},
+		Commands::ListApiKeys => {
+			handle_response_result::<_, ListApiKeysResponse>(
+				client.list_api_keys(ListApiKeysRequest {}).await,
+			);
+		},
+		Commands::RevokeApiKey { name } => {
+			handle_response_result::<_, RevokeApiKeyResponse>(
+				client.revoke_api_key(RevokeApiKeyRequest { name }).await,
+			);
+		},
+		Commands::RotateApiKey { name, grace_period_secs } => {
+			handle_response_result::<_, RotateApiKeyResponse>(
+				client.rotate_api_key(RotateApiKeyRequest { name, grace_period_secs }).await,
+			);
+		},
+		Commands::ListPendingApprovals => {
+			handle_response_result::<_, ListPendingApprovalsResponse>(
+				client.list_pending_approvals(ListPendingApprovalsRequest {}).await,
+			);
+		},
+		Commands::ApprovePayment { id } => {
+			handle_response_result::<_, ApprovePaymentResponse>(
+				client.approve_payment(ApprovePaymentRequest { id }).await,
+			);
+		},
+		Commands::RejectPayment { id } => {
+			handle_response_result::<_, RejectPaymentResponse>(
+				client.reject_payment(RejectPaymentRequest { id }).await,
+			);
+		},
+		Commands::ListAuditLog { number_of_entries, page_token } => {
+			let page_token = page_token
+				.map(|token_str| parse_page_token(&token_str).unwrap_or_else(|e| handle_error(e)));
+
+			handle_response_result::<_, CliListAuditLogResponse>(
+				fetch_paginated(
+					number_of_entries,
+					page_token,
+					|pt| client.list_audit_log(ListAuditLogRequest { page_token: pt }),
+					|r| (r.entries, r.next_page_token),
+				)
+				.await,
+			);
+		},
+		Commands::ListEvents {
+			number_of_entries,
+			page_token,
+			since_sequence_number,
+			event_types,
+		} => {
+			let page_token = page_token
+				.map(|token_str| parse_page_token(&token_str).unwrap_or_else(|e| handle_error(e)));
+
+			handle_response_result::<_, CliListEventsResponse>(
+				fetch_paginated(
+					number_of_entries,
+					page_token,
+					|pt| {
+						client.list_events(ListEventsRequest {
+							page_token: pt,
+							since_sequence_number,
+							event_types: event_types.clone(),
+						})
+					},
+					|r| (r.events, r.next_page_token),
+				)
+				.await,
+			);
+		},
+		Commands::SubscribeEvents => {
+			let mut events = client
+				.subscribe_events(SubscribeEventsRequest {})
+				.await
+				.unwrap_or_else(|e| handle_error(e));
+			loop {
+				match events.next_event().await {
+					Ok(Some(event)) => match serde_json::to_string(&event) {
+						Ok(json) => println!("{json}"),
+						Err(e) => {
+							handle_error_msg(&format!("Error serializing event to JSON: {e}"))
+						},
+					},
+					Ok(None) => break,
+					Err(e) => handle_error(e),
+				}
+			}
+		},
 		Commands::Completions { .. } => unreachable!("Handled above"),
 	}
 }
@@ -892,6 +1464,16 @@ fn build_open_channel_config(
 	})
 }
 
+/// Returns the metadata to attach to a payment, or `None` if none was given.
+fn build_payment_metadata(
+	order_id: Option<String>, customer_reference: Option<String>, labels: Vec<String>,
+) -> Option<PaymentMetadata> {
+	if order_id.is_none() && customer_reference.is_none() && labels.is_empty() {
+		return None;
+	}
+	Some(PaymentMetadata { order_id, customer_reference, labels })
+}
+
 async fn fetch_paginated<T, R, Fut>(
 	target_count: Option<u64>, initial_page_token: Option<PageToken>,
 	fetch_page: impl Fn(Option<PageToken>) -> Fut,
@@ -975,6 +1557,7 @@ fn handle_error(e: LdkServerError) -> ! {
 		AuthError => "Authentication Error",
 		LightningError => "Lightning Error",
 		InternalServerError => "Internal Server Error",
+		SpendingLimitExceededError => "Spending Limit Exceeded",
 		InternalError => "Internal Error",
 	};
 	eprintln!("Error ({}): {}", error_type, e.message);
@@ -16,12 +16,16 @@
 use std::fmt;
 use std::str::FromStr;
 
-use ldk_server_client::ldk_server_protos::types::{ForwardedPayment, PageToken, Payment};
+use ldk_server_client::ldk_server_protos::events::EventEnvelope;
+use ldk_server_client::ldk_server_protos::types::{
+	AuditLogEntry, ForwardedPayment, PageToken, Payment,
+};
 use serde::Serialize;
 
 /// CLI-specific wrapper for paginated responses that formats the page token
 /// as "token:idx" instead of a JSON object.
 #[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CliPaginatedResponse<T> {
 	/// List of items.
 	pub list: Vec<T>,
@@ -38,6 +42,8 @@ impl<T> CliPaginatedResponse<T> {
 
 pub type CliListPaymentsResponse = CliPaginatedResponse<Payment>;
 pub type CliListForwardedPaymentsResponse = CliPaginatedResponse<ForwardedPayment>;
+pub type CliListAuditLogResponse = CliPaginatedResponse<AuditLogEntry>;
+pub type CliListEventsResponse = CliPaginatedResponse<EventEnvelope>;
 
 fn format_page_token(token: PageToken) -> String {
 	format!("{}:{}", token.token, token.index)
@@ -12,40 +12,54 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use bitcoin_hashes::hmac::{Hmac, HmacEngine};
 use bitcoin_hashes::{sha256, Hash, HashEngine};
 use ldk_server_protos::api::{
-	Bolt11ReceiveRequest, Bolt11ReceiveResponse, Bolt11SendRequest, Bolt11SendResponse,
-	Bolt12ReceiveRequest, Bolt12ReceiveResponse, Bolt12SendRequest, Bolt12SendResponse,
-	CloseChannelRequest, CloseChannelResponse, ConnectPeerRequest, ConnectPeerResponse,
+	ApprovePaymentRequest, ApprovePaymentResponse, Bolt11ReceiveRequest, Bolt11ReceiveResponse,
+	Bolt11SendRequest, Bolt11SendResponse, Bolt12ReceiveRequest, Bolt12ReceiveResponse,
+	Bolt12SendRequest, Bolt12SendResponse, CloseChannelRequest, CloseChannelResponse,
+	ConnectPeerRequest, ConnectPeerResponse, CreateApiKeyRequest, CreateApiKeyResponse,
 	DisconnectPeerRequest, DisconnectPeerResponse, ExportPathfindingScoresRequest,
 	ExportPathfindingScoresResponse, ForceCloseChannelRequest, ForceCloseChannelResponse,
-	GetBalancesRequest, GetBalancesResponse, GetNodeInfoRequest, GetNodeInfoResponse,
-	GetPaymentDetailsRequest, GetPaymentDetailsResponse, GraphGetChannelRequest,
-	GraphGetChannelResponse, GraphGetNodeRequest, GraphGetNodeResponse, GraphListChannelsRequest,
-	GraphListChannelsResponse, GraphListNodesRequest, GraphListNodesResponse, ListChannelsRequest,
-	ListChannelsResponse, ListForwardedPaymentsRequest, ListForwardedPaymentsResponse,
-	ListPaymentsRequest, ListPaymentsResponse, OnchainReceiveRequest, OnchainReceiveResponse,
-	OnchainSendRequest, OnchainSendResponse, OpenChannelRequest, OpenChannelResponse,
-	SignMessageRequest, SignMessageResponse, SpliceInRequest, SpliceInResponse, SpliceOutRequest,
-	SpliceOutResponse, SpontaneousSendRequest, SpontaneousSendResponse, UpdateChannelConfigRequest,
-	UpdateChannelConfigResponse, VerifySignatureRequest, VerifySignatureResponse,
+	GetBalancesRequest, GetBalancesResponse, GetForwardingStatsRequest, GetForwardingStatsResponse,
+	GetNodeInfoRequest, GetNodeInfoResponse, GetPaymentDetailsRequest, GetPaymentDetailsResponse,
+	GraphGetChannelRequest, GraphGetChannelResponse, GraphGetNodeRequest, GraphGetNodeResponse,
+	GraphListChannelsRequest, GraphListChannelsResponse, GraphListNodesRequest,
+	GraphListNodesResponse, ListApiKeysRequest, ListApiKeysResponse, ListAuditLogRequest,
+	ListAuditLogResponse, ListChannelsRequest, ListChannelsResponse, ListEventsRequest,
+	ListEventsResponse, ListForwardedPaymentsRequest, ListForwardedPaymentsResponse,
+	ListPaymentsByLabelRequest, ListPaymentsByLabelResponse, ListPaymentsRequest,
+	ListPaymentsResponse, ListPaymentsUpdatedSinceRequest, ListPaymentsUpdatedSinceResponse,
+	ListPendingApprovalsRequest, ListPendingApprovalsResponse, OnchainReceiveRequest,
+	OnchainReceiveResponse, OnchainSendRequest, OnchainSendResponse, OpenChannelRequest,
+	OpenChannelResponse, RejectPaymentRequest, RejectPaymentResponse, RevokeApiKeyRequest,
+	RevokeApiKeyResponse, RotateApiKeyRequest, RotateApiKeyResponse, SignMessageRequest,
+	SignMessageResponse, SpliceInRequest, SpliceInResponse, SpliceOutRequest, SpliceOutResponse,
+	SpontaneousSendRequest, SpontaneousSendResponse, SubscribeEventsRequest,
+	UpdateChannelConfigRequest, UpdateChannelConfigResponse, VerifySignatureRequest,
+	VerifySignatureResponse,
 };
 use ldk_server_protos::endpoints::{
-	BOLT11_RECEIVE_PATH, BOLT11_SEND_PATH, BOLT12_RECEIVE_PATH, BOLT12_SEND_PATH,
-	CLOSE_CHANNEL_PATH, CONNECT_PEER_PATH, DISCONNECT_PEER_PATH, EXPORT_PATHFINDING_SCORES_PATH,
-	FORCE_CLOSE_CHANNEL_PATH, GET_BALANCES_PATH, GET_NODE_INFO_PATH, GET_PAYMENT_DETAILS_PATH,
+	APPROVE_PAYMENT_PATH, BOLT11_RECEIVE_PATH, BOLT11_SEND_PATH, BOLT12_RECEIVE_PATH,
+	BOLT12_SEND_PATH, CLOSE_CHANNEL_PATH, CONNECT_PEER_PATH, CREATE_API_KEY_PATH,
+	DISCONNECT_PEER_PATH, EXPORT_PATHFINDING_SCORES_PATH, FORCE_CLOSE_CHANNEL_PATH,
+	GET_BALANCES_PATH, GET_FORWARDING_STATS_PATH, GET_NODE_INFO_PATH, GET_PAYMENT_DETAILS_PATH,
 	GRAPH_GET_CHANNEL_PATH, GRAPH_GET_NODE_PATH, GRAPH_LIST_CHANNELS_PATH, GRAPH_LIST_NODES_PATH,
-	LIST_CHANNELS_PATH, LIST_FORWARDED_PAYMENTS_PATH, LIST_PAYMENTS_PATH, ONCHAIN_RECEIVE_PATH,
-	ONCHAIN_SEND_PATH, OPEN_CHANNEL_PATH, SIGN_MESSAGE_PATH, SPLICE_IN_PATH, SPLICE_OUT_PATH,
-	SPONTANEOUS_SEND_PATH, UPDATE_CHANNEL_CONFIG_PATH, VERIFY_SIGNATURE_PATH,
+	LIST_API_KEYS_PATH, LIST_AUDIT_LOG_PATH, LIST_CHANNELS_PATH, LIST_EVENTS_PATH,
+	LIST_FORWARDED_PAYMENTS_PATH, LIST_PAYMENTS_BY_LABEL_PATH, LIST_PAYMENTS_PATH,
+	LIST_PAYMENTS_UPDATED_SINCE_PATH, LIST_PENDING_APPROVALS_PATH, ONCHAIN_RECEIVE_PATH,
+	ONCHAIN_SEND_PATH, OPEN_CHANNEL_PATH, REJECT_PAYMENT_PATH, REVOKE_API_KEY_PATH,
+	ROTATE_API_KEY_PATH, SIGN_MESSAGE_PATH, SPLICE_IN_PATH, SPLICE_OUT_PATH, SPONTANEOUS_SEND_PATH,
+	SUBSCRIBE_EVENTS_PATH, UPDATE_CHANNEL_CONFIG_PATH, VERIFY_SIGNATURE_PATH,
 };
 use ldk_server_protos::error::{ErrorCode, ErrorResponse};
 use prost::Message;
 use reqwest::header::CONTENT_TYPE;
-use reqwest::{Certificate, Client};
+use reqwest::{Certificate, Client, Identity, Response, StatusCode};
 
 use crate::error::LdkServerError;
 use crate::error::LdkServerErrorCode::{
 	AuthError, InternalError, InternalServerError, InvalidRequestError, LightningError,
+	SpendingLimitExceededError,
 };
+use crate::event_stream::EventStream;
 
 const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
 
@@ -54,6 +68,9 @@ const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
 /// The client requires the server's TLS certificate to be provided for verification.
 /// This certificate can be found at `<server_storage_dir>/tls.crt` after the
 /// server generates it on first startup.
+///
+/// If the server requires client certificates, a certificate signed by the server's configured
+/// client CA has to be provided as well.
 #[derive(Clone)]
 pub struct LdkServerClient {
 	base_url: String,
@@ -68,33 +85,52 @@ impl LdkServerClient {
 	/// `api_key` is used for HMAC-based authentication.
 	/// `server_cert_pem` is the server's TLS certificate in PEM format. This can be
 	/// found at `<server_storage_dir>/tls.crt` after the server starts.
-	pub fn new(base_url: String, api_key: String, server_cert_pem: &[u8]) -> Result<Self, String> {
+	/// `client_identity_pem` is the client's TLS certificate followed by its PKCS#8 private key,
+	/// both in PEM format. It is only required if the server requires client certificates.
+	pub fn new(
+		base_url: String, api_key: String, server_cert_pem: &[u8],
+		client_identity_pem: Option<&[u8]>,
+	) -> Result<Self, String> {
 		let cert = Certificate::from_pem(server_cert_pem)
 			.map_err(|e| format!("Failed to parse server certificate: {e}"))?;
 
-		let client = Client::builder()
-			.add_root_certificate(cert)
-			.build()
-			.map_err(|e| format!("Failed to build HTTP client: {e}"))?;
+		let mut builder = Client::builder().add_root_certificate(cert);
+		if let Some(client_identity_pem) = client_identity_pem {
+			let identity = Identity::from_pem(client_identity_pem)
+				.map_err(|e| format!("Failed to parse client certificate or key: {e}"))?;
+			builder = builder.identity(identity);
+		}
+
+		let client = builder.build().map_err(|e| format!("Failed to build HTTP client: {e}"))?;
 
 		Ok(Self { base_url, client, api_key })
 	}
 
 	/// Computes the HMAC-SHA256 authentication header value.
-	/// Format: "HMAC <timestamp>:<hmac_hex>"
-	fn compute_auth_header(&self, body: &[u8]) -> String {
+	/// Format: "HMAC <timestamp>:<nonce>:<hmac_hex>"
+	///
+	/// The nonce is a random, hex-encoded 16-byte value which the server uses to reject replayed
+	/// requests, so a fresh header has to be computed for every request.
+	fn compute_auth_header(&self, body: &[u8]) -> Result<String, LdkServerError> {
 		let timestamp = SystemTime::now()
 			.duration_since(UNIX_EPOCH)
 			.expect("System time should be after Unix epoch")
 			.as_secs();
 
-		// Compute HMAC-SHA256(api_key, timestamp_bytes || body)
+		let mut nonce_bytes = [0u8; 16];
+		getrandom::getrandom(&mut nonce_bytes).map_err(|e| {
+			LdkServerError::new(InternalError, format!("Failed to generate nonce: {}", e))
+		})?;
+		let nonce = nonce_bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+
+		// Compute HMAC-SHA256(api_key, timestamp_bytes || nonce || body)
 		let mut hmac_engine: HmacEngine<sha256::Hash> = HmacEngine::new(self.api_key.as_bytes());
 		hmac_engine.input(&timestamp.to_be_bytes());
+		hmac_engine.input(nonce.as_bytes());
 		hmac_engine.input(body);
 		let hmac_result = Hmac::<sha256::Hash>::from_engine(hmac_engine);
 
-		format!("HMAC {}:{}", timestamp, hmac_result)
+		Ok(format!("HMAC {}:{}:{}", timestamp, nonce, hmac_result))
 	}
 
 	/// Retrieve the latest node info like `node_id`, `current_best_block` etc.
@@ -232,6 +268,24 @@ impl LdkServerClient {
 		self.post_request(&request, &url).await
 	}
 
+	/// Retrieves list of all payments last updated at or after a given time, ordered by update time.
+	/// For API contract/usage, refer to docs for [`ListPaymentsUpdatedSinceRequest`] and [`ListPaymentsUpdatedSinceResponse`].
+	pub async fn list_payments_updated_since(
+		&self, request: ListPaymentsUpdatedSinceRequest,
+	) -> Result<ListPaymentsUpdatedSinceResponse, LdkServerError> {
+		let url = format!("https://{}/{LIST_PAYMENTS_UPDATED_SINCE_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Retrieves list of all payments whose metadata carries the given label.
+	/// For API contract/usage, refer to docs for [`ListPaymentsByLabelRequest`] and [`ListPaymentsByLabelResponse`].
+	pub async fn list_payments_by_label(
+		&self, request: ListPaymentsByLabelRequest,
+	) -> Result<ListPaymentsByLabelResponse, LdkServerError> {
+		let url = format!("https://{}/{LIST_PAYMENTS_BY_LABEL_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
 	/// Updates the config for a previously opened channel.
 	/// For API contract/usage, refer to docs for [`UpdateChannelConfigRequest`] and [`UpdateChannelConfigResponse`].
 	pub async fn update_channel_config(
@@ -259,6 +313,15 @@ impl LdkServerClient {
 		self.post_request(&request, &url).await
 	}
 
+	/// Retrieves the number of payments forwarded, the amount forwarded and the fees earned, per channel pair and per day.
+	/// For API contract/usage, refer to docs for [`GetForwardingStatsRequest`] and [`GetForwardingStatsResponse`].
+	pub async fn get_forwarding_stats(
+		&self, request: GetForwardingStatsRequest,
+	) -> Result<GetForwardingStatsResponse, LdkServerError> {
+		let url = format!("https://{}/{GET_FORWARDING_STATS_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
 	/// Connect to a peer on the Lightning Network.
 	/// For API contract/usage, refer to docs for [`ConnectPeerRequest`] and [`ConnectPeerResponse`].
 	pub async fn connect_peer(
@@ -349,27 +412,111 @@ impl LdkServerClient {
 		self.post_request(&request, &url).await
 	}
 
+	/// Creates a new API key with the given permissions.
+	/// For API contract/usage, refer to docs for [`CreateApiKeyRequest`] and [`CreateApiKeyResponse`].
+	pub async fn create_api_key(
+		&self, request: CreateApiKeyRequest,
+	) -> Result<CreateApiKeyResponse, LdkServerError> {
+		let url = format!("https://{}/{CREATE_API_KEY_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Lists all API keys known to the server.
+	/// For API contract/usage, refer to docs for [`ListApiKeysRequest`] and [`ListApiKeysResponse`].
+	pub async fn list_api_keys(
+		&self, request: ListApiKeysRequest,
+	) -> Result<ListApiKeysResponse, LdkServerError> {
+		let url = format!("https://{}/{LIST_API_KEYS_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Revokes an API key.
+	/// For API contract/usage, refer to docs for [`RevokeApiKeyRequest`] and [`RevokeApiKeyResponse`].
+	pub async fn revoke_api_key(
+		&self, request: RevokeApiKeyRequest,
+	) -> Result<RevokeApiKeyResponse, LdkServerError> {
+		let url = format!("https://{}/{REVOKE_API_KEY_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Replaces the secret of an API key, keeping the previous one valid for a grace period.
+	/// For API contract/usage, refer to docs for [`RotateApiKeyRequest`] and [`RotateApiKeyResponse`].
+	pub async fn rotate_api_key(
+		&self, request: RotateApiKeyRequest,
+	) -> Result<RotateApiKeyResponse, LdkServerError> {
+		let url = format!("https://{}/{ROTATE_API_KEY_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Retrieves the outgoing payments held until approved.
+	/// For API contract/usage, refer to docs for [`ListPendingApprovalsRequest`] and [`ListPendingApprovalsResponse`].
+	pub async fn list_pending_approvals(
+		&self, request: ListPendingApprovalsRequest,
+	) -> Result<ListPendingApprovalsResponse, LdkServerError> {
+		let url = format!("https://{}/{LIST_PENDING_APPROVALS_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Approves and sends a payment held for approval.
+	/// For API contract/usage, refer to docs for [`ApprovePaymentRequest`] and [`ApprovePaymentResponse`].
+	pub async fn approve_payment(
+		&self, request: ApprovePaymentRequest,
+	) -> Result<ApprovePaymentResponse, LdkServerError> {
+		let url = format!("https://{}/{APPROVE_PAYMENT_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Rejects a payment held for approval.
+	/// For API contract/usage, refer to docs for [`RejectPaymentRequest`] and [`RejectPaymentResponse`].
+	pub async fn reject_payment(
+		&self, request: RejectPaymentRequest,
+	) -> Result<RejectPaymentResponse, LdkServerError> {
+		let url = format!("https://{}/{REJECT_PAYMENT_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Retrieves the audit log of state-changing API calls.
+	/// For API contract/usage, refer to docs for [`ListAuditLogRequest`] and [`ListAuditLogResponse`].
+	pub async fn list_audit_log(
+		&self, request: ListAuditLogRequest,
+	) -> Result<ListAuditLogResponse, LdkServerError> {
+		let url = format!("https://{}/{LIST_AUDIT_LOG_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Retrieves the history of events emitted by the server, oldest first.
+	/// For API contract/usage, refer to docs for [`ListEventsRequest`] and [`ListEventsResponse`].
+	pub async fn list_events(
+		&self, request: ListEventsRequest,
+	) -> Result<ListEventsResponse, LdkServerError> {
+		let url = format!("https://{}/{LIST_EVENTS_PATH}", self.base_url);
+		self.post_request(&request, &url).await
+	}
+
+	/// Subscribes to the events published by the server, e.g., when a payment is received.
+	/// For API contract/usage, refer to docs for [`SubscribeEventsRequest`] and [`EventStream`].
+	pub async fn subscribe_events(
+		&self, request: SubscribeEventsRequest,
+	) -> Result<EventStream, LdkServerError> {
+		let url = format!("https://{}/{SUBSCRIBE_EVENTS_PATH}", self.base_url);
+		let response_raw = self.send_request(&request, &url).await?;
+
+		let status = response_raw.status();
+		if status.is_success() {
+			Ok(EventStream::new(response_raw))
+		} else {
+			let payload = read_payload(response_raw).await?;
+			Err(decode_error_response(status, &payload)?)
+		}
+	}
+
 	async fn post_request<Rq: Message, Rs: Message + Default>(
 		&self, request: &Rq, url: &str,
 	) -> Result<Rs, LdkServerError> {
-		let request_body = request.encode_to_vec();
-		let auth_header = self.compute_auth_header(&request_body);
-		let response_raw = self
-			.client
-			.post(url)
-			.header(CONTENT_TYPE, APPLICATION_OCTET_STREAM)
-			.header("X-Auth", auth_header)
-			.body(request_body)
-			.send()
-			.await
-			.map_err(|e| {
-				LdkServerError::new(InternalError, format!("HTTP request failed: {}", e))
-			})?;
+		let response_raw = self.send_request(request, url).await?;
 
 		let status = response_raw.status();
-		let payload = response_raw.bytes().await.map_err(|e| {
-			LdkServerError::new(InternalError, format!("Failed to read response body: {}", e))
-		})?;
+		let payload = read_payload(response_raw).await?;
 
 		if status.is_success() {
 			Ok(Rs::decode(&payload[..]).map_err(|e| {
@@ -379,22 +526,53 @@ impl LdkServerClient {
 				)
 			})?)
 		} else {
-			let error_response = ErrorResponse::decode(&payload[..]).map_err(|e| {
-				LdkServerError::new(
-					InternalError,
-					format!("Failed to decode error response (status {}): {}", status, e),
-				)
-			})?;
-
-			let error_code = match ErrorCode::from_i32(error_response.error_code) {
-				Some(ErrorCode::InvalidRequestError) => InvalidRequestError,
-				Some(ErrorCode::AuthError) => AuthError,
-				Some(ErrorCode::LightningError) => LightningError,
-				Some(ErrorCode::InternalServerError) => InternalServerError,
-				Some(ErrorCode::UnknownError) | None => InternalError,
-			};
-
-			Err(LdkServerError::new(error_code, error_response.message))
+			Err(decode_error_response(status, &payload)?)
 		}
 	}
+
+	async fn send_request<Rq: Message>(
+		&self, request: &Rq, url: &str,
+	) -> Result<Response, LdkServerError> {
+		let request_body = request.encode_to_vec();
+		let auth_header = self.compute_auth_header(&request_body)?;
+		self.client
+			.post(url)
+			.header(CONTENT_TYPE, APPLICATION_OCTET_STREAM)
+			.header("X-Auth", auth_header)
+			.body(request_body)
+			.send()
+			.await
+			.map_err(|e| LdkServerError::new(InternalError, format!("HTTP request failed: {}", e)))
+	}
+}
+
+async fn read_payload(response: Response) -> Result<Vec<u8>, LdkServerError> {
+	let payload = response.bytes().await.map_err(|e| {
+		LdkServerError::new(InternalError, format!("Failed to read response body: {}", e))
+	})?;
+	Ok(payload.to_vec())
+}
+
+/// Decodes the [`ErrorResponse`] returned along with a non-success `status` into the error it
+/// describes.
+fn decode_error_response(
+	status: StatusCode, payload: &[u8],
+) -> Result<LdkServerError, LdkServerError> {
+	let error_response = ErrorResponse::decode(payload).map_err(|e| {
+		LdkServerError::new(
+			InternalError,
+			format!("Failed to decode error response (status {}): {}", status, e),
+		)
+	})?;
+
+	let error_code = match ErrorCode::from_i32(error_response.error_code) {
+		Some(ErrorCode::InvalidRequestError) => InvalidRequestError,
+		Some(ErrorCode::AuthError) => AuthError,
+		Some(ErrorCode::LightningError) => LightningError,
+		Some(ErrorCode::InternalServerError) => InternalServerError,
+		Some(ErrorCode::SpendingLimitExceededError) => SpendingLimitExceededError,
+		Some(ErrorCode::UnknownError) | None => InternalError,
+	};
+
+	Ok(LdkServerError::new(error_code, error_response.message))
 }
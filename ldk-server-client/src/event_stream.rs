@@ -0,0 +1,129 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use base64::Engine;
+use ldk_server_protos::events::EventEnvelope;
+use prost::Message;
+use reqwest::Response;
+
+use crate::error::LdkServerError;
+use crate::error::LdkServerErrorCode::InternalError;
+
+/// A stream of the events published by LDK Server, as returned by
+/// [`LdkServerClient::subscribe_events`].
+///
+/// Only events published while the stream is open are delivered. If the server closes the stream,
+/// e.g., because the client did not keep up with the published events, events may have been
+/// missed and should be retrieved using [`LdkServerClient::list_events`] after subscribing again.
+///
+/// [`LdkServerClient::subscribe_events`]: crate::client::LdkServerClient::subscribe_events
+/// [`LdkServerClient::list_events`]: crate::client::LdkServerClient::list_events
+pub struct EventStream {
+	response: Response,
+	buffer: Vec<u8>,
+}
+
+impl EventStream {
+	pub(crate) fn new(response: Response) -> Self {
+		Self { response, buffer: Vec::new() }
+	}
+
+	/// Waits for the next event.
+	///
+	/// Returns `Ok(None)` once the server closed the stream.
+	pub async fn next_event(&mut self) -> Result<Option<EventEnvelope>, LdkServerError> {
+		loop {
+			while let Some(block) = take_event_block(&mut self.buffer) {
+				// Blocks without data, e.g. keep-alive comments, are skipped.
+				if let Some(data) = event_data(&block) {
+					return decode_event(&data).map(Some);
+				}
+			}
+
+			match self.response.chunk().await {
+				Ok(Some(chunk)) => self.buffer.extend_from_slice(&chunk),
+				Ok(None) => return Ok(None),
+				Err(e) => {
+					return Err(LdkServerError::new(
+						InternalError,
+						format!("Failed to read event stream: {}", e),
+					))
+				},
+			}
+		}
+	}
+}
+
+/// Removes the first complete server-sent event, terminated by an empty line, from `buffer`.
+fn take_event_block(buffer: &mut Vec<u8>) -> Option<Vec<u8>> {
+	let end = buffer.windows(2).position(|w| w == b"\n\n")?;
+	let block = buffer[..end].to_vec();
+	buffer.drain(..end + 2);
+	Some(block)
+}
+
+/// Returns the concatenated `data` fields of a server-sent event, if any.
+fn event_data(block: &[u8]) -> Option<Vec<u8>> {
+	let mut data: Option<Vec<u8>> = None;
+	for line in block.split(|b| *b == b'\n') {
+		if let Some(value) = line.strip_prefix(b"data:") {
+			let value = value.strip_prefix(b" ").unwrap_or(value);
+			match data.as_mut() {
+				Some(data) => {
+					data.push(b'\n');
+					data.extend_from_slice(value);
+				},
+				None => data = Some(value.to_vec()),
+			}
+		}
+	}
+	data
+}
+
+fn decode_event(data: &[u8]) -> Result<EventEnvelope, LdkServerError> {
+	let bytes = base64::engine::general_purpose::STANDARD.decode(data).map_err(|e| {
+		LdkServerError::new(InternalError, format!("Failed to decode event data: {}", e))
+	})?;
+	EventEnvelope::decode(&bytes[..])
+		.map_err(|e| LdkServerError::new(InternalError, format!("Failed to decode event: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+	use ldk_server_protos::events::{event_envelope, PaymentFailed};
+	use ldk_server_protos::types::Payment;
+
+	use super::*;
+
+	#[test]
+	fn test_parse_event_stream() {
+		let event = EventEnvelope {
+			event: Some(event_envelope::Event::PaymentFailed(PaymentFailed {
+				payment: Some(Payment { id: "payment_id".to_string(), ..Default::default() }),
+			})),
+			sequence_number: 1,
+			..Default::default()
+		};
+		let data = base64::engine::general_purpose::STANDARD.encode(event.encode_to_vec());
+
+		let mut buffer =
+			format!(": keep-alive\n\nevent: PaymentFailed\ndata: {data}\n\nevent: Paym")
+				.into_bytes();
+
+		let keep_alive = take_event_block(&mut buffer).unwrap();
+		assert_eq!(event_data(&keep_alive), None);
+
+		let block = take_event_block(&mut buffer).unwrap();
+		assert_eq!(decode_event(&event_data(&block).unwrap()).unwrap(), event);
+
+		// Incomplete events stay buffered until the rest is received.
+		assert_eq!(take_event_block(&mut buffer), None);
+		assert_eq!(buffer, b"event: Paym");
+	}
+}
@@ -19,5 +19,8 @@ pub mod client;
 /// Implements the error type ([`error::LdkServerError`]) returned on interacting with [`client::LdkServerClient`]
 pub mod error;
 
+/// Implements the stream of events ([`event_stream::EventStream`]) returned by [`client::LdkServerClient::subscribe_events`].
+pub mod event_stream;
+
 /// Request/Response structs required for interacting with the ldk-ldk-server-client.
 pub use ldk_server_protos;
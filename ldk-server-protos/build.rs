@@ -34,41 +34,13 @@ fn main() {
 
 #[cfg(genproto)]
 fn generate_protos() {
+	let out_dir = env::var("OUT_DIR").unwrap();
+	println!("OUT_DIR: {}", &out_dir);
+	let descriptor_path = Path::new(&out_dir).join("proto_descriptor.bin");
+
 	prost_build::Config::new()
 		.bytes(&["."])
-		.type_attribute(
-			".",
-			"#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]",
-		)
-		.type_attribute(".", "#[cfg_attr(feature = \"serde\", serde(rename_all = \"snake_case\"))]")
-		.field_attribute(
-			"types.Bolt11.secret",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_opt_bytes_hex\"))]",
-		)
-		.field_attribute(
-			"types.Bolt11Jit.secret",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_opt_bytes_hex\"))]",
-		)
-		.field_attribute(
-			"types.Bolt12Offer.secret",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_opt_bytes_hex\"))]",
-		)
-		.field_attribute(
-			"types.Bolt12Refund.secret",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_opt_bytes_hex\"))]",
-		)
-		.field_attribute(
-			"types.Payment.direction",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_payment_direction\"))]",
-		)
-		.field_attribute(
-			"types.Payment.status",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_payment_status\"))]",
-		)
-		.field_attribute(
-			"types.ClaimableAwaitingConfirmations.source",
-			"#[cfg_attr(feature = \"serde\", serde(serialize_with = \"crate::serde_utils::serialize_balance_source\"))]",
-		)
+		.file_descriptor_set_path(&descriptor_path)
 		.compile_protos(
 			&[
 				"src/proto/api.proto",
@@ -79,13 +51,24 @@ fn generate_protos() {
 			&["src/proto/"],
 		)
 		.expect("protobuf compilation failed");
-	let out_dir = env::var("OUT_DIR").unwrap();
-	println!("OUT_DIR: {}", &out_dir);
-	for file in &["api.rs", "types.rs", "events.rs", "error.rs"] {
-		let from_path = Path::new(&out_dir).join(file);
-		let content = fs::read(&from_path).unwrap();
-		let mut dest = fs::File::create(Path::new("src").join(file)).unwrap();
-		dest.write_all(COPYRIGHT_HEADER.as_bytes()).unwrap();
-		dest.write_all(&content).unwrap();
+
+	// Implements the canonical proto3 JSON mapping for all messages, used with the `serde` feature.
+	// Fields with default values are emitted as well, rather than omitted.
+	let descriptor_set = fs::read(&descriptor_path).unwrap();
+	pbjson_build::Builder::new()
+		.register_descriptors(&descriptor_set)
+		.unwrap()
+		.emit_fields()
+		.build(&[".api", ".types", ".events", ".error"])
+		.expect("JSON mapping generation failed");
+
+	for package in &["api", "types", "events", "error"] {
+		for file in &[format!("{package}.rs"), format!("{package}.serde.rs")] {
+			let from_path = Path::new(&out_dir).join(file);
+			let content = fs::read(&from_path).unwrap();
+			let mut dest = fs::File::create(Path::new("src").join(file)).unwrap();
+			dest.write_all(COPYRIGHT_HEADER.as_bytes()).unwrap();
+			dest.write_all(&content).unwrap();
+		}
 	}
 }
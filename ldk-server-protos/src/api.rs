@@ -11,15 +11,11 @@
 /// See more:
 /// - <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.node_id>
 /// - <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.status>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetNodeInfoRequest {}
 /// The response `content` for the `GetNodeInfo` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetNodeInfoResponse {
@@ -83,15 +79,11 @@ pub struct GetNodeInfoResponse {
 }
 /// Retrieve a new on-chain funding address.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.OnchainPayment.html#method.new_address>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OnchainReceiveRequest {}
 /// The response `content` for the `OnchainReceive` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`..
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OnchainReceiveResponse {
@@ -100,8 +92,6 @@ pub struct OnchainReceiveResponse {
 	pub address: ::prost::alloc::string::String,
 }
 /// Send an on-chain payment to the given address.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OnchainSendRequest {
@@ -127,17 +117,23 @@ pub struct OnchainSendRequest {
 	/// a reasonable estimate from BitcoinD.
 	#[prost(uint64, optional, tag = "4")]
 	pub fee_rate_sat_per_vb: ::core::option::Option<u64>,
+	/// Metadata to attach to the payment, returned along with it by `GetPaymentDetails` and
+	/// `ListPayments`.
+	#[prost(message, optional, tag = "5")]
+	pub metadata: ::core::option::Option<super::types::PaymentMetadata>,
 }
 /// The response `content` for the `OnchainSend` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OnchainSendResponse {
 	/// The transaction ID of the broadcasted transaction.
 	#[prost(string, tag = "1")]
 	pub txid: ::prost::alloc::string::String,
+	/// If set, the payment exceeds the server's approval threshold and is held until approved via the
+	/// `ApprovePayment` API, in which case `txid` is empty.
+	#[prost(string, optional, tag = "2")]
+	pub pending_approval_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Return a BOLT11 payable invoice that can be used to request and receive a payment
 /// for the given amount, if specified.
@@ -145,8 +141,6 @@ pub struct OnchainSendResponse {
 /// See more:
 /// - <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt11Payment.html#method.receive>
 /// - <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt11Payment.html#method.receive_variable_amount>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11ReceiveRequest {
@@ -160,11 +154,13 @@ pub struct Bolt11ReceiveRequest {
 	/// Invoice expiry time in seconds.
 	#[prost(uint32, tag = "3")]
 	pub expiry_secs: u32,
+	/// Metadata to attach to the payment received via the invoice, returned along with it by
+	/// `GetPaymentDetails` and `ListPayments`.
+	#[prost(message, optional, tag = "4")]
+	pub metadata: ::core::option::Option<super::types::PaymentMetadata>,
 }
 /// The response `content` for the `Bolt11Receive` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11ReceiveResponse {
@@ -176,8 +172,6 @@ pub struct Bolt11ReceiveResponse {
 }
 /// Send a payment for a BOLT11 invoice.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt11Payment.html#method.send>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11SendRequest {
@@ -192,25 +186,29 @@ pub struct Bolt11SendRequest {
 	/// Configuration options for payment routing and pathfinding.
 	#[prost(message, optional, tag = "3")]
 	pub route_parameters: ::core::option::Option<super::types::RouteParametersConfig>,
+	/// Metadata to attach to the payment, returned along with it by `GetPaymentDetails` and
+	/// `ListPayments`.
+	#[prost(message, optional, tag = "4")]
+	pub metadata: ::core::option::Option<super::types::PaymentMetadata>,
 }
 /// The response `content` for the `Bolt11Send` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11SendResponse {
 	/// An identifier used to uniquely identify a payment in hex-encoded form.
 	#[prost(string, tag = "1")]
 	pub payment_id: ::prost::alloc::string::String,
+	/// If set, the payment exceeds the server's approval threshold and is held until approved via the
+	/// `ApprovePayment` API, in which case `payment_id` is empty.
+	#[prost(string, optional, tag = "2")]
+	pub pending_approval_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Returns a BOLT12 offer for the given amount, if specified.
 ///
 /// See more:
 /// - <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt12Payment.html#method.receive>
 /// - <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt12Payment.html#method.receive_variable_amount>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt12ReceiveRequest {
@@ -227,11 +225,13 @@ pub struct Bolt12ReceiveRequest {
 	/// If set, it represents the number of items requested, can only be set for fixed-amount offers.
 	#[prost(uint64, optional, tag = "4")]
 	pub quantity: ::core::option::Option<u64>,
+	/// Metadata to attach to the payments received via the offer, returned along with them by
+	/// `GetPaymentDetails` and `ListPayments`.
+	#[prost(message, optional, tag = "5")]
+	pub metadata: ::core::option::Option<super::types::PaymentMetadata>,
 }
 /// The response `content` for the `Bolt12Receive` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt12ReceiveResponse {
@@ -245,8 +245,6 @@ pub struct Bolt12ReceiveResponse {
 /// See more:
 /// - <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt12Payment.html#method.send>
 /// - <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.Bolt12Payment.html#method.send_using_amount>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt12SendRequest {
@@ -267,22 +265,26 @@ pub struct Bolt12SendRequest {
 	/// Configuration options for payment routing and pathfinding.
 	#[prost(message, optional, tag = "5")]
 	pub route_parameters: ::core::option::Option<super::types::RouteParametersConfig>,
+	/// Metadata to attach to the payment, returned along with it by `GetPaymentDetails` and
+	/// `ListPayments`.
+	#[prost(message, optional, tag = "6")]
+	pub metadata: ::core::option::Option<super::types::PaymentMetadata>,
 }
 /// The response `content` for the `Bolt12Send` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt12SendResponse {
 	/// An identifier used to uniquely identify a payment in hex-encoded form.
 	#[prost(string, tag = "1")]
 	pub payment_id: ::prost::alloc::string::String,
+	/// If set, the payment exceeds the server's approval threshold and is held until approved via the
+	/// `ApprovePayment` API, in which case `payment_id` is empty.
+	#[prost(string, optional, tag = "2")]
+	pub pending_approval_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Send a spontaneous payment, also known as "keysend", to a node.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.SpontaneousPayment.html#method.send>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpontaneousSendRequest {
@@ -295,22 +297,26 @@ pub struct SpontaneousSendRequest {
 	/// Configuration options for payment routing and pathfinding.
 	#[prost(message, optional, tag = "3")]
 	pub route_parameters: ::core::option::Option<super::types::RouteParametersConfig>,
+	/// Metadata to attach to the payment, returned along with it by `GetPaymentDetails` and
+	/// `ListPayments`.
+	#[prost(message, optional, tag = "4")]
+	pub metadata: ::core::option::Option<super::types::PaymentMetadata>,
 }
 /// The response `content` for the `SpontaneousSend` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpontaneousSendResponse {
 	/// An identifier used to uniquely identify a payment in hex-encoded form.
 	#[prost(string, tag = "1")]
 	pub payment_id: ::prost::alloc::string::String,
+	/// If set, the payment exceeds the server's approval threshold and is held until approved via the
+	/// `ApprovePayment` API, in which case `payment_id` is empty.
+	#[prost(string, optional, tag = "2")]
+	pub pending_approval_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// Creates a new outbound channel to the given remote node.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.connect_open_channel>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OpenChannelRequest {
@@ -336,8 +342,6 @@ pub struct OpenChannelRequest {
 }
 /// The response `content` for the `OpenChannel` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OpenChannelResponse {
@@ -347,8 +351,6 @@ pub struct OpenChannelResponse {
 }
 /// Increases the channel balance by the given amount.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.splice_in>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpliceInRequest {
@@ -364,15 +366,11 @@ pub struct SpliceInRequest {
 }
 /// The response `content` for the `SpliceIn` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpliceInResponse {}
 /// Decreases the channel balance by the given amount.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.splice_out>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpliceOutRequest {
@@ -393,8 +391,6 @@ pub struct SpliceOutRequest {
 }
 /// The response `content` for the `SpliceOut` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpliceOutResponse {
@@ -404,8 +400,6 @@ pub struct SpliceOutResponse {
 }
 /// Update the config for a previously opened channel.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.update_channel_config>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateChannelConfigRequest {
@@ -421,15 +415,11 @@ pub struct UpdateChannelConfigRequest {
 }
 /// The response `content` for the `UpdateChannelConfig` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateChannelConfigResponse {}
 /// Closes the channel specified by given request.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.close_channel>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CloseChannelRequest {
@@ -442,15 +432,11 @@ pub struct CloseChannelRequest {
 }
 /// The response `content` for the `CloseChannel` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CloseChannelResponse {}
 /// Force closes the channel specified by given request.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.force_close_channel>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ForceCloseChannelRequest {
@@ -466,22 +452,16 @@ pub struct ForceCloseChannelRequest {
 }
 /// The response `content` for the `ForceCloseChannel` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ForceCloseChannelResponse {}
 /// Returns a list of known channels.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.list_channels>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListChannelsRequest {}
 /// The response `content` for the `ListChannels` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListChannelsResponse {
@@ -491,8 +471,6 @@ pub struct ListChannelsResponse {
 }
 /// Returns payment details for a given payment_id.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.payment>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetPaymentDetailsRequest {
@@ -502,8 +480,6 @@ pub struct GetPaymentDetailsRequest {
 }
 /// The response `content` for the `GetPaymentDetails` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetPaymentDetailsResponse {
@@ -514,8 +490,6 @@ pub struct GetPaymentDetailsResponse {
 }
 /// Retrieves list of all payments.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.list_payments>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListPaymentsRequest {
@@ -527,11 +501,34 @@ pub struct ListPaymentsRequest {
 	/// page's response.
 	#[prost(message, optional, tag = "1")]
 	pub page_token: ::core::option::Option<super::types::PageToken>,
+	/// If set, only payments with the given status are returned.
+	#[prost(enumeration = "super::types::PaymentStatus", optional, tag = "2")]
+	pub status: ::core::option::Option<i32>,
+	/// If set, only payments in the given direction are returned.
+	#[prost(enumeration = "super::types::PaymentDirection", optional, tag = "3")]
+	pub direction: ::core::option::Option<i32>,
+	/// If set, only payments of the given kind are returned.
+	#[prost(enumeration = "super::types::PaymentKindType", optional, tag = "4")]
+	pub kind: ::core::option::Option<i32>,
+	/// If set, only payments created at or after this timestamp, in seconds since start of the UNIX
+	/// epoch, are returned.
+	#[prost(uint64, optional, tag = "5")]
+	pub min_creation_timestamp: ::core::option::Option<u64>,
+	/// If set, only payments created at or before this timestamp, in seconds since start of the UNIX
+	/// epoch, are returned.
+	#[prost(uint64, optional, tag = "6")]
+	pub max_creation_timestamp: ::core::option::Option<u64>,
+	/// If set, only payments whose `latest_update_timestamp` is at or after this timestamp are
+	/// returned.
+	#[prost(uint64, optional, tag = "7")]
+	pub min_latest_update_timestamp: ::core::option::Option<u64>,
+	/// If set, only payments whose `latest_update_timestamp` is at or before this timestamp are
+	/// returned.
+	#[prost(uint64, optional, tag = "8")]
+	pub max_latest_update_timestamp: ::core::option::Option<u64>,
 }
 /// The response `content` for the `ListPayments` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListPaymentsResponse {
@@ -554,10 +551,91 @@ pub struct ListPaymentsResponse {
 	#[prost(message, optional, tag = "2")]
 	pub next_page_token: ::core::option::Option<super::types::PageToken>,
 }
+/// Retrieves the payments updated since the given time, allowing to sync payments incrementally.
+///
+/// Payments are ordered by when LDK Server persisted their latest update, oldest first, which may
+/// differ from the order of their `latest_update_timestamp`. A payment is returned again once it is
+/// updated after it was returned, so resuming from a `next_page_token` never misses an update.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPaymentsUpdatedSinceRequest {
+	/// Only payments whose `latest_update_timestamp` is at or after this timestamp, in seconds since
+	/// start of the UNIX epoch, are returned.
+	#[prost(uint64, tag = "1")]
+	pub updated_since: u64,
+	/// `page_token` is a pagination token.
+	///
+	/// To query for the first page, `page_token` must not be specified.
+	///
+	/// For subsequent pages, or to resume syncing later on, use the value that was returned as
+	/// `next_page_token` in the previous page's response.
+	#[prost(message, optional, tag = "2")]
+	pub page_token: ::core::option::Option<super::types::PageToken>,
+}
+/// The response `content` for the `ListPaymentsUpdatedSince` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPaymentsUpdatedSinceResponse {
+	/// List of payments.
+	#[prost(message, repeated, tag = "1")]
+	pub payments: ::prost::alloc::vec::Vec<super::types::Payment>,
+	/// `next_page_token` is a pagination token, used to retrieve the next page of results.
+	/// Use this value to query for next-page of paginated operation, by specifying
+	/// this value as the `page_token` in the next request.
+	///
+	/// `next_page_token` is only `None` if no payments were last updated at or after `updated_since`
+	/// since the `page_token`, if any. Otherwise, it points past the last payment returned, so that it
+	/// can be persisted to resume syncing payments updated later on.
+	#[prost(message, optional, tag = "2")]
+	pub next_page_token: ::core::option::Option<super::types::PageToken>,
+}
+/// Retrieves list of all payments whose metadata carries the given label.
+///
+/// Payments are ordered by the time their metadata was supplied, in descending order.
+/// Payments are only returned once they were persisted, i.e., once an event about them, such as
+/// `PaymentSuccessful`, was handled.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPaymentsByLabelRequest {
+	/// The label to look up payments by.
+	#[prost(string, tag = "1")]
+	pub label: ::prost::alloc::string::String,
+	/// `page_token` is a pagination token.
+	///
+	/// To query for the first page, `page_token` must not be specified.
+	///
+	/// For subsequent pages, use the value that was returned as `next_page_token` in the previous
+	/// page's response.
+	#[prost(message, optional, tag = "2")]
+	pub page_token: ::core::option::Option<super::types::PageToken>,
+}
+/// The response `content` for the `ListPaymentsByLabel` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPaymentsByLabelResponse {
+	/// List of payments.
+	#[prost(message, repeated, tag = "1")]
+	pub payments: ::prost::alloc::vec::Vec<super::types::Payment>,
+	/// `next_page_token` is a pagination token, used to retrieve the next page of results.
+	/// Use this value to query for next-page of paginated operation, by specifying
+	/// this value as the `page_token` in the next request.
+	///
+	/// If `next_page_token` is `None`, then the "last page" of results has been processed and
+	/// there is no more data to be retrieved.
+	///
+	/// If `next_page_token` is not `None`, it does not necessarily mean that there is more data in the
+	/// result set. The only way to know when you have reached the end of the result set is when
+	/// `next_page_token` is `None`.
+	///
+	/// **Caution**: Clients must not assume a specific number of records to be present in a page for
+	/// paginated response.
+	#[prost(message, optional, tag = "2")]
+	pub next_page_token: ::core::option::Option<super::types::PageToken>,
+}
 /// Retrieves list of all forwarded payments.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.Event.html#variant.PaymentForwarded>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListForwardedPaymentsRequest {
@@ -569,11 +647,29 @@ pub struct ListForwardedPaymentsRequest {
 	/// page's response.
 	#[prost(message, optional, tag = "1")]
 	pub page_token: ::core::option::Option<super::types::PageToken>,
+	/// If set, only payments forwarded from the channel with this channel id are returned.
+	#[prost(string, optional, tag = "2")]
+	pub prev_channel_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded to the channel with this channel id are returned.
+	#[prost(string, optional, tag = "3")]
+	pub next_channel_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded from the node with this node id are returned.
+	#[prost(string, optional, tag = "4")]
+	pub prev_node_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded to the node with this node id are returned.
+	#[prost(string, optional, tag = "5")]
+	pub next_node_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded at or after this timestamp, in seconds since start of the UNIX
+	/// epoch, are returned.
+	#[prost(uint64, optional, tag = "6")]
+	pub min_timestamp: ::core::option::Option<u64>,
+	/// If set, only payments forwarded at or before this timestamp, in seconds since start of the
+	/// UNIX epoch, are returned.
+	#[prost(uint64, optional, tag = "7")]
+	pub max_timestamp: ::core::option::Option<u64>,
 }
 /// The response `content` for the `ListForwardedPayments` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListForwardedPaymentsResponse {
@@ -596,10 +692,63 @@ pub struct ListForwardedPaymentsResponse {
 	#[prost(message, optional, tag = "2")]
 	pub next_page_token: ::core::option::Option<super::types::PageToken>,
 }
+/// Aggregates the forwarded payments, returning the number of payments forwarded, the amount
+/// forwarded and the fees earned, in total as well as per channel pair and per day.
+///
+/// Only payments matching all of the given filters are taken into account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetForwardingStatsRequest {
+	/// If set, only payments forwarded from the channel with this channel id are taken into account.
+	#[prost(string, optional, tag = "1")]
+	pub prev_channel_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded to the channel with this channel id are taken into account.
+	#[prost(string, optional, tag = "2")]
+	pub next_channel_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded from the node with this node id are taken into account.
+	#[prost(string, optional, tag = "3")]
+	pub prev_node_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// If set, only payments forwarded to the node with this node id are taken into account.
+	#[prost(string, optional, tag = "4")]
+	pub next_node_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// Only payments forwarded at or after this timestamp, in seconds since start of the UNIX epoch,
+	/// are taken into account. Defaults to 90 days before `max_timestamp`.
+	///
+	/// Requests for more than 90 days of forwarded payments are rejected.
+	#[prost(uint64, optional, tag = "5")]
+	pub min_timestamp: ::core::option::Option<u64>,
+	/// Only payments forwarded at or before this timestamp, in seconds since start of the UNIX epoch,
+	/// are taken into account. Defaults to the current time.
+	#[prost(uint64, optional, tag = "6")]
+	pub max_timestamp: ::core::option::Option<u64>,
+}
+/// The response `content` for the `GetForwardingStats` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetForwardingStatsResponse {
+	/// The total number of payments forwarded.
+	#[prost(uint64, tag = "1")]
+	pub forwarded_payments_count: u64,
+	/// The total amount forwarded, in milli-satoshis, after fees are deducted.
+	#[prost(uint64, tag = "2")]
+	pub outbound_amount_forwarded_msat: u64,
+	/// The total fees earned, in milli-satoshis.
+	///
+	/// Payments for which the fee earned is unknown, see `ForwardedPayment.total_fee_earned_msat`,
+	/// are counted with a fee of zero.
+	#[prost(uint64, tag = "3")]
+	pub total_fee_earned_msat: u64,
+	/// The stats per pair of channels payments were forwarded between, sorted by the fees earned in
+	/// descending order. Only the 100 channel pairs with the highest fees earned are returned.
+	#[prost(message, repeated, tag = "4")]
+	pub channel_pairs: ::prost::alloc::vec::Vec<super::types::ChannelPairForwardingStats>,
+	/// The stats per day, sorted in ascending order. Days without forwarded payments are omitted.
+	#[prost(message, repeated, tag = "5")]
+	pub days: ::prost::alloc::vec::Vec<super::types::DailyForwardingStats>,
+}
 /// Sign a message with the node's secret key.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.sign_message>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignMessageRequest {
@@ -609,8 +758,6 @@ pub struct SignMessageRequest {
 }
 /// The response `content` for the `SignMessage` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SignMessageResponse {
@@ -620,8 +767,6 @@ pub struct SignMessageResponse {
 }
 /// Verify a signature against a message and public key.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.verify_signature>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VerifySignatureRequest {
@@ -637,8 +782,6 @@ pub struct VerifySignatureRequest {
 }
 /// The response `content` for the `VerifySignature` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct VerifySignatureResponse {
@@ -648,15 +791,11 @@ pub struct VerifySignatureResponse {
 }
 /// Export the pathfinding scores used by the router.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.export_pathfinding_scores>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExportPathfindingScoresRequest {}
 /// The response `content` for the `ExportPathfindingScores` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExportPathfindingScoresResponse {
@@ -666,15 +805,11 @@ pub struct ExportPathfindingScoresResponse {
 }
 /// Retrieves an overview of all known balances.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.list_balances>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBalancesRequest {}
 /// The response `content` for the `GetBalances` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetBalancesResponse {
@@ -719,8 +854,6 @@ pub struct GetBalancesResponse {
 }
 /// Connect to a peer on the Lightning Network.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.connect>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConnectPeerRequest {
@@ -738,15 +871,11 @@ pub struct ConnectPeerRequest {
 }
 /// The response `content` for the `ConnectPeer` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConnectPeerResponse {}
 /// Disconnect from a peer and remove it from the peer store.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/struct.Node.html#method.disconnect>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DisconnectPeerRequest {
@@ -756,22 +885,16 @@ pub struct DisconnectPeerRequest {
 }
 /// The response `content` for the `DisconnectPeer` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DisconnectPeerResponse {}
 /// Returns a list of all known short channel IDs in the network graph.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/graph/struct.NetworkGraph.html#method.list_channels>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphListChannelsRequest {}
 /// The response `content` for the `GraphListChannels` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphListChannelsResponse {
@@ -781,8 +904,6 @@ pub struct GraphListChannelsResponse {
 }
 /// Returns information on a channel with the given short channel ID from the network graph.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/graph/struct.NetworkGraph.html#method.channel>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphGetChannelRequest {
@@ -792,8 +913,6 @@ pub struct GraphGetChannelRequest {
 }
 /// The response `content` for the `GraphGetChannel` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphGetChannelResponse {
@@ -803,15 +922,11 @@ pub struct GraphGetChannelResponse {
 }
 /// Returns a list of all known node IDs in the network graph.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/graph/struct.NetworkGraph.html#method.list_nodes>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphListNodesRequest {}
 /// The response `content` for the `GraphListNodes` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphListNodesResponse {
@@ -821,8 +936,6 @@ pub struct GraphListNodesResponse {
 }
 /// Returns information on a node with the given ID from the network graph.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/graph/struct.NetworkGraph.html#method.node>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphGetNodeRequest {
@@ -832,8 +945,6 @@ pub struct GraphGetNodeRequest {
 }
 /// The response `content` for the `GraphGetNode` API, when HttpStatusCode is OK (200).
 /// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphGetNodeResponse {
@@ -841,3 +952,240 @@ pub struct GraphGetNodeResponse {
 	#[prost(message, optional, tag = "1")]
 	pub node: ::core::option::Option<super::types::GraphNode>,
 }
+/// Creates a new API key with the given permissions. The key is persisted and accepted immediately,
+/// without requiring a restart. Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateApiKeyRequest {
+	/// The unique name of the API key. Must only contain alphanumeric characters, '-' or '_'.
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+	/// The permissions granted to the API key. Must not be empty.
+	#[prost(enumeration = "super::types::ApiKeyPermission", repeated, tag = "2")]
+	pub permissions: ::prost::alloc::vec::Vec<i32>,
+	/// The maximum amount of a single payment sent using this API key.
+	/// If unset, the amount of payments is not limited.
+	#[prost(uint64, optional, tag = "3")]
+	pub max_send_amount_msat: ::core::option::Option<u64>,
+	/// If set, the API key is only accepted on connections authenticated with a client certificate
+	/// whose subject common name matches this identity. Requires the server to be configured with
+	/// `tls.client_ca_path`.
+	#[prost(string, optional, tag = "4")]
+	pub client_identity: ::core::option::Option<::prost::alloc::string::String>,
+	/// The maximum total amount of all payments sent using this API key within the last 24 hours.
+	/// If unset, only the server-wide daily budget applies.
+	#[prost(uint64, optional, tag = "5")]
+	pub daily_budget_msat: ::core::option::Option<u64>,
+}
+/// The response `content` for the `CreateApiKey` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateApiKeyResponse {
+	/// The hex-encoded secret of the new API key.
+	/// It is only returned once and can't be retrieved afterwards.
+	#[prost(string, tag = "1")]
+	pub api_key: ::prost::alloc::string::String,
+}
+/// Lists all API keys known to the server, including revoked ones. Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListApiKeysRequest {}
+/// The response `content` for the `ListApiKeys` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListApiKeysResponse {
+	/// List of API keys.
+	#[prost(message, repeated, tag = "1")]
+	pub api_keys: ::prost::alloc::vec::Vec<super::types::ApiKeyInfo>,
+}
+/// Revokes the API key with the given name, rejecting any further requests authenticated with it.
+/// API keys defined by the server's configuration can't be revoked. Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeApiKeyRequest {
+	/// The name of the API key to revoke.
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+}
+/// The response `content` for the `RevokeApiKey` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevokeApiKeyResponse {}
+/// Replaces the secret of the API key with the given name by a newly generated one.
+/// The previous secret remains valid for a grace period, allowing clients to switch over without
+/// downtime. Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateApiKeyRequest {
+	/// The name of the API key to rotate.
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+	/// The number of seconds the previous secret remains valid for. Defaults to 3600 if unset.
+	/// Set to 0 to reject the previous secret immediately.
+	#[prost(uint64, optional, tag = "2")]
+	pub grace_period_secs: ::core::option::Option<u64>,
+}
+/// The response `content` for the `RotateApiKey` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RotateApiKeyResponse {
+	/// The hex-encoded new secret of the API key.
+	#[prost(string, tag = "1")]
+	pub api_key: ::prost::alloc::string::String,
+}
+/// Lists all outgoing payments held until approved, because they exceed the server's approval
+/// threshold. Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPendingApprovalsRequest {}
+/// The response `content` for the `ListPendingApprovals` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPendingApprovalsResponse {
+	/// List of payments pending approval, oldest first.
+	#[prost(message, repeated, tag = "1")]
+	pub pending_approvals: ::prost::alloc::vec::Vec<super::types::PendingApproval>,
+}
+/// Approves and sends a payment held for approval.
+/// The payment must be approved using a different API key than the one it was submitted with and,
+/// if both keys are bound to a client certificate, a different client identity.
+/// Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApprovePaymentRequest {
+	/// The id of the pending approval.
+	#[prost(string, tag = "1")]
+	pub id: ::prost::alloc::string::String,
+}
+/// The response `content` for the `ApprovePayment` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApprovePaymentResponse {
+	/// The hex-encoded identifier of the sent payment, or the transaction ID for on-chain payments.
+	#[prost(string, tag = "1")]
+	pub payment_id: ::prost::alloc::string::String,
+}
+/// Rejects a payment held for approval, such that it is never sent. Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectPaymentRequest {
+	/// The id of the pending approval.
+	#[prost(string, tag = "1")]
+	pub id: ::prost::alloc::string::String,
+}
+/// The response `content` for the `RejectPayment` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectPaymentResponse {}
+/// Retrieves the audit log of state-changing API calls, newest first.
+/// Requires the `ADMIN` permission.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAuditLogRequest {
+	/// `page_token` is a pagination token.
+	///
+	/// To query for the first page, `page_token` must not be specified.
+	///
+	/// For subsequent pages, use the value that was returned as `next_page_token` in the previous
+	/// page's response.
+	#[prost(message, optional, tag = "1")]
+	pub page_token: ::core::option::Option<super::types::PageToken>,
+}
+/// The response `content` for the `ListAuditLog` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAuditLogResponse {
+	/// List of audit log entries.
+	#[prost(message, repeated, tag = "1")]
+	pub entries: ::prost::alloc::vec::Vec<super::types::AuditLogEntry>,
+	/// `next_page_token` is a pagination token, used to retrieve the next page of results.
+	/// Use this value to query for next-page of paginated operation, by specifying
+	/// this value as the `page_token` in the next request.
+	///
+	/// If `next_page_token` is `None`, then the "last page" of results has been processed and
+	/// there is no more data to be retrieved.
+	///
+	/// If `next_page_token` is not `None`, it does not necessarily mean that there is more data in the
+	/// result set. The only way to know when you have reached the end of the result set is when
+	/// `next_page_token` is `None`.
+	///
+	/// **Caution**: Clients must not assume a specific number of records to be present in a page for
+	/// paginated response.
+	#[prost(message, optional, tag = "2")]
+	pub next_page_token: ::core::option::Option<super::types::PageToken>,
+}
+/// Retrieves the history of events emitted by the server, e.g. `PaymentReceived`, in the order of
+/// their `sequence_number`, oldest first.
+/// Requires the `READ` permission.
+///
+/// Consumers which missed events, e.g., because they were offline, may use this to catch up on all
+/// events following the last one they processed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEventsRequest {
+	/// `page_token` is a pagination token.
+	///
+	/// To query for the first page, `page_token` must not be specified.
+	///
+	/// For subsequent pages, use the value that was returned as `next_page_token` in the previous
+	/// page's response.
+	#[prost(message, optional, tag = "1")]
+	pub page_token: ::core::option::Option<super::types::PageToken>,
+	/// If set, only events with a `sequence_number` greater than `since_sequence_number` are returned.
+	///
+	/// Only applies to the first page, i.e., is ignored if `page_token` is set.
+	#[prost(uint64, optional, tag = "2")]
+	pub since_sequence_number: ::core::option::Option<u64>,
+	/// If non-empty, only events of the given types are returned. Event types are named after the
+	/// variant of the `events.EventEnvelope` they carry, e.g. `PaymentReceived` or `ChannelClosed`.
+	/// Requests naming an unknown event type are rejected.
+	#[prost(string, repeated, tag = "3")]
+	pub event_types: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// The response `content` for the `ListEvents` API, when HttpStatusCode is OK (200).
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListEventsResponse {
+	/// List of events.
+	#[prost(message, repeated, tag = "1")]
+	pub events: ::prost::alloc::vec::Vec<super::events::EventEnvelope>,
+	/// `next_page_token` is a pagination token, used to retrieve the next page of results.
+	/// Use this value to query for next-page of paginated operation, by specifying
+	/// this value as the `page_token` in the next request.
+	///
+	/// If `next_page_token` is `None`, then the "last page" of results has been processed and
+	/// there is no more data to be retrieved.
+	///
+	/// If `next_page_token` is not `None`, it does not necessarily mean that there is more data in the
+	/// result set. The only way to know when you have reached the end of the result set is when
+	/// `next_page_token` is `None`.
+	///
+	/// **Caution**: Clients must not assume a specific number of records to be present in a page for
+	/// paginated response.
+	#[prost(message, optional, tag = "2")]
+	pub next_page_token: ::core::option::Option<super::types::PageToken>,
+}
+/// Subscribes to the events published by the server, e.g. `PaymentReceived`, as they occur.
+/// Requires the `READ` permission.
+///
+/// Unlike other APIs, the response is not a single message but a stream of server-sent events
+/// (`Content-Type: text/event-stream`), which stays open until either side closes the connection.
+/// Each event is named after the variant of the `events.EventEnvelope` it carries, and its `data`
+/// is the base64-encoded serialized `EventEnvelope`, or its JSON encoding for requests sent with
+/// `Content-Type: application/json`.
+///
+/// Only events published while subscribed are delivered. Subscribers that fall too far behind are
+/// disconnected and should catch up using the `ListEvents` API.
+/// When HttpStatusCode is not OK (non-200), the response `content` contains a serialized `ErrorResponse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeEventsRequest {}
@@ -0,0 +1,8937 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+impl serde::Serialize for ApprovePaymentRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.ApprovePaymentRequest", len)?;
+		struct_ser.serialize_field("id", &self.id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ApprovePaymentRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["id"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Id,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"id" => Ok(GeneratedField::Id),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ApprovePaymentRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ApprovePaymentRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ApprovePaymentRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Id => {
+							if id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("id"));
+							}
+							id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ApprovePaymentRequest { id: id__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.ApprovePaymentRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ApprovePaymentResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.ApprovePaymentResponse", len)?;
+		struct_ser.serialize_field("paymentId", &self.payment_id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ApprovePaymentResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payment_id", "paymentId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PaymentId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"paymentId" | "payment_id" => Ok(GeneratedField::PaymentId),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ApprovePaymentResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ApprovePaymentResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ApprovePaymentResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PaymentId => {
+							if payment_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentId"));
+							}
+							payment_id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ApprovePaymentResponse { payment_id: payment_id__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.ApprovePaymentResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11ReceiveRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.amount_msat.is_some() {
+			len += 1;
+		}
+		if self.description.is_some() {
+			len += 1;
+		}
+		len += 1;
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.Bolt11ReceiveRequest", len)?;
+		if let Some(v) = self.amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.description.as_ref() {
+			struct_ser.serialize_field("description", v)?;
+		}
+		struct_ser.serialize_field("expirySecs", &self.expiry_secs)?;
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11ReceiveRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["amount_msat", "amountMsat", "description", "expiry_secs", "expirySecs", "metadata"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			AmountMsat,
+			Description,
+			ExpirySecs,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"description" => Ok(GeneratedField::Description),
+							"expirySecs" | "expiry_secs" => Ok(GeneratedField::ExpirySecs),
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11ReceiveRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt11ReceiveRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<Bolt11ReceiveRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut amount_msat__ = None;
+				let mut description__ = None;
+				let mut expiry_secs__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Description => {
+							if description__.is_some() {
+								return Err(serde::de::Error::duplicate_field("description"));
+							}
+							description__ = map_.next_value()?;
+						},
+						GeneratedField::ExpirySecs => {
+							if expiry_secs__.is_some() {
+								return Err(serde::de::Error::duplicate_field("expirySecs"));
+							}
+							expiry_secs__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Bolt11ReceiveRequest {
+					amount_msat: amount_msat__,
+					description: description__,
+					expiry_secs: expiry_secs__.unwrap_or_default(),
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt11ReceiveRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11ReceiveResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.Bolt11ReceiveResponse", len)?;
+		struct_ser.serialize_field("invoice", &self.invoice)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11ReceiveResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["invoice"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Invoice,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"invoice" => Ok(GeneratedField::Invoice),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11ReceiveResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt11ReceiveResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<Bolt11ReceiveResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut invoice__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Invoice => {
+							if invoice__.is_some() {
+								return Err(serde::de::Error::duplicate_field("invoice"));
+							}
+							invoice__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(Bolt11ReceiveResponse { invoice: invoice__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt11ReceiveResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11SendRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.amount_msat.is_some() {
+			len += 1;
+		}
+		if self.route_parameters.is_some() {
+			len += 1;
+		}
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.Bolt11SendRequest", len)?;
+		struct_ser.serialize_field("invoice", &self.invoice)?;
+		if let Some(v) = self.amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.route_parameters.as_ref() {
+			struct_ser.serialize_field("routeParameters", v)?;
+		}
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11SendRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"invoice",
+			"amount_msat",
+			"amountMsat",
+			"route_parameters",
+			"routeParameters",
+			"metadata",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Invoice,
+			AmountMsat,
+			RouteParameters,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"invoice" => Ok(GeneratedField::Invoice),
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"routeParameters" | "route_parameters" => {
+								Ok(GeneratedField::RouteParameters)
+							},
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11SendRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt11SendRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt11SendRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut invoice__ = None;
+				let mut amount_msat__ = None;
+				let mut route_parameters__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Invoice => {
+							if invoice__.is_some() {
+								return Err(serde::de::Error::duplicate_field("invoice"));
+							}
+							invoice__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::RouteParameters => {
+							if route_parameters__.is_some() {
+								return Err(serde::de::Error::duplicate_field("routeParameters"));
+							}
+							route_parameters__ = map_.next_value()?;
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Bolt11SendRequest {
+					invoice: invoice__.unwrap_or_default(),
+					amount_msat: amount_msat__,
+					route_parameters: route_parameters__,
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt11SendRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11SendResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.pending_approval_id.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.Bolt11SendResponse", len)?;
+		struct_ser.serialize_field("paymentId", &self.payment_id)?;
+		if let Some(v) = self.pending_approval_id.as_ref() {
+			struct_ser.serialize_field("pendingApprovalId", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11SendResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["payment_id", "paymentId", "pending_approval_id", "pendingApprovalId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PaymentId,
+			PendingApprovalId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"paymentId" | "payment_id" => Ok(GeneratedField::PaymentId),
+							"pendingApprovalId" | "pending_approval_id" => {
+								Ok(GeneratedField::PendingApprovalId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11SendResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt11SendResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt11SendResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment_id__ = None;
+				let mut pending_approval_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PaymentId => {
+							if payment_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentId"));
+							}
+							payment_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PendingApprovalId => {
+							if pending_approval_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pendingApprovalId"));
+							}
+							pending_approval_id__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Bolt11SendResponse {
+					payment_id: payment_id__.unwrap_or_default(),
+					pending_approval_id: pending_approval_id__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt11SendResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt12ReceiveRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.amount_msat.is_some() {
+			len += 1;
+		}
+		if self.expiry_secs.is_some() {
+			len += 1;
+		}
+		if self.quantity.is_some() {
+			len += 1;
+		}
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.Bolt12ReceiveRequest", len)?;
+		struct_ser.serialize_field("description", &self.description)?;
+		if let Some(v) = self.amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.expiry_secs.as_ref() {
+			struct_ser.serialize_field("expirySecs", v)?;
+		}
+		if let Some(v) = self.quantity.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("quantity", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt12ReceiveRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"description",
+			"amount_msat",
+			"amountMsat",
+			"expiry_secs",
+			"expirySecs",
+			"quantity",
+			"metadata",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Description,
+			AmountMsat,
+			ExpirySecs,
+			Quantity,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"description" => Ok(GeneratedField::Description),
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"expirySecs" | "expiry_secs" => Ok(GeneratedField::ExpirySecs),
+							"quantity" => Ok(GeneratedField::Quantity),
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt12ReceiveRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt12ReceiveRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<Bolt12ReceiveRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut description__ = None;
+				let mut amount_msat__ = None;
+				let mut expiry_secs__ = None;
+				let mut quantity__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Description => {
+							if description__.is_some() {
+								return Err(serde::de::Error::duplicate_field("description"));
+							}
+							description__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ExpirySecs => {
+							if expiry_secs__.is_some() {
+								return Err(serde::de::Error::duplicate_field("expirySecs"));
+							}
+							expiry_secs__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Quantity => {
+							if quantity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("quantity"));
+							}
+							quantity__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Bolt12ReceiveRequest {
+					description: description__.unwrap_or_default(),
+					amount_msat: amount_msat__,
+					expiry_secs: expiry_secs__,
+					quantity: quantity__,
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt12ReceiveRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt12ReceiveResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.Bolt12ReceiveResponse", len)?;
+		struct_ser.serialize_field("offer", &self.offer)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt12ReceiveResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["offer"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Offer,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"offer" => Ok(GeneratedField::Offer),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt12ReceiveResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt12ReceiveResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<Bolt12ReceiveResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut offer__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Offer => {
+							if offer__.is_some() {
+								return Err(serde::de::Error::duplicate_field("offer"));
+							}
+							offer__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(Bolt12ReceiveResponse { offer: offer__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt12ReceiveResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt12SendRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.amount_msat.is_some() {
+			len += 1;
+		}
+		if self.quantity.is_some() {
+			len += 1;
+		}
+		if self.payer_note.is_some() {
+			len += 1;
+		}
+		if self.route_parameters.is_some() {
+			len += 1;
+		}
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.Bolt12SendRequest", len)?;
+		struct_ser.serialize_field("offer", &self.offer)?;
+		if let Some(v) = self.amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.quantity.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("quantity", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.payer_note.as_ref() {
+			struct_ser.serialize_field("payerNote", v)?;
+		}
+		if let Some(v) = self.route_parameters.as_ref() {
+			struct_ser.serialize_field("routeParameters", v)?;
+		}
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt12SendRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"offer",
+			"amount_msat",
+			"amountMsat",
+			"quantity",
+			"payer_note",
+			"payerNote",
+			"route_parameters",
+			"routeParameters",
+			"metadata",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Offer,
+			AmountMsat,
+			Quantity,
+			PayerNote,
+			RouteParameters,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"offer" => Ok(GeneratedField::Offer),
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"quantity" => Ok(GeneratedField::Quantity),
+							"payerNote" | "payer_note" => Ok(GeneratedField::PayerNote),
+							"routeParameters" | "route_parameters" => {
+								Ok(GeneratedField::RouteParameters)
+							},
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt12SendRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt12SendRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt12SendRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut offer__ = None;
+				let mut amount_msat__ = None;
+				let mut quantity__ = None;
+				let mut payer_note__ = None;
+				let mut route_parameters__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Offer => {
+							if offer__.is_some() {
+								return Err(serde::de::Error::duplicate_field("offer"));
+							}
+							offer__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Quantity => {
+							if quantity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("quantity"));
+							}
+							quantity__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::PayerNote => {
+							if payer_note__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payerNote"));
+							}
+							payer_note__ = map_.next_value()?;
+						},
+						GeneratedField::RouteParameters => {
+							if route_parameters__.is_some() {
+								return Err(serde::de::Error::duplicate_field("routeParameters"));
+							}
+							route_parameters__ = map_.next_value()?;
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Bolt12SendRequest {
+					offer: offer__.unwrap_or_default(),
+					amount_msat: amount_msat__,
+					quantity: quantity__,
+					payer_note: payer_note__,
+					route_parameters: route_parameters__,
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt12SendRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt12SendResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.pending_approval_id.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.Bolt12SendResponse", len)?;
+		struct_ser.serialize_field("paymentId", &self.payment_id)?;
+		if let Some(v) = self.pending_approval_id.as_ref() {
+			struct_ser.serialize_field("pendingApprovalId", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt12SendResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["payment_id", "paymentId", "pending_approval_id", "pendingApprovalId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PaymentId,
+			PendingApprovalId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"paymentId" | "payment_id" => Ok(GeneratedField::PaymentId),
+							"pendingApprovalId" | "pending_approval_id" => {
+								Ok(GeneratedField::PendingApprovalId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt12SendResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.Bolt12SendResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt12SendResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment_id__ = None;
+				let mut pending_approval_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PaymentId => {
+							if payment_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentId"));
+							}
+							payment_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PendingApprovalId => {
+							if pending_approval_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pendingApprovalId"));
+							}
+							pending_approval_id__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Bolt12SendResponse {
+					payment_id: payment_id__.unwrap_or_default(),
+					pending_approval_id: pending_approval_id__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.Bolt12SendResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for CloseChannelRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.CloseChannelRequest", len)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for CloseChannelRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["user_channel_id", "userChannelId", "counterparty_node_id", "counterpartyNodeId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UserChannelId,
+			CounterpartyNodeId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = CloseChannelRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.CloseChannelRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<CloseChannelRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(CloseChannelRequest {
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.CloseChannelRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for CloseChannelResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.CloseChannelResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for CloseChannelResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = CloseChannelResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.CloseChannelResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<CloseChannelResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(CloseChannelResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.CloseChannelResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ConnectPeerRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.ConnectPeerRequest", len)?;
+		struct_ser.serialize_field("nodePubkey", &self.node_pubkey)?;
+		struct_ser.serialize_field("address", &self.address)?;
+		struct_ser.serialize_field("persist", &self.persist)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ConnectPeerRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["node_pubkey", "nodePubkey", "address", "persist"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodePubkey,
+			Address,
+			Persist,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodePubkey" | "node_pubkey" => Ok(GeneratedField::NodePubkey),
+							"address" => Ok(GeneratedField::Address),
+							"persist" => Ok(GeneratedField::Persist),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ConnectPeerRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ConnectPeerRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectPeerRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_pubkey__ = None;
+				let mut address__ = None;
+				let mut persist__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodePubkey => {
+							if node_pubkey__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodePubkey"));
+							}
+							node_pubkey__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Address => {
+							if address__.is_some() {
+								return Err(serde::de::Error::duplicate_field("address"));
+							}
+							address__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Persist => {
+							if persist__.is_some() {
+								return Err(serde::de::Error::duplicate_field("persist"));
+							}
+							persist__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ConnectPeerRequest {
+					node_pubkey: node_pubkey__.unwrap_or_default(),
+					address: address__.unwrap_or_default(),
+					persist: persist__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ConnectPeerRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ConnectPeerResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.ConnectPeerResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ConnectPeerResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ConnectPeerResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ConnectPeerResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConnectPeerResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(ConnectPeerResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.ConnectPeerResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for CreateApiKeyRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.max_send_amount_msat.is_some() {
+			len += 1;
+		}
+		if self.client_identity.is_some() {
+			len += 1;
+		}
+		if self.daily_budget_msat.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.CreateApiKeyRequest", len)?;
+		struct_ser.serialize_field("name", &self.name)?;
+		let v = self
+			.permissions
+			.iter()
+			.cloned()
+			.map(|v| {
+				super::types::ApiKeyPermission::from_i32(v)
+					.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+			})
+			.collect::<std::result::Result<Vec<_>, _>>()?;
+		struct_ser.serialize_field("permissions", &v)?;
+		if let Some(v) = self.max_send_amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("maxSendAmountMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.client_identity.as_ref() {
+			struct_ser.serialize_field("clientIdentity", v)?;
+		}
+		if let Some(v) = self.daily_budget_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("dailyBudgetMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for CreateApiKeyRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"name",
+			"permissions",
+			"max_send_amount_msat",
+			"maxSendAmountMsat",
+			"client_identity",
+			"clientIdentity",
+			"daily_budget_msat",
+			"dailyBudgetMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Name,
+			Permissions,
+			MaxSendAmountMsat,
+			ClientIdentity,
+			DailyBudgetMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"name" => Ok(GeneratedField::Name),
+							"permissions" => Ok(GeneratedField::Permissions),
+							"maxSendAmountMsat" | "max_send_amount_msat" => {
+								Ok(GeneratedField::MaxSendAmountMsat)
+							},
+							"clientIdentity" | "client_identity" => {
+								Ok(GeneratedField::ClientIdentity)
+							},
+							"dailyBudgetMsat" | "daily_budget_msat" => {
+								Ok(GeneratedField::DailyBudgetMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = CreateApiKeyRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.CreateApiKeyRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<CreateApiKeyRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut name__ = None;
+				let mut permissions__ = None;
+				let mut max_send_amount_msat__ = None;
+				let mut client_identity__ = None;
+				let mut daily_budget_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Name => {
+							if name__.is_some() {
+								return Err(serde::de::Error::duplicate_field("name"));
+							}
+							name__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Permissions => {
+							if permissions__.is_some() {
+								return Err(serde::de::Error::duplicate_field("permissions"));
+							}
+							permissions__ = Some(
+								map_.next_value::<Vec<super::types::ApiKeyPermission>>()?
+									.into_iter()
+									.map(|x| x as i32)
+									.collect(),
+							);
+						},
+						GeneratedField::MaxSendAmountMsat => {
+							if max_send_amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("maxSendAmountMsat"));
+							}
+							max_send_amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ClientIdentity => {
+							if client_identity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("clientIdentity"));
+							}
+							client_identity__ = map_.next_value()?;
+						},
+						GeneratedField::DailyBudgetMsat => {
+							if daily_budget_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("dailyBudgetMsat"));
+							}
+							daily_budget_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(CreateApiKeyRequest {
+					name: name__.unwrap_or_default(),
+					permissions: permissions__.unwrap_or_default(),
+					max_send_amount_msat: max_send_amount_msat__,
+					client_identity: client_identity__,
+					daily_budget_msat: daily_budget_msat__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.CreateApiKeyRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for CreateApiKeyResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.CreateApiKeyResponse", len)?;
+		struct_ser.serialize_field("apiKey", &self.api_key)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for CreateApiKeyResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["api_key", "apiKey"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ApiKey,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"apiKey" | "api_key" => Ok(GeneratedField::ApiKey),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = CreateApiKeyResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.CreateApiKeyResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<CreateApiKeyResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut api_key__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ApiKey => {
+							if api_key__.is_some() {
+								return Err(serde::de::Error::duplicate_field("apiKey"));
+							}
+							api_key__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(CreateApiKeyResponse { api_key: api_key__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.CreateApiKeyResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for DisconnectPeerRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.DisconnectPeerRequest", len)?;
+		struct_ser.serialize_field("nodePubkey", &self.node_pubkey)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for DisconnectPeerRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["node_pubkey", "nodePubkey"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodePubkey,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodePubkey" | "node_pubkey" => Ok(GeneratedField::NodePubkey),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = DisconnectPeerRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.DisconnectPeerRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<DisconnectPeerRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_pubkey__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodePubkey => {
+							if node_pubkey__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodePubkey"));
+							}
+							node_pubkey__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(DisconnectPeerRequest { node_pubkey: node_pubkey__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.DisconnectPeerRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for DisconnectPeerResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.DisconnectPeerResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for DisconnectPeerResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = DisconnectPeerResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.DisconnectPeerResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<DisconnectPeerResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(DisconnectPeerResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.DisconnectPeerResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ExportPathfindingScoresRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.ExportPathfindingScoresRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ExportPathfindingScoresRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ExportPathfindingScoresRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ExportPathfindingScoresRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ExportPathfindingScoresRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(ExportPathfindingScoresRequest {})
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ExportPathfindingScoresRequest",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ExportPathfindingScoresResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("api.ExportPathfindingScoresResponse", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser
+			.serialize_field("scores", pbjson::private::base64::encode(&self.scores).as_str())?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ExportPathfindingScoresResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["scores"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Scores,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"scores" => Ok(GeneratedField::Scores),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ExportPathfindingScoresResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ExportPathfindingScoresResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ExportPathfindingScoresResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut scores__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Scores => {
+							if scores__.is_some() {
+								return Err(serde::de::Error::duplicate_field("scores"));
+							}
+							scores__ = Some(
+								map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(ExportPathfindingScoresResponse { scores: scores__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ExportPathfindingScoresResponse",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ForceCloseChannelRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.force_close_reason.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ForceCloseChannelRequest", len)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.force_close_reason.as_ref() {
+			struct_ser.serialize_field("forceCloseReason", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ForceCloseChannelRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"force_close_reason",
+			"forceCloseReason",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UserChannelId,
+			CounterpartyNodeId,
+			ForceCloseReason,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"forceCloseReason" | "force_close_reason" => {
+								Ok(GeneratedField::ForceCloseReason)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ForceCloseChannelRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ForceCloseChannelRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ForceCloseChannelRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut force_close_reason__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ForceCloseReason => {
+							if force_close_reason__.is_some() {
+								return Err(serde::de::Error::duplicate_field("forceCloseReason"));
+							}
+							force_close_reason__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ForceCloseChannelRequest {
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					force_close_reason: force_close_reason__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ForceCloseChannelRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ForceCloseChannelResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.ForceCloseChannelResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ForceCloseChannelResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ForceCloseChannelResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ForceCloseChannelResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ForceCloseChannelResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(ForceCloseChannelResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.ForceCloseChannelResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetBalancesRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.GetBalancesRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetBalancesRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetBalancesRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetBalancesRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetBalancesRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(GetBalancesRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.GetBalancesRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetBalancesResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GetBalancesResponse", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"totalOnchainBalanceSats",
+			ToString::to_string(&self.total_onchain_balance_sats).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"spendableOnchainBalanceSats",
+			ToString::to_string(&self.spendable_onchain_balance_sats).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"totalAnchorChannelsReserveSats",
+			ToString::to_string(&self.total_anchor_channels_reserve_sats).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"totalLightningBalanceSats",
+			ToString::to_string(&self.total_lightning_balance_sats).as_str(),
+		)?;
+		struct_ser.serialize_field("lightningBalances", &self.lightning_balances)?;
+		struct_ser.serialize_field(
+			"pendingBalancesFromChannelClosures",
+			&self.pending_balances_from_channel_closures,
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetBalancesResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"total_onchain_balance_sats",
+			"totalOnchainBalanceSats",
+			"spendable_onchain_balance_sats",
+			"spendableOnchainBalanceSats",
+			"total_anchor_channels_reserve_sats",
+			"totalAnchorChannelsReserveSats",
+			"total_lightning_balance_sats",
+			"totalLightningBalanceSats",
+			"lightning_balances",
+			"lightningBalances",
+			"pending_balances_from_channel_closures",
+			"pendingBalancesFromChannelClosures",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			TotalOnchainBalanceSats,
+			SpendableOnchainBalanceSats,
+			TotalAnchorChannelsReserveSats,
+			TotalLightningBalanceSats,
+			LightningBalances,
+			PendingBalancesFromChannelClosures,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"totalOnchainBalanceSats" | "total_onchain_balance_sats" => {
+								Ok(GeneratedField::TotalOnchainBalanceSats)
+							},
+							"spendableOnchainBalanceSats" | "spendable_onchain_balance_sats" => {
+								Ok(GeneratedField::SpendableOnchainBalanceSats)
+							},
+							"totalAnchorChannelsReserveSats"
+							| "total_anchor_channels_reserve_sats" => Ok(GeneratedField::TotalAnchorChannelsReserveSats),
+							"totalLightningBalanceSats" | "total_lightning_balance_sats" => {
+								Ok(GeneratedField::TotalLightningBalanceSats)
+							},
+							"lightningBalances" | "lightning_balances" => {
+								Ok(GeneratedField::LightningBalances)
+							},
+							"pendingBalancesFromChannelClosures"
+							| "pending_balances_from_channel_closures" => {
+								Ok(GeneratedField::PendingBalancesFromChannelClosures)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetBalancesResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetBalancesResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetBalancesResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut total_onchain_balance_sats__ = None;
+				let mut spendable_onchain_balance_sats__ = None;
+				let mut total_anchor_channels_reserve_sats__ = None;
+				let mut total_lightning_balance_sats__ = None;
+				let mut lightning_balances__ = None;
+				let mut pending_balances_from_channel_closures__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::TotalOnchainBalanceSats => {
+							if total_onchain_balance_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalOnchainBalanceSats",
+								));
+							}
+							total_onchain_balance_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::SpendableOnchainBalanceSats => {
+							if spendable_onchain_balance_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"spendableOnchainBalanceSats",
+								));
+							}
+							spendable_onchain_balance_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TotalAnchorChannelsReserveSats => {
+							if total_anchor_channels_reserve_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalAnchorChannelsReserveSats",
+								));
+							}
+							total_anchor_channels_reserve_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TotalLightningBalanceSats => {
+							if total_lightning_balance_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalLightningBalanceSats",
+								));
+							}
+							total_lightning_balance_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::LightningBalances => {
+							if lightning_balances__.is_some() {
+								return Err(serde::de::Error::duplicate_field("lightningBalances"));
+							}
+							lightning_balances__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PendingBalancesFromChannelClosures => {
+							if pending_balances_from_channel_closures__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"pendingBalancesFromChannelClosures",
+								));
+							}
+							pending_balances_from_channel_closures__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GetBalancesResponse {
+					total_onchain_balance_sats: total_onchain_balance_sats__.unwrap_or_default(),
+					spendable_onchain_balance_sats: spendable_onchain_balance_sats__
+						.unwrap_or_default(),
+					total_anchor_channels_reserve_sats: total_anchor_channels_reserve_sats__
+						.unwrap_or_default(),
+					total_lightning_balance_sats: total_lightning_balance_sats__
+						.unwrap_or_default(),
+					lightning_balances: lightning_balances__.unwrap_or_default(),
+					pending_balances_from_channel_closures:
+						pending_balances_from_channel_closures__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.GetBalancesResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetForwardingStatsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.prev_channel_id.is_some() {
+			len += 1;
+		}
+		if self.next_channel_id.is_some() {
+			len += 1;
+		}
+		if self.prev_node_id.is_some() {
+			len += 1;
+		}
+		if self.next_node_id.is_some() {
+			len += 1;
+		}
+		if self.min_timestamp.is_some() {
+			len += 1;
+		}
+		if self.max_timestamp.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.GetForwardingStatsRequest", len)?;
+		if let Some(v) = self.prev_channel_id.as_ref() {
+			struct_ser.serialize_field("prevChannelId", v)?;
+		}
+		if let Some(v) = self.next_channel_id.as_ref() {
+			struct_ser.serialize_field("nextChannelId", v)?;
+		}
+		if let Some(v) = self.prev_node_id.as_ref() {
+			struct_ser.serialize_field("prevNodeId", v)?;
+		}
+		if let Some(v) = self.next_node_id.as_ref() {
+			struct_ser.serialize_field("nextNodeId", v)?;
+		}
+		if let Some(v) = self.min_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("minTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.max_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("maxTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetForwardingStatsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"prev_channel_id",
+			"prevChannelId",
+			"next_channel_id",
+			"nextChannelId",
+			"prev_node_id",
+			"prevNodeId",
+			"next_node_id",
+			"nextNodeId",
+			"min_timestamp",
+			"minTimestamp",
+			"max_timestamp",
+			"maxTimestamp",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PrevChannelId,
+			NextChannelId,
+			PrevNodeId,
+			NextNodeId,
+			MinTimestamp,
+			MaxTimestamp,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"prevChannelId" | "prev_channel_id" => {
+								Ok(GeneratedField::PrevChannelId)
+							},
+							"nextChannelId" | "next_channel_id" => {
+								Ok(GeneratedField::NextChannelId)
+							},
+							"prevNodeId" | "prev_node_id" => Ok(GeneratedField::PrevNodeId),
+							"nextNodeId" | "next_node_id" => Ok(GeneratedField::NextNodeId),
+							"minTimestamp" | "min_timestamp" => Ok(GeneratedField::MinTimestamp),
+							"maxTimestamp" | "max_timestamp" => Ok(GeneratedField::MaxTimestamp),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetForwardingStatsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetForwardingStatsRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GetForwardingStatsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut prev_channel_id__ = None;
+				let mut next_channel_id__ = None;
+				let mut prev_node_id__ = None;
+				let mut next_node_id__ = None;
+				let mut min_timestamp__ = None;
+				let mut max_timestamp__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PrevChannelId => {
+							if prev_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevChannelId"));
+							}
+							prev_channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::NextChannelId => {
+							if next_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextChannelId"));
+							}
+							next_channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::PrevNodeId => {
+							if prev_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevNodeId"));
+							}
+							prev_node_id__ = map_.next_value()?;
+						},
+						GeneratedField::NextNodeId => {
+							if next_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextNodeId"));
+							}
+							next_node_id__ = map_.next_value()?;
+						},
+						GeneratedField::MinTimestamp => {
+							if min_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("minTimestamp"));
+							}
+							min_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MaxTimestamp => {
+							if max_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("maxTimestamp"));
+							}
+							max_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(GetForwardingStatsRequest {
+					prev_channel_id: prev_channel_id__,
+					next_channel_id: next_channel_id__,
+					prev_node_id: prev_node_id__,
+					next_node_id: next_node_id__,
+					min_timestamp: min_timestamp__,
+					max_timestamp: max_timestamp__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.GetForwardingStatsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetForwardingStatsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GetForwardingStatsResponse", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"forwardedPaymentsCount",
+			ToString::to_string(&self.forwarded_payments_count).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"outboundAmountForwardedMsat",
+			ToString::to_string(&self.outbound_amount_forwarded_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"totalFeeEarnedMsat",
+			ToString::to_string(&self.total_fee_earned_msat).as_str(),
+		)?;
+		struct_ser.serialize_field("channelPairs", &self.channel_pairs)?;
+		struct_ser.serialize_field("days", &self.days)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetForwardingStatsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"forwarded_payments_count",
+			"forwardedPaymentsCount",
+			"outbound_amount_forwarded_msat",
+			"outboundAmountForwardedMsat",
+			"total_fee_earned_msat",
+			"totalFeeEarnedMsat",
+			"channel_pairs",
+			"channelPairs",
+			"days",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ForwardedPaymentsCount,
+			OutboundAmountForwardedMsat,
+			TotalFeeEarnedMsat,
+			ChannelPairs,
+			Days,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"forwardedPaymentsCount" | "forwarded_payments_count" => {
+								Ok(GeneratedField::ForwardedPaymentsCount)
+							},
+							"outboundAmountForwardedMsat" | "outbound_amount_forwarded_msat" => {
+								Ok(GeneratedField::OutboundAmountForwardedMsat)
+							},
+							"totalFeeEarnedMsat" | "total_fee_earned_msat" => {
+								Ok(GeneratedField::TotalFeeEarnedMsat)
+							},
+							"channelPairs" | "channel_pairs" => Ok(GeneratedField::ChannelPairs),
+							"days" => Ok(GeneratedField::Days),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetForwardingStatsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetForwardingStatsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GetForwardingStatsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut forwarded_payments_count__ = None;
+				let mut outbound_amount_forwarded_msat__ = None;
+				let mut total_fee_earned_msat__ = None;
+				let mut channel_pairs__ = None;
+				let mut days__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ForwardedPaymentsCount => {
+							if forwarded_payments_count__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forwardedPaymentsCount",
+								));
+							}
+							forwarded_payments_count__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::OutboundAmountForwardedMsat => {
+							if outbound_amount_forwarded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundAmountForwardedMsat",
+								));
+							}
+							outbound_amount_forwarded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TotalFeeEarnedMsat => {
+							if total_fee_earned_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalFeeEarnedMsat",
+								));
+							}
+							total_fee_earned_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ChannelPairs => {
+							if channel_pairs__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelPairs"));
+							}
+							channel_pairs__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Days => {
+							if days__.is_some() {
+								return Err(serde::de::Error::duplicate_field("days"));
+							}
+							days__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GetForwardingStatsResponse {
+					forwarded_payments_count: forwarded_payments_count__.unwrap_or_default(),
+					outbound_amount_forwarded_msat: outbound_amount_forwarded_msat__
+						.unwrap_or_default(),
+					total_fee_earned_msat: total_fee_earned_msat__.unwrap_or_default(),
+					channel_pairs: channel_pairs__.unwrap_or_default(),
+					days: days__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.GetForwardingStatsResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetNodeInfoRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.GetNodeInfoRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetNodeInfoRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetNodeInfoRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetNodeInfoRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNodeInfoRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(GetNodeInfoRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.GetNodeInfoRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetNodeInfoResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.current_best_block.is_some() {
+			len += 1;
+		}
+		if self.latest_lightning_wallet_sync_timestamp.is_some() {
+			len += 1;
+		}
+		if self.latest_onchain_wallet_sync_timestamp.is_some() {
+			len += 1;
+		}
+		if self.latest_fee_rate_cache_update_timestamp.is_some() {
+			len += 1;
+		}
+		if self.latest_rgs_snapshot_timestamp.is_some() {
+			len += 1;
+		}
+		if self.latest_node_announcement_broadcast_timestamp.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		if self.node_alias.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GetNodeInfoResponse", len)?;
+		struct_ser.serialize_field("nodeId", &self.node_id)?;
+		if let Some(v) = self.current_best_block.as_ref() {
+			struct_ser.serialize_field("currentBestBlock", v)?;
+		}
+		if let Some(v) = self.latest_lightning_wallet_sync_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"latestLightningWalletSyncTimestamp",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		if let Some(v) = self.latest_onchain_wallet_sync_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"latestOnchainWalletSyncTimestamp",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		if let Some(v) = self.latest_fee_rate_cache_update_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"latestFeeRateCacheUpdateTimestamp",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		if let Some(v) = self.latest_rgs_snapshot_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("latestRgsSnapshotTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.latest_node_announcement_broadcast_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"latestNodeAnnouncementBroadcastTimestamp",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		struct_ser.serialize_field("listeningAddresses", &self.listening_addresses)?;
+		struct_ser.serialize_field("announcementAddresses", &self.announcement_addresses)?;
+		if let Some(v) = self.node_alias.as_ref() {
+			struct_ser.serialize_field("nodeAlias", v)?;
+		}
+		struct_ser.serialize_field("nodeUris", &self.node_uris)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetNodeInfoResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"node_id",
+			"nodeId",
+			"current_best_block",
+			"currentBestBlock",
+			"latest_lightning_wallet_sync_timestamp",
+			"latestLightningWalletSyncTimestamp",
+			"latest_onchain_wallet_sync_timestamp",
+			"latestOnchainWalletSyncTimestamp",
+			"latest_fee_rate_cache_update_timestamp",
+			"latestFeeRateCacheUpdateTimestamp",
+			"latest_rgs_snapshot_timestamp",
+			"latestRgsSnapshotTimestamp",
+			"latest_node_announcement_broadcast_timestamp",
+			"latestNodeAnnouncementBroadcastTimestamp",
+			"listening_addresses",
+			"listeningAddresses",
+			"announcement_addresses",
+			"announcementAddresses",
+			"node_alias",
+			"nodeAlias",
+			"node_uris",
+			"nodeUris",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodeId,
+			CurrentBestBlock,
+			LatestLightningWalletSyncTimestamp,
+			LatestOnchainWalletSyncTimestamp,
+			LatestFeeRateCacheUpdateTimestamp,
+			LatestRgsSnapshotTimestamp,
+			LatestNodeAnnouncementBroadcastTimestamp,
+			ListeningAddresses,
+			AnnouncementAddresses,
+			NodeAlias,
+			NodeUris,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+							"currentBestBlock" | "current_best_block" => {
+								Ok(GeneratedField::CurrentBestBlock)
+							},
+							"latestLightningWalletSyncTimestamp"
+							| "latest_lightning_wallet_sync_timestamp" => {
+								Ok(GeneratedField::LatestLightningWalletSyncTimestamp)
+							},
+							"latestOnchainWalletSyncTimestamp"
+							| "latest_onchain_wallet_sync_timestamp" => {
+								Ok(GeneratedField::LatestOnchainWalletSyncTimestamp)
+							},
+							"latestFeeRateCacheUpdateTimestamp"
+							| "latest_fee_rate_cache_update_timestamp" => {
+								Ok(GeneratedField::LatestFeeRateCacheUpdateTimestamp)
+							},
+							"latestRgsSnapshotTimestamp" | "latest_rgs_snapshot_timestamp" => {
+								Ok(GeneratedField::LatestRgsSnapshotTimestamp)
+							},
+							"latestNodeAnnouncementBroadcastTimestamp"
+							| "latest_node_announcement_broadcast_timestamp" => {
+								Ok(GeneratedField::LatestNodeAnnouncementBroadcastTimestamp)
+							},
+							"listeningAddresses" | "listening_addresses" => {
+								Ok(GeneratedField::ListeningAddresses)
+							},
+							"announcementAddresses" | "announcement_addresses" => {
+								Ok(GeneratedField::AnnouncementAddresses)
+							},
+							"nodeAlias" | "node_alias" => Ok(GeneratedField::NodeAlias),
+							"nodeUris" | "node_uris" => Ok(GeneratedField::NodeUris),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetNodeInfoResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetNodeInfoResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetNodeInfoResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_id__ = None;
+				let mut current_best_block__ = None;
+				let mut latest_lightning_wallet_sync_timestamp__ = None;
+				let mut latest_onchain_wallet_sync_timestamp__ = None;
+				let mut latest_fee_rate_cache_update_timestamp__ = None;
+				let mut latest_rgs_snapshot_timestamp__ = None;
+				let mut latest_node_announcement_broadcast_timestamp__ = None;
+				let mut listening_addresses__ = None;
+				let mut announcement_addresses__ = None;
+				let mut node_alias__ = None;
+				let mut node_uris__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodeId => {
+							if node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeId"));
+							}
+							node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CurrentBestBlock => {
+							if current_best_block__.is_some() {
+								return Err(serde::de::Error::duplicate_field("currentBestBlock"));
+							}
+							current_best_block__ = map_.next_value()?;
+						},
+						GeneratedField::LatestLightningWalletSyncTimestamp => {
+							if latest_lightning_wallet_sync_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestLightningWalletSyncTimestamp",
+								));
+							}
+							latest_lightning_wallet_sync_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::LatestOnchainWalletSyncTimestamp => {
+							if latest_onchain_wallet_sync_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestOnchainWalletSyncTimestamp",
+								));
+							}
+							latest_onchain_wallet_sync_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::LatestFeeRateCacheUpdateTimestamp => {
+							if latest_fee_rate_cache_update_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestFeeRateCacheUpdateTimestamp",
+								));
+							}
+							latest_fee_rate_cache_update_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::LatestRgsSnapshotTimestamp => {
+							if latest_rgs_snapshot_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestRgsSnapshotTimestamp",
+								));
+							}
+							latest_rgs_snapshot_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::LatestNodeAnnouncementBroadcastTimestamp => {
+							if latest_node_announcement_broadcast_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestNodeAnnouncementBroadcastTimestamp",
+								));
+							}
+							latest_node_announcement_broadcast_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ListeningAddresses => {
+							if listening_addresses__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"listeningAddresses",
+								));
+							}
+							listening_addresses__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AnnouncementAddresses => {
+							if announcement_addresses__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"announcementAddresses",
+								));
+							}
+							announcement_addresses__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NodeAlias => {
+							if node_alias__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeAlias"));
+							}
+							node_alias__ = map_.next_value()?;
+						},
+						GeneratedField::NodeUris => {
+							if node_uris__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeUris"));
+							}
+							node_uris__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GetNodeInfoResponse {
+					node_id: node_id__.unwrap_or_default(),
+					current_best_block: current_best_block__,
+					latest_lightning_wallet_sync_timestamp:
+						latest_lightning_wallet_sync_timestamp__,
+					latest_onchain_wallet_sync_timestamp: latest_onchain_wallet_sync_timestamp__,
+					latest_fee_rate_cache_update_timestamp:
+						latest_fee_rate_cache_update_timestamp__,
+					latest_rgs_snapshot_timestamp: latest_rgs_snapshot_timestamp__,
+					latest_node_announcement_broadcast_timestamp:
+						latest_node_announcement_broadcast_timestamp__,
+					listening_addresses: listening_addresses__.unwrap_or_default(),
+					announcement_addresses: announcement_addresses__.unwrap_or_default(),
+					node_alias: node_alias__,
+					node_uris: node_uris__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.GetNodeInfoResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetPaymentDetailsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GetPaymentDetailsRequest", len)?;
+		struct_ser.serialize_field("paymentId", &self.payment_id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetPaymentDetailsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payment_id", "paymentId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PaymentId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"paymentId" | "payment_id" => Ok(GeneratedField::PaymentId),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetPaymentDetailsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetPaymentDetailsRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GetPaymentDetailsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PaymentId => {
+							if payment_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentId"));
+							}
+							payment_id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GetPaymentDetailsRequest { payment_id: payment_id__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.GetPaymentDetailsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GetPaymentDetailsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.payment.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.GetPaymentDetailsResponse", len)?;
+		if let Some(v) = self.payment.as_ref() {
+			struct_ser.serialize_field("payment", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GetPaymentDetailsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payment"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payment,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payment" => Ok(GeneratedField::Payment),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GetPaymentDetailsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GetPaymentDetailsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GetPaymentDetailsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payment => {
+							if payment__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payment"));
+							}
+							payment__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(GetPaymentDetailsResponse { payment: payment__ })
+			}
+		}
+		deserializer.deserialize_struct("api.GetPaymentDetailsResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphGetChannelRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GraphGetChannelRequest", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"shortChannelId",
+			ToString::to_string(&self.short_channel_id).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphGetChannelRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["short_channel_id", "shortChannelId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ShortChannelId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"shortChannelId" | "short_channel_id" => {
+								Ok(GeneratedField::ShortChannelId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphGetChannelRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphGetChannelRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphGetChannelRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut short_channel_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ShortChannelId => {
+							if short_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("shortChannelId"));
+							}
+							short_channel_id__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(GraphGetChannelRequest {
+					short_channel_id: short_channel_id__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.GraphGetChannelRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphGetChannelResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.channel.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.GraphGetChannelResponse", len)?;
+		if let Some(v) = self.channel.as_ref() {
+			struct_ser.serialize_field("channel", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphGetChannelResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["channel"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Channel,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channel" => Ok(GeneratedField::Channel),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphGetChannelResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphGetChannelResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphGetChannelResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Channel => {
+							if channel__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channel"));
+							}
+							channel__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(GraphGetChannelResponse { channel: channel__ })
+			}
+		}
+		deserializer.deserialize_struct("api.GraphGetChannelResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphGetNodeRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GraphGetNodeRequest", len)?;
+		struct_ser.serialize_field("nodeId", &self.node_id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphGetNodeRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["node_id", "nodeId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodeId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphGetNodeRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphGetNodeRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GraphGetNodeRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodeId => {
+							if node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeId"));
+							}
+							node_id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GraphGetNodeRequest { node_id: node_id__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.GraphGetNodeRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphGetNodeResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.node.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.GraphGetNodeResponse", len)?;
+		if let Some(v) = self.node.as_ref() {
+			struct_ser.serialize_field("node", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphGetNodeResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["node"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Node,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"node" => Ok(GeneratedField::Node),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphGetNodeResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphGetNodeResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphGetNodeResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Node => {
+							if node__.is_some() {
+								return Err(serde::de::Error::duplicate_field("node"));
+							}
+							node__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(GraphGetNodeResponse { node: node__ })
+			}
+		}
+		deserializer.deserialize_struct("api.GraphGetNodeResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphListChannelsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.GraphListChannelsRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphListChannelsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphListChannelsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphListChannelsRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphListChannelsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(GraphListChannelsRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.GraphListChannelsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphListChannelsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GraphListChannelsResponse", len)?;
+		struct_ser.serialize_field(
+			"shortChannelIds",
+			&self.short_channel_ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphListChannelsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["short_channel_ids", "shortChannelIds"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ShortChannelIds,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"shortChannelIds" | "short_channel_ids" => {
+								Ok(GeneratedField::ShortChannelIds)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphListChannelsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphListChannelsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphListChannelsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut short_channel_ids__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ShortChannelIds => {
+							if short_channel_ids__.is_some() {
+								return Err(serde::de::Error::duplicate_field("shortChannelIds"));
+							}
+							short_channel_ids__ = Some(
+								map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+									.into_iter()
+									.map(|x| x.0)
+									.collect(),
+							);
+						},
+					}
+				}
+				Ok(GraphListChannelsResponse {
+					short_channel_ids: short_channel_ids__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.GraphListChannelsResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphListNodesRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.GraphListNodesRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphListNodesRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphListNodesRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphListNodesRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphListNodesRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(GraphListNodesRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.GraphListNodesRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphListNodesResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.GraphListNodesResponse", len)?;
+		struct_ser.serialize_field("nodeIds", &self.node_ids)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphListNodesResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["node_ids", "nodeIds"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodeIds,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodeIds" | "node_ids" => Ok(GeneratedField::NodeIds),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphListNodesResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.GraphListNodesResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphListNodesResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_ids__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodeIds => {
+							if node_ids__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeIds"));
+							}
+							node_ids__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GraphListNodesResponse { node_ids: node_ids__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.GraphListNodesResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListApiKeysRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.ListApiKeysRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListApiKeysRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListApiKeysRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListApiKeysRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListApiKeysRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(ListApiKeysRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.ListApiKeysRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListApiKeysResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.ListApiKeysResponse", len)?;
+		struct_ser.serialize_field("apiKeys", &self.api_keys)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListApiKeysResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["api_keys", "apiKeys"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ApiKeys,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"apiKeys" | "api_keys" => Ok(GeneratedField::ApiKeys),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListApiKeysResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListApiKeysResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListApiKeysResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut api_keys__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ApiKeys => {
+							if api_keys__.is_some() {
+								return Err(serde::de::Error::duplicate_field("apiKeys"));
+							}
+							api_keys__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ListApiKeysResponse { api_keys: api_keys__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.ListApiKeysResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListAuditLogRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListAuditLogRequest", len)?;
+		if let Some(v) = self.page_token.as_ref() {
+			struct_ser.serialize_field("pageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListAuditLogRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["page_token", "pageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListAuditLogRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListAuditLogRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListAuditLogRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PageToken => {
+							if page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pageToken"));
+							}
+							page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListAuditLogRequest { page_token: page_token__ })
+			}
+		}
+		deserializer.deserialize_struct("api.ListAuditLogRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListAuditLogResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.next_page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListAuditLogResponse", len)?;
+		struct_ser.serialize_field("entries", &self.entries)?;
+		if let Some(v) = self.next_page_token.as_ref() {
+			struct_ser.serialize_field("nextPageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListAuditLogResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["entries", "next_page_token", "nextPageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Entries,
+			NextPageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"entries" => Ok(GeneratedField::Entries),
+							"nextPageToken" | "next_page_token" => {
+								Ok(GeneratedField::NextPageToken)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListAuditLogResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListAuditLogResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListAuditLogResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut entries__ = None;
+				let mut next_page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Entries => {
+							if entries__.is_some() {
+								return Err(serde::de::Error::duplicate_field("entries"));
+							}
+							entries__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextPageToken => {
+							if next_page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextPageToken"));
+							}
+							next_page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListAuditLogResponse {
+					entries: entries__.unwrap_or_default(),
+					next_page_token: next_page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListAuditLogResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListChannelsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.ListChannelsRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListChannelsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListChannelsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListChannelsRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListChannelsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(ListChannelsRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.ListChannelsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListChannelsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.ListChannelsResponse", len)?;
+		struct_ser.serialize_field("channels", &self.channels)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListChannelsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["channels"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Channels,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channels" => Ok(GeneratedField::Channels),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListChannelsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListChannelsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListChannelsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channels__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Channels => {
+							if channels__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channels"));
+							}
+							channels__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ListChannelsResponse { channels: channels__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.ListChannelsResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListEventsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.page_token.is_some() {
+			len += 1;
+		}
+		if self.since_sequence_number.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.ListEventsRequest", len)?;
+		if let Some(v) = self.page_token.as_ref() {
+			struct_ser.serialize_field("pageToken", v)?;
+		}
+		if let Some(v) = self.since_sequence_number.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("sinceSequenceNumber", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.serialize_field("eventTypes", &self.event_types)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListEventsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"page_token",
+			"pageToken",
+			"since_sequence_number",
+			"sinceSequenceNumber",
+			"event_types",
+			"eventTypes",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PageToken,
+			SinceSequenceNumber,
+			EventTypes,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+							"sinceSequenceNumber" | "since_sequence_number" => {
+								Ok(GeneratedField::SinceSequenceNumber)
+							},
+							"eventTypes" | "event_types" => Ok(GeneratedField::EventTypes),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListEventsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListEventsRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListEventsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut page_token__ = None;
+				let mut since_sequence_number__ = None;
+				let mut event_types__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PageToken => {
+							if page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pageToken"));
+							}
+							page_token__ = map_.next_value()?;
+						},
+						GeneratedField::SinceSequenceNumber => {
+							if since_sequence_number__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"sinceSequenceNumber",
+								));
+							}
+							since_sequence_number__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::EventTypes => {
+							if event_types__.is_some() {
+								return Err(serde::de::Error::duplicate_field("eventTypes"));
+							}
+							event_types__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ListEventsRequest {
+					page_token: page_token__,
+					since_sequence_number: since_sequence_number__,
+					event_types: event_types__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListEventsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListEventsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.next_page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListEventsResponse", len)?;
+		struct_ser.serialize_field("events", &self.events)?;
+		if let Some(v) = self.next_page_token.as_ref() {
+			struct_ser.serialize_field("nextPageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListEventsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["events", "next_page_token", "nextPageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Events,
+			NextPageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"events" => Ok(GeneratedField::Events),
+							"nextPageToken" | "next_page_token" => {
+								Ok(GeneratedField::NextPageToken)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListEventsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListEventsResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListEventsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut events__ = None;
+				let mut next_page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Events => {
+							if events__.is_some() {
+								return Err(serde::de::Error::duplicate_field("events"));
+							}
+							events__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextPageToken => {
+							if next_page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextPageToken"));
+							}
+							next_page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListEventsResponse {
+					events: events__.unwrap_or_default(),
+					next_page_token: next_page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListEventsResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListForwardedPaymentsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.page_token.is_some() {
+			len += 1;
+		}
+		if self.prev_channel_id.is_some() {
+			len += 1;
+		}
+		if self.next_channel_id.is_some() {
+			len += 1;
+		}
+		if self.prev_node_id.is_some() {
+			len += 1;
+		}
+		if self.next_node_id.is_some() {
+			len += 1;
+		}
+		if self.min_timestamp.is_some() {
+			len += 1;
+		}
+		if self.max_timestamp.is_some() {
+			len += 1;
+		}
+		let mut struct_ser =
+			serializer.serialize_struct("api.ListForwardedPaymentsRequest", len)?;
+		if let Some(v) = self.page_token.as_ref() {
+			struct_ser.serialize_field("pageToken", v)?;
+		}
+		if let Some(v) = self.prev_channel_id.as_ref() {
+			struct_ser.serialize_field("prevChannelId", v)?;
+		}
+		if let Some(v) = self.next_channel_id.as_ref() {
+			struct_ser.serialize_field("nextChannelId", v)?;
+		}
+		if let Some(v) = self.prev_node_id.as_ref() {
+			struct_ser.serialize_field("prevNodeId", v)?;
+		}
+		if let Some(v) = self.next_node_id.as_ref() {
+			struct_ser.serialize_field("nextNodeId", v)?;
+		}
+		if let Some(v) = self.min_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("minTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.max_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("maxTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListForwardedPaymentsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"page_token",
+			"pageToken",
+			"prev_channel_id",
+			"prevChannelId",
+			"next_channel_id",
+			"nextChannelId",
+			"prev_node_id",
+			"prevNodeId",
+			"next_node_id",
+			"nextNodeId",
+			"min_timestamp",
+			"minTimestamp",
+			"max_timestamp",
+			"maxTimestamp",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PageToken,
+			PrevChannelId,
+			NextChannelId,
+			PrevNodeId,
+			NextNodeId,
+			MinTimestamp,
+			MaxTimestamp,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+							"prevChannelId" | "prev_channel_id" => {
+								Ok(GeneratedField::PrevChannelId)
+							},
+							"nextChannelId" | "next_channel_id" => {
+								Ok(GeneratedField::NextChannelId)
+							},
+							"prevNodeId" | "prev_node_id" => Ok(GeneratedField::PrevNodeId),
+							"nextNodeId" | "next_node_id" => Ok(GeneratedField::NextNodeId),
+							"minTimestamp" | "min_timestamp" => Ok(GeneratedField::MinTimestamp),
+							"maxTimestamp" | "max_timestamp" => Ok(GeneratedField::MaxTimestamp),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListForwardedPaymentsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListForwardedPaymentsRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListForwardedPaymentsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut page_token__ = None;
+				let mut prev_channel_id__ = None;
+				let mut next_channel_id__ = None;
+				let mut prev_node_id__ = None;
+				let mut next_node_id__ = None;
+				let mut min_timestamp__ = None;
+				let mut max_timestamp__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PageToken => {
+							if page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pageToken"));
+							}
+							page_token__ = map_.next_value()?;
+						},
+						GeneratedField::PrevChannelId => {
+							if prev_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevChannelId"));
+							}
+							prev_channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::NextChannelId => {
+							if next_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextChannelId"));
+							}
+							next_channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::PrevNodeId => {
+							if prev_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevNodeId"));
+							}
+							prev_node_id__ = map_.next_value()?;
+						},
+						GeneratedField::NextNodeId => {
+							if next_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextNodeId"));
+							}
+							next_node_id__ = map_.next_value()?;
+						},
+						GeneratedField::MinTimestamp => {
+							if min_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("minTimestamp"));
+							}
+							min_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MaxTimestamp => {
+							if max_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("maxTimestamp"));
+							}
+							max_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(ListForwardedPaymentsRequest {
+					page_token: page_token__,
+					prev_channel_id: prev_channel_id__,
+					next_channel_id: next_channel_id__,
+					prev_node_id: prev_node_id__,
+					next_node_id: next_node_id__,
+					min_timestamp: min_timestamp__,
+					max_timestamp: max_timestamp__,
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ListForwardedPaymentsRequest",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ListForwardedPaymentsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.next_page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser =
+			serializer.serialize_struct("api.ListForwardedPaymentsResponse", len)?;
+		struct_ser.serialize_field("forwardedPayments", &self.forwarded_payments)?;
+		if let Some(v) = self.next_page_token.as_ref() {
+			struct_ser.serialize_field("nextPageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListForwardedPaymentsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["forwarded_payments", "forwardedPayments", "next_page_token", "nextPageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ForwardedPayments,
+			NextPageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"forwardedPayments" | "forwarded_payments" => {
+								Ok(GeneratedField::ForwardedPayments)
+							},
+							"nextPageToken" | "next_page_token" => {
+								Ok(GeneratedField::NextPageToken)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListForwardedPaymentsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListForwardedPaymentsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListForwardedPaymentsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut forwarded_payments__ = None;
+				let mut next_page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ForwardedPayments => {
+							if forwarded_payments__.is_some() {
+								return Err(serde::de::Error::duplicate_field("forwardedPayments"));
+							}
+							forwarded_payments__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextPageToken => {
+							if next_page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextPageToken"));
+							}
+							next_page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListForwardedPaymentsResponse {
+					forwarded_payments: forwarded_payments__.unwrap_or_default(),
+					next_page_token: next_page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ListForwardedPaymentsResponse",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ListPaymentsByLabelRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListPaymentsByLabelRequest", len)?;
+		struct_ser.serialize_field("label", &self.label)?;
+		if let Some(v) = self.page_token.as_ref() {
+			struct_ser.serialize_field("pageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPaymentsByLabelRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["label", "page_token", "pageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Label,
+			PageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"label" => Ok(GeneratedField::Label),
+							"pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPaymentsByLabelRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPaymentsByLabelRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPaymentsByLabelRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut label__ = None;
+				let mut page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Label => {
+							if label__.is_some() {
+								return Err(serde::de::Error::duplicate_field("label"));
+							}
+							label__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PageToken => {
+							if page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pageToken"));
+							}
+							page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListPaymentsByLabelRequest {
+					label: label__.unwrap_or_default(),
+					page_token: page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListPaymentsByLabelRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListPaymentsByLabelResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.next_page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListPaymentsByLabelResponse", len)?;
+		struct_ser.serialize_field("payments", &self.payments)?;
+		if let Some(v) = self.next_page_token.as_ref() {
+			struct_ser.serialize_field("nextPageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPaymentsByLabelResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payments", "next_page_token", "nextPageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payments,
+			NextPageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payments" => Ok(GeneratedField::Payments),
+							"nextPageToken" | "next_page_token" => {
+								Ok(GeneratedField::NextPageToken)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPaymentsByLabelResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPaymentsByLabelResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPaymentsByLabelResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payments__ = None;
+				let mut next_page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payments => {
+							if payments__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payments"));
+							}
+							payments__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextPageToken => {
+							if next_page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextPageToken"));
+							}
+							next_page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListPaymentsByLabelResponse {
+					payments: payments__.unwrap_or_default(),
+					next_page_token: next_page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListPaymentsByLabelResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListPaymentsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.page_token.is_some() {
+			len += 1;
+		}
+		if self.status.is_some() {
+			len += 1;
+		}
+		if self.direction.is_some() {
+			len += 1;
+		}
+		if self.kind.is_some() {
+			len += 1;
+		}
+		if self.min_creation_timestamp.is_some() {
+			len += 1;
+		}
+		if self.max_creation_timestamp.is_some() {
+			len += 1;
+		}
+		if self.min_latest_update_timestamp.is_some() {
+			len += 1;
+		}
+		if self.max_latest_update_timestamp.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListPaymentsRequest", len)?;
+		if let Some(v) = self.page_token.as_ref() {
+			struct_ser.serialize_field("pageToken", v)?;
+		}
+		if let Some(v) = self.status.as_ref() {
+			let v = super::types::PaymentStatus::from_i32(*v)
+				.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+			struct_ser.serialize_field("status", &v)?;
+		}
+		if let Some(v) = self.direction.as_ref() {
+			let v = super::types::PaymentDirection::from_i32(*v)
+				.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+			struct_ser.serialize_field("direction", &v)?;
+		}
+		if let Some(v) = self.kind.as_ref() {
+			let v = super::types::PaymentKindType::from_i32(*v)
+				.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", *v)))?;
+			struct_ser.serialize_field("kind", &v)?;
+		}
+		if let Some(v) = self.min_creation_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("minCreationTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.max_creation_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("maxCreationTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.min_latest_update_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("minLatestUpdateTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.max_latest_update_timestamp.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("maxLatestUpdateTimestamp", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPaymentsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"page_token",
+			"pageToken",
+			"status",
+			"direction",
+			"kind",
+			"min_creation_timestamp",
+			"minCreationTimestamp",
+			"max_creation_timestamp",
+			"maxCreationTimestamp",
+			"min_latest_update_timestamp",
+			"minLatestUpdateTimestamp",
+			"max_latest_update_timestamp",
+			"maxLatestUpdateTimestamp",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PageToken,
+			Status,
+			Direction,
+			Kind,
+			MinCreationTimestamp,
+			MaxCreationTimestamp,
+			MinLatestUpdateTimestamp,
+			MaxLatestUpdateTimestamp,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+							"status" => Ok(GeneratedField::Status),
+							"direction" => Ok(GeneratedField::Direction),
+							"kind" => Ok(GeneratedField::Kind),
+							"minCreationTimestamp" | "min_creation_timestamp" => {
+								Ok(GeneratedField::MinCreationTimestamp)
+							},
+							"maxCreationTimestamp" | "max_creation_timestamp" => {
+								Ok(GeneratedField::MaxCreationTimestamp)
+							},
+							"minLatestUpdateTimestamp" | "min_latest_update_timestamp" => {
+								Ok(GeneratedField::MinLatestUpdateTimestamp)
+							},
+							"maxLatestUpdateTimestamp" | "max_latest_update_timestamp" => {
+								Ok(GeneratedField::MaxLatestUpdateTimestamp)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPaymentsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPaymentsRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ListPaymentsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut page_token__ = None;
+				let mut status__ = None;
+				let mut direction__ = None;
+				let mut kind__ = None;
+				let mut min_creation_timestamp__ = None;
+				let mut max_creation_timestamp__ = None;
+				let mut min_latest_update_timestamp__ = None;
+				let mut max_latest_update_timestamp__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PageToken => {
+							if page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pageToken"));
+							}
+							page_token__ = map_.next_value()?;
+						},
+						GeneratedField::Status => {
+							if status__.is_some() {
+								return Err(serde::de::Error::duplicate_field("status"));
+							}
+							status__ = map_
+								.next_value::<::std::option::Option<super::types::PaymentStatus>>()?
+								.map(|x| x as i32);
+						},
+						GeneratedField::Direction => {
+							if direction__.is_some() {
+								return Err(serde::de::Error::duplicate_field("direction"));
+							}
+							direction__ = map_
+								.next_value::<::std::option::Option<super::types::PaymentDirection>>(
+								)?
+								.map(|x| x as i32);
+						},
+						GeneratedField::Kind => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("kind"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<super::types::PaymentKindType>>(
+								)?
+								.map(|x| x as i32);
+						},
+						GeneratedField::MinCreationTimestamp => {
+							if min_creation_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"minCreationTimestamp",
+								));
+							}
+							min_creation_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MaxCreationTimestamp => {
+							if max_creation_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxCreationTimestamp",
+								));
+							}
+							max_creation_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MinLatestUpdateTimestamp => {
+							if min_latest_update_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"minLatestUpdateTimestamp",
+								));
+							}
+							min_latest_update_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MaxLatestUpdateTimestamp => {
+							if max_latest_update_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxLatestUpdateTimestamp",
+								));
+							}
+							max_latest_update_timestamp__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(ListPaymentsRequest {
+					page_token: page_token__,
+					status: status__,
+					direction: direction__,
+					kind: kind__,
+					min_creation_timestamp: min_creation_timestamp__,
+					max_creation_timestamp: max_creation_timestamp__,
+					min_latest_update_timestamp: min_latest_update_timestamp__,
+					max_latest_update_timestamp: max_latest_update_timestamp__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListPaymentsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListPaymentsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.next_page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.ListPaymentsResponse", len)?;
+		struct_ser.serialize_field("payments", &self.payments)?;
+		if let Some(v) = self.next_page_token.as_ref() {
+			struct_ser.serialize_field("nextPageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPaymentsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payments", "next_page_token", "nextPageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payments,
+			NextPageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payments" => Ok(GeneratedField::Payments),
+							"nextPageToken" | "next_page_token" => {
+								Ok(GeneratedField::NextPageToken)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPaymentsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPaymentsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPaymentsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payments__ = None;
+				let mut next_page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payments => {
+							if payments__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payments"));
+							}
+							payments__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextPageToken => {
+							if next_page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextPageToken"));
+							}
+							next_page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListPaymentsResponse {
+					payments: payments__.unwrap_or_default(),
+					next_page_token: next_page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.ListPaymentsResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListPaymentsUpdatedSinceRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser =
+			serializer.serialize_struct("api.ListPaymentsUpdatedSinceRequest", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser
+			.serialize_field("updatedSince", ToString::to_string(&self.updated_since).as_str())?;
+		if let Some(v) = self.page_token.as_ref() {
+			struct_ser.serialize_field("pageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPaymentsUpdatedSinceRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["updated_since", "updatedSince", "page_token", "pageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UpdatedSince,
+			PageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"updatedSince" | "updated_since" => Ok(GeneratedField::UpdatedSince),
+							"pageToken" | "page_token" => Ok(GeneratedField::PageToken),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPaymentsUpdatedSinceRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPaymentsUpdatedSinceRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPaymentsUpdatedSinceRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut updated_since__ = None;
+				let mut page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UpdatedSince => {
+							if updated_since__.is_some() {
+								return Err(serde::de::Error::duplicate_field("updatedSince"));
+							}
+							updated_since__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::PageToken => {
+							if page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pageToken"));
+							}
+							page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListPaymentsUpdatedSinceRequest {
+					updated_since: updated_since__.unwrap_or_default(),
+					page_token: page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ListPaymentsUpdatedSinceRequest",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ListPaymentsUpdatedSinceResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.next_page_token.is_some() {
+			len += 1;
+		}
+		let mut struct_ser =
+			serializer.serialize_struct("api.ListPaymentsUpdatedSinceResponse", len)?;
+		struct_ser.serialize_field("payments", &self.payments)?;
+		if let Some(v) = self.next_page_token.as_ref() {
+			struct_ser.serialize_field("nextPageToken", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPaymentsUpdatedSinceResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payments", "next_page_token", "nextPageToken"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payments,
+			NextPageToken,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payments" => Ok(GeneratedField::Payments),
+							"nextPageToken" | "next_page_token" => {
+								Ok(GeneratedField::NextPageToken)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPaymentsUpdatedSinceResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPaymentsUpdatedSinceResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPaymentsUpdatedSinceResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payments__ = None;
+				let mut next_page_token__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payments => {
+							if payments__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payments"));
+							}
+							payments__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextPageToken => {
+							if next_page_token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextPageToken"));
+							}
+							next_page_token__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ListPaymentsUpdatedSinceResponse {
+					payments: payments__.unwrap_or_default(),
+					next_page_token: next_page_token__,
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ListPaymentsUpdatedSinceResponse",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ListPendingApprovalsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.ListPendingApprovalsRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPendingApprovalsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPendingApprovalsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPendingApprovalsRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPendingApprovalsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(ListPendingApprovalsRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.ListPendingApprovalsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ListPendingApprovalsResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("api.ListPendingApprovalsResponse", len)?;
+		struct_ser.serialize_field("pendingApprovals", &self.pending_approvals)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ListPendingApprovalsResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["pending_approvals", "pendingApprovals"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PendingApprovals,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"pendingApprovals" | "pending_approvals" => {
+								Ok(GeneratedField::PendingApprovals)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ListPendingApprovalsResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.ListPendingApprovalsResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ListPendingApprovalsResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut pending_approvals__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PendingApprovals => {
+							if pending_approvals__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pendingApprovals"));
+							}
+							pending_approvals__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ListPendingApprovalsResponse {
+					pending_approvals: pending_approvals__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"api.ListPendingApprovalsResponse",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for OnchainReceiveRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.OnchainReceiveRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OnchainReceiveRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OnchainReceiveRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.OnchainReceiveRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<OnchainReceiveRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(OnchainReceiveRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.OnchainReceiveRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for OnchainReceiveResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.OnchainReceiveResponse", len)?;
+		struct_ser.serialize_field("address", &self.address)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OnchainReceiveResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["address"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Address,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"address" => Ok(GeneratedField::Address),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OnchainReceiveResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.OnchainReceiveResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<OnchainReceiveResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut address__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Address => {
+							if address__.is_some() {
+								return Err(serde::de::Error::duplicate_field("address"));
+							}
+							address__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(OnchainReceiveResponse { address: address__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.OnchainReceiveResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for OnchainSendRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.amount_sats.is_some() {
+			len += 1;
+		}
+		if self.send_all.is_some() {
+			len += 1;
+		}
+		if self.fee_rate_sat_per_vb.is_some() {
+			len += 1;
+		}
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.OnchainSendRequest", len)?;
+		struct_ser.serialize_field("address", &self.address)?;
+		if let Some(v) = self.amount_sats.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountSats", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.send_all.as_ref() {
+			struct_ser.serialize_field("sendAll", v)?;
+		}
+		if let Some(v) = self.fee_rate_sat_per_vb.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("feeRateSatPerVb", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OnchainSendRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"address",
+			"amount_sats",
+			"amountSats",
+			"send_all",
+			"sendAll",
+			"fee_rate_sat_per_vb",
+			"feeRateSatPerVb",
+			"metadata",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Address,
+			AmountSats,
+			SendAll,
+			FeeRateSatPerVb,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"address" => Ok(GeneratedField::Address),
+							"amountSats" | "amount_sats" => Ok(GeneratedField::AmountSats),
+							"sendAll" | "send_all" => Ok(GeneratedField::SendAll),
+							"feeRateSatPerVb" | "fee_rate_sat_per_vb" => {
+								Ok(GeneratedField::FeeRateSatPerVb)
+							},
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OnchainSendRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.OnchainSendRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<OnchainSendRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut address__ = None;
+				let mut amount_sats__ = None;
+				let mut send_all__ = None;
+				let mut fee_rate_sat_per_vb__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Address => {
+							if address__.is_some() {
+								return Err(serde::de::Error::duplicate_field("address"));
+							}
+							address__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSats => {
+							if amount_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSats"));
+							}
+							amount_sats__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::SendAll => {
+							if send_all__.is_some() {
+								return Err(serde::de::Error::duplicate_field("sendAll"));
+							}
+							send_all__ = map_.next_value()?;
+						},
+						GeneratedField::FeeRateSatPerVb => {
+							if fee_rate_sat_per_vb__.is_some() {
+								return Err(serde::de::Error::duplicate_field("feeRateSatPerVb"));
+							}
+							fee_rate_sat_per_vb__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(OnchainSendRequest {
+					address: address__.unwrap_or_default(),
+					amount_sats: amount_sats__,
+					send_all: send_all__,
+					fee_rate_sat_per_vb: fee_rate_sat_per_vb__,
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.OnchainSendRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for OnchainSendResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.pending_approval_id.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.OnchainSendResponse", len)?;
+		struct_ser.serialize_field("txid", &self.txid)?;
+		if let Some(v) = self.pending_approval_id.as_ref() {
+			struct_ser.serialize_field("pendingApprovalId", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OnchainSendResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["txid", "pending_approval_id", "pendingApprovalId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Txid,
+			PendingApprovalId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"txid" => Ok(GeneratedField::Txid),
+							"pendingApprovalId" | "pending_approval_id" => {
+								Ok(GeneratedField::PendingApprovalId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OnchainSendResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.OnchainSendResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<OnchainSendResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut txid__ = None;
+				let mut pending_approval_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Txid => {
+							if txid__.is_some() {
+								return Err(serde::de::Error::duplicate_field("txid"));
+							}
+							txid__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PendingApprovalId => {
+							if pending_approval_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pendingApprovalId"));
+							}
+							pending_approval_id__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(OnchainSendResponse {
+					txid: txid__.unwrap_or_default(),
+					pending_approval_id: pending_approval_id__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.OnchainSendResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for OpenChannelRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.push_to_counterparty_msat.is_some() {
+			len += 1;
+		}
+		if self.channel_config.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.OpenChannelRequest", len)?;
+		struct_ser.serialize_field("nodePubkey", &self.node_pubkey)?;
+		struct_ser.serialize_field("address", &self.address)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"channelAmountSats",
+			ToString::to_string(&self.channel_amount_sats).as_str(),
+		)?;
+		if let Some(v) = self.push_to_counterparty_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("pushToCounterpartyMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.channel_config.as_ref() {
+			struct_ser.serialize_field("channelConfig", v)?;
+		}
+		struct_ser.serialize_field("announceChannel", &self.announce_channel)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OpenChannelRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"node_pubkey",
+			"nodePubkey",
+			"address",
+			"channel_amount_sats",
+			"channelAmountSats",
+			"push_to_counterparty_msat",
+			"pushToCounterpartyMsat",
+			"channel_config",
+			"channelConfig",
+			"announce_channel",
+			"announceChannel",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodePubkey,
+			Address,
+			ChannelAmountSats,
+			PushToCounterpartyMsat,
+			ChannelConfig,
+			AnnounceChannel,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodePubkey" | "node_pubkey" => Ok(GeneratedField::NodePubkey),
+							"address" => Ok(GeneratedField::Address),
+							"channelAmountSats" | "channel_amount_sats" => {
+								Ok(GeneratedField::ChannelAmountSats)
+							},
+							"pushToCounterpartyMsat" | "push_to_counterparty_msat" => {
+								Ok(GeneratedField::PushToCounterpartyMsat)
+							},
+							"channelConfig" | "channel_config" => Ok(GeneratedField::ChannelConfig),
+							"announceChannel" | "announce_channel" => {
+								Ok(GeneratedField::AnnounceChannel)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OpenChannelRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.OpenChannelRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<OpenChannelRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_pubkey__ = None;
+				let mut address__ = None;
+				let mut channel_amount_sats__ = None;
+				let mut push_to_counterparty_msat__ = None;
+				let mut channel_config__ = None;
+				let mut announce_channel__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodePubkey => {
+							if node_pubkey__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodePubkey"));
+							}
+							node_pubkey__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Address => {
+							if address__.is_some() {
+								return Err(serde::de::Error::duplicate_field("address"));
+							}
+							address__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ChannelAmountSats => {
+							if channel_amount_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelAmountSats"));
+							}
+							channel_amount_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::PushToCounterpartyMsat => {
+							if push_to_counterparty_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"pushToCounterpartyMsat",
+								));
+							}
+							push_to_counterparty_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ChannelConfig => {
+							if channel_config__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelConfig"));
+							}
+							channel_config__ = map_.next_value()?;
+						},
+						GeneratedField::AnnounceChannel => {
+							if announce_channel__.is_some() {
+								return Err(serde::de::Error::duplicate_field("announceChannel"));
+							}
+							announce_channel__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(OpenChannelRequest {
+					node_pubkey: node_pubkey__.unwrap_or_default(),
+					address: address__.unwrap_or_default(),
+					channel_amount_sats: channel_amount_sats__.unwrap_or_default(),
+					push_to_counterparty_msat: push_to_counterparty_msat__,
+					channel_config: channel_config__,
+					announce_channel: announce_channel__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.OpenChannelRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for OpenChannelResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.OpenChannelResponse", len)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OpenChannelResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["user_channel_id", "userChannelId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UserChannelId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OpenChannelResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.OpenChannelResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<OpenChannelResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut user_channel_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(OpenChannelResponse { user_channel_id: user_channel_id__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.OpenChannelResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RejectPaymentRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.RejectPaymentRequest", len)?;
+		struct_ser.serialize_field("id", &self.id)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RejectPaymentRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["id"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Id,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"id" => Ok(GeneratedField::Id),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RejectPaymentRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.RejectPaymentRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<RejectPaymentRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Id => {
+							if id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("id"));
+							}
+							id__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(RejectPaymentRequest { id: id__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.RejectPaymentRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RejectPaymentResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.RejectPaymentResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RejectPaymentResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RejectPaymentResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.RejectPaymentResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<RejectPaymentResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(RejectPaymentResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.RejectPaymentResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RevokeApiKeyRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.RevokeApiKeyRequest", len)?;
+		struct_ser.serialize_field("name", &self.name)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RevokeApiKeyRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["name"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Name,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"name" => Ok(GeneratedField::Name),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RevokeApiKeyRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.RevokeApiKeyRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<RevokeApiKeyRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut name__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Name => {
+							if name__.is_some() {
+								return Err(serde::de::Error::duplicate_field("name"));
+							}
+							name__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(RevokeApiKeyRequest { name: name__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.RevokeApiKeyRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RevokeApiKeyResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.RevokeApiKeyResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RevokeApiKeyResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RevokeApiKeyResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.RevokeApiKeyResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<RevokeApiKeyResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(RevokeApiKeyResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.RevokeApiKeyResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RotateApiKeyRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.grace_period_secs.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.RotateApiKeyRequest", len)?;
+		struct_ser.serialize_field("name", &self.name)?;
+		if let Some(v) = self.grace_period_secs.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("gracePeriodSecs", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RotateApiKeyRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["name", "grace_period_secs", "gracePeriodSecs"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Name,
+			GracePeriodSecs,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"name" => Ok(GeneratedField::Name),
+							"gracePeriodSecs" | "grace_period_secs" => {
+								Ok(GeneratedField::GracePeriodSecs)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RotateApiKeyRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.RotateApiKeyRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<RotateApiKeyRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut name__ = None;
+				let mut grace_period_secs__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Name => {
+							if name__.is_some() {
+								return Err(serde::de::Error::duplicate_field("name"));
+							}
+							name__ = Some(map_.next_value()?);
+						},
+						GeneratedField::GracePeriodSecs => {
+							if grace_period_secs__.is_some() {
+								return Err(serde::de::Error::duplicate_field("gracePeriodSecs"));
+							}
+							grace_period_secs__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(RotateApiKeyRequest {
+					name: name__.unwrap_or_default(),
+					grace_period_secs: grace_period_secs__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.RotateApiKeyRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RotateApiKeyResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.RotateApiKeyResponse", len)?;
+		struct_ser.serialize_field("apiKey", &self.api_key)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RotateApiKeyResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["api_key", "apiKey"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ApiKey,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"apiKey" | "api_key" => Ok(GeneratedField::ApiKey),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RotateApiKeyResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.RotateApiKeyResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<RotateApiKeyResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut api_key__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ApiKey => {
+							if api_key__.is_some() {
+								return Err(serde::de::Error::duplicate_field("apiKey"));
+							}
+							api_key__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(RotateApiKeyResponse { api_key: api_key__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.RotateApiKeyResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SignMessageRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.SignMessageRequest", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser
+			.serialize_field("message", pbjson::private::base64::encode(&self.message).as_str())?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SignMessageRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["message"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Message,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"message" => Ok(GeneratedField::Message),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SignMessageRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SignMessageRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignMessageRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut message__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Message => {
+							if message__.is_some() {
+								return Err(serde::de::Error::duplicate_field("message"));
+							}
+							message__ = Some(
+								map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(SignMessageRequest { message: message__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.SignMessageRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SignMessageResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.SignMessageResponse", len)?;
+		struct_ser.serialize_field("signature", &self.signature)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SignMessageResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["signature"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Signature,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"signature" => Ok(GeneratedField::Signature),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SignMessageResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SignMessageResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignMessageResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut signature__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Signature => {
+							if signature__.is_some() {
+								return Err(serde::de::Error::duplicate_field("signature"));
+							}
+							signature__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(SignMessageResponse { signature: signature__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.SignMessageResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpliceInRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.SpliceInRequest", len)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"spliceAmountSats",
+			ToString::to_string(&self.splice_amount_sats).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpliceInRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"splice_amount_sats",
+			"spliceAmountSats",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UserChannelId,
+			CounterpartyNodeId,
+			SpliceAmountSats,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"spliceAmountSats" | "splice_amount_sats" => {
+								Ok(GeneratedField::SpliceAmountSats)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpliceInRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SpliceInRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpliceInRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut splice_amount_sats__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::SpliceAmountSats => {
+							if splice_amount_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field("spliceAmountSats"));
+							}
+							splice_amount_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(SpliceInRequest {
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					splice_amount_sats: splice_amount_sats__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.SpliceInRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpliceInResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.SpliceInResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpliceInResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpliceInResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SpliceInResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpliceInResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(SpliceInResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.SpliceInResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpliceOutRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.address.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.SpliceOutRequest", len)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.address.as_ref() {
+			struct_ser.serialize_field("address", v)?;
+		}
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"spliceAmountSats",
+			ToString::to_string(&self.splice_amount_sats).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpliceOutRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"address",
+			"splice_amount_sats",
+			"spliceAmountSats",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UserChannelId,
+			CounterpartyNodeId,
+			Address,
+			SpliceAmountSats,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"address" => Ok(GeneratedField::Address),
+							"spliceAmountSats" | "splice_amount_sats" => {
+								Ok(GeneratedField::SpliceAmountSats)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpliceOutRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SpliceOutRequest")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpliceOutRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut address__ = None;
+				let mut splice_amount_sats__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Address => {
+							if address__.is_some() {
+								return Err(serde::de::Error::duplicate_field("address"));
+							}
+							address__ = map_.next_value()?;
+						},
+						GeneratedField::SpliceAmountSats => {
+							if splice_amount_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field("spliceAmountSats"));
+							}
+							splice_amount_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(SpliceOutRequest {
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					address: address__,
+					splice_amount_sats: splice_amount_sats__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.SpliceOutRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpliceOutResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.SpliceOutResponse", len)?;
+		struct_ser.serialize_field("address", &self.address)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpliceOutResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["address"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Address,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"address" => Ok(GeneratedField::Address),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpliceOutResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SpliceOutResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpliceOutResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut address__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Address => {
+							if address__.is_some() {
+								return Err(serde::de::Error::duplicate_field("address"));
+							}
+							address__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(SpliceOutResponse { address: address__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.SpliceOutResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpontaneousSendRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.route_parameters.is_some() {
+			len += 1;
+		}
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.SpontaneousSendRequest", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser
+			.serialize_field("amountMsat", ToString::to_string(&self.amount_msat).as_str())?;
+		struct_ser.serialize_field("nodeId", &self.node_id)?;
+		if let Some(v) = self.route_parameters.as_ref() {
+			struct_ser.serialize_field("routeParameters", v)?;
+		}
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpontaneousSendRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"amount_msat",
+			"amountMsat",
+			"node_id",
+			"nodeId",
+			"route_parameters",
+			"routeParameters",
+			"metadata",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			AmountMsat,
+			NodeId,
+			RouteParameters,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+							"routeParameters" | "route_parameters" => {
+								Ok(GeneratedField::RouteParameters)
+							},
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpontaneousSendRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SpontaneousSendRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<SpontaneousSendRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut amount_msat__ = None;
+				let mut node_id__ = None;
+				let mut route_parameters__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::NodeId => {
+							if node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeId"));
+							}
+							node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::RouteParameters => {
+							if route_parameters__.is_some() {
+								return Err(serde::de::Error::duplicate_field("routeParameters"));
+							}
+							route_parameters__ = map_.next_value()?;
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(SpontaneousSendRequest {
+					amount_msat: amount_msat__.unwrap_or_default(),
+					node_id: node_id__.unwrap_or_default(),
+					route_parameters: route_parameters__,
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.SpontaneousSendRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpontaneousSendResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.pending_approval_id.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.SpontaneousSendResponse", len)?;
+		struct_ser.serialize_field("paymentId", &self.payment_id)?;
+		if let Some(v) = self.pending_approval_id.as_ref() {
+			struct_ser.serialize_field("pendingApprovalId", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpontaneousSendResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["payment_id", "paymentId", "pending_approval_id", "pendingApprovalId"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PaymentId,
+			PendingApprovalId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"paymentId" | "payment_id" => Ok(GeneratedField::PaymentId),
+							"pendingApprovalId" | "pending_approval_id" => {
+								Ok(GeneratedField::PendingApprovalId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpontaneousSendResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SpontaneousSendResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<SpontaneousSendResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment_id__ = None;
+				let mut pending_approval_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PaymentId => {
+							if payment_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentId"));
+							}
+							payment_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PendingApprovalId => {
+							if pending_approval_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pendingApprovalId"));
+							}
+							pending_approval_id__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(SpontaneousSendResponse {
+					payment_id: payment_id__.unwrap_or_default(),
+					pending_approval_id: pending_approval_id__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.SpontaneousSendResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SubscribeEventsRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.SubscribeEventsRequest", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SubscribeEventsRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SubscribeEventsRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.SubscribeEventsRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<SubscribeEventsRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(SubscribeEventsRequest {})
+			}
+		}
+		deserializer.deserialize_struct("api.SubscribeEventsRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for UpdateChannelConfigRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.channel_config.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("api.UpdateChannelConfigRequest", len)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.channel_config.as_ref() {
+			struct_ser.serialize_field("channelConfig", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for UpdateChannelConfigRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"channel_config",
+			"channelConfig",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			UserChannelId,
+			CounterpartyNodeId,
+			ChannelConfig,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"channelConfig" | "channel_config" => Ok(GeneratedField::ChannelConfig),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = UpdateChannelConfigRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.UpdateChannelConfigRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<UpdateChannelConfigRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut channel_config__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ChannelConfig => {
+							if channel_config__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelConfig"));
+							}
+							channel_config__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(UpdateChannelConfigRequest {
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					channel_config: channel_config__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.UpdateChannelConfigRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for UpdateChannelConfigResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("api.UpdateChannelConfigResponse", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for UpdateChannelConfigResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = UpdateChannelConfigResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.UpdateChannelConfigResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<UpdateChannelConfigResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(UpdateChannelConfigResponse {})
+			}
+		}
+		deserializer.deserialize_struct("api.UpdateChannelConfigResponse", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for VerifySignatureRequest {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.VerifySignatureRequest", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser
+			.serialize_field("message", pbjson::private::base64::encode(&self.message).as_str())?;
+		struct_ser.serialize_field("signature", &self.signature)?;
+		struct_ser.serialize_field("publicKey", &self.public_key)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for VerifySignatureRequest {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["message", "signature", "public_key", "publicKey"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Message,
+			Signature,
+			PublicKey,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"message" => Ok(GeneratedField::Message),
+							"signature" => Ok(GeneratedField::Signature),
+							"publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = VerifySignatureRequest;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.VerifySignatureRequest")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<VerifySignatureRequest, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut message__ = None;
+				let mut signature__ = None;
+				let mut public_key__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Message => {
+							if message__.is_some() {
+								return Err(serde::de::Error::duplicate_field("message"));
+							}
+							message__ = Some(
+								map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Signature => {
+							if signature__.is_some() {
+								return Err(serde::de::Error::duplicate_field("signature"));
+							}
+							signature__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PublicKey => {
+							if public_key__.is_some() {
+								return Err(serde::de::Error::duplicate_field("publicKey"));
+							}
+							public_key__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(VerifySignatureRequest {
+					message: message__.unwrap_or_default(),
+					signature: signature__.unwrap_or_default(),
+					public_key: public_key__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("api.VerifySignatureRequest", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for VerifySignatureResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("api.VerifySignatureResponse", len)?;
+		struct_ser.serialize_field("valid", &self.valid)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for VerifySignatureResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["valid"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Valid,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"valid" => Ok(GeneratedField::Valid),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = VerifySignatureResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct api.VerifySignatureResponse")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<VerifySignatureResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut valid__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Valid => {
+							if valid__.is_some() {
+								return Err(serde::de::Error::duplicate_field("valid"));
+							}
+							valid__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(VerifySignatureResponse { valid: valid__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("api.VerifySignatureResponse", FIELDS, GeneratedVisitor)
+	}
+}
@@ -22,7 +22,10 @@ pub const CLOSE_CHANNEL_PATH: &str = "CloseChannel";
 pub const FORCE_CLOSE_CHANNEL_PATH: &str = "ForceCloseChannel";
 pub const LIST_CHANNELS_PATH: &str = "ListChannels";
 pub const LIST_PAYMENTS_PATH: &str = "ListPayments";
+pub const LIST_PAYMENTS_UPDATED_SINCE_PATH: &str = "ListPaymentsUpdatedSince";
+pub const LIST_PAYMENTS_BY_LABEL_PATH: &str = "ListPaymentsByLabel";
 pub const LIST_FORWARDED_PAYMENTS_PATH: &str = "ListForwardedPayments";
+pub const GET_FORWARDING_STATS_PATH: &str = "GetForwardingStats";
 pub const UPDATE_CHANNEL_CONFIG_PATH: &str = "UpdateChannelConfig";
 pub const GET_PAYMENT_DETAILS_PATH: &str = "GetPaymentDetails";
 pub const CONNECT_PEER_PATH: &str = "ConnectPeer";
@@ -35,3 +38,13 @@ pub const GRAPH_LIST_CHANNELS_PATH: &str = "GraphListChannels";
 pub const GRAPH_GET_CHANNEL_PATH: &str = "GraphGetChannel";
 pub const GRAPH_LIST_NODES_PATH: &str = "GraphListNodes";
 pub const GRAPH_GET_NODE_PATH: &str = "GraphGetNode";
+pub const CREATE_API_KEY_PATH: &str = "CreateApiKey";
+pub const LIST_API_KEYS_PATH: &str = "ListApiKeys";
+pub const REVOKE_API_KEY_PATH: &str = "RevokeApiKey";
+pub const ROTATE_API_KEY_PATH: &str = "RotateApiKey";
+pub const LIST_PENDING_APPROVALS_PATH: &str = "ListPendingApprovals";
+pub const APPROVE_PAYMENT_PATH: &str = "ApprovePayment";
+pub const REJECT_PAYMENT_PATH: &str = "RejectPayment";
+pub const LIST_AUDIT_LOG_PATH: &str = "ListAuditLog";
+pub const LIST_EVENTS_PATH: &str = "ListEvents";
+pub const SUBSCRIBE_EVENTS_PATH: &str = "SubscribeEvents";
@@ -9,8 +9,6 @@
 
 /// When HttpStatusCode is not ok (200), the response `content` contains a serialized `ErrorResponse`
 /// with the relevant ErrorCode and `message`
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ErrorResponse {
@@ -28,8 +26,6 @@ pub struct ErrorResponse {
 	#[prost(enumeration = "ErrorCode", tag = "2")]
 	pub error_code: i32,
 }
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum ErrorCode {
@@ -50,6 +46,9 @@ pub enum ErrorCode {
 	LightningError = 3,
 	/// Used when an internal server error occurred. The client is probably at no fault.
 	InternalServerError = 4,
+	/// Used when a payment would exceed the per-payment limit or daily budget configured for the
+	/// server or the API key used.
+	SpendingLimitExceededError = 5,
 }
 impl ErrorCode {
 	/// String value of the enum field names used in the ProtoBuf definition.
@@ -63,6 +62,7 @@ impl ErrorCode {
 			ErrorCode::AuthError => "AUTH_ERROR",
 			ErrorCode::LightningError => "LIGHTNING_ERROR",
 			ErrorCode::InternalServerError => "INTERNAL_SERVER_ERROR",
+			ErrorCode::SpendingLimitExceededError => "SPENDING_LIMIT_EXCEEDED_ERROR",
 		}
 	}
 	/// Creates an enum from field names used in the ProtoBuf definition.
@@ -73,6 +73,7 @@ impl ErrorCode {
 			"AUTH_ERROR" => Some(Self::AuthError),
 			"LIGHTNING_ERROR" => Some(Self::LightningError),
 			"INTERNAL_SERVER_ERROR" => Some(Self::InternalServerError),
+			"SPENDING_LIMIT_EXCEEDED_ERROR" => Some(Self::SpendingLimitExceededError),
 			_ => None,
 		}
 	}
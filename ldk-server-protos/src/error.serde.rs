@@ -0,0 +1,190 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+impl serde::Serialize for ErrorCode {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let variant = match self {
+			Self::UnknownError => "UNKNOWN_ERROR",
+			Self::InvalidRequestError => "INVALID_REQUEST_ERROR",
+			Self::AuthError => "AUTH_ERROR",
+			Self::LightningError => "LIGHTNING_ERROR",
+			Self::InternalServerError => "INTERNAL_SERVER_ERROR",
+			Self::SpendingLimitExceededError => "SPENDING_LIMIT_EXCEEDED_ERROR",
+		};
+		serializer.serialize_str(variant)
+	}
+}
+impl<'de> serde::Deserialize<'de> for ErrorCode {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"UNKNOWN_ERROR",
+			"INVALID_REQUEST_ERROR",
+			"AUTH_ERROR",
+			"LIGHTNING_ERROR",
+			"INTERNAL_SERVER_ERROR",
+			"SPENDING_LIMIT_EXCEEDED_ERROR",
+		];
+
+		struct GeneratedVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ErrorCode;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(formatter, "expected one of: {:?}", &FIELDS)
+			}
+
+			fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(ErrorCode::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+				})
+			}
+
+			fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(ErrorCode::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+				})
+			}
+
+			fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				match value {
+					"UNKNOWN_ERROR" => Ok(ErrorCode::UnknownError),
+					"INVALID_REQUEST_ERROR" => Ok(ErrorCode::InvalidRequestError),
+					"AUTH_ERROR" => Ok(ErrorCode::AuthError),
+					"LIGHTNING_ERROR" => Ok(ErrorCode::LightningError),
+					"INTERNAL_SERVER_ERROR" => Ok(ErrorCode::InternalServerError),
+					"SPENDING_LIMIT_EXCEEDED_ERROR" => Ok(ErrorCode::SpendingLimitExceededError),
+					_ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+				}
+			}
+		}
+		deserializer.deserialize_any(GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ErrorResponse {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("error.ErrorResponse", len)?;
+		struct_ser.serialize_field("message", &self.message)?;
+		let v = ErrorCode::from_i32(self.error_code).ok_or_else(|| {
+			serde::ser::Error::custom(format!("Invalid variant {}", self.error_code))
+		})?;
+		struct_ser.serialize_field("errorCode", &v)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ErrorResponse {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["message", "error_code", "errorCode"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Message,
+			ErrorCode,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"message" => Ok(GeneratedField::Message),
+							"errorCode" | "error_code" => Ok(GeneratedField::ErrorCode),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ErrorResponse;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct error.ErrorResponse")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ErrorResponse, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut message__ = None;
+				let mut error_code__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Message => {
+							if message__.is_some() {
+								return Err(serde::de::Error::duplicate_field("message"));
+							}
+							message__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ErrorCode => {
+							if error_code__.is_some() {
+								return Err(serde::de::Error::duplicate_field("errorCode"));
+							}
+							error_code__ = Some(map_.next_value::<ErrorCode>()? as i32);
+						},
+					}
+				}
+				Ok(ErrorResponse {
+					message: message__.unwrap_or_default(),
+					error_code: error_code__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("error.ErrorResponse", FIELDS, GeneratedVisitor)
+	}
+}
@@ -8,18 +8,28 @@
 // licenses.
 
 /// EventEnvelope wraps different event types in a single message to be used by EventPublisher.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventEnvelope {
-	#[prost(oneof = "event_envelope::Event", tags = "2, 3, 4, 6")]
+	#[prost(oneof = "event_envelope::Event", tags = "2, 3, 4, 6, 7, 8, 9, 10, 11")]
 	pub event: ::core::option::Option<event_envelope::Event>,
+	/// The sequence number of the event, increasing by one with every event emitted by the node.
+	/// Consumers may use it to order events and to detect missed ones.
+	#[prost(uint64, tag = "12")]
+	pub sequence_number: u64,
+	/// The unique identifier of the event. Redeliveries of an event carry the same `event_id`, which
+	/// consumers may use to deduplicate events.
+	#[prost(string, tag = "13")]
+	pub event_id: ::prost::alloc::string::String,
+	/// The time at which the event was emitted, in seconds since the UNIX epoch.
+	#[prost(uint64, tag = "14")]
+	pub timestamp: u64,
+	/// The hex-encoded node id of the node which emitted the event.
+	#[prost(string, tag = "15")]
+	pub node_id: ::prost::alloc::string::String,
 }
 /// Nested message and enum types in `EventEnvelope`.
 pub mod event_envelope {
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum Event {
@@ -31,11 +41,19 @@ pub mod event_envelope {
 		PaymentFailed(super::PaymentFailed),
 		#[prost(message, tag = "6")]
 		PaymentForwarded(super::PaymentForwarded),
+		#[prost(message, tag = "7")]
+		ChannelPending(super::ChannelPending),
+		#[prost(message, tag = "8")]
+		ChannelReady(super::ChannelReady),
+		#[prost(message, tag = "9")]
+		ChannelClosed(super::ChannelClosed),
+		#[prost(message, tag = "10")]
+		SplicePending(super::SplicePending),
+		#[prost(message, tag = "11")]
+		SpliceFailed(super::SpliceFailed),
 	}
 }
 /// PaymentReceived indicates a payment has been received.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PaymentReceived {
@@ -44,8 +62,6 @@ pub struct PaymentReceived {
 	pub payment: ::core::option::Option<super::types::Payment>,
 }
 /// PaymentSuccessful indicates a sent payment was successful.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PaymentSuccessful {
@@ -54,8 +70,6 @@ pub struct PaymentSuccessful {
 	pub payment: ::core::option::Option<super::types::Payment>,
 }
 /// PaymentFailed indicates a sent payment has failed.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PaymentFailed {
@@ -64,11 +78,95 @@ pub struct PaymentFailed {
 	pub payment: ::core::option::Option<super::types::Payment>,
 }
 /// PaymentForwarded indicates a payment was forwarded through the node.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PaymentForwarded {
 	#[prost(message, optional, tag = "1")]
 	pub forwarded_payment: ::core::option::Option<super::types::ForwardedPayment>,
 }
+/// ChannelPending indicates a channel was created and its funding transaction is pending confirmation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelPending {
+	/// The hex-encoded channel id.
+	#[prost(string, tag = "1")]
+	pub channel_id: ::prost::alloc::string::String,
+	/// The local `user_channel_id` of the channel.
+	#[prost(string, tag = "2")]
+	pub user_channel_id: ::prost::alloc::string::String,
+	/// The hex-encoded node id of the channel counterparty.
+	#[prost(string, tag = "3")]
+	pub counterparty_node_id: ::prost::alloc::string::String,
+	/// The outpoint of the channel's funding transaction.
+	#[prost(message, optional, tag = "4")]
+	pub funding_txo: ::core::option::Option<super::types::OutPoint>,
+}
+/// ChannelReady indicates a channel is ready to be used.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelReady {
+	/// The hex-encoded channel id.
+	#[prost(string, tag = "1")]
+	pub channel_id: ::prost::alloc::string::String,
+	/// The local `user_channel_id` of the channel.
+	#[prost(string, tag = "2")]
+	pub user_channel_id: ::prost::alloc::string::String,
+	/// The hex-encoded node id of the channel counterparty.
+	#[prost(string, optional, tag = "3")]
+	pub counterparty_node_id: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// ChannelClosed indicates a channel was closed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelClosed {
+	/// The hex-encoded channel id.
+	#[prost(string, tag = "1")]
+	pub channel_id: ::prost::alloc::string::String,
+	/// The local `user_channel_id` of the channel.
+	#[prost(string, tag = "2")]
+	pub user_channel_id: ::prost::alloc::string::String,
+	/// The hex-encoded node id of the channel counterparty.
+	#[prost(string, optional, tag = "3")]
+	pub counterparty_node_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// A human-readable description of why the channel was closed, if known.
+	#[prost(string, optional, tag = "4")]
+	pub reason: ::core::option::Option<::prost::alloc::string::String>,
+	/// Whether the channel was force-closed by either us or the counterparty, i.e., closed by
+	/// broadcasting a commitment transaction rather than cooperatively.
+	#[prost(bool, tag = "5")]
+	pub force_closed: bool,
+}
+/// SplicePending indicates a splice of a channel was negotiated and its transaction is pending confirmation.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SplicePending {
+	/// The hex-encoded channel id.
+	#[prost(string, tag = "1")]
+	pub channel_id: ::prost::alloc::string::String,
+	/// The local `user_channel_id` of the channel.
+	#[prost(string, tag = "2")]
+	pub user_channel_id: ::prost::alloc::string::String,
+	/// The hex-encoded node id of the channel counterparty.
+	#[prost(string, tag = "3")]
+	pub counterparty_node_id: ::prost::alloc::string::String,
+	/// The outpoint of the channel's new funding transaction.
+	#[prost(message, optional, tag = "4")]
+	pub new_funding_txo: ::core::option::Option<super::types::OutPoint>,
+}
+/// SpliceFailed indicates a splice of a channel failed, leaving the channel's funding unchanged.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpliceFailed {
+	/// The hex-encoded channel id.
+	#[prost(string, tag = "1")]
+	pub channel_id: ::prost::alloc::string::String,
+	/// The local `user_channel_id` of the channel.
+	#[prost(string, tag = "2")]
+	pub user_channel_id: ::prost::alloc::string::String,
+	/// The hex-encoded node id of the channel counterparty.
+	#[prost(string, tag = "3")]
+	pub counterparty_node_id: ::prost::alloc::string::String,
+	/// The outpoint of the abandoned funding transaction of the splice, if one was negotiated.
+	#[prost(message, optional, tag = "4")]
+	pub abandoned_funding_txo: ::core::option::Option<super::types::OutPoint>,
+}
@@ -0,0 +1,1380 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+impl serde::Serialize for ChannelClosed {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.counterparty_node_id.is_some() {
+			len += 1;
+		}
+		if self.reason.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("events.ChannelClosed", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		if let Some(v) = self.counterparty_node_id.as_ref() {
+			struct_ser.serialize_field("counterpartyNodeId", v)?;
+		}
+		if let Some(v) = self.reason.as_ref() {
+			struct_ser.serialize_field("reason", v)?;
+		}
+		struct_ser.serialize_field("forceClosed", &self.force_closed)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ChannelClosed {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"reason",
+			"force_closed",
+			"forceClosed",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			UserChannelId,
+			CounterpartyNodeId,
+			Reason,
+			ForceClosed,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"reason" => Ok(GeneratedField::Reason),
+							"forceClosed" | "force_closed" => Ok(GeneratedField::ForceClosed),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ChannelClosed;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.ChannelClosed")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChannelClosed, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut reason__ = None;
+				let mut force_closed__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = map_.next_value()?;
+						},
+						GeneratedField::Reason => {
+							if reason__.is_some() {
+								return Err(serde::de::Error::duplicate_field("reason"));
+							}
+							reason__ = map_.next_value()?;
+						},
+						GeneratedField::ForceClosed => {
+							if force_closed__.is_some() {
+								return Err(serde::de::Error::duplicate_field("forceClosed"));
+							}
+							force_closed__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ChannelClosed {
+					channel_id: channel_id__.unwrap_or_default(),
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__,
+					reason: reason__,
+					force_closed: force_closed__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("events.ChannelClosed", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ChannelPending {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.funding_txo.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.ChannelPending", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.funding_txo.as_ref() {
+			struct_ser.serialize_field("fundingTxo", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ChannelPending {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"funding_txo",
+			"fundingTxo",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			UserChannelId,
+			CounterpartyNodeId,
+			FundingTxo,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"fundingTxo" | "funding_txo" => Ok(GeneratedField::FundingTxo),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ChannelPending;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.ChannelPending")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChannelPending, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut funding_txo__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::FundingTxo => {
+							if funding_txo__.is_some() {
+								return Err(serde::de::Error::duplicate_field("fundingTxo"));
+							}
+							funding_txo__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ChannelPending {
+					channel_id: channel_id__.unwrap_or_default(),
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					funding_txo: funding_txo__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("events.ChannelPending", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ChannelReady {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.counterparty_node_id.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.ChannelReady", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		if let Some(v) = self.counterparty_node_id.as_ref() {
+			struct_ser.serialize_field("counterpartyNodeId", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ChannelReady {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			UserChannelId,
+			CounterpartyNodeId,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ChannelReady;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.ChannelReady")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChannelReady, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(ChannelReady {
+					channel_id: channel_id__.unwrap_or_default(),
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("events.ChannelReady", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for EventEnvelope {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.event.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.EventEnvelope", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"sequenceNumber",
+			ToString::to_string(&self.sequence_number).as_str(),
+		)?;
+		struct_ser.serialize_field("eventId", &self.event_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+		struct_ser.serialize_field("nodeId", &self.node_id)?;
+		if let Some(v) = self.event.as_ref() {
+			match v {
+				event_envelope::Event::PaymentReceived(v) => {
+					struct_ser.serialize_field("paymentReceived", v)?;
+				},
+				event_envelope::Event::PaymentSuccessful(v) => {
+					struct_ser.serialize_field("paymentSuccessful", v)?;
+				},
+				event_envelope::Event::PaymentFailed(v) => {
+					struct_ser.serialize_field("paymentFailed", v)?;
+				},
+				event_envelope::Event::PaymentForwarded(v) => {
+					struct_ser.serialize_field("paymentForwarded", v)?;
+				},
+				event_envelope::Event::ChannelPending(v) => {
+					struct_ser.serialize_field("channelPending", v)?;
+				},
+				event_envelope::Event::ChannelReady(v) => {
+					struct_ser.serialize_field("channelReady", v)?;
+				},
+				event_envelope::Event::ChannelClosed(v) => {
+					struct_ser.serialize_field("channelClosed", v)?;
+				},
+				event_envelope::Event::SplicePending(v) => {
+					struct_ser.serialize_field("splicePending", v)?;
+				},
+				event_envelope::Event::SpliceFailed(v) => {
+					struct_ser.serialize_field("spliceFailed", v)?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for EventEnvelope {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"sequence_number",
+			"sequenceNumber",
+			"event_id",
+			"eventId",
+			"timestamp",
+			"node_id",
+			"nodeId",
+			"payment_received",
+			"paymentReceived",
+			"payment_successful",
+			"paymentSuccessful",
+			"payment_failed",
+			"paymentFailed",
+			"payment_forwarded",
+			"paymentForwarded",
+			"channel_pending",
+			"channelPending",
+			"channel_ready",
+			"channelReady",
+			"channel_closed",
+			"channelClosed",
+			"splice_pending",
+			"splicePending",
+			"splice_failed",
+			"spliceFailed",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			SequenceNumber,
+			EventId,
+			Timestamp,
+			NodeId,
+			PaymentReceived,
+			PaymentSuccessful,
+			PaymentFailed,
+			PaymentForwarded,
+			ChannelPending,
+			ChannelReady,
+			ChannelClosed,
+			SplicePending,
+			SpliceFailed,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"sequenceNumber" | "sequence_number" => {
+								Ok(GeneratedField::SequenceNumber)
+							},
+							"eventId" | "event_id" => Ok(GeneratedField::EventId),
+							"timestamp" => Ok(GeneratedField::Timestamp),
+							"nodeId" | "node_id" => Ok(GeneratedField::NodeId),
+							"paymentReceived" | "payment_received" => {
+								Ok(GeneratedField::PaymentReceived)
+							},
+							"paymentSuccessful" | "payment_successful" => {
+								Ok(GeneratedField::PaymentSuccessful)
+							},
+							"paymentFailed" | "payment_failed" => Ok(GeneratedField::PaymentFailed),
+							"paymentForwarded" | "payment_forwarded" => {
+								Ok(GeneratedField::PaymentForwarded)
+							},
+							"channelPending" | "channel_pending" => {
+								Ok(GeneratedField::ChannelPending)
+							},
+							"channelReady" | "channel_ready" => Ok(GeneratedField::ChannelReady),
+							"channelClosed" | "channel_closed" => Ok(GeneratedField::ChannelClosed),
+							"splicePending" | "splice_pending" => Ok(GeneratedField::SplicePending),
+							"spliceFailed" | "splice_failed" => Ok(GeneratedField::SpliceFailed),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = EventEnvelope;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.EventEnvelope")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventEnvelope, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut sequence_number__ = None;
+				let mut event_id__ = None;
+				let mut timestamp__ = None;
+				let mut node_id__ = None;
+				let mut event__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::SequenceNumber => {
+							if sequence_number__.is_some() {
+								return Err(serde::de::Error::duplicate_field("sequenceNumber"));
+							}
+							sequence_number__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::EventId => {
+							if event_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("eventId"));
+							}
+							event_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Timestamp => {
+							if timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("timestamp"));
+							}
+							timestamp__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::NodeId => {
+							if node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeId"));
+							}
+							node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PaymentReceived => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentReceived"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::PaymentReceived);
+						},
+						GeneratedField::PaymentSuccessful => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentSuccessful"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::PaymentSuccessful);
+						},
+						GeneratedField::PaymentFailed => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentFailed"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::PaymentFailed);
+						},
+						GeneratedField::PaymentForwarded => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentForwarded"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::PaymentForwarded);
+						},
+						GeneratedField::ChannelPending => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelPending"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::ChannelPending);
+						},
+						GeneratedField::ChannelReady => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelReady"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::ChannelReady);
+						},
+						GeneratedField::ChannelClosed => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelClosed"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::ChannelClosed);
+						},
+						GeneratedField::SplicePending => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("splicePending"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::SplicePending);
+						},
+						GeneratedField::SpliceFailed => {
+							if event__.is_some() {
+								return Err(serde::de::Error::duplicate_field("spliceFailed"));
+							}
+							event__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(event_envelope::Event::SpliceFailed);
+						},
+					}
+				}
+				Ok(EventEnvelope {
+					sequence_number: sequence_number__.unwrap_or_default(),
+					event_id: event_id__.unwrap_or_default(),
+					timestamp: timestamp__.unwrap_or_default(),
+					node_id: node_id__.unwrap_or_default(),
+					event: event__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("events.EventEnvelope", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentFailed {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.payment.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.PaymentFailed", len)?;
+		if let Some(v) = self.payment.as_ref() {
+			struct_ser.serialize_field("payment", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentFailed {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payment"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payment,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payment" => Ok(GeneratedField::Payment),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentFailed;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.PaymentFailed")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentFailed, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payment => {
+							if payment__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payment"));
+							}
+							payment__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(PaymentFailed { payment: payment__ })
+			}
+		}
+		deserializer.deserialize_struct("events.PaymentFailed", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentForwarded {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.forwarded_payment.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.PaymentForwarded", len)?;
+		if let Some(v) = self.forwarded_payment.as_ref() {
+			struct_ser.serialize_field("forwardedPayment", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentForwarded {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["forwarded_payment", "forwardedPayment"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ForwardedPayment,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"forwardedPayment" | "forwarded_payment" => {
+								Ok(GeneratedField::ForwardedPayment)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentForwarded;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.PaymentForwarded")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentForwarded, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut forwarded_payment__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ForwardedPayment => {
+							if forwarded_payment__.is_some() {
+								return Err(serde::de::Error::duplicate_field("forwardedPayment"));
+							}
+							forwarded_payment__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(PaymentForwarded { forwarded_payment: forwarded_payment__ })
+			}
+		}
+		deserializer.deserialize_struct("events.PaymentForwarded", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentReceived {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.payment.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.PaymentReceived", len)?;
+		if let Some(v) = self.payment.as_ref() {
+			struct_ser.serialize_field("payment", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentReceived {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payment"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payment,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payment" => Ok(GeneratedField::Payment),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentReceived;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.PaymentReceived")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentReceived, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payment => {
+							if payment__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payment"));
+							}
+							payment__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(PaymentReceived { payment: payment__ })
+			}
+		}
+		deserializer.deserialize_struct("events.PaymentReceived", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentSuccessful {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.payment.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.PaymentSuccessful", len)?;
+		if let Some(v) = self.payment.as_ref() {
+			struct_ser.serialize_field("payment", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentSuccessful {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["payment"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Payment,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"payment" => Ok(GeneratedField::Payment),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentSuccessful;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.PaymentSuccessful")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentSuccessful, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut payment__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Payment => {
+							if payment__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payment"));
+							}
+							payment__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(PaymentSuccessful { payment: payment__ })
+			}
+		}
+		deserializer.deserialize_struct("events.PaymentSuccessful", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SpliceFailed {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.abandoned_funding_txo.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.SpliceFailed", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.abandoned_funding_txo.as_ref() {
+			struct_ser.serialize_field("abandonedFundingTxo", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SpliceFailed {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"abandoned_funding_txo",
+			"abandonedFundingTxo",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			UserChannelId,
+			CounterpartyNodeId,
+			AbandonedFundingTxo,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"abandonedFundingTxo" | "abandoned_funding_txo" => {
+								Ok(GeneratedField::AbandonedFundingTxo)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SpliceFailed;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.SpliceFailed")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpliceFailed, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut abandoned_funding_txo__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AbandonedFundingTxo => {
+							if abandoned_funding_txo__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"abandonedFundingTxo",
+								));
+							}
+							abandoned_funding_txo__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(SpliceFailed {
+					channel_id: channel_id__.unwrap_or_default(),
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					abandoned_funding_txo: abandoned_funding_txo__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("events.SpliceFailed", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for SplicePending {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.new_funding_txo.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("events.SplicePending", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.new_funding_txo.as_ref() {
+			struct_ser.serialize_field("newFundingTxo", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for SplicePending {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"user_channel_id",
+			"userChannelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"new_funding_txo",
+			"newFundingTxo",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			UserChannelId,
+			CounterpartyNodeId,
+			NewFundingTxo,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"newFundingTxo" | "new_funding_txo" => {
+								Ok(GeneratedField::NewFundingTxo)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = SplicePending;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct events.SplicePending")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<SplicePending, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut user_channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut new_funding_txo__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NewFundingTxo => {
+							if new_funding_txo__.is_some() {
+								return Err(serde::de::Error::duplicate_field("newFundingTxo"));
+							}
+							new_funding_txo__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(SplicePending {
+					channel_id: channel_id__.unwrap_or_default(),
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					new_funding_txo: new_funding_txo__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("events.SplicePending", FIELDS, GeneratedVisitor)
+	}
+}
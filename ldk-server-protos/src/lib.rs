@@ -7,10 +7,32 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
-pub mod api;
+//! The messages of the LDK Server API, generated from the `.proto` definitions in `src/proto`.
+//!
+//! With the `serde` feature, all messages implement `serde::Serialize` and `serde::Deserialize`
+//! following the canonical proto3 JSON mapping.
+
+pub mod api {
+	include!("api.rs");
+	#[cfg(feature = "serde")]
+	include!("api.serde.rs");
+}
 pub mod endpoints;
-pub mod error;
-pub mod events;
-#[cfg(feature = "serde")]
-pub mod serde_utils;
-pub mod types;
+pub mod error {
+	include!("error.rs");
+	#[cfg(feature = "serde")]
+	include!("error.serde.rs");
+}
+pub mod events {
+	include!("events.rs");
+	#[cfg(feature = "serde")]
+	include!("events.serde.rs");
+}
+pub mod types {
+	include!("types.rs");
+	#[cfg(feature = "serde")]
+	include!("types.serde.rs");
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests;
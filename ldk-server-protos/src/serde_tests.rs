@@ -0,0 +1,646 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+//! Tests of the JSON mapping of the messages, as implemented with the `serde` feature.
+
+use std::fmt::Debug;
+
+use prost::bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{api, error, events, types};
+
+/// Asserts that `message` reads back from its JSON representation unchanged.
+fn assert_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(message: T) {
+	let json = serde_json::to_string(&message).unwrap();
+	let decoded: T = serde_json::from_str(&json).unwrap_or_else(|e| panic!("{e}: {json}"));
+	assert_eq!(decoded, message, "{json}");
+}
+
+#[test]
+fn payment_json() {
+	let payment = types::Payment {
+		id: "payment_id".to_string(),
+		kind: Some(types::PaymentKind {
+			kind: Some(types::payment_kind::Kind::Bolt11(types::Bolt11 {
+				hash: "hash".to_string(),
+				preimage: None,
+				secret: Some(Bytes::from_static(&[0xab; 4])),
+			})),
+		}),
+		amount_msat: Some(u64::MAX),
+		direction: types::PaymentDirection::Outbound as i32,
+		status: types::PaymentStatus::Succeeded as i32,
+		..Default::default()
+	};
+	let json = serde_json::to_value(&payment).unwrap();
+	assert_eq!(json["amountMsat"], "18446744073709551615");
+	assert_eq!(json["direction"], "OUTBOUND");
+	assert_eq!(json["status"], "SUCCEEDED");
+	assert_eq!(json["kind"]["bolt11"]["secret"], "q6urqw==");
+	assert_round_trip(payment);
+}
+
+#[test]
+fn api_messages_round_trip() {
+	assert_round_trip(api::GetNodeInfoRequest {});
+	assert_round_trip(api::GetNodeInfoResponse {
+		node_id: "node_id".to_string(),
+		current_best_block: Some(types::BestBlock::default()),
+		latest_lightning_wallet_sync_timestamp: Some(u64::MAX),
+		latest_onchain_wallet_sync_timestamp: Some(u64::MAX),
+		latest_fee_rate_cache_update_timestamp: Some(u64::MAX),
+		latest_rgs_snapshot_timestamp: Some(u64::MAX),
+		latest_node_announcement_broadcast_timestamp: Some(u64::MAX),
+		listening_addresses: vec!["listening_addresses".to_string()],
+		announcement_addresses: vec!["announcement_addresses".to_string()],
+		node_alias: Some("node_alias".to_string()),
+		node_uris: vec!["node_uris".to_string()],
+	});
+	assert_round_trip(api::OnchainReceiveRequest {});
+	assert_round_trip(api::OnchainReceiveResponse { address: "address".to_string() });
+	assert_round_trip(api::OnchainSendRequest {
+		address: "address".to_string(),
+		amount_sats: Some(u64::MAX),
+		send_all: Some(true),
+		fee_rate_sat_per_vb: Some(u64::MAX),
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(api::OnchainSendResponse {
+		txid: "txid".to_string(),
+		pending_approval_id: Some("pending_approval_id".to_string()),
+	});
+	assert_round_trip(api::Bolt11ReceiveRequest {
+		amount_msat: Some(u64::MAX),
+		description: Some(types::Bolt11InvoiceDescription::default()),
+		expiry_secs: u32::MAX,
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(api::Bolt11ReceiveResponse { invoice: "invoice".to_string() });
+	assert_round_trip(api::Bolt11SendRequest {
+		invoice: "invoice".to_string(),
+		amount_msat: Some(u64::MAX),
+		route_parameters: Some(types::RouteParametersConfig::default()),
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(api::Bolt11SendResponse {
+		payment_id: "payment_id".to_string(),
+		pending_approval_id: Some("pending_approval_id".to_string()),
+	});
+	assert_round_trip(api::Bolt12ReceiveRequest {
+		description: "description".to_string(),
+		amount_msat: Some(u64::MAX),
+		expiry_secs: Some(u32::MAX),
+		quantity: Some(u64::MAX),
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(api::Bolt12ReceiveResponse { offer: "offer".to_string() });
+	assert_round_trip(api::Bolt12SendRequest {
+		offer: "offer".to_string(),
+		amount_msat: Some(u64::MAX),
+		quantity: Some(u64::MAX),
+		payer_note: Some("payer_note".to_string()),
+		route_parameters: Some(types::RouteParametersConfig::default()),
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(api::Bolt12SendResponse {
+		payment_id: "payment_id".to_string(),
+		pending_approval_id: Some("pending_approval_id".to_string()),
+	});
+	assert_round_trip(api::SpontaneousSendRequest {
+		amount_msat: u64::MAX,
+		node_id: "node_id".to_string(),
+		route_parameters: Some(types::RouteParametersConfig::default()),
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(api::SpontaneousSendResponse {
+		payment_id: "payment_id".to_string(),
+		pending_approval_id: Some("pending_approval_id".to_string()),
+	});
+	assert_round_trip(api::OpenChannelRequest {
+		node_pubkey: "node_pubkey".to_string(),
+		address: "address".to_string(),
+		channel_amount_sats: u64::MAX,
+		push_to_counterparty_msat: Some(u64::MAX),
+		channel_config: Some(types::ChannelConfig::default()),
+		announce_channel: true,
+	});
+	assert_round_trip(api::OpenChannelResponse { user_channel_id: "user_channel_id".to_string() });
+	assert_round_trip(api::SpliceInRequest {
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		splice_amount_sats: u64::MAX,
+	});
+	assert_round_trip(api::SpliceInResponse {});
+	assert_round_trip(api::SpliceOutRequest {
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		address: Some("address".to_string()),
+		splice_amount_sats: u64::MAX,
+	});
+	assert_round_trip(api::SpliceOutResponse { address: "address".to_string() });
+	assert_round_trip(api::UpdateChannelConfigRequest {
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		channel_config: Some(types::ChannelConfig::default()),
+	});
+	assert_round_trip(api::UpdateChannelConfigResponse {});
+	assert_round_trip(api::CloseChannelRequest {
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+	});
+	assert_round_trip(api::CloseChannelResponse {});
+	assert_round_trip(api::ForceCloseChannelRequest {
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		force_close_reason: Some("force_close_reason".to_string()),
+	});
+	assert_round_trip(api::ForceCloseChannelResponse {});
+	assert_round_trip(api::ListChannelsRequest {});
+	assert_round_trip(api::ListChannelsResponse { channels: vec![types::Channel::default()] });
+	assert_round_trip(api::GetPaymentDetailsRequest { payment_id: "payment_id".to_string() });
+	assert_round_trip(api::GetPaymentDetailsResponse { payment: Some(types::Payment::default()) });
+	assert_round_trip(api::ListPaymentsRequest {
+		page_token: Some(types::PageToken::default()),
+		status: Some(types::PaymentStatus::Failed as i32),
+		direction: Some(types::PaymentDirection::Outbound as i32),
+		kind: Some(types::PaymentKindType::Spontaneous as i32),
+		min_creation_timestamp: Some(u64::MAX),
+		max_creation_timestamp: Some(u64::MAX),
+		min_latest_update_timestamp: Some(u64::MAX),
+		max_latest_update_timestamp: Some(u64::MAX),
+	});
+	assert_round_trip(api::ListPaymentsResponse {
+		payments: vec![types::Payment::default()],
+		next_page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::ListPaymentsUpdatedSinceRequest {
+		updated_since: u64::MAX,
+		page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::ListPaymentsUpdatedSinceResponse {
+		payments: vec![types::Payment::default()],
+		next_page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::ListPaymentsByLabelRequest {
+		label: "label".to_string(),
+		page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::ListPaymentsByLabelResponse {
+		payments: vec![types::Payment::default()],
+		next_page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::ListForwardedPaymentsRequest {
+		page_token: Some(types::PageToken::default()),
+		prev_channel_id: Some("prev_channel_id".to_string()),
+		next_channel_id: Some("next_channel_id".to_string()),
+		prev_node_id: Some("prev_node_id".to_string()),
+		next_node_id: Some("next_node_id".to_string()),
+		min_timestamp: Some(u64::MAX),
+		max_timestamp: Some(u64::MAX),
+	});
+	assert_round_trip(api::ListForwardedPaymentsResponse {
+		forwarded_payments: vec![types::ForwardedPayment::default()],
+		next_page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::GetForwardingStatsRequest {
+		prev_channel_id: Some("prev_channel_id".to_string()),
+		next_channel_id: Some("next_channel_id".to_string()),
+		prev_node_id: Some("prev_node_id".to_string()),
+		next_node_id: Some("next_node_id".to_string()),
+		min_timestamp: Some(u64::MAX),
+		max_timestamp: Some(u64::MAX),
+	});
+	assert_round_trip(api::GetForwardingStatsResponse {
+		forwarded_payments_count: u64::MAX,
+		outbound_amount_forwarded_msat: u64::MAX,
+		total_fee_earned_msat: u64::MAX,
+		channel_pairs: vec![types::ChannelPairForwardingStats::default()],
+		days: vec![types::DailyForwardingStats::default()],
+	});
+	assert_round_trip(api::SignMessageRequest { message: Bytes::from_static(&[0, 1, 254, 255]) });
+	assert_round_trip(api::SignMessageResponse { signature: "signature".to_string() });
+	assert_round_trip(api::VerifySignatureRequest {
+		message: Bytes::from_static(&[0, 1, 254, 255]),
+		signature: "signature".to_string(),
+		public_key: "public_key".to_string(),
+	});
+	assert_round_trip(api::VerifySignatureResponse { valid: true });
+	assert_round_trip(api::ExportPathfindingScoresRequest {});
+	assert_round_trip(api::ExportPathfindingScoresResponse {
+		scores: Bytes::from_static(&[0, 1, 254, 255]),
+	});
+	assert_round_trip(api::GetBalancesRequest {});
+	assert_round_trip(api::GetBalancesResponse {
+		total_onchain_balance_sats: u64::MAX,
+		spendable_onchain_balance_sats: u64::MAX,
+		total_anchor_channels_reserve_sats: u64::MAX,
+		total_lightning_balance_sats: u64::MAX,
+		lightning_balances: vec![types::LightningBalance::default()],
+		pending_balances_from_channel_closures: vec![types::PendingSweepBalance::default()],
+	});
+	assert_round_trip(api::ConnectPeerRequest {
+		node_pubkey: "node_pubkey".to_string(),
+		address: "address".to_string(),
+		persist: true,
+	});
+	assert_round_trip(api::ConnectPeerResponse {});
+	assert_round_trip(api::DisconnectPeerRequest { node_pubkey: "node_pubkey".to_string() });
+	assert_round_trip(api::DisconnectPeerResponse {});
+	assert_round_trip(api::GraphListChannelsRequest {});
+	assert_round_trip(api::GraphListChannelsResponse { short_channel_ids: vec![u64::MAX] });
+	assert_round_trip(api::GraphGetChannelRequest { short_channel_id: u64::MAX });
+	assert_round_trip(api::GraphGetChannelResponse {
+		channel: Some(types::GraphChannel::default()),
+	});
+	assert_round_trip(api::GraphListNodesRequest {});
+	assert_round_trip(api::GraphListNodesResponse { node_ids: vec!["node_ids".to_string()] });
+	assert_round_trip(api::GraphGetNodeRequest { node_id: "node_id".to_string() });
+	assert_round_trip(api::GraphGetNodeResponse { node: Some(types::GraphNode::default()) });
+	assert_round_trip(api::CreateApiKeyRequest {
+		name: "name".to_string(),
+		permissions: vec![types::ApiKeyPermission::Admin as i32],
+		max_send_amount_msat: Some(u64::MAX),
+		client_identity: Some("client_identity".to_string()),
+		daily_budget_msat: Some(u64::MAX),
+	});
+	assert_round_trip(api::CreateApiKeyResponse { api_key: "api_key".to_string() });
+	assert_round_trip(api::ListApiKeysRequest {});
+	assert_round_trip(api::ListApiKeysResponse { api_keys: vec![types::ApiKeyInfo::default()] });
+	assert_round_trip(api::RevokeApiKeyRequest { name: "name".to_string() });
+	assert_round_trip(api::RevokeApiKeyResponse {});
+	assert_round_trip(api::RotateApiKeyRequest {
+		name: "name".to_string(),
+		grace_period_secs: Some(u64::MAX),
+	});
+	assert_round_trip(api::RotateApiKeyResponse { api_key: "api_key".to_string() });
+	assert_round_trip(api::ListPendingApprovalsRequest {});
+	assert_round_trip(api::ListPendingApprovalsResponse {
+		pending_approvals: vec![types::PendingApproval::default()],
+	});
+	assert_round_trip(api::ApprovePaymentRequest { id: "id".to_string() });
+	assert_round_trip(api::ApprovePaymentResponse { payment_id: "payment_id".to_string() });
+	assert_round_trip(api::RejectPaymentRequest { id: "id".to_string() });
+	assert_round_trip(api::RejectPaymentResponse {});
+	assert_round_trip(api::ListAuditLogRequest { page_token: Some(types::PageToken::default()) });
+	assert_round_trip(api::ListAuditLogResponse {
+		entries: vec![types::AuditLogEntry::default()],
+		next_page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::ListEventsRequest {
+		page_token: Some(types::PageToken::default()),
+		since_sequence_number: Some(u64::MAX),
+		event_types: vec!["event_types".to_string()],
+	});
+	assert_round_trip(api::ListEventsResponse {
+		events: vec![events::EventEnvelope::default()],
+		next_page_token: Some(types::PageToken::default()),
+	});
+	assert_round_trip(api::SubscribeEventsRequest {});
+}
+
+#[test]
+fn types_messages_round_trip() {
+	assert_round_trip(types::Payment {
+		id: "id".to_string(),
+		kind: Some(types::PaymentKind::default()),
+		amount_msat: Some(u64::MAX),
+		fee_paid_msat: Some(u64::MAX),
+		direction: types::PaymentDirection::Outbound as i32,
+		status: types::PaymentStatus::Failed as i32,
+		latest_update_timestamp: u64::MAX,
+		metadata: Some(types::PaymentMetadata::default()),
+	});
+	assert_round_trip(types::PaymentMetadata {
+		order_id: Some("order_id".to_string()),
+		customer_reference: Some("customer_reference".to_string()),
+		labels: vec!["labels".to_string()],
+	});
+	assert_round_trip(types::PaymentKind {
+		kind: Some(types::payment_kind::Kind::Spontaneous(types::Spontaneous::default())),
+	});
+	assert_round_trip(types::Onchain {
+		txid: "txid".to_string(),
+		status: Some(types::ConfirmationStatus::default()),
+	});
+	assert_round_trip(types::ConfirmationStatus {
+		status: Some(
+			types::confirmation_status::Status::Unconfirmed(types::Unconfirmed::default()),
+		),
+	});
+	assert_round_trip(types::Confirmed {
+		block_hash: "block_hash".to_string(),
+		height: u32::MAX,
+		timestamp: u64::MAX,
+	});
+	assert_round_trip(types::Unconfirmed {});
+	assert_round_trip(types::Bolt11 {
+		hash: "hash".to_string(),
+		preimage: Some("preimage".to_string()),
+		secret: Some(Bytes::from_static(&[0, 1, 254, 255])),
+	});
+	assert_round_trip(types::Bolt11Jit {
+		hash: "hash".to_string(),
+		preimage: Some("preimage".to_string()),
+		secret: Some(Bytes::from_static(&[0, 1, 254, 255])),
+		lsp_fee_limits: Some(types::LspFeeLimits::default()),
+		counterparty_skimmed_fee_msat: Some(u64::MAX),
+	});
+	assert_round_trip(types::Bolt12Offer {
+		hash: Some("hash".to_string()),
+		preimage: Some("preimage".to_string()),
+		secret: Some(Bytes::from_static(&[0, 1, 254, 255])),
+		offer_id: "offer_id".to_string(),
+		payer_note: Some("payer_note".to_string()),
+		quantity: Some(u64::MAX),
+	});
+	assert_round_trip(types::Bolt12Refund {
+		hash: Some("hash".to_string()),
+		preimage: Some("preimage".to_string()),
+		secret: Some(Bytes::from_static(&[0, 1, 254, 255])),
+		payer_note: Some("payer_note".to_string()),
+		quantity: Some(u64::MAX),
+	});
+	assert_round_trip(types::Spontaneous {
+		hash: "hash".to_string(),
+		preimage: Some("preimage".to_string()),
+	});
+	assert_round_trip(types::LspFeeLimits {
+		max_total_opening_fee_msat: Some(u64::MAX),
+		max_proportional_opening_fee_ppm_msat: Some(u64::MAX),
+	});
+	assert_round_trip(types::ForwardedPayment {
+		prev_channel_id: "prev_channel_id".to_string(),
+		next_channel_id: "next_channel_id".to_string(),
+		prev_user_channel_id: "prev_user_channel_id".to_string(),
+		prev_node_id: "prev_node_id".to_string(),
+		next_node_id: "next_node_id".to_string(),
+		next_user_channel_id: Some("next_user_channel_id".to_string()),
+		total_fee_earned_msat: Some(u64::MAX),
+		skimmed_fee_msat: Some(u64::MAX),
+		claim_from_onchain_tx: true,
+		outbound_amount_forwarded_msat: Some(u64::MAX),
+		timestamp: u64::MAX,
+	});
+	assert_round_trip(types::ChannelPairForwardingStats {
+		prev_channel_id: "prev_channel_id".to_string(),
+		next_channel_id: "next_channel_id".to_string(),
+		prev_node_id: "prev_node_id".to_string(),
+		next_node_id: "next_node_id".to_string(),
+		forwarded_payments_count: u64::MAX,
+		outbound_amount_forwarded_msat: u64::MAX,
+		total_fee_earned_msat: u64::MAX,
+	});
+	assert_round_trip(types::DailyForwardingStats {
+		day_start_timestamp: u64::MAX,
+		forwarded_payments_count: u64::MAX,
+		outbound_amount_forwarded_msat: u64::MAX,
+		total_fee_earned_msat: u64::MAX,
+	});
+	assert_round_trip(types::Channel {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		funding_txo: Some(types::OutPoint::default()),
+		user_channel_id: "user_channel_id".to_string(),
+		unspendable_punishment_reserve: Some(u64::MAX),
+		channel_value_sats: u64::MAX,
+		feerate_sat_per_1000_weight: u32::MAX,
+		outbound_capacity_msat: u64::MAX,
+		inbound_capacity_msat: u64::MAX,
+		confirmations_required: Some(u32::MAX),
+		confirmations: Some(u32::MAX),
+		is_outbound: true,
+		is_channel_ready: true,
+		is_usable: true,
+		is_announced: true,
+		channel_config: Some(types::ChannelConfig::default()),
+		next_outbound_htlc_limit_msat: u64::MAX,
+		next_outbound_htlc_minimum_msat: u64::MAX,
+		force_close_spend_delay: Some(u32::MAX),
+		counterparty_outbound_htlc_minimum_msat: Some(u64::MAX),
+		counterparty_outbound_htlc_maximum_msat: Some(u64::MAX),
+		counterparty_unspendable_punishment_reserve: u64::MAX,
+		counterparty_forwarding_info_fee_base_msat: Some(u32::MAX),
+		counterparty_forwarding_info_fee_proportional_millionths: Some(u32::MAX),
+		counterparty_forwarding_info_cltv_expiry_delta: Some(u32::MAX),
+	});
+	assert_round_trip(types::ChannelConfig {
+		forwarding_fee_proportional_millionths: Some(u32::MAX),
+		forwarding_fee_base_msat: Some(u32::MAX),
+		cltv_expiry_delta: Some(u32::MAX),
+		force_close_avoidance_max_fee_satoshis: Some(u64::MAX),
+		accept_underpaying_htlcs: Some(true),
+		max_dust_htlc_exposure: Some(
+			types::channel_config::MaxDustHtlcExposure::FeeRateMultiplier(u64::MAX),
+		),
+	});
+	assert_round_trip(types::OutPoint { txid: "txid".to_string(), vout: u32::MAX });
+	assert_round_trip(types::BestBlock { block_hash: "block_hash".to_string(), height: u32::MAX });
+	assert_round_trip(types::LightningBalance {
+		balance_type: Some(
+			types::lightning_balance::BalanceType::CounterpartyRevokedOutputClaimable(
+				types::CounterpartyRevokedOutputClaimable::default(),
+			),
+		),
+	});
+	assert_round_trip(types::ClaimableOnChannelClose {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		amount_satoshis: u64::MAX,
+		transaction_fee_satoshis: u64::MAX,
+		outbound_payment_htlc_rounded_msat: u64::MAX,
+		outbound_forwarded_htlc_rounded_msat: u64::MAX,
+		inbound_claiming_htlc_rounded_msat: u64::MAX,
+		inbound_htlc_rounded_msat: u64::MAX,
+	});
+	assert_round_trip(types::ClaimableAwaitingConfirmations {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		amount_satoshis: u64::MAX,
+		confirmation_height: u32::MAX,
+		source: types::BalanceSource::Htlc as i32,
+	});
+	assert_round_trip(types::ContentiousClaimable {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		amount_satoshis: u64::MAX,
+		timeout_height: u32::MAX,
+		payment_hash: "payment_hash".to_string(),
+		payment_preimage: "payment_preimage".to_string(),
+	});
+	assert_round_trip(types::MaybeTimeoutClaimableHtlc {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		amount_satoshis: u64::MAX,
+		claimable_height: u32::MAX,
+		payment_hash: "payment_hash".to_string(),
+		outbound_payment: true,
+	});
+	assert_round_trip(types::MaybePreimageClaimableHtlc {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		amount_satoshis: u64::MAX,
+		expiry_height: u32::MAX,
+		payment_hash: "payment_hash".to_string(),
+	});
+	assert_round_trip(types::CounterpartyRevokedOutputClaimable {
+		channel_id: "channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		amount_satoshis: u64::MAX,
+	});
+	assert_round_trip(types::PendingSweepBalance {
+		balance_type: Some(
+			types::pending_sweep_balance::BalanceType::AwaitingThresholdConfirmations(
+				types::AwaitingThresholdConfirmations::default(),
+			),
+		),
+	});
+	assert_round_trip(types::PendingBroadcast {
+		channel_id: Some("channel_id".to_string()),
+		amount_satoshis: u64::MAX,
+	});
+	assert_round_trip(types::BroadcastAwaitingConfirmation {
+		channel_id: Some("channel_id".to_string()),
+		latest_broadcast_height: u32::MAX,
+		latest_spending_txid: "latest_spending_txid".to_string(),
+		amount_satoshis: u64::MAX,
+	});
+	assert_round_trip(types::AwaitingThresholdConfirmations {
+		channel_id: Some("channel_id".to_string()),
+		latest_spending_txid: "latest_spending_txid".to_string(),
+		confirmation_hash: "confirmation_hash".to_string(),
+		confirmation_height: u32::MAX,
+		amount_satoshis: u64::MAX,
+	});
+	assert_round_trip(types::PageToken { token: "token".to_string(), index: i64::MIN });
+	assert_round_trip(types::Bolt11InvoiceDescription {
+		kind: Some(types::bolt11_invoice_description::Kind::Hash("hash".to_string())),
+	});
+	assert_round_trip(types::RouteParametersConfig {
+		max_total_routing_fee_msat: Some(u64::MAX),
+		max_total_cltv_expiry_delta: u32::MAX,
+		max_path_count: u32::MAX,
+		max_channel_saturation_power_of_half: u32::MAX,
+	});
+	assert_round_trip(types::GraphRoutingFees {
+		base_msat: u32::MAX,
+		proportional_millionths: u32::MAX,
+	});
+	assert_round_trip(types::GraphChannelUpdate {
+		last_update: u32::MAX,
+		enabled: true,
+		cltv_expiry_delta: u32::MAX,
+		htlc_minimum_msat: u64::MAX,
+		htlc_maximum_msat: u64::MAX,
+		fees: Some(types::GraphRoutingFees::default()),
+	});
+	assert_round_trip(types::GraphChannel {
+		node_one: "node_one".to_string(),
+		node_two: "node_two".to_string(),
+		capacity_sats: Some(u64::MAX),
+		one_to_two: Some(types::GraphChannelUpdate::default()),
+		two_to_one: Some(types::GraphChannelUpdate::default()),
+	});
+	assert_round_trip(types::GraphNodeAnnouncement {
+		last_update: u32::MAX,
+		alias: "alias".to_string(),
+		rgb: "rgb".to_string(),
+		addresses: vec!["addresses".to_string()],
+	});
+	assert_round_trip(types::GraphNode {
+		channels: vec![u64::MAX],
+		announcement_info: Some(types::GraphNodeAnnouncement::default()),
+	});
+	assert_round_trip(types::ApiKeyInfo {
+		name: "name".to_string(),
+		permissions: vec![types::ApiKeyPermission::Admin as i32],
+		max_send_amount_msat: Some(u64::MAX),
+		configured: true,
+		created_at: Some(u64::MAX),
+		previous_key_expires_at: Some(u64::MAX),
+		revoked_at: Some(u64::MAX),
+		client_identity: Some("client_identity".to_string()),
+		daily_budget_msat: Some(u64::MAX),
+	});
+	assert_round_trip(types::PendingApproval {
+		id: "id".to_string(),
+		endpoint: "endpoint".to_string(),
+		amount_msat: Some(u64::MAX),
+		destination: "destination".to_string(),
+		submitted_by: "submitted_by".to_string(),
+		created_at: u64::MAX,
+	});
+	assert_round_trip(types::AuditLogEntry {
+		id: "id".to_string(),
+		timestamp: u64::MAX,
+		api_key_name: "api_key_name".to_string(),
+		client_identity: Some("client_identity".to_string()),
+		endpoint: "endpoint".to_string(),
+		request_summary: "request_summary".to_string(),
+		success: true,
+		error: Some("error".to_string()),
+		completed: true,
+	});
+}
+
+#[test]
+fn events_messages_round_trip() {
+	assert_round_trip(events::EventEnvelope {
+		sequence_number: u64::MAX,
+		event_id: "event_id".to_string(),
+		timestamp: u64::MAX,
+		node_id: "node_id".to_string(),
+		event: Some(events::event_envelope::Event::SpliceFailed(events::SpliceFailed::default())),
+	});
+	assert_round_trip(events::PaymentReceived { payment: Some(types::Payment::default()) });
+	assert_round_trip(events::PaymentSuccessful { payment: Some(types::Payment::default()) });
+	assert_round_trip(events::PaymentFailed { payment: Some(types::Payment::default()) });
+	assert_round_trip(events::PaymentForwarded {
+		forwarded_payment: Some(types::ForwardedPayment::default()),
+	});
+	assert_round_trip(events::ChannelPending {
+		channel_id: "channel_id".to_string(),
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		funding_txo: Some(types::OutPoint::default()),
+	});
+	assert_round_trip(events::ChannelReady {
+		channel_id: "channel_id".to_string(),
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: Some("counterparty_node_id".to_string()),
+	});
+	assert_round_trip(events::ChannelClosed {
+		channel_id: "channel_id".to_string(),
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: Some("counterparty_node_id".to_string()),
+		reason: Some("reason".to_string()),
+		force_closed: true,
+	});
+	assert_round_trip(events::SplicePending {
+		channel_id: "channel_id".to_string(),
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		new_funding_txo: Some(types::OutPoint::default()),
+	});
+	assert_round_trip(events::SpliceFailed {
+		channel_id: "channel_id".to_string(),
+		user_channel_id: "user_channel_id".to_string(),
+		counterparty_node_id: "counterparty_node_id".to_string(),
+		abandoned_funding_txo: Some(types::OutPoint::default()),
+	});
+}
+
+#[test]
+fn error_messages_round_trip() {
+	assert_round_trip(error::ErrorResponse {
+		message: "message".to_string(),
+		error_code: error::ErrorCode::SpendingLimitExceededError as i32,
+	});
+}
@@ -9,8 +9,6 @@
 
 /// Represents a payment.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/payment/struct.PaymentDetails.html>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Payment {
@@ -31,24 +29,34 @@ pub struct Payment {
 	pub fee_paid_msat: ::core::option::Option<u64>,
 	/// The direction of the payment.
 	#[prost(enumeration = "PaymentDirection", tag = "4")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_payment_direction")
-	)]
 	pub direction: i32,
 	/// The status of the payment.
 	#[prost(enumeration = "PaymentStatus", tag = "5")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_payment_status")
-	)]
 	pub status: i32,
 	/// The timestamp, in seconds since start of the UNIX epoch, when this entry was last updated.
 	#[prost(uint64, tag = "6")]
 	pub latest_update_timestamp: u64,
+	/// The metadata supplied when the payment, or the offer it is for, was created.
+	///
+	/// Not set on payments included in events.
+	#[prost(message, optional, tag = "8")]
+	pub metadata: ::core::option::Option<PaymentMetadata>,
+}
+/// Metadata supplied by the caller when receiving or sending a payment, e.g., to relate the payment
+/// to an order.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentMetadata {
+	/// The identifier of the order the payment is for.
+	#[prost(string, optional, tag = "1")]
+	pub order_id: ::core::option::Option<::prost::alloc::string::String>,
+	/// A reference to the customer the payment is from or to.
+	#[prost(string, optional, tag = "2")]
+	pub customer_reference: ::core::option::Option<::prost::alloc::string::String>,
+	/// Free-form labels, allowing payments to be looked up via the `ListPaymentsByLabel` API.
+	#[prost(string, repeated, tag = "3")]
+	pub labels: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PaymentKind {
@@ -57,8 +65,6 @@ pub struct PaymentKind {
 }
 /// Nested message and enum types in `PaymentKind`.
 pub mod payment_kind {
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum Kind {
@@ -77,8 +83,6 @@ pub mod payment_kind {
 	}
 }
 /// Represents an on-chain payment.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Onchain {
@@ -89,8 +93,6 @@ pub struct Onchain {
 	#[prost(message, optional, tag = "2")]
 	pub status: ::core::option::Option<ConfirmationStatus>,
 }
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfirmationStatus {
@@ -99,8 +101,6 @@ pub struct ConfirmationStatus {
 }
 /// Nested message and enum types in `ConfirmationStatus`.
 pub mod confirmation_status {
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum Status {
@@ -111,8 +111,6 @@ pub mod confirmation_status {
 	}
 }
 /// The on-chain transaction is confirmed in the best chain.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Confirmed {
@@ -127,14 +125,10 @@ pub struct Confirmed {
 	pub timestamp: u64,
 }
 /// The on-chain transaction is unconfirmed.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Unconfirmed {}
 /// Represents a BOLT 11 payment.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11 {
@@ -146,15 +140,9 @@ pub struct Bolt11 {
 	pub preimage: ::core::option::Option<::prost::alloc::string::String>,
 	/// The secret used by the payment.
 	#[prost(bytes = "bytes", optional, tag = "3")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_opt_bytes_hex")
-	)]
 	pub secret: ::core::option::Option<::prost::bytes::Bytes>,
 }
 /// Represents a BOLT 11 payment intended to open an LSPS 2 just-in-time channel.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11Jit {
@@ -166,10 +154,6 @@ pub struct Bolt11Jit {
 	pub preimage: ::core::option::Option<::prost::alloc::string::String>,
 	/// The secret used by the payment.
 	#[prost(bytes = "bytes", optional, tag = "3")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_opt_bytes_hex")
-	)]
 	pub secret: ::core::option::Option<::prost::bytes::Bytes>,
 	/// Limits applying to how much fee we allow an LSP to deduct from the payment amount.
 	///
@@ -187,8 +171,6 @@ pub struct Bolt11Jit {
 	pub counterparty_skimmed_fee_msat: ::core::option::Option<u64>,
 }
 /// Represents a BOLT 12 ‘offer’ payment, i.e., a payment for an Offer.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt12Offer {
@@ -200,10 +182,6 @@ pub struct Bolt12Offer {
 	pub preimage: ::core::option::Option<::prost::alloc::string::String>,
 	/// The secret used by the payment.
 	#[prost(bytes = "bytes", optional, tag = "3")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_opt_bytes_hex")
-	)]
 	pub secret: ::core::option::Option<::prost::bytes::Bytes>,
 	/// The hex-encoded ID of the offer this payment is for.
 	#[prost(string, tag = "4")]
@@ -220,8 +198,6 @@ pub struct Bolt12Offer {
 	pub quantity: ::core::option::Option<u64>,
 }
 /// Represents a BOLT 12 ‘refund’ payment, i.e., a payment for a Refund.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt12Refund {
@@ -233,10 +209,6 @@ pub struct Bolt12Refund {
 	pub preimage: ::core::option::Option<::prost::alloc::string::String>,
 	/// The secret used by the payment.
 	#[prost(bytes = "bytes", optional, tag = "3")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_opt_bytes_hex")
-	)]
 	pub secret: ::core::option::Option<::prost::bytes::Bytes>,
 	/// The payer's note for the payment.
 	/// Truncated to \[PAYER_NOTE_LIMIT\](<https://docs.rs/lightning/latest/lightning/offers/invoice_request/constant.PAYER_NOTE_LIMIT.html>).
@@ -250,8 +222,6 @@ pub struct Bolt12Refund {
 	pub quantity: ::core::option::Option<u64>,
 }
 /// Represents a spontaneous (“keysend”) payment.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Spontaneous {
@@ -266,8 +236,6 @@ pub struct Spontaneous {
 /// See \[`LdkChannelConfig::accept_underpaying_htlcs`\] for more information.
 ///
 /// \[`LdkChannelConfig::accept_underpaying_htlcs`\]: lightning::util::config::ChannelConfig::accept_underpaying_htlcs
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LspFeeLimits {
@@ -282,8 +250,6 @@ pub struct LspFeeLimits {
 }
 /// A forwarded payment through our node.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.Event.html#variant.PaymentForwarded>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ForwardedPayment {
@@ -333,9 +299,53 @@ pub struct ForwardedPayment {
 	/// The caveat described above the `total_fee_earned_msat` field applies here as well.
 	#[prost(uint64, optional, tag = "8")]
 	pub outbound_amount_forwarded_msat: ::core::option::Option<u64>,
+	/// The timestamp, in seconds since start of the UNIX epoch, when the payment was forwarded.
+	#[prost(uint64, tag = "11")]
+	pub timestamp: u64,
+}
+/// The forwarding stats of a pair of channels payments were forwarded between.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChannelPairForwardingStats {
+	/// The channel id of the incoming channel between the previous node and us.
+	#[prost(string, tag = "1")]
+	pub prev_channel_id: ::prost::alloc::string::String,
+	/// The channel id of the outgoing channel between the next node and us.
+	#[prost(string, tag = "2")]
+	pub next_channel_id: ::prost::alloc::string::String,
+	/// The node id of the previous node.
+	#[prost(string, tag = "3")]
+	pub prev_node_id: ::prost::alloc::string::String,
+	/// The node id of the next node.
+	#[prost(string, tag = "4")]
+	pub next_node_id: ::prost::alloc::string::String,
+	/// The number of payments forwarded between the channels.
+	#[prost(uint64, tag = "5")]
+	pub forwarded_payments_count: u64,
+	/// The amount forwarded between the channels, in milli-satoshis, after fees are deducted.
+	#[prost(uint64, tag = "6")]
+	pub outbound_amount_forwarded_msat: u64,
+	/// The fees earned by forwarding payments between the channels, in milli-satoshis.
+	#[prost(uint64, tag = "7")]
+	pub total_fee_earned_msat: u64,
+}
+/// The forwarding stats of a single day.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DailyForwardingStats {
+	/// The timestamp, in seconds since start of the UNIX epoch, of the start of the day, in UTC.
+	#[prost(uint64, tag = "1")]
+	pub day_start_timestamp: u64,
+	/// The number of payments forwarded during the day.
+	#[prost(uint64, tag = "2")]
+	pub forwarded_payments_count: u64,
+	/// The amount forwarded during the day, in milli-satoshis, after fees are deducted.
+	#[prost(uint64, tag = "3")]
+	pub outbound_amount_forwarded_msat: u64,
+	/// The fees earned by forwarding payments during the day, in milli-satoshis.
+	#[prost(uint64, tag = "4")]
+	pub total_fee_earned_msat: u64,
 }
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Channel {
@@ -468,8 +478,6 @@ pub struct Channel {
 }
 /// ChannelConfig represents the configuration settings for a channel in a Lightning Network node.
 /// See more: <https://docs.rs/lightning/latest/lightning/util/config/struct.ChannelConfig.html>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChannelConfig {
@@ -513,8 +521,6 @@ pub mod channel_config {
 	/// and fees on commitment transaction(s) broadcasted by our counterparty in excess of
 	/// our own fee estimate.
 	/// See more: <https://docs.rs/lightning/latest/lightning/util/config/struct.ChannelConfig.html#structfield.max_dust_htlc_exposure>
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum MaxDustHtlcExposure {
@@ -529,8 +535,6 @@ pub mod channel_config {
 	}
 }
 /// Represent a transaction outpoint.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OutPoint {
@@ -541,8 +545,6 @@ pub struct OutPoint {
 	#[prost(uint32, tag = "2")]
 	pub vout: u32,
 }
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BestBlock {
@@ -554,8 +556,6 @@ pub struct BestBlock {
 	pub height: u32,
 }
 /// Details about the status of a known Lightning balance.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LightningBalance {
@@ -564,8 +564,6 @@ pub struct LightningBalance {
 }
 /// Nested message and enum types in `LightningBalance`.
 pub mod lightning_balance {
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum BalanceType {
@@ -586,8 +584,6 @@ pub mod lightning_balance {
 /// The channel is not yet closed (or the commitment or closing transaction has not yet appeared in a block).
 /// The given balance is claimable (less on-chain fees) if the channel is force-closed now.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.LightningBalance.html#variant.ClaimableOnChannelClose>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClaimableOnChannelClose {
@@ -643,8 +639,6 @@ pub struct ClaimableOnChannelClose {
 }
 /// The channel has been closed, and the given balance is ours but awaiting confirmations until we consider it spendable.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.LightningBalance.html#variant.ClaimableAwaitingConfirmations>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClaimableAwaitingConfirmations {
@@ -663,10 +657,6 @@ pub struct ClaimableAwaitingConfirmations {
 	pub confirmation_height: u32,
 	/// Whether this balance is a result of cooperative close, a force-close, or an HTLC.
 	#[prost(enumeration = "BalanceSource", tag = "5")]
-	#[cfg_attr(
-		feature = "serde",
-		serde(serialize_with = "crate::serde_utils::serialize_balance_source")
-	)]
 	pub source: i32,
 }
 /// The channel has been closed, and the given balance should be ours but awaiting spending transaction confirmation.
@@ -676,8 +666,6 @@ pub struct ClaimableAwaitingConfirmations {
 /// Once the spending transaction confirms, before it has reached enough confirmations to be considered safe from chain
 /// reorganizations, the balance will instead be provided via `LightningBalance::ClaimableAwaitingConfirmations`.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.LightningBalance.html#variant.ContentiousClaimable>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ContentiousClaimable {
@@ -704,8 +692,6 @@ pub struct ContentiousClaimable {
 /// HTLCs which we sent to our counterparty which are claimable after a timeout (less on-chain fees) if the counterparty
 /// does not know the preimage for the HTLCs. These are somewhat likely to be claimed by our counterparty before we do.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.LightningBalance.html#variant.MaybeTimeoutClaimableHTLC>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MaybeTimeoutClaimableHtlc {
@@ -733,8 +719,6 @@ pub struct MaybeTimeoutClaimableHtlc {
 /// This will only be claimable if we receive the preimage from the node to which we forwarded this HTLC before the
 /// timeout.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.LightningBalance.html#variant.MaybePreimageClaimableHTLC>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct MaybePreimageClaimableHtlc {
@@ -761,8 +745,6 @@ pub struct MaybePreimageClaimableHtlc {
 /// Thus, we’re able to claim all outputs in the commitment transaction, one of which has the following amount.
 ///
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.LightningBalance.html#variant.CounterpartyRevokedOutputClaimable>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CounterpartyRevokedOutputClaimable {
@@ -777,8 +759,6 @@ pub struct CounterpartyRevokedOutputClaimable {
 	pub amount_satoshis: u64,
 }
 /// Details about the status of a known balance currently being swept to our on-chain wallet.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PendingSweepBalance {
@@ -787,8 +767,6 @@ pub struct PendingSweepBalance {
 }
 /// Nested message and enum types in `PendingSweepBalance`.
 pub mod pending_sweep_balance {
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum BalanceType {
@@ -802,8 +780,6 @@ pub mod pending_sweep_balance {
 }
 /// The spendable output is about to be swept, but a spending transaction has yet to be generated and broadcast.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.PendingSweepBalance.html#variant.PendingBroadcast>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PendingBroadcast {
@@ -816,8 +792,6 @@ pub struct PendingBroadcast {
 }
 /// A spending transaction has been generated and broadcast and is awaiting confirmation on-chain.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.PendingSweepBalance.html#variant.BroadcastAwaitingConfirmation>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastAwaitingConfirmation {
@@ -838,8 +812,6 @@ pub struct BroadcastAwaitingConfirmation {
 ///
 /// It will be considered irrevocably confirmed after reaching `ANTI_REORG_DELAY`.
 /// See more: <https://docs.rs/ldk-node/latest/ldk_node/enum.PendingSweepBalance.html#variant.AwaitingThresholdConfirmations>
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AwaitingThresholdConfirmations {
@@ -860,8 +832,6 @@ pub struct AwaitingThresholdConfirmations {
 	pub amount_satoshis: u64,
 }
 /// Token used to determine start of next page in paginated APIs.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PageToken {
@@ -870,8 +840,6 @@ pub struct PageToken {
 	#[prost(int64, tag = "2")]
 	pub index: i64,
 }
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Bolt11InvoiceDescription {
@@ -880,8 +848,6 @@ pub struct Bolt11InvoiceDescription {
 }
 /// Nested message and enum types in `Bolt11InvoiceDescription`.
 pub mod bolt11_invoice_description {
-	#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-	#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 	#[allow(clippy::derive_partial_eq_without_eq)]
 	#[derive(Clone, PartialEq, ::prost::Oneof)]
 	pub enum Kind {
@@ -893,8 +859,6 @@ pub mod bolt11_invoice_description {
 }
 /// Configuration options for payment routing and pathfinding.
 /// See <https://docs.rs/lightning/0.2.0/lightning/routing/router/struct.RouteParametersConfig.html> for more details on each field.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RouteParametersConfig {
@@ -917,8 +881,6 @@ pub struct RouteParametersConfig {
 	pub max_channel_saturation_power_of_half: u32,
 }
 /// Routing fees for a channel as part of the network graph.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphRoutingFees {
@@ -931,8 +893,6 @@ pub struct GraphRoutingFees {
 }
 /// Details about one direction of a channel in the network graph,
 /// as received within a `ChannelUpdate`.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphChannelUpdate {
@@ -958,8 +918,6 @@ pub struct GraphChannelUpdate {
 }
 /// Details about a channel in the network graph (both directions).
 /// Received within a channel announcement.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphChannel {
@@ -980,8 +938,6 @@ pub struct GraphChannel {
 	pub two_to_one: ::core::option::Option<GraphChannelUpdate>,
 }
 /// Information received in the latest node_announcement from this node.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphNodeAnnouncement {
@@ -1001,8 +957,6 @@ pub struct GraphNodeAnnouncement {
 	pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// Details about a node in the network graph, known from the network announcement.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GraphNode {
@@ -1015,9 +969,101 @@ pub struct GraphNode {
 	#[prost(message, optional, tag = "2")]
 	pub announcement_info: ::core::option::Option<GraphNodeAnnouncement>,
 }
+/// Details of an API key accepted by the server. The secret of the key is never included.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiKeyInfo {
+	/// The unique name of the API key.
+	#[prost(string, tag = "1")]
+	pub name: ::prost::alloc::string::String,
+	/// The permissions granted to the API key.
+	#[prost(enumeration = "ApiKeyPermission", repeated, tag = "2")]
+	pub permissions: ::prost::alloc::vec::Vec<i32>,
+	/// The maximum amount of a single payment sent using this API key, if limited.
+	#[prost(uint64, optional, tag = "3")]
+	pub max_send_amount_msat: ::core::option::Option<u64>,
+	/// Whether the API key is defined by the server's configuration rather than created via the
+	/// `CreateApiKey` API. Such keys can be rotated, but not revoked at runtime.
+	#[prost(bool, tag = "4")]
+	pub configured: bool,
+	/// The time the API key was created at, in seconds since the UNIX epoch.
+	/// Not set for API keys defined by the server's configuration.
+	#[prost(uint64, optional, tag = "5")]
+	pub created_at: ::core::option::Option<u64>,
+	/// The time until which the previous secret of a rotated API key is still accepted, in seconds
+	/// since the UNIX epoch.
+	#[prost(uint64, optional, tag = "6")]
+	pub previous_key_expires_at: ::core::option::Option<u64>,
+	/// The time the API key was revoked at, in seconds since the UNIX epoch.
+	#[prost(uint64, optional, tag = "7")]
+	pub revoked_at: ::core::option::Option<u64>,
+	/// The client certificate identity requests using this API key must be made with, if any.
+	#[prost(string, optional, tag = "8")]
+	pub client_identity: ::core::option::Option<::prost::alloc::string::String>,
+	/// The maximum total amount of all payments sent using this API key within the last 24 hours,
+	/// if limited.
+	#[prost(uint64, optional, tag = "9")]
+	pub daily_budget_msat: ::core::option::Option<u64>,
+}
+/// An outgoing payment held until approved, because it exceeds the server's approval threshold.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingApproval {
+	/// The unique id of the pending approval.
+	#[prost(string, tag = "1")]
+	pub id: ::prost::alloc::string::String,
+	/// The name of the API the payment was submitted via, e.g. `Bolt11Send` or `OnchainSend`.
+	#[prost(string, tag = "2")]
+	pub endpoint: ::prost::alloc::string::String,
+	/// The amount of the payment, if known up front.
+	/// Not set for payments whose amount is determined when sending, e.g. when sending the full
+	/// on-chain balance.
+	#[prost(uint64, optional, tag = "3")]
+	pub amount_msat: ::core::option::Option<u64>,
+	/// The invoice, offer, node id or address the payment is sent to.
+	#[prost(string, tag = "4")]
+	pub destination: ::prost::alloc::string::String,
+	/// The name of the API key the payment was submitted with.
+	#[prost(string, tag = "5")]
+	pub submitted_by: ::prost::alloc::string::String,
+	/// The time the payment was submitted at, in seconds since the UNIX epoch.
+	#[prost(uint64, tag = "6")]
+	pub created_at: u64,
+}
+/// A record of a state-changing API call, kept in the server's audit log.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuditLogEntry {
+	/// The unique id of the audit log entry.
+	#[prost(string, tag = "1")]
+	pub id: ::prost::alloc::string::String,
+	/// The time the call was made at, in seconds since the UNIX epoch.
+	#[prost(uint64, tag = "2")]
+	pub timestamp: u64,
+	/// The name of the API key the call was authenticated with.
+	#[prost(string, tag = "3")]
+	pub api_key_name: ::prost::alloc::string::String,
+	/// The identity of the client certificate the call was made with, if any.
+	#[prost(string, optional, tag = "4")]
+	pub client_identity: ::core::option::Option<::prost::alloc::string::String>,
+	/// The name of the API that was called, e.g. `Bolt11Send` or `OpenChannel`.
+	#[prost(string, tag = "5")]
+	pub endpoint: ::prost::alloc::string::String,
+	/// A summary of the request parameters, omitting any free-form or sensitive fields.
+	#[prost(string, tag = "6")]
+	pub request_summary: ::prost::alloc::string::String,
+	/// Whether the call succeeded.
+	#[prost(bool, tag = "7")]
+	pub success: bool,
+	/// The error message returned to the caller, if the call failed.
+	#[prost(string, optional, tag = "8")]
+	pub error: ::core::option::Option<::prost::alloc::string::String>,
+	/// Whether the call was processed to completion. Calls are recorded before they are processed, so
+	/// an entry that is not completed belongs to a call the server stopped while processing.
+	#[prost(bool, tag = "9")]
+	pub completed: bool,
+}
 /// Represents the direction of a payment.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PaymentDirection {
@@ -1047,8 +1093,6 @@ impl PaymentDirection {
 	}
 }
 /// Represents the current status of a payment.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum PaymentStatus {
@@ -1081,11 +1125,54 @@ impl PaymentStatus {
 		}
 	}
 }
+/// Represents the kind of a payment, i.e., the variant of its `PaymentKind`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PaymentKindType {
+	/// The payment is an on-chain payment.
+	Onchain = 0,
+	/// The payment is a BOLT 11 payment.
+	Bolt11 = 1,
+	/// The payment is a BOLT 11 payment intended to open an LSPS 2 just-in-time channel.
+	Bolt11Jit = 2,
+	/// The payment is a BOLT 12 'offer' payment.
+	Bolt12Offer = 3,
+	/// The payment is a BOLT 12 'refund' payment.
+	Bolt12Refund = 4,
+	/// The payment is a spontaneous ("keysend") payment.
+	Spontaneous = 5,
+}
+impl PaymentKindType {
+	/// String value of the enum field names used in the ProtoBuf definition.
+	///
+	/// The values are not transformed in any way and thus are considered stable
+	/// (if the ProtoBuf definition does not change) and safe for programmatic use.
+	pub fn as_str_name(&self) -> &'static str {
+		match self {
+			PaymentKindType::Onchain => "ONCHAIN",
+			PaymentKindType::Bolt11 => "BOLT11",
+			PaymentKindType::Bolt11Jit => "BOLT11_JIT",
+			PaymentKindType::Bolt12Offer => "BOLT12_OFFER",
+			PaymentKindType::Bolt12Refund => "BOLT12_REFUND",
+			PaymentKindType::Spontaneous => "SPONTANEOUS",
+		}
+	}
+	/// Creates an enum from field names used in the ProtoBuf definition.
+	pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+		match value {
+			"ONCHAIN" => Some(Self::Onchain),
+			"BOLT11" => Some(Self::Bolt11),
+			"BOLT11_JIT" => Some(Self::Bolt11Jit),
+			"BOLT12_OFFER" => Some(Self::Bolt12Offer),
+			"BOLT12_REFUND" => Some(Self::Bolt12Refund),
+			"SPONTANEOUS" => Some(Self::Spontaneous),
+			_ => None,
+		}
+	}
+}
 
 /// Indicates whether the balance is derived from a cooperative close, a force-close (for holder or counterparty),
 /// or whether it is for an HTLC.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum BalanceSource {
@@ -1123,3 +1210,40 @@ impl BalanceSource {
 		}
 	}
 }
+/// Permissions that can be granted to an API key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ApiKeyPermission {
+	/// Read-only access to node, balance, channel, payment and network graph state.
+	Read = 0,
+	/// Allows creating invoices, offers and on-chain addresses to receive funds.
+	Invoice = 1,
+	/// Allows sending on-chain and lightning payments.
+	Send = 2,
+	/// Unrestricted access, including channel, peer and API key management.
+	Admin = 3,
+}
+impl ApiKeyPermission {
+	/// String value of the enum field names used in the ProtoBuf definition.
+	///
+	/// The values are not transformed in any way and thus are considered stable
+	/// (if the ProtoBuf definition does not change) and safe for programmatic use.
+	pub fn as_str_name(&self) -> &'static str {
+		match self {
+			ApiKeyPermission::Read => "READ",
+			ApiKeyPermission::Invoice => "INVOICE",
+			ApiKeyPermission::Send => "SEND",
+			ApiKeyPermission::Admin => "ADMIN",
+		}
+	}
+	/// Creates an enum from field names used in the ProtoBuf definition.
+	pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+		match value {
+			"READ" => Some(Self::Read),
+			"INVOICE" => Some(Self::Invoice),
+			"SEND" => Some(Self::Send),
+			"ADMIN" => Some(Self::Admin),
+			_ => None,
+		}
+	}
+}
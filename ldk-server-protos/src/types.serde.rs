@@ -0,0 +1,7475 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+impl serde::Serialize for ApiKeyInfo {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.max_send_amount_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		if self.created_at.is_some() {
+			len += 1;
+		}
+		if self.previous_key_expires_at.is_some() {
+			len += 1;
+		}
+		if self.revoked_at.is_some() {
+			len += 1;
+		}
+		if self.client_identity.is_some() {
+			len += 1;
+		}
+		if self.daily_budget_msat.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.ApiKeyInfo", len)?;
+		struct_ser.serialize_field("name", &self.name)?;
+		let v = self
+			.permissions
+			.iter()
+			.cloned()
+			.map(|v| {
+				ApiKeyPermission::from_i32(v)
+					.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+			})
+			.collect::<std::result::Result<Vec<_>, _>>()?;
+		struct_ser.serialize_field("permissions", &v)?;
+		if let Some(v) = self.max_send_amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("maxSendAmountMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.serialize_field("configured", &self.configured)?;
+		if let Some(v) = self.created_at.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("createdAt", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.previous_key_expires_at.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("previousKeyExpiresAt", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.revoked_at.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("revokedAt", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.client_identity.as_ref() {
+			struct_ser.serialize_field("clientIdentity", v)?;
+		}
+		if let Some(v) = self.daily_budget_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("dailyBudgetMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ApiKeyInfo {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"name",
+			"permissions",
+			"max_send_amount_msat",
+			"maxSendAmountMsat",
+			"configured",
+			"created_at",
+			"createdAt",
+			"previous_key_expires_at",
+			"previousKeyExpiresAt",
+			"revoked_at",
+			"revokedAt",
+			"client_identity",
+			"clientIdentity",
+			"daily_budget_msat",
+			"dailyBudgetMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Name,
+			Permissions,
+			MaxSendAmountMsat,
+			Configured,
+			CreatedAt,
+			PreviousKeyExpiresAt,
+			RevokedAt,
+			ClientIdentity,
+			DailyBudgetMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"name" => Ok(GeneratedField::Name),
+							"permissions" => Ok(GeneratedField::Permissions),
+							"maxSendAmountMsat" | "max_send_amount_msat" => {
+								Ok(GeneratedField::MaxSendAmountMsat)
+							},
+							"configured" => Ok(GeneratedField::Configured),
+							"createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+							"previousKeyExpiresAt" | "previous_key_expires_at" => {
+								Ok(GeneratedField::PreviousKeyExpiresAt)
+							},
+							"revokedAt" | "revoked_at" => Ok(GeneratedField::RevokedAt),
+							"clientIdentity" | "client_identity" => {
+								Ok(GeneratedField::ClientIdentity)
+							},
+							"dailyBudgetMsat" | "daily_budget_msat" => {
+								Ok(GeneratedField::DailyBudgetMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ApiKeyInfo;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ApiKeyInfo")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ApiKeyInfo, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut name__ = None;
+				let mut permissions__ = None;
+				let mut max_send_amount_msat__ = None;
+				let mut configured__ = None;
+				let mut created_at__ = None;
+				let mut previous_key_expires_at__ = None;
+				let mut revoked_at__ = None;
+				let mut client_identity__ = None;
+				let mut daily_budget_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Name => {
+							if name__.is_some() {
+								return Err(serde::de::Error::duplicate_field("name"));
+							}
+							name__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Permissions => {
+							if permissions__.is_some() {
+								return Err(serde::de::Error::duplicate_field("permissions"));
+							}
+							permissions__ = Some(
+								map_.next_value::<Vec<ApiKeyPermission>>()?
+									.into_iter()
+									.map(|x| x as i32)
+									.collect(),
+							);
+						},
+						GeneratedField::MaxSendAmountMsat => {
+							if max_send_amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("maxSendAmountMsat"));
+							}
+							max_send_amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Configured => {
+							if configured__.is_some() {
+								return Err(serde::de::Error::duplicate_field("configured"));
+							}
+							configured__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CreatedAt => {
+							if created_at__.is_some() {
+								return Err(serde::de::Error::duplicate_field("createdAt"));
+							}
+							created_at__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::PreviousKeyExpiresAt => {
+							if previous_key_expires_at__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"previousKeyExpiresAt",
+								));
+							}
+							previous_key_expires_at__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::RevokedAt => {
+							if revoked_at__.is_some() {
+								return Err(serde::de::Error::duplicate_field("revokedAt"));
+							}
+							revoked_at__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ClientIdentity => {
+							if client_identity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("clientIdentity"));
+							}
+							client_identity__ = map_.next_value()?;
+						},
+						GeneratedField::DailyBudgetMsat => {
+							if daily_budget_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("dailyBudgetMsat"));
+							}
+							daily_budget_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(ApiKeyInfo {
+					name: name__.unwrap_or_default(),
+					permissions: permissions__.unwrap_or_default(),
+					max_send_amount_msat: max_send_amount_msat__,
+					configured: configured__.unwrap_or_default(),
+					created_at: created_at__,
+					previous_key_expires_at: previous_key_expires_at__,
+					revoked_at: revoked_at__,
+					client_identity: client_identity__,
+					daily_budget_msat: daily_budget_msat__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.ApiKeyInfo", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ApiKeyPermission {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let variant = match self {
+			Self::Read => "READ",
+			Self::Invoice => "INVOICE",
+			Self::Send => "SEND",
+			Self::Admin => "ADMIN",
+		};
+		serializer.serialize_str(variant)
+	}
+}
+impl<'de> serde::Deserialize<'de> for ApiKeyPermission {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["READ", "INVOICE", "SEND", "ADMIN"];
+
+		struct GeneratedVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ApiKeyPermission;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(formatter, "expected one of: {:?}", &FIELDS)
+			}
+
+			fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(ApiKeyPermission::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+				})
+			}
+
+			fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(ApiKeyPermission::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+				})
+			}
+
+			fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				match value {
+					"READ" => Ok(ApiKeyPermission::Read),
+					"INVOICE" => Ok(ApiKeyPermission::Invoice),
+					"SEND" => Ok(ApiKeyPermission::Send),
+					"ADMIN" => Ok(ApiKeyPermission::Admin),
+					_ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+				}
+			}
+		}
+		deserializer.deserialize_any(GeneratedVisitor)
+	}
+}
+impl serde::Serialize for AuditLogEntry {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.client_identity.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.error.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.AuditLogEntry", len)?;
+		struct_ser.serialize_field("id", &self.id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+		struct_ser.serialize_field("apiKeyName", &self.api_key_name)?;
+		if let Some(v) = self.client_identity.as_ref() {
+			struct_ser.serialize_field("clientIdentity", v)?;
+		}
+		struct_ser.serialize_field("endpoint", &self.endpoint)?;
+		struct_ser.serialize_field("requestSummary", &self.request_summary)?;
+		struct_ser.serialize_field("success", &self.success)?;
+		if let Some(v) = self.error.as_ref() {
+			struct_ser.serialize_field("error", v)?;
+		}
+		struct_ser.serialize_field("completed", &self.completed)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for AuditLogEntry {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"id",
+			"timestamp",
+			"api_key_name",
+			"apiKeyName",
+			"client_identity",
+			"clientIdentity",
+			"endpoint",
+			"request_summary",
+			"requestSummary",
+			"success",
+			"error",
+			"completed",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Id,
+			Timestamp,
+			ApiKeyName,
+			ClientIdentity,
+			Endpoint,
+			RequestSummary,
+			Success,
+			Error,
+			Completed,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"id" => Ok(GeneratedField::Id),
+							"timestamp" => Ok(GeneratedField::Timestamp),
+							"apiKeyName" | "api_key_name" => Ok(GeneratedField::ApiKeyName),
+							"clientIdentity" | "client_identity" => {
+								Ok(GeneratedField::ClientIdentity)
+							},
+							"endpoint" => Ok(GeneratedField::Endpoint),
+							"requestSummary" | "request_summary" => {
+								Ok(GeneratedField::RequestSummary)
+							},
+							"success" => Ok(GeneratedField::Success),
+							"error" => Ok(GeneratedField::Error),
+							"completed" => Ok(GeneratedField::Completed),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = AuditLogEntry;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.AuditLogEntry")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuditLogEntry, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut id__ = None;
+				let mut timestamp__ = None;
+				let mut api_key_name__ = None;
+				let mut client_identity__ = None;
+				let mut endpoint__ = None;
+				let mut request_summary__ = None;
+				let mut success__ = None;
+				let mut error__ = None;
+				let mut completed__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Id => {
+							if id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("id"));
+							}
+							id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Timestamp => {
+							if timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("timestamp"));
+							}
+							timestamp__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ApiKeyName => {
+							if api_key_name__.is_some() {
+								return Err(serde::de::Error::duplicate_field("apiKeyName"));
+							}
+							api_key_name__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ClientIdentity => {
+							if client_identity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("clientIdentity"));
+							}
+							client_identity__ = map_.next_value()?;
+						},
+						GeneratedField::Endpoint => {
+							if endpoint__.is_some() {
+								return Err(serde::de::Error::duplicate_field("endpoint"));
+							}
+							endpoint__ = Some(map_.next_value()?);
+						},
+						GeneratedField::RequestSummary => {
+							if request_summary__.is_some() {
+								return Err(serde::de::Error::duplicate_field("requestSummary"));
+							}
+							request_summary__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Success => {
+							if success__.is_some() {
+								return Err(serde::de::Error::duplicate_field("success"));
+							}
+							success__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Error => {
+							if error__.is_some() {
+								return Err(serde::de::Error::duplicate_field("error"));
+							}
+							error__ = map_.next_value()?;
+						},
+						GeneratedField::Completed => {
+							if completed__.is_some() {
+								return Err(serde::de::Error::duplicate_field("completed"));
+							}
+							completed__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(AuditLogEntry {
+					id: id__.unwrap_or_default(),
+					timestamp: timestamp__.unwrap_or_default(),
+					api_key_name: api_key_name__.unwrap_or_default(),
+					client_identity: client_identity__,
+					endpoint: endpoint__.unwrap_or_default(),
+					request_summary: request_summary__.unwrap_or_default(),
+					success: success__.unwrap_or_default(),
+					error: error__,
+					completed: completed__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.AuditLogEntry", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for AwaitingThresholdConfirmations {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.channel_id.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("types.AwaitingThresholdConfirmations", len)?;
+		if let Some(v) = self.channel_id.as_ref() {
+			struct_ser.serialize_field("channelId", v)?;
+		}
+		struct_ser.serialize_field("latestSpendingTxid", &self.latest_spending_txid)?;
+		struct_ser.serialize_field("confirmationHash", &self.confirmation_hash)?;
+		struct_ser.serialize_field("confirmationHeight", &self.confirmation_height)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for AwaitingThresholdConfirmations {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"latest_spending_txid",
+			"latestSpendingTxid",
+			"confirmation_hash",
+			"confirmationHash",
+			"confirmation_height",
+			"confirmationHeight",
+			"amount_satoshis",
+			"amountSatoshis",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			LatestSpendingTxid,
+			ConfirmationHash,
+			ConfirmationHeight,
+			AmountSatoshis,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"latestSpendingTxid" | "latest_spending_txid" => {
+								Ok(GeneratedField::LatestSpendingTxid)
+							},
+							"confirmationHash" | "confirmation_hash" => {
+								Ok(GeneratedField::ConfirmationHash)
+							},
+							"confirmationHeight" | "confirmation_height" => {
+								Ok(GeneratedField::ConfirmationHeight)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = AwaitingThresholdConfirmations;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.AwaitingThresholdConfirmations")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<AwaitingThresholdConfirmations, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut latest_spending_txid__ = None;
+				let mut confirmation_hash__ = None;
+				let mut confirmation_height__ = None;
+				let mut amount_satoshis__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::LatestSpendingTxid => {
+							if latest_spending_txid__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestSpendingTxid",
+								));
+							}
+							latest_spending_txid__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ConfirmationHash => {
+							if confirmation_hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("confirmationHash"));
+							}
+							confirmation_hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ConfirmationHeight => {
+							if confirmation_height__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"confirmationHeight",
+								));
+							}
+							confirmation_height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(AwaitingThresholdConfirmations {
+					channel_id: channel_id__,
+					latest_spending_txid: latest_spending_txid__.unwrap_or_default(),
+					confirmation_hash: confirmation_hash__.unwrap_or_default(),
+					confirmation_height: confirmation_height__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"types.AwaitingThresholdConfirmations",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for BalanceSource {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let variant = match self {
+			Self::HolderForceClosed => "HOLDER_FORCE_CLOSED",
+			Self::CounterpartyForceClosed => "COUNTERPARTY_FORCE_CLOSED",
+			Self::CoopClose => "COOP_CLOSE",
+			Self::Htlc => "HTLC",
+		};
+		serializer.serialize_str(variant)
+	}
+}
+impl<'de> serde::Deserialize<'de> for BalanceSource {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["HOLDER_FORCE_CLOSED", "COUNTERPARTY_FORCE_CLOSED", "COOP_CLOSE", "HTLC"];
+
+		struct GeneratedVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = BalanceSource;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(formatter, "expected one of: {:?}", &FIELDS)
+			}
+
+			fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(BalanceSource::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+				})
+			}
+
+			fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(BalanceSource::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+				})
+			}
+
+			fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				match value {
+					"HOLDER_FORCE_CLOSED" => Ok(BalanceSource::HolderForceClosed),
+					"COUNTERPARTY_FORCE_CLOSED" => Ok(BalanceSource::CounterpartyForceClosed),
+					"COOP_CLOSE" => Ok(BalanceSource::CoopClose),
+					"HTLC" => Ok(BalanceSource::Htlc),
+					_ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+				}
+			}
+		}
+		deserializer.deserialize_any(GeneratedVisitor)
+	}
+}
+impl serde::Serialize for BestBlock {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.BestBlock", len)?;
+		struct_ser.serialize_field("blockHash", &self.block_hash)?;
+		struct_ser.serialize_field("height", &self.height)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for BestBlock {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["block_hash", "blockHash", "height"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			BlockHash,
+			Height,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+							"height" => Ok(GeneratedField::Height),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = BestBlock;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.BestBlock")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<BestBlock, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut block_hash__ = None;
+				let mut height__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::BlockHash => {
+							if block_hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("blockHash"));
+							}
+							block_hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Height => {
+							if height__.is_some() {
+								return Err(serde::de::Error::duplicate_field("height"));
+							}
+							height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(BestBlock {
+					block_hash: block_hash__.unwrap_or_default(),
+					height: height__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.BestBlock", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11 {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.preimage.is_some() {
+			len += 1;
+		}
+		if self.secret.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Bolt11", len)?;
+		struct_ser.serialize_field("hash", &self.hash)?;
+		if let Some(v) = self.preimage.as_ref() {
+			struct_ser.serialize_field("preimage", v)?;
+		}
+		if let Some(v) = self.secret.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("secret", pbjson::private::base64::encode(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11 {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["hash", "preimage", "secret"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Hash,
+			Preimage,
+			Secret,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"hash" => Ok(GeneratedField::Hash),
+							"preimage" => Ok(GeneratedField::Preimage),
+							"secret" => Ok(GeneratedField::Secret),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Bolt11")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt11, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut hash__ = None;
+				let mut preimage__ = None;
+				let mut secret__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Hash => {
+							if hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("hash"));
+							}
+							hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Preimage => {
+							if preimage__.is_some() {
+								return Err(serde::de::Error::duplicate_field("preimage"));
+							}
+							preimage__ = map_.next_value()?;
+						},
+						GeneratedField::Secret => {
+							if secret__.is_some() {
+								return Err(serde::de::Error::duplicate_field("secret"));
+							}
+							secret__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(Bolt11 {
+					hash: hash__.unwrap_or_default(),
+					preimage: preimage__,
+					secret: secret__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Bolt11", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11InvoiceDescription {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.kind.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Bolt11InvoiceDescription", len)?;
+		if let Some(v) = self.kind.as_ref() {
+			match v {
+				bolt11_invoice_description::Kind::Direct(v) => {
+					struct_ser.serialize_field("direct", v)?;
+				},
+				bolt11_invoice_description::Kind::Hash(v) => {
+					struct_ser.serialize_field("hash", v)?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11InvoiceDescription {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["direct", "hash"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Direct,
+			Hash,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"direct" => Ok(GeneratedField::Direct),
+							"hash" => Ok(GeneratedField::Hash),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11InvoiceDescription;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Bolt11InvoiceDescription")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<Bolt11InvoiceDescription, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut kind__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Direct => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("direct"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(bolt11_invoice_description::Kind::Direct);
+						},
+						GeneratedField::Hash => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("hash"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(bolt11_invoice_description::Kind::Hash);
+						},
+					}
+				}
+				Ok(Bolt11InvoiceDescription { kind: kind__ })
+			}
+		}
+		deserializer.deserialize_struct("types.Bolt11InvoiceDescription", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt11Jit {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.preimage.is_some() {
+			len += 1;
+		}
+		if self.secret.is_some() {
+			len += 1;
+		}
+		if self.lsp_fee_limits.is_some() {
+			len += 1;
+		}
+		if self.counterparty_skimmed_fee_msat.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Bolt11Jit", len)?;
+		struct_ser.serialize_field("hash", &self.hash)?;
+		if let Some(v) = self.preimage.as_ref() {
+			struct_ser.serialize_field("preimage", v)?;
+		}
+		if let Some(v) = self.secret.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("secret", pbjson::private::base64::encode(&v).as_str())?;
+		}
+		if let Some(v) = self.lsp_fee_limits.as_ref() {
+			struct_ser.serialize_field("lspFeeLimits", v)?;
+		}
+		if let Some(v) = self.counterparty_skimmed_fee_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("counterpartySkimmedFeeMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt11Jit {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"hash",
+			"preimage",
+			"secret",
+			"lsp_fee_limits",
+			"lspFeeLimits",
+			"counterparty_skimmed_fee_msat",
+			"counterpartySkimmedFeeMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Hash,
+			Preimage,
+			Secret,
+			LspFeeLimits,
+			CounterpartySkimmedFeeMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"hash" => Ok(GeneratedField::Hash),
+							"preimage" => Ok(GeneratedField::Preimage),
+							"secret" => Ok(GeneratedField::Secret),
+							"lspFeeLimits" | "lsp_fee_limits" => Ok(GeneratedField::LspFeeLimits),
+							"counterpartySkimmedFeeMsat" | "counterparty_skimmed_fee_msat" => {
+								Ok(GeneratedField::CounterpartySkimmedFeeMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt11Jit;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Bolt11Jit")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt11Jit, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut hash__ = None;
+				let mut preimage__ = None;
+				let mut secret__ = None;
+				let mut lsp_fee_limits__ = None;
+				let mut counterparty_skimmed_fee_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Hash => {
+							if hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("hash"));
+							}
+							hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Preimage => {
+							if preimage__.is_some() {
+								return Err(serde::de::Error::duplicate_field("preimage"));
+							}
+							preimage__ = map_.next_value()?;
+						},
+						GeneratedField::Secret => {
+							if secret__.is_some() {
+								return Err(serde::de::Error::duplicate_field("secret"));
+							}
+							secret__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::LspFeeLimits => {
+							if lsp_fee_limits__.is_some() {
+								return Err(serde::de::Error::duplicate_field("lspFeeLimits"));
+							}
+							lsp_fee_limits__ = map_.next_value()?;
+						},
+						GeneratedField::CounterpartySkimmedFeeMsat => {
+							if counterparty_skimmed_fee_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartySkimmedFeeMsat",
+								));
+							}
+							counterparty_skimmed_fee_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(Bolt11Jit {
+					hash: hash__.unwrap_or_default(),
+					preimage: preimage__,
+					secret: secret__,
+					lsp_fee_limits: lsp_fee_limits__,
+					counterparty_skimmed_fee_msat: counterparty_skimmed_fee_msat__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Bolt11Jit", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt12Offer {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.hash.is_some() {
+			len += 1;
+		}
+		if self.preimage.is_some() {
+			len += 1;
+		}
+		if self.secret.is_some() {
+			len += 1;
+		}
+		len += 1;
+		if self.payer_note.is_some() {
+			len += 1;
+		}
+		if self.quantity.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Bolt12Offer", len)?;
+		if let Some(v) = self.hash.as_ref() {
+			struct_ser.serialize_field("hash", v)?;
+		}
+		if let Some(v) = self.preimage.as_ref() {
+			struct_ser.serialize_field("preimage", v)?;
+		}
+		if let Some(v) = self.secret.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("secret", pbjson::private::base64::encode(&v).as_str())?;
+		}
+		struct_ser.serialize_field("offerId", &self.offer_id)?;
+		if let Some(v) = self.payer_note.as_ref() {
+			struct_ser.serialize_field("payerNote", v)?;
+		}
+		if let Some(v) = self.quantity.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("quantity", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt12Offer {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"hash",
+			"preimage",
+			"secret",
+			"offer_id",
+			"offerId",
+			"payer_note",
+			"payerNote",
+			"quantity",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Hash,
+			Preimage,
+			Secret,
+			OfferId,
+			PayerNote,
+			Quantity,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"hash" => Ok(GeneratedField::Hash),
+							"preimage" => Ok(GeneratedField::Preimage),
+							"secret" => Ok(GeneratedField::Secret),
+							"offerId" | "offer_id" => Ok(GeneratedField::OfferId),
+							"payerNote" | "payer_note" => Ok(GeneratedField::PayerNote),
+							"quantity" => Ok(GeneratedField::Quantity),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt12Offer;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Bolt12Offer")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt12Offer, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut hash__ = None;
+				let mut preimage__ = None;
+				let mut secret__ = None;
+				let mut offer_id__ = None;
+				let mut payer_note__ = None;
+				let mut quantity__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Hash => {
+							if hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("hash"));
+							}
+							hash__ = map_.next_value()?;
+						},
+						GeneratedField::Preimage => {
+							if preimage__.is_some() {
+								return Err(serde::de::Error::duplicate_field("preimage"));
+							}
+							preimage__ = map_.next_value()?;
+						},
+						GeneratedField::Secret => {
+							if secret__.is_some() {
+								return Err(serde::de::Error::duplicate_field("secret"));
+							}
+							secret__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::OfferId => {
+							if offer_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("offerId"));
+							}
+							offer_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PayerNote => {
+							if payer_note__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payerNote"));
+							}
+							payer_note__ = map_.next_value()?;
+						},
+						GeneratedField::Quantity => {
+							if quantity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("quantity"));
+							}
+							quantity__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(Bolt12Offer {
+					hash: hash__,
+					preimage: preimage__,
+					secret: secret__,
+					offer_id: offer_id__.unwrap_or_default(),
+					payer_note: payer_note__,
+					quantity: quantity__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Bolt12Offer", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Bolt12Refund {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.hash.is_some() {
+			len += 1;
+		}
+		if self.preimage.is_some() {
+			len += 1;
+		}
+		if self.secret.is_some() {
+			len += 1;
+		}
+		if self.payer_note.is_some() {
+			len += 1;
+		}
+		if self.quantity.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Bolt12Refund", len)?;
+		if let Some(v) = self.hash.as_ref() {
+			struct_ser.serialize_field("hash", v)?;
+		}
+		if let Some(v) = self.preimage.as_ref() {
+			struct_ser.serialize_field("preimage", v)?;
+		}
+		if let Some(v) = self.secret.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("secret", pbjson::private::base64::encode(&v).as_str())?;
+		}
+		if let Some(v) = self.payer_note.as_ref() {
+			struct_ser.serialize_field("payerNote", v)?;
+		}
+		if let Some(v) = self.quantity.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("quantity", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Bolt12Refund {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["hash", "preimage", "secret", "payer_note", "payerNote", "quantity"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Hash,
+			Preimage,
+			Secret,
+			PayerNote,
+			Quantity,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"hash" => Ok(GeneratedField::Hash),
+							"preimage" => Ok(GeneratedField::Preimage),
+							"secret" => Ok(GeneratedField::Secret),
+							"payerNote" | "payer_note" => Ok(GeneratedField::PayerNote),
+							"quantity" => Ok(GeneratedField::Quantity),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Bolt12Refund;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Bolt12Refund")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Bolt12Refund, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut hash__ = None;
+				let mut preimage__ = None;
+				let mut secret__ = None;
+				let mut payer_note__ = None;
+				let mut quantity__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Hash => {
+							if hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("hash"));
+							}
+							hash__ = map_.next_value()?;
+						},
+						GeneratedField::Preimage => {
+							if preimage__.is_some() {
+								return Err(serde::de::Error::duplicate_field("preimage"));
+							}
+							preimage__ = map_.next_value()?;
+						},
+						GeneratedField::Secret => {
+							if secret__.is_some() {
+								return Err(serde::de::Error::duplicate_field("secret"));
+							}
+							secret__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::PayerNote => {
+							if payer_note__.is_some() {
+								return Err(serde::de::Error::duplicate_field("payerNote"));
+							}
+							payer_note__ = map_.next_value()?;
+						},
+						GeneratedField::Quantity => {
+							if quantity__.is_some() {
+								return Err(serde::de::Error::duplicate_field("quantity"));
+							}
+							quantity__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(Bolt12Refund {
+					hash: hash__,
+					preimage: preimage__,
+					secret: secret__,
+					payer_note: payer_note__,
+					quantity: quantity__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Bolt12Refund", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for BroadcastAwaitingConfirmation {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.channel_id.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("types.BroadcastAwaitingConfirmation", len)?;
+		if let Some(v) = self.channel_id.as_ref() {
+			struct_ser.serialize_field("channelId", v)?;
+		}
+		struct_ser.serialize_field("latestBroadcastHeight", &self.latest_broadcast_height)?;
+		struct_ser.serialize_field("latestSpendingTxid", &self.latest_spending_txid)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for BroadcastAwaitingConfirmation {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"latest_broadcast_height",
+			"latestBroadcastHeight",
+			"latest_spending_txid",
+			"latestSpendingTxid",
+			"amount_satoshis",
+			"amountSatoshis",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			LatestBroadcastHeight,
+			LatestSpendingTxid,
+			AmountSatoshis,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"latestBroadcastHeight" | "latest_broadcast_height" => {
+								Ok(GeneratedField::LatestBroadcastHeight)
+							},
+							"latestSpendingTxid" | "latest_spending_txid" => {
+								Ok(GeneratedField::LatestSpendingTxid)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = BroadcastAwaitingConfirmation;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.BroadcastAwaitingConfirmation")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<BroadcastAwaitingConfirmation, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut latest_broadcast_height__ = None;
+				let mut latest_spending_txid__ = None;
+				let mut amount_satoshis__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::LatestBroadcastHeight => {
+							if latest_broadcast_height__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestBroadcastHeight",
+								));
+							}
+							latest_broadcast_height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::LatestSpendingTxid => {
+							if latest_spending_txid__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestSpendingTxid",
+								));
+							}
+							latest_spending_txid__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(BroadcastAwaitingConfirmation {
+					channel_id: channel_id__,
+					latest_broadcast_height: latest_broadcast_height__.unwrap_or_default(),
+					latest_spending_txid: latest_spending_txid__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"types.BroadcastAwaitingConfirmation",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for Channel {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.funding_txo.is_some() {
+			len += 1;
+		}
+		len += 1;
+		if self.unspendable_punishment_reserve.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.confirmations_required.is_some() {
+			len += 1;
+		}
+		if self.confirmations.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.channel_config.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		if self.force_close_spend_delay.is_some() {
+			len += 1;
+		}
+		if self.counterparty_outbound_htlc_minimum_msat.is_some() {
+			len += 1;
+		}
+		if self.counterparty_outbound_htlc_maximum_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		if self.counterparty_forwarding_info_fee_base_msat.is_some() {
+			len += 1;
+		}
+		if self.counterparty_forwarding_info_fee_proportional_millionths.is_some() {
+			len += 1;
+		}
+		if self.counterparty_forwarding_info_cltv_expiry_delta.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Channel", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		if let Some(v) = self.funding_txo.as_ref() {
+			struct_ser.serialize_field("fundingTxo", v)?;
+		}
+		struct_ser.serialize_field("userChannelId", &self.user_channel_id)?;
+		if let Some(v) = self.unspendable_punishment_reserve.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"unspendablePunishmentReserve",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"channelValueSats",
+			ToString::to_string(&self.channel_value_sats).as_str(),
+		)?;
+		struct_ser.serialize_field("feerateSatPer1000Weight", &self.feerate_sat_per_1000_weight)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"outboundCapacityMsat",
+			ToString::to_string(&self.outbound_capacity_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"inboundCapacityMsat",
+			ToString::to_string(&self.inbound_capacity_msat).as_str(),
+		)?;
+		if let Some(v) = self.confirmations_required.as_ref() {
+			struct_ser.serialize_field("confirmationsRequired", v)?;
+		}
+		if let Some(v) = self.confirmations.as_ref() {
+			struct_ser.serialize_field("confirmations", v)?;
+		}
+		struct_ser.serialize_field("isOutbound", &self.is_outbound)?;
+		struct_ser.serialize_field("isChannelReady", &self.is_channel_ready)?;
+		struct_ser.serialize_field("isUsable", &self.is_usable)?;
+		struct_ser.serialize_field("isAnnounced", &self.is_announced)?;
+		if let Some(v) = self.channel_config.as_ref() {
+			struct_ser.serialize_field("channelConfig", v)?;
+		}
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"nextOutboundHtlcLimitMsat",
+			ToString::to_string(&self.next_outbound_htlc_limit_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"nextOutboundHtlcMinimumMsat",
+			ToString::to_string(&self.next_outbound_htlc_minimum_msat).as_str(),
+		)?;
+		if let Some(v) = self.force_close_spend_delay.as_ref() {
+			struct_ser.serialize_field("forceCloseSpendDelay", v)?;
+		}
+		if let Some(v) = self.counterparty_outbound_htlc_minimum_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"counterpartyOutboundHtlcMinimumMsat",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		if let Some(v) = self.counterparty_outbound_htlc_maximum_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"counterpartyOutboundHtlcMaximumMsat",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"counterpartyUnspendablePunishmentReserve",
+			ToString::to_string(&self.counterparty_unspendable_punishment_reserve).as_str(),
+		)?;
+		if let Some(v) = self.counterparty_forwarding_info_fee_base_msat.as_ref() {
+			struct_ser.serialize_field("counterpartyForwardingInfoFeeBaseMsat", v)?;
+		}
+		if let Some(v) = self.counterparty_forwarding_info_fee_proportional_millionths.as_ref() {
+			struct_ser.serialize_field("counterpartyForwardingInfoFeeProportionalMillionths", v)?;
+		}
+		if let Some(v) = self.counterparty_forwarding_info_cltv_expiry_delta.as_ref() {
+			struct_ser.serialize_field("counterpartyForwardingInfoCltvExpiryDelta", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Channel {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"funding_txo",
+			"fundingTxo",
+			"user_channel_id",
+			"userChannelId",
+			"unspendable_punishment_reserve",
+			"unspendablePunishmentReserve",
+			"channel_value_sats",
+			"channelValueSats",
+			"feerate_sat_per_1000_weight",
+			"feerateSatPer1000Weight",
+			"outbound_capacity_msat",
+			"outboundCapacityMsat",
+			"inbound_capacity_msat",
+			"inboundCapacityMsat",
+			"confirmations_required",
+			"confirmationsRequired",
+			"confirmations",
+			"is_outbound",
+			"isOutbound",
+			"is_channel_ready",
+			"isChannelReady",
+			"is_usable",
+			"isUsable",
+			"is_announced",
+			"isAnnounced",
+			"channel_config",
+			"channelConfig",
+			"next_outbound_htlc_limit_msat",
+			"nextOutboundHtlcLimitMsat",
+			"next_outbound_htlc_minimum_msat",
+			"nextOutboundHtlcMinimumMsat",
+			"force_close_spend_delay",
+			"forceCloseSpendDelay",
+			"counterparty_outbound_htlc_minimum_msat",
+			"counterpartyOutboundHtlcMinimumMsat",
+			"counterparty_outbound_htlc_maximum_msat",
+			"counterpartyOutboundHtlcMaximumMsat",
+			"counterparty_unspendable_punishment_reserve",
+			"counterpartyUnspendablePunishmentReserve",
+			"counterparty_forwarding_info_fee_base_msat",
+			"counterpartyForwardingInfoFeeBaseMsat",
+			"counterparty_forwarding_info_fee_proportional_millionths",
+			"counterpartyForwardingInfoFeeProportionalMillionths",
+			"counterparty_forwarding_info_cltv_expiry_delta",
+			"counterpartyForwardingInfoCltvExpiryDelta",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			FundingTxo,
+			UserChannelId,
+			UnspendablePunishmentReserve,
+			ChannelValueSats,
+			FeerateSatPer1000Weight,
+			OutboundCapacityMsat,
+			InboundCapacityMsat,
+			ConfirmationsRequired,
+			Confirmations,
+			IsOutbound,
+			IsChannelReady,
+			IsUsable,
+			IsAnnounced,
+			ChannelConfig,
+			NextOutboundHtlcLimitMsat,
+			NextOutboundHtlcMinimumMsat,
+			ForceCloseSpendDelay,
+			CounterpartyOutboundHtlcMinimumMsat,
+			CounterpartyOutboundHtlcMaximumMsat,
+			CounterpartyUnspendablePunishmentReserve,
+			CounterpartyForwardingInfoFeeBaseMsat,
+			CounterpartyForwardingInfoFeeProportionalMillionths,
+			CounterpartyForwardingInfoCltvExpiryDelta,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"fundingTxo" | "funding_txo" => Ok(GeneratedField::FundingTxo),
+							"userChannelId" | "user_channel_id" => {
+								Ok(GeneratedField::UserChannelId)
+							},
+							"unspendablePunishmentReserve" | "unspendable_punishment_reserve" => {
+								Ok(GeneratedField::UnspendablePunishmentReserve)
+							},
+							"channelValueSats" | "channel_value_sats" => {
+								Ok(GeneratedField::ChannelValueSats)
+							},
+							"feerateSatPer1000Weight" | "feerate_sat_per_1000_weight" => {
+								Ok(GeneratedField::FeerateSatPer1000Weight)
+							},
+							"outboundCapacityMsat" | "outbound_capacity_msat" => {
+								Ok(GeneratedField::OutboundCapacityMsat)
+							},
+							"inboundCapacityMsat" | "inbound_capacity_msat" => {
+								Ok(GeneratedField::InboundCapacityMsat)
+							},
+							"confirmationsRequired" | "confirmations_required" => {
+								Ok(GeneratedField::ConfirmationsRequired)
+							},
+							"confirmations" => Ok(GeneratedField::Confirmations),
+							"isOutbound" | "is_outbound" => Ok(GeneratedField::IsOutbound),
+							"isChannelReady" | "is_channel_ready" => {
+								Ok(GeneratedField::IsChannelReady)
+							},
+							"isUsable" | "is_usable" => Ok(GeneratedField::IsUsable),
+							"isAnnounced" | "is_announced" => Ok(GeneratedField::IsAnnounced),
+							"channelConfig" | "channel_config" => Ok(GeneratedField::ChannelConfig),
+							"nextOutboundHtlcLimitMsat" | "next_outbound_htlc_limit_msat" => {
+								Ok(GeneratedField::NextOutboundHtlcLimitMsat)
+							},
+							"nextOutboundHtlcMinimumMsat" | "next_outbound_htlc_minimum_msat" => {
+								Ok(GeneratedField::NextOutboundHtlcMinimumMsat)
+							},
+							"forceCloseSpendDelay" | "force_close_spend_delay" => {
+								Ok(GeneratedField::ForceCloseSpendDelay)
+							},
+							"counterpartyOutboundHtlcMinimumMsat"
+							| "counterparty_outbound_htlc_minimum_msat" => {
+								Ok(GeneratedField::CounterpartyOutboundHtlcMinimumMsat)
+							},
+							"counterpartyOutboundHtlcMaximumMsat"
+							| "counterparty_outbound_htlc_maximum_msat" => {
+								Ok(GeneratedField::CounterpartyOutboundHtlcMaximumMsat)
+							},
+							"counterpartyUnspendablePunishmentReserve"
+							| "counterparty_unspendable_punishment_reserve" => {
+								Ok(GeneratedField::CounterpartyUnspendablePunishmentReserve)
+							},
+							"counterpartyForwardingInfoFeeBaseMsat"
+							| "counterparty_forwarding_info_fee_base_msat" => {
+								Ok(GeneratedField::CounterpartyForwardingInfoFeeBaseMsat)
+							},
+							"counterpartyForwardingInfoFeeProportionalMillionths"
+							| "counterparty_forwarding_info_fee_proportional_millionths" => Ok(
+								GeneratedField::CounterpartyForwardingInfoFeeProportionalMillionths,
+							),
+							"counterpartyForwardingInfoCltvExpiryDelta"
+							| "counterparty_forwarding_info_cltv_expiry_delta" => {
+								Ok(GeneratedField::CounterpartyForwardingInfoCltvExpiryDelta)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Channel;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Channel")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Channel, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut funding_txo__ = None;
+				let mut user_channel_id__ = None;
+				let mut unspendable_punishment_reserve__ = None;
+				let mut channel_value_sats__ = None;
+				let mut feerate_sat_per_1000_weight__ = None;
+				let mut outbound_capacity_msat__ = None;
+				let mut inbound_capacity_msat__ = None;
+				let mut confirmations_required__ = None;
+				let mut confirmations__ = None;
+				let mut is_outbound__ = None;
+				let mut is_channel_ready__ = None;
+				let mut is_usable__ = None;
+				let mut is_announced__ = None;
+				let mut channel_config__ = None;
+				let mut next_outbound_htlc_limit_msat__ = None;
+				let mut next_outbound_htlc_minimum_msat__ = None;
+				let mut force_close_spend_delay__ = None;
+				let mut counterparty_outbound_htlc_minimum_msat__ = None;
+				let mut counterparty_outbound_htlc_maximum_msat__ = None;
+				let mut counterparty_unspendable_punishment_reserve__ = None;
+				let mut counterparty_forwarding_info_fee_base_msat__ = None;
+				let mut counterparty_forwarding_info_fee_proportional_millionths__ = None;
+				let mut counterparty_forwarding_info_cltv_expiry_delta__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::FundingTxo => {
+							if funding_txo__.is_some() {
+								return Err(serde::de::Error::duplicate_field("fundingTxo"));
+							}
+							funding_txo__ = map_.next_value()?;
+						},
+						GeneratedField::UserChannelId => {
+							if user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("userChannelId"));
+							}
+							user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::UnspendablePunishmentReserve => {
+							if unspendable_punishment_reserve__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"unspendablePunishmentReserve",
+								));
+							}
+							unspendable_punishment_reserve__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ChannelValueSats => {
+							if channel_value_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelValueSats"));
+							}
+							channel_value_sats__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::FeerateSatPer1000Weight => {
+							if feerate_sat_per_1000_weight__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"feerateSatPer1000Weight",
+								));
+							}
+							feerate_sat_per_1000_weight__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::OutboundCapacityMsat => {
+							if outbound_capacity_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundCapacityMsat",
+								));
+							}
+							outbound_capacity_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::InboundCapacityMsat => {
+							if inbound_capacity_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"inboundCapacityMsat",
+								));
+							}
+							inbound_capacity_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ConfirmationsRequired => {
+							if confirmations_required__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"confirmationsRequired",
+								));
+							}
+							confirmations_required__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Confirmations => {
+							if confirmations__.is_some() {
+								return Err(serde::de::Error::duplicate_field("confirmations"));
+							}
+							confirmations__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::IsOutbound => {
+							if is_outbound__.is_some() {
+								return Err(serde::de::Error::duplicate_field("isOutbound"));
+							}
+							is_outbound__ = Some(map_.next_value()?);
+						},
+						GeneratedField::IsChannelReady => {
+							if is_channel_ready__.is_some() {
+								return Err(serde::de::Error::duplicate_field("isChannelReady"));
+							}
+							is_channel_ready__ = Some(map_.next_value()?);
+						},
+						GeneratedField::IsUsable => {
+							if is_usable__.is_some() {
+								return Err(serde::de::Error::duplicate_field("isUsable"));
+							}
+							is_usable__ = Some(map_.next_value()?);
+						},
+						GeneratedField::IsAnnounced => {
+							if is_announced__.is_some() {
+								return Err(serde::de::Error::duplicate_field("isAnnounced"));
+							}
+							is_announced__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ChannelConfig => {
+							if channel_config__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelConfig"));
+							}
+							channel_config__ = map_.next_value()?;
+						},
+						GeneratedField::NextOutboundHtlcLimitMsat => {
+							if next_outbound_htlc_limit_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"nextOutboundHtlcLimitMsat",
+								));
+							}
+							next_outbound_htlc_limit_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::NextOutboundHtlcMinimumMsat => {
+							if next_outbound_htlc_minimum_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"nextOutboundHtlcMinimumMsat",
+								));
+							}
+							next_outbound_htlc_minimum_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ForceCloseSpendDelay => {
+							if force_close_spend_delay__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forceCloseSpendDelay",
+								));
+							}
+							force_close_spend_delay__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::CounterpartyOutboundHtlcMinimumMsat => {
+							if counterparty_outbound_htlc_minimum_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyOutboundHtlcMinimumMsat",
+								));
+							}
+							counterparty_outbound_htlc_minimum_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::CounterpartyOutboundHtlcMaximumMsat => {
+							if counterparty_outbound_htlc_maximum_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyOutboundHtlcMaximumMsat",
+								));
+							}
+							counterparty_outbound_htlc_maximum_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::CounterpartyUnspendablePunishmentReserve => {
+							if counterparty_unspendable_punishment_reserve__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyUnspendablePunishmentReserve",
+								));
+							}
+							counterparty_unspendable_punishment_reserve__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::CounterpartyForwardingInfoFeeBaseMsat => {
+							if counterparty_forwarding_info_fee_base_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyForwardingInfoFeeBaseMsat",
+								));
+							}
+							counterparty_forwarding_info_fee_base_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::CounterpartyForwardingInfoFeeProportionalMillionths => {
+							if counterparty_forwarding_info_fee_proportional_millionths__.is_some()
+							{
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyForwardingInfoFeeProportionalMillionths",
+								));
+							}
+							counterparty_forwarding_info_fee_proportional_millionths__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::CounterpartyForwardingInfoCltvExpiryDelta => {
+							if counterparty_forwarding_info_cltv_expiry_delta__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyForwardingInfoCltvExpiryDelta",
+								));
+							}
+							counterparty_forwarding_info_cltv_expiry_delta__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(Channel {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					funding_txo: funding_txo__,
+					user_channel_id: user_channel_id__.unwrap_or_default(),
+					unspendable_punishment_reserve: unspendable_punishment_reserve__,
+					channel_value_sats: channel_value_sats__.unwrap_or_default(),
+					feerate_sat_per_1000_weight: feerate_sat_per_1000_weight__.unwrap_or_default(),
+					outbound_capacity_msat: outbound_capacity_msat__.unwrap_or_default(),
+					inbound_capacity_msat: inbound_capacity_msat__.unwrap_or_default(),
+					confirmations_required: confirmations_required__,
+					confirmations: confirmations__,
+					is_outbound: is_outbound__.unwrap_or_default(),
+					is_channel_ready: is_channel_ready__.unwrap_or_default(),
+					is_usable: is_usable__.unwrap_or_default(),
+					is_announced: is_announced__.unwrap_or_default(),
+					channel_config: channel_config__,
+					next_outbound_htlc_limit_msat: next_outbound_htlc_limit_msat__
+						.unwrap_or_default(),
+					next_outbound_htlc_minimum_msat: next_outbound_htlc_minimum_msat__
+						.unwrap_or_default(),
+					force_close_spend_delay: force_close_spend_delay__,
+					counterparty_outbound_htlc_minimum_msat:
+						counterparty_outbound_htlc_minimum_msat__,
+					counterparty_outbound_htlc_maximum_msat:
+						counterparty_outbound_htlc_maximum_msat__,
+					counterparty_unspendable_punishment_reserve:
+						counterparty_unspendable_punishment_reserve__.unwrap_or_default(),
+					counterparty_forwarding_info_fee_base_msat:
+						counterparty_forwarding_info_fee_base_msat__,
+					counterparty_forwarding_info_fee_proportional_millionths:
+						counterparty_forwarding_info_fee_proportional_millionths__,
+					counterparty_forwarding_info_cltv_expiry_delta:
+						counterparty_forwarding_info_cltv_expiry_delta__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Channel", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ChannelConfig {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.forwarding_fee_proportional_millionths.is_some() {
+			len += 1;
+		}
+		if self.forwarding_fee_base_msat.is_some() {
+			len += 1;
+		}
+		if self.cltv_expiry_delta.is_some() {
+			len += 1;
+		}
+		if self.force_close_avoidance_max_fee_satoshis.is_some() {
+			len += 1;
+		}
+		if self.accept_underpaying_htlcs.is_some() {
+			len += 1;
+		}
+		if self.max_dust_htlc_exposure.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.ChannelConfig", len)?;
+		if let Some(v) = self.forwarding_fee_proportional_millionths.as_ref() {
+			struct_ser.serialize_field("forwardingFeeProportionalMillionths", v)?;
+		}
+		if let Some(v) = self.forwarding_fee_base_msat.as_ref() {
+			struct_ser.serialize_field("forwardingFeeBaseMsat", v)?;
+		}
+		if let Some(v) = self.cltv_expiry_delta.as_ref() {
+			struct_ser.serialize_field("cltvExpiryDelta", v)?;
+		}
+		if let Some(v) = self.force_close_avoidance_max_fee_satoshis.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"forceCloseAvoidanceMaxFeeSatoshis",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		if let Some(v) = self.accept_underpaying_htlcs.as_ref() {
+			struct_ser.serialize_field("acceptUnderpayingHtlcs", v)?;
+		}
+		if let Some(v) = self.max_dust_htlc_exposure.as_ref() {
+			match v {
+				channel_config::MaxDustHtlcExposure::FixedLimitMsat(v) => {
+					#[allow(clippy::needless_borrow)]
+					struct_ser
+						.serialize_field("fixedLimitMsat", ToString::to_string(&v).as_str())?;
+				},
+				channel_config::MaxDustHtlcExposure::FeeRateMultiplier(v) => {
+					#[allow(clippy::needless_borrow)]
+					struct_ser
+						.serialize_field("feeRateMultiplier", ToString::to_string(&v).as_str())?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ChannelConfig {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"forwarding_fee_proportional_millionths",
+			"forwardingFeeProportionalMillionths",
+			"forwarding_fee_base_msat",
+			"forwardingFeeBaseMsat",
+			"cltv_expiry_delta",
+			"cltvExpiryDelta",
+			"force_close_avoidance_max_fee_satoshis",
+			"forceCloseAvoidanceMaxFeeSatoshis",
+			"accept_underpaying_htlcs",
+			"acceptUnderpayingHtlcs",
+			"fixed_limit_msat",
+			"fixedLimitMsat",
+			"fee_rate_multiplier",
+			"feeRateMultiplier",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ForwardingFeeProportionalMillionths,
+			ForwardingFeeBaseMsat,
+			CltvExpiryDelta,
+			ForceCloseAvoidanceMaxFeeSatoshis,
+			AcceptUnderpayingHtlcs,
+			FixedLimitMsat,
+			FeeRateMultiplier,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"forwardingFeeProportionalMillionths"
+							| "forwarding_fee_proportional_millionths" => {
+								Ok(GeneratedField::ForwardingFeeProportionalMillionths)
+							},
+							"forwardingFeeBaseMsat" | "forwarding_fee_base_msat" => {
+								Ok(GeneratedField::ForwardingFeeBaseMsat)
+							},
+							"cltvExpiryDelta" | "cltv_expiry_delta" => {
+								Ok(GeneratedField::CltvExpiryDelta)
+							},
+							"forceCloseAvoidanceMaxFeeSatoshis"
+							| "force_close_avoidance_max_fee_satoshis" => {
+								Ok(GeneratedField::ForceCloseAvoidanceMaxFeeSatoshis)
+							},
+							"acceptUnderpayingHtlcs" | "accept_underpaying_htlcs" => {
+								Ok(GeneratedField::AcceptUnderpayingHtlcs)
+							},
+							"fixedLimitMsat" | "fixed_limit_msat" => {
+								Ok(GeneratedField::FixedLimitMsat)
+							},
+							"feeRateMultiplier" | "fee_rate_multiplier" => {
+								Ok(GeneratedField::FeeRateMultiplier)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ChannelConfig;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ChannelConfig")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ChannelConfig, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut forwarding_fee_proportional_millionths__ = None;
+				let mut forwarding_fee_base_msat__ = None;
+				let mut cltv_expiry_delta__ = None;
+				let mut force_close_avoidance_max_fee_satoshis__ = None;
+				let mut accept_underpaying_htlcs__ = None;
+				let mut max_dust_htlc_exposure__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ForwardingFeeProportionalMillionths => {
+							if forwarding_fee_proportional_millionths__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forwardingFeeProportionalMillionths",
+								));
+							}
+							forwarding_fee_proportional_millionths__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ForwardingFeeBaseMsat => {
+							if forwarding_fee_base_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forwardingFeeBaseMsat",
+								));
+							}
+							forwarding_fee_base_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::CltvExpiryDelta => {
+							if cltv_expiry_delta__.is_some() {
+								return Err(serde::de::Error::duplicate_field("cltvExpiryDelta"));
+							}
+							cltv_expiry_delta__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ForceCloseAvoidanceMaxFeeSatoshis => {
+							if force_close_avoidance_max_fee_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forceCloseAvoidanceMaxFeeSatoshis",
+								));
+							}
+							force_close_avoidance_max_fee_satoshis__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::AcceptUnderpayingHtlcs => {
+							if accept_underpaying_htlcs__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"acceptUnderpayingHtlcs",
+								));
+							}
+							accept_underpaying_htlcs__ = map_.next_value()?;
+						},
+						GeneratedField::FixedLimitMsat => {
+							if max_dust_htlc_exposure__.is_some() {
+								return Err(serde::de::Error::duplicate_field("fixedLimitMsat"));
+							}
+							max_dust_htlc_exposure__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| channel_config::MaxDustHtlcExposure::FixedLimitMsat(x.0));
+						},
+						GeneratedField::FeeRateMultiplier => {
+							if max_dust_htlc_exposure__.is_some() {
+								return Err(serde::de::Error::duplicate_field("feeRateMultiplier"));
+							}
+							max_dust_htlc_exposure__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| {
+									channel_config::MaxDustHtlcExposure::FeeRateMultiplier(x.0)
+								});
+						},
+					}
+				}
+				Ok(ChannelConfig {
+					forwarding_fee_proportional_millionths:
+						forwarding_fee_proportional_millionths__,
+					forwarding_fee_base_msat: forwarding_fee_base_msat__,
+					cltv_expiry_delta: cltv_expiry_delta__,
+					force_close_avoidance_max_fee_satoshis:
+						force_close_avoidance_max_fee_satoshis__,
+					accept_underpaying_htlcs: accept_underpaying_htlcs__,
+					max_dust_htlc_exposure: max_dust_htlc_exposure__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.ChannelConfig", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ChannelPairForwardingStats {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("types.ChannelPairForwardingStats", len)?;
+		struct_ser.serialize_field("prevChannelId", &self.prev_channel_id)?;
+		struct_ser.serialize_field("nextChannelId", &self.next_channel_id)?;
+		struct_ser.serialize_field("prevNodeId", &self.prev_node_id)?;
+		struct_ser.serialize_field("nextNodeId", &self.next_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"forwardedPaymentsCount",
+			ToString::to_string(&self.forwarded_payments_count).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"outboundAmountForwardedMsat",
+			ToString::to_string(&self.outbound_amount_forwarded_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"totalFeeEarnedMsat",
+			ToString::to_string(&self.total_fee_earned_msat).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ChannelPairForwardingStats {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"prev_channel_id",
+			"prevChannelId",
+			"next_channel_id",
+			"nextChannelId",
+			"prev_node_id",
+			"prevNodeId",
+			"next_node_id",
+			"nextNodeId",
+			"forwarded_payments_count",
+			"forwardedPaymentsCount",
+			"outbound_amount_forwarded_msat",
+			"outboundAmountForwardedMsat",
+			"total_fee_earned_msat",
+			"totalFeeEarnedMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PrevChannelId,
+			NextChannelId,
+			PrevNodeId,
+			NextNodeId,
+			ForwardedPaymentsCount,
+			OutboundAmountForwardedMsat,
+			TotalFeeEarnedMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"prevChannelId" | "prev_channel_id" => {
+								Ok(GeneratedField::PrevChannelId)
+							},
+							"nextChannelId" | "next_channel_id" => {
+								Ok(GeneratedField::NextChannelId)
+							},
+							"prevNodeId" | "prev_node_id" => Ok(GeneratedField::PrevNodeId),
+							"nextNodeId" | "next_node_id" => Ok(GeneratedField::NextNodeId),
+							"forwardedPaymentsCount" | "forwarded_payments_count" => {
+								Ok(GeneratedField::ForwardedPaymentsCount)
+							},
+							"outboundAmountForwardedMsat" | "outbound_amount_forwarded_msat" => {
+								Ok(GeneratedField::OutboundAmountForwardedMsat)
+							},
+							"totalFeeEarnedMsat" | "total_fee_earned_msat" => {
+								Ok(GeneratedField::TotalFeeEarnedMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ChannelPairForwardingStats;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ChannelPairForwardingStats")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ChannelPairForwardingStats, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut prev_channel_id__ = None;
+				let mut next_channel_id__ = None;
+				let mut prev_node_id__ = None;
+				let mut next_node_id__ = None;
+				let mut forwarded_payments_count__ = None;
+				let mut outbound_amount_forwarded_msat__ = None;
+				let mut total_fee_earned_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PrevChannelId => {
+							if prev_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevChannelId"));
+							}
+							prev_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextChannelId => {
+							if next_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextChannelId"));
+							}
+							next_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PrevNodeId => {
+							if prev_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevNodeId"));
+							}
+							prev_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextNodeId => {
+							if next_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextNodeId"));
+							}
+							next_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::ForwardedPaymentsCount => {
+							if forwarded_payments_count__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forwardedPaymentsCount",
+								));
+							}
+							forwarded_payments_count__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::OutboundAmountForwardedMsat => {
+							if outbound_amount_forwarded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundAmountForwardedMsat",
+								));
+							}
+							outbound_amount_forwarded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TotalFeeEarnedMsat => {
+							if total_fee_earned_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalFeeEarnedMsat",
+								));
+							}
+							total_fee_earned_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(ChannelPairForwardingStats {
+					prev_channel_id: prev_channel_id__.unwrap_or_default(),
+					next_channel_id: next_channel_id__.unwrap_or_default(),
+					prev_node_id: prev_node_id__.unwrap_or_default(),
+					next_node_id: next_node_id__.unwrap_or_default(),
+					forwarded_payments_count: forwarded_payments_count__.unwrap_or_default(),
+					outbound_amount_forwarded_msat: outbound_amount_forwarded_msat__
+						.unwrap_or_default(),
+					total_fee_earned_msat: total_fee_earned_msat__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"types.ChannelPairForwardingStats",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ClaimableAwaitingConfirmations {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("types.ClaimableAwaitingConfirmations", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.serialize_field("confirmationHeight", &self.confirmation_height)?;
+		let v = BalanceSource::from_i32(self.source)
+			.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.source)))?;
+		struct_ser.serialize_field("source", &v)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ClaimableAwaitingConfirmations {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"amount_satoshis",
+			"amountSatoshis",
+			"confirmation_height",
+			"confirmationHeight",
+			"source",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			AmountSatoshis,
+			ConfirmationHeight,
+			Source,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							"confirmationHeight" | "confirmation_height" => {
+								Ok(GeneratedField::ConfirmationHeight)
+							},
+							"source" => Ok(GeneratedField::Source),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ClaimableAwaitingConfirmations;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ClaimableAwaitingConfirmations")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ClaimableAwaitingConfirmations, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut amount_satoshis__ = None;
+				let mut confirmation_height__ = None;
+				let mut source__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ConfirmationHeight => {
+							if confirmation_height__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"confirmationHeight",
+								));
+							}
+							confirmation_height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Source => {
+							if source__.is_some() {
+								return Err(serde::de::Error::duplicate_field("source"));
+							}
+							source__ = Some(map_.next_value::<BalanceSource>()? as i32);
+						},
+					}
+				}
+				Ok(ClaimableAwaitingConfirmations {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+					confirmation_height: confirmation_height__.unwrap_or_default(),
+					source: source__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"types.ClaimableAwaitingConfirmations",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for ClaimableOnChannelClose {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.ClaimableOnChannelClose", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"transactionFeeSatoshis",
+			ToString::to_string(&self.transaction_fee_satoshis).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"outboundPaymentHtlcRoundedMsat",
+			ToString::to_string(&self.outbound_payment_htlc_rounded_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"outboundForwardedHtlcRoundedMsat",
+			ToString::to_string(&self.outbound_forwarded_htlc_rounded_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"inboundClaimingHtlcRoundedMsat",
+			ToString::to_string(&self.inbound_claiming_htlc_rounded_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"inboundHtlcRoundedMsat",
+			ToString::to_string(&self.inbound_htlc_rounded_msat).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ClaimableOnChannelClose {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"amount_satoshis",
+			"amountSatoshis",
+			"transaction_fee_satoshis",
+			"transactionFeeSatoshis",
+			"outbound_payment_htlc_rounded_msat",
+			"outboundPaymentHtlcRoundedMsat",
+			"outbound_forwarded_htlc_rounded_msat",
+			"outboundForwardedHtlcRoundedMsat",
+			"inbound_claiming_htlc_rounded_msat",
+			"inboundClaimingHtlcRoundedMsat",
+			"inbound_htlc_rounded_msat",
+			"inboundHtlcRoundedMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			AmountSatoshis,
+			TransactionFeeSatoshis,
+			OutboundPaymentHtlcRoundedMsat,
+			OutboundForwardedHtlcRoundedMsat,
+			InboundClaimingHtlcRoundedMsat,
+			InboundHtlcRoundedMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							"transactionFeeSatoshis" | "transaction_fee_satoshis" => {
+								Ok(GeneratedField::TransactionFeeSatoshis)
+							},
+							"outboundPaymentHtlcRoundedMsat"
+							| "outbound_payment_htlc_rounded_msat" => Ok(GeneratedField::OutboundPaymentHtlcRoundedMsat),
+							"outboundForwardedHtlcRoundedMsat"
+							| "outbound_forwarded_htlc_rounded_msat" => {
+								Ok(GeneratedField::OutboundForwardedHtlcRoundedMsat)
+							},
+							"inboundClaimingHtlcRoundedMsat"
+							| "inbound_claiming_htlc_rounded_msat" => Ok(GeneratedField::InboundClaimingHtlcRoundedMsat),
+							"inboundHtlcRoundedMsat" | "inbound_htlc_rounded_msat" => {
+								Ok(GeneratedField::InboundHtlcRoundedMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ClaimableOnChannelClose;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ClaimableOnChannelClose")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ClaimableOnChannelClose, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut amount_satoshis__ = None;
+				let mut transaction_fee_satoshis__ = None;
+				let mut outbound_payment_htlc_rounded_msat__ = None;
+				let mut outbound_forwarded_htlc_rounded_msat__ = None;
+				let mut inbound_claiming_htlc_rounded_msat__ = None;
+				let mut inbound_htlc_rounded_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TransactionFeeSatoshis => {
+							if transaction_fee_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"transactionFeeSatoshis",
+								));
+							}
+							transaction_fee_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::OutboundPaymentHtlcRoundedMsat => {
+							if outbound_payment_htlc_rounded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundPaymentHtlcRoundedMsat",
+								));
+							}
+							outbound_payment_htlc_rounded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::OutboundForwardedHtlcRoundedMsat => {
+							if outbound_forwarded_htlc_rounded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundForwardedHtlcRoundedMsat",
+								));
+							}
+							outbound_forwarded_htlc_rounded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::InboundClaimingHtlcRoundedMsat => {
+							if inbound_claiming_htlc_rounded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"inboundClaimingHtlcRoundedMsat",
+								));
+							}
+							inbound_claiming_htlc_rounded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::InboundHtlcRoundedMsat => {
+							if inbound_htlc_rounded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"inboundHtlcRoundedMsat",
+								));
+							}
+							inbound_htlc_rounded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(ClaimableOnChannelClose {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+					transaction_fee_satoshis: transaction_fee_satoshis__.unwrap_or_default(),
+					outbound_payment_htlc_rounded_msat: outbound_payment_htlc_rounded_msat__
+						.unwrap_or_default(),
+					outbound_forwarded_htlc_rounded_msat: outbound_forwarded_htlc_rounded_msat__
+						.unwrap_or_default(),
+					inbound_claiming_htlc_rounded_msat: inbound_claiming_htlc_rounded_msat__
+						.unwrap_or_default(),
+					inbound_htlc_rounded_msat: inbound_htlc_rounded_msat__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.ClaimableOnChannelClose", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ConfirmationStatus {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.status.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.ConfirmationStatus", len)?;
+		if let Some(v) = self.status.as_ref() {
+			match v {
+				confirmation_status::Status::Confirmed(v) => {
+					struct_ser.serialize_field("confirmed", v)?;
+				},
+				confirmation_status::Status::Unconfirmed(v) => {
+					struct_ser.serialize_field("unconfirmed", v)?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ConfirmationStatus {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["confirmed", "unconfirmed"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Confirmed,
+			Unconfirmed,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"confirmed" => Ok(GeneratedField::Confirmed),
+							"unconfirmed" => Ok(GeneratedField::Unconfirmed),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ConfirmationStatus;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ConfirmationStatus")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ConfirmationStatus, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut status__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Confirmed => {
+							if status__.is_some() {
+								return Err(serde::de::Error::duplicate_field("confirmed"));
+							}
+							status__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(confirmation_status::Status::Confirmed);
+						},
+						GeneratedField::Unconfirmed => {
+							if status__.is_some() {
+								return Err(serde::de::Error::duplicate_field("unconfirmed"));
+							}
+							status__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(confirmation_status::Status::Unconfirmed);
+						},
+					}
+				}
+				Ok(ConfirmationStatus { status: status__ })
+			}
+		}
+		deserializer.deserialize_struct("types.ConfirmationStatus", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Confirmed {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.Confirmed", len)?;
+		struct_ser.serialize_field("blockHash", &self.block_hash)?;
+		struct_ser.serialize_field("height", &self.height)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Confirmed {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["block_hash", "blockHash", "height", "timestamp"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			BlockHash,
+			Height,
+			Timestamp,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+							"height" => Ok(GeneratedField::Height),
+							"timestamp" => Ok(GeneratedField::Timestamp),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Confirmed;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Confirmed")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Confirmed, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut block_hash__ = None;
+				let mut height__ = None;
+				let mut timestamp__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::BlockHash => {
+							if block_hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("blockHash"));
+							}
+							block_hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Height => {
+							if height__.is_some() {
+								return Err(serde::de::Error::duplicate_field("height"));
+							}
+							height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Timestamp => {
+							if timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("timestamp"));
+							}
+							timestamp__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(Confirmed {
+					block_hash: block_hash__.unwrap_or_default(),
+					height: height__.unwrap_or_default(),
+					timestamp: timestamp__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Confirmed", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ContentiousClaimable {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.ContentiousClaimable", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.serialize_field("timeoutHeight", &self.timeout_height)?;
+		struct_ser.serialize_field("paymentHash", &self.payment_hash)?;
+		struct_ser.serialize_field("paymentPreimage", &self.payment_preimage)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ContentiousClaimable {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"amount_satoshis",
+			"amountSatoshis",
+			"timeout_height",
+			"timeoutHeight",
+			"payment_hash",
+			"paymentHash",
+			"payment_preimage",
+			"paymentPreimage",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			AmountSatoshis,
+			TimeoutHeight,
+			PaymentHash,
+			PaymentPreimage,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							"timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+							"paymentHash" | "payment_hash" => Ok(GeneratedField::PaymentHash),
+							"paymentPreimage" | "payment_preimage" => {
+								Ok(GeneratedField::PaymentPreimage)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ContentiousClaimable;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ContentiousClaimable")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<ContentiousClaimable, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut amount_satoshis__ = None;
+				let mut timeout_height__ = None;
+				let mut payment_hash__ = None;
+				let mut payment_preimage__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TimeoutHeight => {
+							if timeout_height__.is_some() {
+								return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+							}
+							timeout_height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::PaymentHash => {
+							if payment_hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentHash"));
+							}
+							payment_hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PaymentPreimage => {
+							if payment_preimage__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentPreimage"));
+							}
+							payment_preimage__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(ContentiousClaimable {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+					timeout_height: timeout_height__.unwrap_or_default(),
+					payment_hash: payment_hash__.unwrap_or_default(),
+					payment_preimage: payment_preimage__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.ContentiousClaimable", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for CounterpartyRevokedOutputClaimable {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("types.CounterpartyRevokedOutputClaimable", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for CounterpartyRevokedOutputClaimable {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"amount_satoshis",
+			"amountSatoshis",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			AmountSatoshis,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = CounterpartyRevokedOutputClaimable;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.CounterpartyRevokedOutputClaimable")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<CounterpartyRevokedOutputClaimable, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut amount_satoshis__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(CounterpartyRevokedOutputClaimable {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"types.CounterpartyRevokedOutputClaimable",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for DailyForwardingStats {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.DailyForwardingStats", len)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"dayStartTimestamp",
+			ToString::to_string(&self.day_start_timestamp).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"forwardedPaymentsCount",
+			ToString::to_string(&self.forwarded_payments_count).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"outboundAmountForwardedMsat",
+			ToString::to_string(&self.outbound_amount_forwarded_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"totalFeeEarnedMsat",
+			ToString::to_string(&self.total_fee_earned_msat).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for DailyForwardingStats {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"day_start_timestamp",
+			"dayStartTimestamp",
+			"forwarded_payments_count",
+			"forwardedPaymentsCount",
+			"outbound_amount_forwarded_msat",
+			"outboundAmountForwardedMsat",
+			"total_fee_earned_msat",
+			"totalFeeEarnedMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			DayStartTimestamp,
+			ForwardedPaymentsCount,
+			OutboundAmountForwardedMsat,
+			TotalFeeEarnedMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"dayStartTimestamp" | "day_start_timestamp" => {
+								Ok(GeneratedField::DayStartTimestamp)
+							},
+							"forwardedPaymentsCount" | "forwarded_payments_count" => {
+								Ok(GeneratedField::ForwardedPaymentsCount)
+							},
+							"outboundAmountForwardedMsat" | "outbound_amount_forwarded_msat" => {
+								Ok(GeneratedField::OutboundAmountForwardedMsat)
+							},
+							"totalFeeEarnedMsat" | "total_fee_earned_msat" => {
+								Ok(GeneratedField::TotalFeeEarnedMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = DailyForwardingStats;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.DailyForwardingStats")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<DailyForwardingStats, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut day_start_timestamp__ = None;
+				let mut forwarded_payments_count__ = None;
+				let mut outbound_amount_forwarded_msat__ = None;
+				let mut total_fee_earned_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::DayStartTimestamp => {
+							if day_start_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("dayStartTimestamp"));
+							}
+							day_start_timestamp__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ForwardedPaymentsCount => {
+							if forwarded_payments_count__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"forwardedPaymentsCount",
+								));
+							}
+							forwarded_payments_count__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::OutboundAmountForwardedMsat => {
+							if outbound_amount_forwarded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundAmountForwardedMsat",
+								));
+							}
+							outbound_amount_forwarded_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::TotalFeeEarnedMsat => {
+							if total_fee_earned_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalFeeEarnedMsat",
+								));
+							}
+							total_fee_earned_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(DailyForwardingStats {
+					day_start_timestamp: day_start_timestamp__.unwrap_or_default(),
+					forwarded_payments_count: forwarded_payments_count__.unwrap_or_default(),
+					outbound_amount_forwarded_msat: outbound_amount_forwarded_msat__
+						.unwrap_or_default(),
+					total_fee_earned_msat: total_fee_earned_msat__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.DailyForwardingStats", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for ForwardedPayment {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.next_user_channel_id.is_some() {
+			len += 1;
+		}
+		if self.total_fee_earned_msat.is_some() {
+			len += 1;
+		}
+		if self.skimmed_fee_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		if self.outbound_amount_forwarded_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.ForwardedPayment", len)?;
+		struct_ser.serialize_field("prevChannelId", &self.prev_channel_id)?;
+		struct_ser.serialize_field("nextChannelId", &self.next_channel_id)?;
+		struct_ser.serialize_field("prevUserChannelId", &self.prev_user_channel_id)?;
+		struct_ser.serialize_field("prevNodeId", &self.prev_node_id)?;
+		struct_ser.serialize_field("nextNodeId", &self.next_node_id)?;
+		if let Some(v) = self.next_user_channel_id.as_ref() {
+			struct_ser.serialize_field("nextUserChannelId", v)?;
+		}
+		if let Some(v) = self.total_fee_earned_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("totalFeeEarnedMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.skimmed_fee_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("skimmedFeeMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.serialize_field("claimFromOnchainTx", &self.claim_from_onchain_tx)?;
+		if let Some(v) = self.outbound_amount_forwarded_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("outboundAmountForwardedMsat", ToString::to_string(&v).as_str())?;
+		}
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field("timestamp", ToString::to_string(&self.timestamp).as_str())?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for ForwardedPayment {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"prev_channel_id",
+			"prevChannelId",
+			"next_channel_id",
+			"nextChannelId",
+			"prev_user_channel_id",
+			"prevUserChannelId",
+			"prev_node_id",
+			"prevNodeId",
+			"next_node_id",
+			"nextNodeId",
+			"next_user_channel_id",
+			"nextUserChannelId",
+			"total_fee_earned_msat",
+			"totalFeeEarnedMsat",
+			"skimmed_fee_msat",
+			"skimmedFeeMsat",
+			"claim_from_onchain_tx",
+			"claimFromOnchainTx",
+			"outbound_amount_forwarded_msat",
+			"outboundAmountForwardedMsat",
+			"timestamp",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PrevChannelId,
+			NextChannelId,
+			PrevUserChannelId,
+			PrevNodeId,
+			NextNodeId,
+			NextUserChannelId,
+			TotalFeeEarnedMsat,
+			SkimmedFeeMsat,
+			ClaimFromOnchainTx,
+			OutboundAmountForwardedMsat,
+			Timestamp,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"prevChannelId" | "prev_channel_id" => {
+								Ok(GeneratedField::PrevChannelId)
+							},
+							"nextChannelId" | "next_channel_id" => {
+								Ok(GeneratedField::NextChannelId)
+							},
+							"prevUserChannelId" | "prev_user_channel_id" => {
+								Ok(GeneratedField::PrevUserChannelId)
+							},
+							"prevNodeId" | "prev_node_id" => Ok(GeneratedField::PrevNodeId),
+							"nextNodeId" | "next_node_id" => Ok(GeneratedField::NextNodeId),
+							"nextUserChannelId" | "next_user_channel_id" => {
+								Ok(GeneratedField::NextUserChannelId)
+							},
+							"totalFeeEarnedMsat" | "total_fee_earned_msat" => {
+								Ok(GeneratedField::TotalFeeEarnedMsat)
+							},
+							"skimmedFeeMsat" | "skimmed_fee_msat" => {
+								Ok(GeneratedField::SkimmedFeeMsat)
+							},
+							"claimFromOnchainTx" | "claim_from_onchain_tx" => {
+								Ok(GeneratedField::ClaimFromOnchainTx)
+							},
+							"outboundAmountForwardedMsat" | "outbound_amount_forwarded_msat" => {
+								Ok(GeneratedField::OutboundAmountForwardedMsat)
+							},
+							"timestamp" => Ok(GeneratedField::Timestamp),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = ForwardedPayment;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.ForwardedPayment")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<ForwardedPayment, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut prev_channel_id__ = None;
+				let mut next_channel_id__ = None;
+				let mut prev_user_channel_id__ = None;
+				let mut prev_node_id__ = None;
+				let mut next_node_id__ = None;
+				let mut next_user_channel_id__ = None;
+				let mut total_fee_earned_msat__ = None;
+				let mut skimmed_fee_msat__ = None;
+				let mut claim_from_onchain_tx__ = None;
+				let mut outbound_amount_forwarded_msat__ = None;
+				let mut timestamp__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PrevChannelId => {
+							if prev_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevChannelId"));
+							}
+							prev_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextChannelId => {
+							if next_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextChannelId"));
+							}
+							next_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PrevUserChannelId => {
+							if prev_user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevUserChannelId"));
+							}
+							prev_user_channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::PrevNodeId => {
+							if prev_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("prevNodeId"));
+							}
+							prev_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextNodeId => {
+							if next_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextNodeId"));
+							}
+							next_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NextUserChannelId => {
+							if next_user_channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nextUserChannelId"));
+							}
+							next_user_channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::TotalFeeEarnedMsat => {
+							if total_fee_earned_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"totalFeeEarnedMsat",
+								));
+							}
+							total_fee_earned_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::SkimmedFeeMsat => {
+							if skimmed_fee_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("skimmedFeeMsat"));
+							}
+							skimmed_fee_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::ClaimFromOnchainTx => {
+							if claim_from_onchain_tx__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"claimFromOnchainTx",
+								));
+							}
+							claim_from_onchain_tx__ = Some(map_.next_value()?);
+						},
+						GeneratedField::OutboundAmountForwardedMsat => {
+							if outbound_amount_forwarded_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"outboundAmountForwardedMsat",
+								));
+							}
+							outbound_amount_forwarded_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Timestamp => {
+							if timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field("timestamp"));
+							}
+							timestamp__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(ForwardedPayment {
+					prev_channel_id: prev_channel_id__.unwrap_or_default(),
+					next_channel_id: next_channel_id__.unwrap_or_default(),
+					prev_user_channel_id: prev_user_channel_id__.unwrap_or_default(),
+					prev_node_id: prev_node_id__.unwrap_or_default(),
+					next_node_id: next_node_id__.unwrap_or_default(),
+					next_user_channel_id: next_user_channel_id__,
+					total_fee_earned_msat: total_fee_earned_msat__,
+					skimmed_fee_msat: skimmed_fee_msat__,
+					claim_from_onchain_tx: claim_from_onchain_tx__.unwrap_or_default(),
+					outbound_amount_forwarded_msat: outbound_amount_forwarded_msat__,
+					timestamp: timestamp__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.ForwardedPayment", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphChannel {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.capacity_sats.is_some() {
+			len += 1;
+		}
+		if self.one_to_two.is_some() {
+			len += 1;
+		}
+		if self.two_to_one.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.GraphChannel", len)?;
+		struct_ser.serialize_field("nodeOne", &self.node_one)?;
+		struct_ser.serialize_field("nodeTwo", &self.node_two)?;
+		if let Some(v) = self.capacity_sats.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("capacitySats", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.one_to_two.as_ref() {
+			struct_ser.serialize_field("oneToTwo", v)?;
+		}
+		if let Some(v) = self.two_to_one.as_ref() {
+			struct_ser.serialize_field("twoToOne", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphChannel {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"node_one",
+			"nodeOne",
+			"node_two",
+			"nodeTwo",
+			"capacity_sats",
+			"capacitySats",
+			"one_to_two",
+			"oneToTwo",
+			"two_to_one",
+			"twoToOne",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			NodeOne,
+			NodeTwo,
+			CapacitySats,
+			OneToTwo,
+			TwoToOne,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"nodeOne" | "node_one" => Ok(GeneratedField::NodeOne),
+							"nodeTwo" | "node_two" => Ok(GeneratedField::NodeTwo),
+							"capacitySats" | "capacity_sats" => Ok(GeneratedField::CapacitySats),
+							"oneToTwo" | "one_to_two" => Ok(GeneratedField::OneToTwo),
+							"twoToOne" | "two_to_one" => Ok(GeneratedField::TwoToOne),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphChannel;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.GraphChannel")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GraphChannel, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut node_one__ = None;
+				let mut node_two__ = None;
+				let mut capacity_sats__ = None;
+				let mut one_to_two__ = None;
+				let mut two_to_one__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::NodeOne => {
+							if node_one__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeOne"));
+							}
+							node_one__ = Some(map_.next_value()?);
+						},
+						GeneratedField::NodeTwo => {
+							if node_two__.is_some() {
+								return Err(serde::de::Error::duplicate_field("nodeTwo"));
+							}
+							node_two__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CapacitySats => {
+							if capacity_sats__.is_some() {
+								return Err(serde::de::Error::duplicate_field("capacitySats"));
+							}
+							capacity_sats__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::OneToTwo => {
+							if one_to_two__.is_some() {
+								return Err(serde::de::Error::duplicate_field("oneToTwo"));
+							}
+							one_to_two__ = map_.next_value()?;
+						},
+						GeneratedField::TwoToOne => {
+							if two_to_one__.is_some() {
+								return Err(serde::de::Error::duplicate_field("twoToOne"));
+							}
+							two_to_one__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(GraphChannel {
+					node_one: node_one__.unwrap_or_default(),
+					node_two: node_two__.unwrap_or_default(),
+					capacity_sats: capacity_sats__,
+					one_to_two: one_to_two__,
+					two_to_one: two_to_one__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.GraphChannel", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphChannelUpdate {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.fees.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.GraphChannelUpdate", len)?;
+		struct_ser.serialize_field("lastUpdate", &self.last_update)?;
+		struct_ser.serialize_field("enabled", &self.enabled)?;
+		struct_ser.serialize_field("cltvExpiryDelta", &self.cltv_expiry_delta)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"htlcMinimumMsat",
+			ToString::to_string(&self.htlc_minimum_msat).as_str(),
+		)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"htlcMaximumMsat",
+			ToString::to_string(&self.htlc_maximum_msat).as_str(),
+		)?;
+		if let Some(v) = self.fees.as_ref() {
+			struct_ser.serialize_field("fees", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphChannelUpdate {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"last_update",
+			"lastUpdate",
+			"enabled",
+			"cltv_expiry_delta",
+			"cltvExpiryDelta",
+			"htlc_minimum_msat",
+			"htlcMinimumMsat",
+			"htlc_maximum_msat",
+			"htlcMaximumMsat",
+			"fees",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			LastUpdate,
+			Enabled,
+			CltvExpiryDelta,
+			HtlcMinimumMsat,
+			HtlcMaximumMsat,
+			Fees,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"lastUpdate" | "last_update" => Ok(GeneratedField::LastUpdate),
+							"enabled" => Ok(GeneratedField::Enabled),
+							"cltvExpiryDelta" | "cltv_expiry_delta" => {
+								Ok(GeneratedField::CltvExpiryDelta)
+							},
+							"htlcMinimumMsat" | "htlc_minimum_msat" => {
+								Ok(GeneratedField::HtlcMinimumMsat)
+							},
+							"htlcMaximumMsat" | "htlc_maximum_msat" => {
+								Ok(GeneratedField::HtlcMaximumMsat)
+							},
+							"fees" => Ok(GeneratedField::Fees),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphChannelUpdate;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.GraphChannelUpdate")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GraphChannelUpdate, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut last_update__ = None;
+				let mut enabled__ = None;
+				let mut cltv_expiry_delta__ = None;
+				let mut htlc_minimum_msat__ = None;
+				let mut htlc_maximum_msat__ = None;
+				let mut fees__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::LastUpdate => {
+							if last_update__.is_some() {
+								return Err(serde::de::Error::duplicate_field("lastUpdate"));
+							}
+							last_update__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Enabled => {
+							if enabled__.is_some() {
+								return Err(serde::de::Error::duplicate_field("enabled"));
+							}
+							enabled__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CltvExpiryDelta => {
+							if cltv_expiry_delta__.is_some() {
+								return Err(serde::de::Error::duplicate_field("cltvExpiryDelta"));
+							}
+							cltv_expiry_delta__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::HtlcMinimumMsat => {
+							if htlc_minimum_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("htlcMinimumMsat"));
+							}
+							htlc_minimum_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::HtlcMaximumMsat => {
+							if htlc_maximum_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("htlcMaximumMsat"));
+							}
+							htlc_maximum_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Fees => {
+							if fees__.is_some() {
+								return Err(serde::de::Error::duplicate_field("fees"));
+							}
+							fees__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(GraphChannelUpdate {
+					last_update: last_update__.unwrap_or_default(),
+					enabled: enabled__.unwrap_or_default(),
+					cltv_expiry_delta: cltv_expiry_delta__.unwrap_or_default(),
+					htlc_minimum_msat: htlc_minimum_msat__.unwrap_or_default(),
+					htlc_maximum_msat: htlc_maximum_msat__.unwrap_or_default(),
+					fees: fees__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.GraphChannelUpdate", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphNode {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.announcement_info.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.GraphNode", len)?;
+		struct_ser.serialize_field(
+			"channels",
+			&self.channels.iter().map(ToString::to_string).collect::<Vec<_>>(),
+		)?;
+		if let Some(v) = self.announcement_info.as_ref() {
+			struct_ser.serialize_field("announcementInfo", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphNode {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["channels", "announcement_info", "announcementInfo"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Channels,
+			AnnouncementInfo,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channels" => Ok(GeneratedField::Channels),
+							"announcementInfo" | "announcement_info" => {
+								Ok(GeneratedField::AnnouncementInfo)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphNode;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.GraphNode")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GraphNode, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channels__ = None;
+				let mut announcement_info__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Channels => {
+							if channels__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channels"));
+							}
+							channels__ = Some(
+								map_.next_value::<Vec<::pbjson::private::NumberDeserialize<_>>>()?
+									.into_iter()
+									.map(|x| x.0)
+									.collect(),
+							);
+						},
+						GeneratedField::AnnouncementInfo => {
+							if announcement_info__.is_some() {
+								return Err(serde::de::Error::duplicate_field("announcementInfo"));
+							}
+							announcement_info__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(GraphNode {
+					channels: channels__.unwrap_or_default(),
+					announcement_info: announcement_info__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.GraphNode", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphNodeAnnouncement {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.GraphNodeAnnouncement", len)?;
+		struct_ser.serialize_field("lastUpdate", &self.last_update)?;
+		struct_ser.serialize_field("alias", &self.alias)?;
+		struct_ser.serialize_field("rgb", &self.rgb)?;
+		struct_ser.serialize_field("addresses", &self.addresses)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphNodeAnnouncement {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["last_update", "lastUpdate", "alias", "rgb", "addresses"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			LastUpdate,
+			Alias,
+			Rgb,
+			Addresses,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"lastUpdate" | "last_update" => Ok(GeneratedField::LastUpdate),
+							"alias" => Ok(GeneratedField::Alias),
+							"rgb" => Ok(GeneratedField::Rgb),
+							"addresses" => Ok(GeneratedField::Addresses),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphNodeAnnouncement;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.GraphNodeAnnouncement")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<GraphNodeAnnouncement, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut last_update__ = None;
+				let mut alias__ = None;
+				let mut rgb__ = None;
+				let mut addresses__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::LastUpdate => {
+							if last_update__.is_some() {
+								return Err(serde::de::Error::duplicate_field("lastUpdate"));
+							}
+							last_update__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Alias => {
+							if alias__.is_some() {
+								return Err(serde::de::Error::duplicate_field("alias"));
+							}
+							alias__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Rgb => {
+							if rgb__.is_some() {
+								return Err(serde::de::Error::duplicate_field("rgb"));
+							}
+							rgb__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Addresses => {
+							if addresses__.is_some() {
+								return Err(serde::de::Error::duplicate_field("addresses"));
+							}
+							addresses__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(GraphNodeAnnouncement {
+					last_update: last_update__.unwrap_or_default(),
+					alias: alias__.unwrap_or_default(),
+					rgb: rgb__.unwrap_or_default(),
+					addresses: addresses__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.GraphNodeAnnouncement", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for GraphRoutingFees {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.GraphRoutingFees", len)?;
+		struct_ser.serialize_field("baseMsat", &self.base_msat)?;
+		struct_ser.serialize_field("proportionalMillionths", &self.proportional_millionths)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for GraphRoutingFees {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["base_msat", "baseMsat", "proportional_millionths", "proportionalMillionths"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			BaseMsat,
+			ProportionalMillionths,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"baseMsat" | "base_msat" => Ok(GeneratedField::BaseMsat),
+							"proportionalMillionths" | "proportional_millionths" => {
+								Ok(GeneratedField::ProportionalMillionths)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = GraphRoutingFees;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.GraphRoutingFees")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<GraphRoutingFees, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut base_msat__ = None;
+				let mut proportional_millionths__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::BaseMsat => {
+							if base_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("baseMsat"));
+							}
+							base_msat__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ProportionalMillionths => {
+							if proportional_millionths__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"proportionalMillionths",
+								));
+							}
+							proportional_millionths__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(GraphRoutingFees {
+					base_msat: base_msat__.unwrap_or_default(),
+					proportional_millionths: proportional_millionths__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.GraphRoutingFees", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for LspFeeLimits {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.max_total_opening_fee_msat.is_some() {
+			len += 1;
+		}
+		if self.max_proportional_opening_fee_ppm_msat.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.LSPFeeLimits", len)?;
+		if let Some(v) = self.max_total_opening_fee_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("maxTotalOpeningFeeMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.max_proportional_opening_fee_ppm_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field(
+				"maxProportionalOpeningFeePpmMsat",
+				ToString::to_string(&v).as_str(),
+			)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for LspFeeLimits {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"max_total_opening_fee_msat",
+			"maxTotalOpeningFeeMsat",
+			"max_proportional_opening_fee_ppm_msat",
+			"maxProportionalOpeningFeePpmMsat",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			MaxTotalOpeningFeeMsat,
+			MaxProportionalOpeningFeePpmMsat,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"maxTotalOpeningFeeMsat" | "max_total_opening_fee_msat" => {
+								Ok(GeneratedField::MaxTotalOpeningFeeMsat)
+							},
+							"maxProportionalOpeningFeePpmMsat"
+							| "max_proportional_opening_fee_ppm_msat" => {
+								Ok(GeneratedField::MaxProportionalOpeningFeePpmMsat)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = LspFeeLimits;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.LSPFeeLimits")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<LspFeeLimits, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut max_total_opening_fee_msat__ = None;
+				let mut max_proportional_opening_fee_ppm_msat__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::MaxTotalOpeningFeeMsat => {
+							if max_total_opening_fee_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxTotalOpeningFeeMsat",
+								));
+							}
+							max_total_opening_fee_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MaxProportionalOpeningFeePpmMsat => {
+							if max_proportional_opening_fee_ppm_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxProportionalOpeningFeePpmMsat",
+								));
+							}
+							max_proportional_opening_fee_ppm_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+					}
+				}
+				Ok(LspFeeLimits {
+					max_total_opening_fee_msat: max_total_opening_fee_msat__,
+					max_proportional_opening_fee_ppm_msat: max_proportional_opening_fee_ppm_msat__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.LSPFeeLimits", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for LightningBalance {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.balance_type.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.LightningBalance", len)?;
+		if let Some(v) = self.balance_type.as_ref() {
+			match v {
+				lightning_balance::BalanceType::ClaimableOnChannelClose(v) => {
+					struct_ser.serialize_field("claimableOnChannelClose", v)?;
+				},
+				lightning_balance::BalanceType::ClaimableAwaitingConfirmations(v) => {
+					struct_ser.serialize_field("claimableAwaitingConfirmations", v)?;
+				},
+				lightning_balance::BalanceType::ContentiousClaimable(v) => {
+					struct_ser.serialize_field("contentiousClaimable", v)?;
+				},
+				lightning_balance::BalanceType::MaybeTimeoutClaimableHtlc(v) => {
+					struct_ser.serialize_field("maybeTimeoutClaimableHtlc", v)?;
+				},
+				lightning_balance::BalanceType::MaybePreimageClaimableHtlc(v) => {
+					struct_ser.serialize_field("maybePreimageClaimableHtlc", v)?;
+				},
+				lightning_balance::BalanceType::CounterpartyRevokedOutputClaimable(v) => {
+					struct_ser.serialize_field("counterpartyRevokedOutputClaimable", v)?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for LightningBalance {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"claimable_on_channel_close",
+			"claimableOnChannelClose",
+			"claimable_awaiting_confirmations",
+			"claimableAwaitingConfirmations",
+			"contentious_claimable",
+			"contentiousClaimable",
+			"maybe_timeout_claimable_htlc",
+			"maybeTimeoutClaimableHtlc",
+			"maybe_preimage_claimable_htlc",
+			"maybePreimageClaimableHtlc",
+			"counterparty_revoked_output_claimable",
+			"counterpartyRevokedOutputClaimable",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ClaimableOnChannelClose,
+			ClaimableAwaitingConfirmations,
+			ContentiousClaimable,
+			MaybeTimeoutClaimableHtlc,
+			MaybePreimageClaimableHtlc,
+			CounterpartyRevokedOutputClaimable,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"claimableOnChannelClose" | "claimable_on_channel_close" => {
+								Ok(GeneratedField::ClaimableOnChannelClose)
+							},
+							"claimableAwaitingConfirmations"
+							| "claimable_awaiting_confirmations" => Ok(GeneratedField::ClaimableAwaitingConfirmations),
+							"contentiousClaimable" | "contentious_claimable" => {
+								Ok(GeneratedField::ContentiousClaimable)
+							},
+							"maybeTimeoutClaimableHtlc" | "maybe_timeout_claimable_htlc" => {
+								Ok(GeneratedField::MaybeTimeoutClaimableHtlc)
+							},
+							"maybePreimageClaimableHtlc" | "maybe_preimage_claimable_htlc" => {
+								Ok(GeneratedField::MaybePreimageClaimableHtlc)
+							},
+							"counterpartyRevokedOutputClaimable"
+							| "counterparty_revoked_output_claimable" => {
+								Ok(GeneratedField::CounterpartyRevokedOutputClaimable)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = LightningBalance;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.LightningBalance")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<LightningBalance, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut balance_type__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ClaimableOnChannelClose => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"claimableOnChannelClose",
+								));
+							}
+							balance_type__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(lightning_balance::BalanceType::ClaimableOnChannelClose);
+						},
+						GeneratedField::ClaimableAwaitingConfirmations => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"claimableAwaitingConfirmations",
+								));
+							}
+							balance_type__ = map_.next_value::<::std::option::Option<_>>()?.map(
+								lightning_balance::BalanceType::ClaimableAwaitingConfirmations,
+							);
+						},
+						GeneratedField::ContentiousClaimable => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"contentiousClaimable",
+								));
+							}
+							balance_type__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(lightning_balance::BalanceType::ContentiousClaimable);
+						},
+						GeneratedField::MaybeTimeoutClaimableHtlc => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maybeTimeoutClaimableHtlc",
+								));
+							}
+							balance_type__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(lightning_balance::BalanceType::MaybeTimeoutClaimableHtlc);
+						},
+						GeneratedField::MaybePreimageClaimableHtlc => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maybePreimageClaimableHtlc",
+								));
+							}
+							balance_type__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(lightning_balance::BalanceType::MaybePreimageClaimableHtlc);
+						},
+						GeneratedField::CounterpartyRevokedOutputClaimable => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyRevokedOutputClaimable",
+								));
+							}
+							balance_type__ = map_.next_value::<::std::option::Option<_>>()?.map(
+								lightning_balance::BalanceType::CounterpartyRevokedOutputClaimable,
+							);
+						},
+					}
+				}
+				Ok(LightningBalance { balance_type: balance_type__ })
+			}
+		}
+		deserializer.deserialize_struct("types.LightningBalance", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for MaybePreimageClaimableHtlc {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser =
+			serializer.serialize_struct("types.MaybePreimageClaimableHTLC", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.serialize_field("expiryHeight", &self.expiry_height)?;
+		struct_ser.serialize_field("paymentHash", &self.payment_hash)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for MaybePreimageClaimableHtlc {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"amount_satoshis",
+			"amountSatoshis",
+			"expiry_height",
+			"expiryHeight",
+			"payment_hash",
+			"paymentHash",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			AmountSatoshis,
+			ExpiryHeight,
+			PaymentHash,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							"expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+							"paymentHash" | "payment_hash" => Ok(GeneratedField::PaymentHash),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = MaybePreimageClaimableHtlc;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.MaybePreimageClaimableHTLC")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<MaybePreimageClaimableHtlc, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut amount_satoshis__ = None;
+				let mut expiry_height__ = None;
+				let mut payment_hash__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ExpiryHeight => {
+							if expiry_height__.is_some() {
+								return Err(serde::de::Error::duplicate_field("expiryHeight"));
+							}
+							expiry_height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::PaymentHash => {
+							if payment_hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentHash"));
+							}
+							payment_hash__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(MaybePreimageClaimableHtlc {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+					expiry_height: expiry_height__.unwrap_or_default(),
+					payment_hash: payment_hash__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct(
+			"types.MaybePreimageClaimableHTLC",
+			FIELDS,
+			GeneratedVisitor,
+		)
+	}
+}
+impl serde::Serialize for MaybeTimeoutClaimableHtlc {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.MaybeTimeoutClaimableHTLC", len)?;
+		struct_ser.serialize_field("channelId", &self.channel_id)?;
+		struct_ser.serialize_field("counterpartyNodeId", &self.counterparty_node_id)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.serialize_field("claimableHeight", &self.claimable_height)?;
+		struct_ser.serialize_field("paymentHash", &self.payment_hash)?;
+		struct_ser.serialize_field("outboundPayment", &self.outbound_payment)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for MaybeTimeoutClaimableHtlc {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"channel_id",
+			"channelId",
+			"counterparty_node_id",
+			"counterpartyNodeId",
+			"amount_satoshis",
+			"amountSatoshis",
+			"claimable_height",
+			"claimableHeight",
+			"payment_hash",
+			"paymentHash",
+			"outbound_payment",
+			"outboundPayment",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			CounterpartyNodeId,
+			AmountSatoshis,
+			ClaimableHeight,
+			PaymentHash,
+			OutboundPayment,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"counterpartyNodeId" | "counterparty_node_id" => {
+								Ok(GeneratedField::CounterpartyNodeId)
+							},
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							"claimableHeight" | "claimable_height" => {
+								Ok(GeneratedField::ClaimableHeight)
+							},
+							"paymentHash" | "payment_hash" => Ok(GeneratedField::PaymentHash),
+							"outboundPayment" | "outbound_payment" => {
+								Ok(GeneratedField::OutboundPayment)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = MaybeTimeoutClaimableHtlc;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.MaybeTimeoutClaimableHTLC")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<MaybeTimeoutClaimableHtlc, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut counterparty_node_id__ = None;
+				let mut amount_satoshis__ = None;
+				let mut claimable_height__ = None;
+				let mut payment_hash__ = None;
+				let mut outbound_payment__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CounterpartyNodeId => {
+							if counterparty_node_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"counterpartyNodeId",
+								));
+							}
+							counterparty_node_id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::ClaimableHeight => {
+							if claimable_height__.is_some() {
+								return Err(serde::de::Error::duplicate_field("claimableHeight"));
+							}
+							claimable_height__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::PaymentHash => {
+							if payment_hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("paymentHash"));
+							}
+							payment_hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::OutboundPayment => {
+							if outbound_payment__.is_some() {
+								return Err(serde::de::Error::duplicate_field("outboundPayment"));
+							}
+							outbound_payment__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(MaybeTimeoutClaimableHtlc {
+					channel_id: channel_id__.unwrap_or_default(),
+					counterparty_node_id: counterparty_node_id__.unwrap_or_default(),
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+					claimable_height: claimable_height__.unwrap_or_default(),
+					payment_hash: payment_hash__.unwrap_or_default(),
+					outbound_payment: outbound_payment__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.MaybeTimeoutClaimableHTLC", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Onchain {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.status.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Onchain", len)?;
+		struct_ser.serialize_field("txid", &self.txid)?;
+		if let Some(v) = self.status.as_ref() {
+			struct_ser.serialize_field("status", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Onchain {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["txid", "status"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Txid,
+			Status,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"txid" => Ok(GeneratedField::Txid),
+							"status" => Ok(GeneratedField::Status),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Onchain;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Onchain")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Onchain, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut txid__ = None;
+				let mut status__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Txid => {
+							if txid__.is_some() {
+								return Err(serde::de::Error::duplicate_field("txid"));
+							}
+							txid__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Status => {
+							if status__.is_some() {
+								return Err(serde::de::Error::duplicate_field("status"));
+							}
+							status__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Onchain { txid: txid__.unwrap_or_default(), status: status__ })
+			}
+		}
+		deserializer.deserialize_struct("types.Onchain", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for OutPoint {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.OutPoint", len)?;
+		struct_ser.serialize_field("txid", &self.txid)?;
+		struct_ser.serialize_field("vout", &self.vout)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for OutPoint {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["txid", "vout"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Txid,
+			Vout,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"txid" => Ok(GeneratedField::Txid),
+							"vout" => Ok(GeneratedField::Vout),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = OutPoint;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.OutPoint")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<OutPoint, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut txid__ = None;
+				let mut vout__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Txid => {
+							if txid__.is_some() {
+								return Err(serde::de::Error::duplicate_field("txid"));
+							}
+							txid__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Vout => {
+							if vout__.is_some() {
+								return Err(serde::de::Error::duplicate_field("vout"));
+							}
+							vout__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(OutPoint { txid: txid__.unwrap_or_default(), vout: vout__.unwrap_or_default() })
+			}
+		}
+		deserializer.deserialize_struct("types.OutPoint", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PageToken {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.PageToken", len)?;
+		struct_ser.serialize_field("token", &self.token)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field("index", ToString::to_string(&self.index).as_str())?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PageToken {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["token", "index"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Token,
+			Index,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"token" => Ok(GeneratedField::Token),
+							"index" => Ok(GeneratedField::Index),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PageToken;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.PageToken")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PageToken, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut token__ = None;
+				let mut index__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Token => {
+							if token__.is_some() {
+								return Err(serde::de::Error::duplicate_field("token"));
+							}
+							token__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Index => {
+							if index__.is_some() {
+								return Err(serde::de::Error::duplicate_field("index"));
+							}
+							index__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(PageToken {
+					token: token__.unwrap_or_default(),
+					index: index__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.PageToken", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Payment {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.kind.is_some() {
+			len += 1;
+		}
+		if self.amount_msat.is_some() {
+			len += 1;
+		}
+		if self.fee_paid_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		if self.metadata.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Payment", len)?;
+		struct_ser.serialize_field("id", &self.id)?;
+		if let Some(v) = self.kind.as_ref() {
+			struct_ser.serialize_field("kind", v)?;
+		}
+		if let Some(v) = self.amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountMsat", ToString::to_string(&v).as_str())?;
+		}
+		if let Some(v) = self.fee_paid_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("feePaidMsat", ToString::to_string(&v).as_str())?;
+		}
+		let v = PaymentDirection::from_i32(self.direction).ok_or_else(|| {
+			serde::ser::Error::custom(format!("Invalid variant {}", self.direction))
+		})?;
+		struct_ser.serialize_field("direction", &v)?;
+		let v = PaymentStatus::from_i32(self.status)
+			.ok_or_else(|| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+		struct_ser.serialize_field("status", &v)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"latestUpdateTimestamp",
+			ToString::to_string(&self.latest_update_timestamp).as_str(),
+		)?;
+		if let Some(v) = self.metadata.as_ref() {
+			struct_ser.serialize_field("metadata", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Payment {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"id",
+			"kind",
+			"amount_msat",
+			"amountMsat",
+			"fee_paid_msat",
+			"feePaidMsat",
+			"direction",
+			"status",
+			"latest_update_timestamp",
+			"latestUpdateTimestamp",
+			"metadata",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Id,
+			Kind,
+			AmountMsat,
+			FeePaidMsat,
+			Direction,
+			Status,
+			LatestUpdateTimestamp,
+			Metadata,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"id" => Ok(GeneratedField::Id),
+							"kind" => Ok(GeneratedField::Kind),
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"feePaidMsat" | "fee_paid_msat" => Ok(GeneratedField::FeePaidMsat),
+							"direction" => Ok(GeneratedField::Direction),
+							"status" => Ok(GeneratedField::Status),
+							"latestUpdateTimestamp" | "latest_update_timestamp" => {
+								Ok(GeneratedField::LatestUpdateTimestamp)
+							},
+							"metadata" => Ok(GeneratedField::Metadata),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Payment;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Payment")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Payment, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut id__ = None;
+				let mut kind__ = None;
+				let mut amount_msat__ = None;
+				let mut fee_paid_msat__ = None;
+				let mut direction__ = None;
+				let mut status__ = None;
+				let mut latest_update_timestamp__ = None;
+				let mut metadata__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Id => {
+							if id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("id"));
+							}
+							id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Kind => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("kind"));
+							}
+							kind__ = map_.next_value()?;
+						},
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::FeePaidMsat => {
+							if fee_paid_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("feePaidMsat"));
+							}
+							fee_paid_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Direction => {
+							if direction__.is_some() {
+								return Err(serde::de::Error::duplicate_field("direction"));
+							}
+							direction__ = Some(map_.next_value::<PaymentDirection>()? as i32);
+						},
+						GeneratedField::Status => {
+							if status__.is_some() {
+								return Err(serde::de::Error::duplicate_field("status"));
+							}
+							status__ = Some(map_.next_value::<PaymentStatus>()? as i32);
+						},
+						GeneratedField::LatestUpdateTimestamp => {
+							if latest_update_timestamp__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"latestUpdateTimestamp",
+								));
+							}
+							latest_update_timestamp__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::Metadata => {
+							if metadata__.is_some() {
+								return Err(serde::de::Error::duplicate_field("metadata"));
+							}
+							metadata__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Payment {
+					id: id__.unwrap_or_default(),
+					kind: kind__,
+					amount_msat: amount_msat__,
+					fee_paid_msat: fee_paid_msat__,
+					direction: direction__.unwrap_or_default(),
+					status: status__.unwrap_or_default(),
+					latest_update_timestamp: latest_update_timestamp__.unwrap_or_default(),
+					metadata: metadata__,
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.Payment", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentDirection {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let variant = match self {
+			Self::Inbound => "INBOUND",
+			Self::Outbound => "OUTBOUND",
+		};
+		serializer.serialize_str(variant)
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentDirection {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["INBOUND", "OUTBOUND"];
+
+		struct GeneratedVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentDirection;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(formatter, "expected one of: {:?}", &FIELDS)
+			}
+
+			fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(PaymentDirection::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+				})
+			}
+
+			fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(PaymentDirection::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+				})
+			}
+
+			fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				match value {
+					"INBOUND" => Ok(PaymentDirection::Inbound),
+					"OUTBOUND" => Ok(PaymentDirection::Outbound),
+					_ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+				}
+			}
+		}
+		deserializer.deserialize_any(GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentKind {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.kind.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.PaymentKind", len)?;
+		if let Some(v) = self.kind.as_ref() {
+			match v {
+				payment_kind::Kind::Onchain(v) => {
+					struct_ser.serialize_field("onchain", v)?;
+				},
+				payment_kind::Kind::Bolt11(v) => {
+					struct_ser.serialize_field("bolt11", v)?;
+				},
+				payment_kind::Kind::Bolt11Jit(v) => {
+					struct_ser.serialize_field("bolt11Jit", v)?;
+				},
+				payment_kind::Kind::Bolt12Offer(v) => {
+					struct_ser.serialize_field("bolt12Offer", v)?;
+				},
+				payment_kind::Kind::Bolt12Refund(v) => {
+					struct_ser.serialize_field("bolt12Refund", v)?;
+				},
+				payment_kind::Kind::Spontaneous(v) => {
+					struct_ser.serialize_field("spontaneous", v)?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentKind {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"onchain",
+			"bolt11",
+			"bolt11_jit",
+			"bolt11Jit",
+			"bolt12_offer",
+			"bolt12Offer",
+			"bolt12_refund",
+			"bolt12Refund",
+			"spontaneous",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Onchain,
+			Bolt11,
+			Bolt11Jit,
+			Bolt12Offer,
+			Bolt12Refund,
+			Spontaneous,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"onchain" => Ok(GeneratedField::Onchain),
+							"bolt11" => Ok(GeneratedField::Bolt11),
+							"bolt11Jit" | "bolt11_jit" => Ok(GeneratedField::Bolt11Jit),
+							"bolt12Offer" | "bolt12_offer" => Ok(GeneratedField::Bolt12Offer),
+							"bolt12Refund" | "bolt12_refund" => Ok(GeneratedField::Bolt12Refund),
+							"spontaneous" => Ok(GeneratedField::Spontaneous),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentKind;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.PaymentKind")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentKind, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut kind__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Onchain => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("onchain"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(payment_kind::Kind::Onchain);
+						},
+						GeneratedField::Bolt11 => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("bolt11"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(payment_kind::Kind::Bolt11);
+						},
+						GeneratedField::Bolt11Jit => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("bolt11Jit"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(payment_kind::Kind::Bolt11Jit);
+						},
+						GeneratedField::Bolt12Offer => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("bolt12Offer"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(payment_kind::Kind::Bolt12Offer);
+						},
+						GeneratedField::Bolt12Refund => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("bolt12Refund"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(payment_kind::Kind::Bolt12Refund);
+						},
+						GeneratedField::Spontaneous => {
+							if kind__.is_some() {
+								return Err(serde::de::Error::duplicate_field("spontaneous"));
+							}
+							kind__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(payment_kind::Kind::Spontaneous);
+						},
+					}
+				}
+				Ok(PaymentKind { kind: kind__ })
+			}
+		}
+		deserializer.deserialize_struct("types.PaymentKind", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentKindType {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let variant = match self {
+			Self::Onchain => "ONCHAIN",
+			Self::Bolt11 => "BOLT11",
+			Self::Bolt11Jit => "BOLT11_JIT",
+			Self::Bolt12Offer => "BOLT12_OFFER",
+			Self::Bolt12Refund => "BOLT12_REFUND",
+			Self::Spontaneous => "SPONTANEOUS",
+		};
+		serializer.serialize_str(variant)
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentKindType {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["ONCHAIN", "BOLT11", "BOLT11_JIT", "BOLT12_OFFER", "BOLT12_REFUND", "SPONTANEOUS"];
+
+		struct GeneratedVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentKindType;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(formatter, "expected one of: {:?}", &FIELDS)
+			}
+
+			fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(PaymentKindType::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+				})
+			}
+
+			fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(PaymentKindType::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+				})
+			}
+
+			fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				match value {
+					"ONCHAIN" => Ok(PaymentKindType::Onchain),
+					"BOLT11" => Ok(PaymentKindType::Bolt11),
+					"BOLT11_JIT" => Ok(PaymentKindType::Bolt11Jit),
+					"BOLT12_OFFER" => Ok(PaymentKindType::Bolt12Offer),
+					"BOLT12_REFUND" => Ok(PaymentKindType::Bolt12Refund),
+					"SPONTANEOUS" => Ok(PaymentKindType::Spontaneous),
+					_ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+				}
+			}
+		}
+		deserializer.deserialize_any(GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentMetadata {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.order_id.is_some() {
+			len += 1;
+		}
+		if self.customer_reference.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.PaymentMetadata", len)?;
+		if let Some(v) = self.order_id.as_ref() {
+			struct_ser.serialize_field("orderId", v)?;
+		}
+		if let Some(v) = self.customer_reference.as_ref() {
+			struct_ser.serialize_field("customerReference", v)?;
+		}
+		struct_ser.serialize_field("labels", &self.labels)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentMetadata {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] =
+			&["order_id", "orderId", "customer_reference", "customerReference", "labels"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			OrderId,
+			CustomerReference,
+			Labels,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"orderId" | "order_id" => Ok(GeneratedField::OrderId),
+							"customerReference" | "customer_reference" => {
+								Ok(GeneratedField::CustomerReference)
+							},
+							"labels" => Ok(GeneratedField::Labels),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentMetadata;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.PaymentMetadata")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentMetadata, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut order_id__ = None;
+				let mut customer_reference__ = None;
+				let mut labels__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::OrderId => {
+							if order_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("orderId"));
+							}
+							order_id__ = map_.next_value()?;
+						},
+						GeneratedField::CustomerReference => {
+							if customer_reference__.is_some() {
+								return Err(serde::de::Error::duplicate_field("customerReference"));
+							}
+							customer_reference__ = map_.next_value()?;
+						},
+						GeneratedField::Labels => {
+							if labels__.is_some() {
+								return Err(serde::de::Error::duplicate_field("labels"));
+							}
+							labels__ = Some(map_.next_value()?);
+						},
+					}
+				}
+				Ok(PaymentMetadata {
+					order_id: order_id__,
+					customer_reference: customer_reference__,
+					labels: labels__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.PaymentMetadata", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PaymentStatus {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let variant = match self {
+			Self::Pending => "PENDING",
+			Self::Succeeded => "SUCCEEDED",
+			Self::Failed => "FAILED",
+		};
+		serializer.serialize_str(variant)
+	}
+}
+impl<'de> serde::Deserialize<'de> for PaymentStatus {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["PENDING", "SUCCEEDED", "FAILED"];
+
+		struct GeneratedVisitor;
+
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PaymentStatus;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				write!(formatter, "expected one of: {:?}", &FIELDS)
+			}
+
+			fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(PaymentStatus::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+				})
+			}
+
+			fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				use std::convert::TryFrom;
+				i32::try_from(v).ok().and_then(PaymentStatus::from_i32).ok_or_else(|| {
+					serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+				})
+			}
+
+			fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+			where
+				E: serde::de::Error,
+			{
+				match value {
+					"PENDING" => Ok(PaymentStatus::Pending),
+					"SUCCEEDED" => Ok(PaymentStatus::Succeeded),
+					"FAILED" => Ok(PaymentStatus::Failed),
+					_ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+				}
+			}
+		}
+		deserializer.deserialize_any(GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PendingApproval {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		len += 1;
+		if self.amount_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.PendingApproval", len)?;
+		struct_ser.serialize_field("id", &self.id)?;
+		struct_ser.serialize_field("endpoint", &self.endpoint)?;
+		if let Some(v) = self.amount_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser.serialize_field("amountMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.serialize_field("destination", &self.destination)?;
+		struct_ser.serialize_field("submittedBy", &self.submitted_by)?;
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field("createdAt", ToString::to_string(&self.created_at).as_str())?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PendingApproval {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"id",
+			"endpoint",
+			"amount_msat",
+			"amountMsat",
+			"destination",
+			"submitted_by",
+			"submittedBy",
+			"created_at",
+			"createdAt",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Id,
+			Endpoint,
+			AmountMsat,
+			Destination,
+			SubmittedBy,
+			CreatedAt,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"id" => Ok(GeneratedField::Id),
+							"endpoint" => Ok(GeneratedField::Endpoint),
+							"amountMsat" | "amount_msat" => Ok(GeneratedField::AmountMsat),
+							"destination" => Ok(GeneratedField::Destination),
+							"submittedBy" | "submitted_by" => Ok(GeneratedField::SubmittedBy),
+							"createdAt" | "created_at" => Ok(GeneratedField::CreatedAt),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PendingApproval;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.PendingApproval")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingApproval, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut id__ = None;
+				let mut endpoint__ = None;
+				let mut amount_msat__ = None;
+				let mut destination__ = None;
+				let mut submitted_by__ = None;
+				let mut created_at__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Id => {
+							if id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("id"));
+							}
+							id__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Endpoint => {
+							if endpoint__.is_some() {
+								return Err(serde::de::Error::duplicate_field("endpoint"));
+							}
+							endpoint__ = Some(map_.next_value()?);
+						},
+						GeneratedField::AmountMsat => {
+							if amount_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountMsat"));
+							}
+							amount_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::Destination => {
+							if destination__.is_some() {
+								return Err(serde::de::Error::duplicate_field("destination"));
+							}
+							destination__ = Some(map_.next_value()?);
+						},
+						GeneratedField::SubmittedBy => {
+							if submitted_by__.is_some() {
+								return Err(serde::de::Error::duplicate_field("submittedBy"));
+							}
+							submitted_by__ = Some(map_.next_value()?);
+						},
+						GeneratedField::CreatedAt => {
+							if created_at__.is_some() {
+								return Err(serde::de::Error::duplicate_field("createdAt"));
+							}
+							created_at__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(PendingApproval {
+					id: id__.unwrap_or_default(),
+					endpoint: endpoint__.unwrap_or_default(),
+					amount_msat: amount_msat__,
+					destination: destination__.unwrap_or_default(),
+					submitted_by: submitted_by__.unwrap_or_default(),
+					created_at: created_at__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.PendingApproval", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PendingBroadcast {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.channel_id.is_some() {
+			len += 1;
+		}
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.PendingBroadcast", len)?;
+		if let Some(v) = self.channel_id.as_ref() {
+			struct_ser.serialize_field("channelId", v)?;
+		}
+		#[allow(clippy::needless_borrow)]
+		struct_ser.serialize_field(
+			"amountSatoshis",
+			ToString::to_string(&self.amount_satoshis).as_str(),
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PendingBroadcast {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["channel_id", "channelId", "amount_satoshis", "amountSatoshis"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			ChannelId,
+			AmountSatoshis,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"channelId" | "channel_id" => Ok(GeneratedField::ChannelId),
+							"amountSatoshis" | "amount_satoshis" => {
+								Ok(GeneratedField::AmountSatoshis)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PendingBroadcast;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.PendingBroadcast")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingBroadcast, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut channel_id__ = None;
+				let mut amount_satoshis__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::ChannelId => {
+							if channel_id__.is_some() {
+								return Err(serde::de::Error::duplicate_field("channelId"));
+							}
+							channel_id__ = map_.next_value()?;
+						},
+						GeneratedField::AmountSatoshis => {
+							if amount_satoshis__.is_some() {
+								return Err(serde::de::Error::duplicate_field("amountSatoshis"));
+							}
+							amount_satoshis__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(PendingBroadcast {
+					channel_id: channel_id__,
+					amount_satoshis: amount_satoshis__.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.PendingBroadcast", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for PendingSweepBalance {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.balance_type.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.PendingSweepBalance", len)?;
+		if let Some(v) = self.balance_type.as_ref() {
+			match v {
+				pending_sweep_balance::BalanceType::PendingBroadcast(v) => {
+					struct_ser.serialize_field("pendingBroadcast", v)?;
+				},
+				pending_sweep_balance::BalanceType::BroadcastAwaitingConfirmation(v) => {
+					struct_ser.serialize_field("broadcastAwaitingConfirmation", v)?;
+				},
+				pending_sweep_balance::BalanceType::AwaitingThresholdConfirmations(v) => {
+					struct_ser.serialize_field("awaitingThresholdConfirmations", v)?;
+				},
+			}
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for PendingSweepBalance {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"pending_broadcast",
+			"pendingBroadcast",
+			"broadcast_awaiting_confirmation",
+			"broadcastAwaitingConfirmation",
+			"awaiting_threshold_confirmations",
+			"awaitingThresholdConfirmations",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			PendingBroadcast,
+			BroadcastAwaitingConfirmation,
+			AwaitingThresholdConfirmations,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"pendingBroadcast" | "pending_broadcast" => {
+								Ok(GeneratedField::PendingBroadcast)
+							},
+							"broadcastAwaitingConfirmation" | "broadcast_awaiting_confirmation" => {
+								Ok(GeneratedField::BroadcastAwaitingConfirmation)
+							},
+							"awaitingThresholdConfirmations"
+							| "awaiting_threshold_confirmations" => Ok(GeneratedField::AwaitingThresholdConfirmations),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = PendingSweepBalance;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.PendingSweepBalance")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingSweepBalance, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut balance_type__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::PendingBroadcast => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field("pendingBroadcast"));
+							}
+							balance_type__ = map_
+								.next_value::<::std::option::Option<_>>()?
+								.map(pending_sweep_balance::BalanceType::PendingBroadcast);
+						},
+						GeneratedField::BroadcastAwaitingConfirmation => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"broadcastAwaitingConfirmation",
+								));
+							}
+							balance_type__ = map_.next_value::<::std::option::Option<_>>()?.map(
+								pending_sweep_balance::BalanceType::BroadcastAwaitingConfirmation,
+							);
+						},
+						GeneratedField::AwaitingThresholdConfirmations => {
+							if balance_type__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"awaitingThresholdConfirmations",
+								));
+							}
+							balance_type__ = map_.next_value::<::std::option::Option<_>>()?.map(
+								pending_sweep_balance::BalanceType::AwaitingThresholdConfirmations,
+							);
+						},
+					}
+				}
+				Ok(PendingSweepBalance { balance_type: balance_type__ })
+			}
+		}
+		deserializer.deserialize_struct("types.PendingSweepBalance", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for RouteParametersConfig {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		if self.max_total_routing_fee_msat.is_some() {
+			len += 1;
+		}
+		len += 1;
+		len += 1;
+		len += 1;
+		let mut struct_ser = serializer.serialize_struct("types.RouteParametersConfig", len)?;
+		if let Some(v) = self.max_total_routing_fee_msat.as_ref() {
+			#[allow(clippy::needless_borrow)]
+			struct_ser
+				.serialize_field("maxTotalRoutingFeeMsat", ToString::to_string(&v).as_str())?;
+		}
+		struct_ser.serialize_field("maxTotalCltvExpiryDelta", &self.max_total_cltv_expiry_delta)?;
+		struct_ser.serialize_field("maxPathCount", &self.max_path_count)?;
+		struct_ser.serialize_field(
+			"maxChannelSaturationPowerOfHalf",
+			&self.max_channel_saturation_power_of_half,
+		)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for RouteParametersConfig {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[
+			"max_total_routing_fee_msat",
+			"maxTotalRoutingFeeMsat",
+			"max_total_cltv_expiry_delta",
+			"maxTotalCltvExpiryDelta",
+			"max_path_count",
+			"maxPathCount",
+			"max_channel_saturation_power_of_half",
+			"maxChannelSaturationPowerOfHalf",
+		];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			MaxTotalRoutingFeeMsat,
+			MaxTotalCltvExpiryDelta,
+			MaxPathCount,
+			MaxChannelSaturationPowerOfHalf,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"maxTotalRoutingFeeMsat" | "max_total_routing_fee_msat" => {
+								Ok(GeneratedField::MaxTotalRoutingFeeMsat)
+							},
+							"maxTotalCltvExpiryDelta" | "max_total_cltv_expiry_delta" => {
+								Ok(GeneratedField::MaxTotalCltvExpiryDelta)
+							},
+							"maxPathCount" | "max_path_count" => Ok(GeneratedField::MaxPathCount),
+							"maxChannelSaturationPowerOfHalf"
+							| "max_channel_saturation_power_of_half" => {
+								Ok(GeneratedField::MaxChannelSaturationPowerOfHalf)
+							},
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = RouteParametersConfig;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.RouteParametersConfig")
+			}
+
+			fn visit_map<V>(
+				self, mut map_: V,
+			) -> std::result::Result<RouteParametersConfig, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut max_total_routing_fee_msat__ = None;
+				let mut max_total_cltv_expiry_delta__ = None;
+				let mut max_path_count__ = None;
+				let mut max_channel_saturation_power_of_half__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::MaxTotalRoutingFeeMsat => {
+							if max_total_routing_fee_msat__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxTotalRoutingFeeMsat",
+								));
+							}
+							max_total_routing_fee_msat__ = map_
+								.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>(
+								)?
+								.map(|x| x.0);
+						},
+						GeneratedField::MaxTotalCltvExpiryDelta => {
+							if max_total_cltv_expiry_delta__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxTotalCltvExpiryDelta",
+								));
+							}
+							max_total_cltv_expiry_delta__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::MaxPathCount => {
+							if max_path_count__.is_some() {
+								return Err(serde::de::Error::duplicate_field("maxPathCount"));
+							}
+							max_path_count__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+						GeneratedField::MaxChannelSaturationPowerOfHalf => {
+							if max_channel_saturation_power_of_half__.is_some() {
+								return Err(serde::de::Error::duplicate_field(
+									"maxChannelSaturationPowerOfHalf",
+								));
+							}
+							max_channel_saturation_power_of_half__ = Some(
+								map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0,
+							);
+						},
+					}
+				}
+				Ok(RouteParametersConfig {
+					max_total_routing_fee_msat: max_total_routing_fee_msat__,
+					max_total_cltv_expiry_delta: max_total_cltv_expiry_delta__.unwrap_or_default(),
+					max_path_count: max_path_count__.unwrap_or_default(),
+					max_channel_saturation_power_of_half: max_channel_saturation_power_of_half__
+						.unwrap_or_default(),
+				})
+			}
+		}
+		deserializer.deserialize_struct("types.RouteParametersConfig", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Spontaneous {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let mut len = 0;
+		len += 1;
+		if self.preimage.is_some() {
+			len += 1;
+		}
+		let mut struct_ser = serializer.serialize_struct("types.Spontaneous", len)?;
+		struct_ser.serialize_field("hash", &self.hash)?;
+		if let Some(v) = self.preimage.as_ref() {
+			struct_ser.serialize_field("preimage", v)?;
+		}
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Spontaneous {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &["hash", "preimage"];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {
+			Hash,
+			Preimage,
+		}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						match value {
+							"hash" => Ok(GeneratedField::Hash),
+							"preimage" => Ok(GeneratedField::Preimage),
+							_ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+						}
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Spontaneous;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Spontaneous")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Spontaneous, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				let mut hash__ = None;
+				let mut preimage__ = None;
+				while let Some(k) = map_.next_key()? {
+					match k {
+						GeneratedField::Hash => {
+							if hash__.is_some() {
+								return Err(serde::de::Error::duplicate_field("hash"));
+							}
+							hash__ = Some(map_.next_value()?);
+						},
+						GeneratedField::Preimage => {
+							if preimage__.is_some() {
+								return Err(serde::de::Error::duplicate_field("preimage"));
+							}
+							preimage__ = map_.next_value()?;
+						},
+					}
+				}
+				Ok(Spontaneous { hash: hash__.unwrap_or_default(), preimage: preimage__ })
+			}
+		}
+		deserializer.deserialize_struct("types.Spontaneous", FIELDS, GeneratedVisitor)
+	}
+}
+impl serde::Serialize for Unconfirmed {
+	#[allow(deprecated)]
+	fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		use serde::ser::SerializeStruct;
+		let len = 0;
+		let struct_ser = serializer.serialize_struct("types.Unconfirmed", len)?;
+		struct_ser.end()
+	}
+}
+impl<'de> serde::Deserialize<'de> for Unconfirmed {
+	#[allow(deprecated)]
+	fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		const FIELDS: &[&str] = &[];
+
+		#[allow(clippy::enum_variant_names)]
+		enum GeneratedField {}
+		impl<'de> serde::Deserialize<'de> for GeneratedField {
+			fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+			where
+				D: serde::Deserializer<'de>,
+			{
+				struct GeneratedVisitor;
+
+				impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+					type Value = GeneratedField;
+
+					fn expecting(
+						&self, formatter: &mut std::fmt::Formatter<'_>,
+					) -> std::fmt::Result {
+						write!(formatter, "expected one of: {:?}", &FIELDS)
+					}
+
+					#[allow(unused_variables)]
+					fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+					where
+						E: serde::de::Error,
+					{
+						Err(serde::de::Error::unknown_field(value, FIELDS))
+					}
+				}
+				deserializer.deserialize_identifier(GeneratedVisitor)
+			}
+		}
+		struct GeneratedVisitor;
+		impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+			type Value = Unconfirmed;
+
+			fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				formatter.write_str("struct types.Unconfirmed")
+			}
+
+			fn visit_map<V>(self, mut map_: V) -> std::result::Result<Unconfirmed, V::Error>
+			where
+				V: serde::de::MapAccess<'de>,
+			{
+				while map_.next_key::<GeneratedField>()?.is_some() {
+					let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+				}
+				Ok(Unconfirmed {})
+			}
+		}
+		deserializer.deserialize_struct("types.Unconfirmed", FIELDS, GeneratedVisitor)
+	}
+}
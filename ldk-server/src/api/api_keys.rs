@@ -0,0 +1,72 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use ldk_server_protos::api::{
+	CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeysRequest, ListApiKeysResponse,
+	RevokeApiKeyRequest, RevokeApiKeyResponse, RotateApiKeyRequest, RotateApiKeyResponse,
+};
+use ldk_server_protos::types::ApiKeyPermission;
+
+use crate::api::current_time;
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::InvalidRequestError;
+use crate::auth::api_key_manager::DEFAULT_ROTATION_GRACE_PERIOD_SECS;
+use crate::auth::spending_limits::SpendingLimits;
+use crate::auth::Permission;
+use crate::service::Context;
+
+pub(crate) fn handle_create_api_key_request(
+	context: Context, request: CreateApiKeyRequest,
+) -> Result<CreateApiKeyResponse, LdkServerError> {
+	let permissions = request
+		.permissions
+		.into_iter()
+		.map(|p| {
+			ApiKeyPermission::from_i32(p).map(Permission::from).ok_or_else(|| {
+				LdkServerError::new(InvalidRequestError, format!("Invalid API key permission: {p}"))
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let spending_limits = SpendingLimits {
+		max_payment_amount_msat: request.max_send_amount_msat,
+		daily_budget_msat: request.daily_budget_msat,
+	};
+
+	let api_key = context.api_keys.create(
+		request.name,
+		permissions,
+		spending_limits,
+		request.client_identity,
+		current_time(),
+	)?;
+
+	Ok(CreateApiKeyResponse { api_key })
+}
+
+pub(crate) fn handle_list_api_keys_request(
+	context: Context, _request: ListApiKeysRequest,
+) -> Result<ListApiKeysResponse, LdkServerError> {
+	Ok(ListApiKeysResponse { api_keys: context.api_keys.list(current_time()) })
+}
+
+pub(crate) fn handle_revoke_api_key_request(
+	context: Context, request: RevokeApiKeyRequest,
+) -> Result<RevokeApiKeyResponse, LdkServerError> {
+	context.api_keys.revoke(&request.name, current_time())?;
+	Ok(RevokeApiKeyResponse {})
+}
+
+pub(crate) fn handle_rotate_api_key_request(
+	context: Context, request: RotateApiKeyRequest,
+) -> Result<RotateApiKeyResponse, LdkServerError> {
+	let grace_period_secs = request.grace_period_secs.unwrap_or(DEFAULT_ROTATION_GRACE_PERIOD_SECS);
+	let api_key = context.api_keys.rotate(&request.name, grace_period_secs, current_time())?;
+	Ok(RotateApiKeyResponse { api_key })
+}
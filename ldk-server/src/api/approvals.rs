@@ -0,0 +1,122 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use ldk_server_protos::api::{
+	ApprovePaymentRequest, ApprovePaymentResponse, Bolt11SendRequest, Bolt12SendRequest,
+	ListPendingApprovalsRequest, ListPendingApprovalsResponse, OnchainSendRequest,
+	RejectPaymentRequest, RejectPaymentResponse, SpontaneousSendRequest,
+};
+use ldk_server_protos::endpoints::{
+	BOLT11_SEND_PATH, BOLT12_SEND_PATH, ONCHAIN_SEND_PATH, SPONTANEOUS_SEND_PATH,
+};
+use prost::Message;
+
+use crate::api::bolt11_send::send_bolt11_payment;
+use crate::api::bolt12_send::send_bolt12_payment;
+use crate::api::current_time;
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::api::onchain_send::send_onchain_payment;
+use crate::api::spontaneous_send::send_spontaneous_payment;
+use crate::service::Context;
+
+/// Holds the payment submitted via `endpoint` with `request` for approval, if its amount exceeds
+/// the configured threshold.
+///
+/// Returns the id of the pending approval if the payment is held, in which case it must not be
+/// sent.
+///
+/// Payments exceeding the per-payment spending limits of the submitting API key are rejected up
+/// front, as they could never be sent once approved.
+pub(crate) fn hold_for_approval_if_required<T: Message>(
+	context: &Context, endpoint: &str, request: &T, amount_msat: Option<u64>, destination: String,
+) -> Result<Option<String>, LdkServerError> {
+	if !context.approval_queue.requires_approval(amount_msat) {
+		return Ok(None);
+	}
+	context.spending_tracker.check_payment_amount(&context.api_key, amount_msat)?;
+
+	let pending_approval_id = context.approval_queue.submit(
+		endpoint,
+		request.encode_to_vec(),
+		amount_msat,
+		destination,
+		&context.api_key,
+		context.client_identity.clone(),
+		current_time(),
+	)?;
+	Ok(Some(pending_approval_id))
+}
+
+pub(crate) fn handle_list_pending_approvals_request(
+	context: Context, _request: ListPendingApprovalsRequest,
+) -> Result<ListPendingApprovalsResponse, LdkServerError> {
+	Ok(ListPendingApprovalsResponse { pending_approvals: context.approval_queue.list() })
+}
+
+pub(crate) fn handle_approve_payment_request(
+	context: Context, request: ApprovePaymentRequest,
+) -> Result<ApprovePaymentResponse, LdkServerError> {
+	let payment_id = context.approval_queue.approve(
+		&request.id,
+		&context.api_key,
+		context.client_identity.as_deref(),
+		current_time(),
+		|payment| {
+			// The payment still counts towards the spending limits of the key it was submitted with.
+			let submitter = context.api_keys.get(payment.submitted_by).ok_or_else(|| {
+				LdkServerError::new(
+					InvalidRequestError,
+					format!(
+						"API key '{}' the payment was submitted with no longer exists",
+						payment.submitted_by
+					),
+				)
+			})?;
+
+			match payment.endpoint {
+				BOLT11_SEND_PATH => {
+					let request = decode_request::<Bolt11SendRequest>(payment.request)?;
+					Ok(send_bolt11_payment(&context, &submitter, request)?.to_string())
+				},
+				BOLT12_SEND_PATH => {
+					let request = decode_request::<Bolt12SendRequest>(payment.request)?;
+					Ok(send_bolt12_payment(&context, &submitter, request)?.to_string())
+				},
+				SPONTANEOUS_SEND_PATH => {
+					let request = decode_request::<SpontaneousSendRequest>(payment.request)?;
+					Ok(send_spontaneous_payment(&context, &submitter, request)?.to_string())
+				},
+				ONCHAIN_SEND_PATH => {
+					let request = decode_request::<OnchainSendRequest>(payment.request)?;
+					Ok(send_onchain_payment(&context, &submitter, request)?.to_string())
+				},
+				endpoint => Err(LdkServerError::new(
+					InternalServerError,
+					format!("Payments submitted via '{endpoint}' can't be approved"),
+				)),
+			}
+		},
+	)?;
+
+	Ok(ApprovePaymentResponse { payment_id })
+}
+
+pub(crate) fn handle_reject_payment_request(
+	context: Context, request: RejectPaymentRequest,
+) -> Result<RejectPaymentResponse, LdkServerError> {
+	context.approval_queue.reject(&request.id, &context.api_key, current_time())?;
+	Ok(RejectPaymentResponse {})
+}
+
+fn decode_request<T: Message + Default>(request: &[u8]) -> Result<T, LdkServerError> {
+	T::decode(request).map_err(|e| {
+		LdkServerError::new(InternalServerError, format!("Failed to decode pending payment: {e}"))
+	})
+}
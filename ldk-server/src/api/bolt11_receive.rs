@@ -10,12 +10,18 @@
 use ldk_server_protos::api::{Bolt11ReceiveRequest, Bolt11ReceiveResponse};
 
 use crate::api::error::LdkServerError;
+use crate::api::payment_metadata::{validate_payment_metadata, write_payment_metadata};
+use crate::io::persist::{
+	PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+	PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+};
 use crate::service::Context;
 use crate::util::proto_adapter::proto_to_bolt11_description;
 
 pub(crate) fn handle_bolt11_receive_request(
 	context: Context, request: Bolt11ReceiveRequest,
 ) -> Result<Bolt11ReceiveResponse, LdkServerError> {
+	validate_payment_metadata(request.metadata.as_ref())?;
 	let description = proto_to_bolt11_description(request.description)?;
 	let invoice = match request.amount_msat {
 		Some(amount_msat) => {
@@ -27,6 +33,17 @@ pub(crate) fn handle_bolt11_receive_request(
 			.receive_variable_amount(&description, request.expiry_secs)?,
 	};
 
+	// Payments received via an invoice are identified by its payment hash.
+	if let Some(metadata) = request.metadata {
+		write_payment_metadata(
+			&context,
+			PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			&invoice.payment_hash().to_string(),
+			&metadata,
+		)?;
+	}
+
 	let response = Bolt11ReceiveResponse { invoice: invoice.to_string() };
 	Ok(response)
 }
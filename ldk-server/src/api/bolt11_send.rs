@@ -9,28 +9,73 @@
 
 use std::str::FromStr;
 
+use ldk_node::bitcoin::hashes::Hash;
+use ldk_node::lightning::ln::channelmanager::PaymentId;
 use ldk_node::lightning_invoice::Bolt11Invoice;
 use ldk_server_protos::api::{Bolt11SendRequest, Bolt11SendResponse};
+use ldk_server_protos::endpoints::BOLT11_SEND_PATH;
 
+use crate::api::approvals::hold_for_approval_if_required;
 use crate::api::build_route_parameters_config_from_proto;
 use crate::api::error::LdkServerError;
+use crate::api::payment_metadata::{send_with_payment_metadata, validate_payment_metadata};
+use crate::auth::ApiKey;
 use crate::service::Context;
 
 pub(crate) fn handle_bolt11_send_request(
 	context: Context, request: Bolt11SendRequest,
 ) -> Result<Bolt11SendResponse, LdkServerError> {
-	let invoice = Bolt11Invoice::from_str(request.invoice.as_str())
-		.map_err(|_| ldk_node::NodeError::InvalidInvoice)?;
+	let invoice = parse_invoice(&request)?;
+	let amount_msat = request.amount_msat.or(invoice.amount_milli_satoshis());
+	validate_payment_metadata(request.metadata.as_ref())?;
 
-	let route_parameters = build_route_parameters_config_from_proto(request.route_parameters)?;
+	if let Some(pending_approval_id) = hold_for_approval_if_required(
+		&context,
+		BOLT11_SEND_PATH,
+		&request,
+		amount_msat,
+		request.invoice.clone(),
+	)? {
+		let response = Bolt11SendResponse {
+			payment_id: String::new(),
+			pending_approval_id: Some(pending_approval_id),
+		};
+		return Ok(response);
+	}
 
-	let payment_id = match request.amount_msat {
-		None => context.node.bolt11_payment().send(&invoice, route_parameters),
-		Some(amount_msat) => {
-			context.node.bolt11_payment().send_using_amount(&invoice, amount_msat, route_parameters)
-		},
-	}?;
+	let payment_id = send_bolt11_payment(&context, &context.api_key, request)?;
 
-	let response = Bolt11SendResponse { payment_id: payment_id.to_string() };
+	let response =
+		Bolt11SendResponse { payment_id: payment_id.to_string(), pending_approval_id: None };
 	Ok(response)
 }
+
+/// Sends the payment described by `request`, counting it towards the spending limits of `api_key`.
+pub(crate) fn send_bolt11_payment(
+	context: &Context, api_key: &ApiKey, request: Bolt11SendRequest,
+) -> Result<PaymentId, LdkServerError> {
+	let invoice = parse_invoice(&request)?;
+	let route_parameters = build_route_parameters_config_from_proto(request.route_parameters)?;
+
+	let amount_msat = request.amount_msat.or(invoice.amount_milli_satoshis());
+	// BOLT11 payments are identified by their payment hash, so their metadata can be persisted
+	// before sending them.
+	let payment_id = PaymentId(invoice.payment_hash().to_byte_array());
+	send_with_payment_metadata(context, &payment_id, request.metadata, || {
+		context.spending_tracker.spend(api_key, amount_msat, || {
+			Ok(match request.amount_msat {
+				None => context.node.bolt11_payment().send(&invoice, route_parameters),
+				Some(amount_msat) => context.node.bolt11_payment().send_using_amount(
+					&invoice,
+					amount_msat,
+					route_parameters,
+				),
+			}?)
+		})
+	})
+}
+
+fn parse_invoice(request: &Bolt11SendRequest) -> Result<Bolt11Invoice, LdkServerError> {
+	Ok(Bolt11Invoice::from_str(request.invoice.as_str())
+		.map_err(|_| ldk_node::NodeError::InvalidInvoice)?)
+}
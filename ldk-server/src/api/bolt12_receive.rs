@@ -7,14 +7,20 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
+use hex::DisplayHex;
 use ldk_server_protos::api::{Bolt12ReceiveRequest, Bolt12ReceiveResponse};
 
 use crate::api::error::LdkServerError;
+use crate::api::payment_metadata::{validate_payment_metadata, write_payment_metadata};
+use crate::io::persist::{
+	OFFER_METADATA_PERSISTENCE_PRIMARY_NAMESPACE, OFFER_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+};
 use crate::service::Context;
 
 pub(crate) fn handle_bolt12_receive_request(
 	context: Context, request: Bolt12ReceiveRequest,
 ) -> Result<Bolt12ReceiveResponse, LdkServerError> {
+	validate_payment_metadata(request.metadata.as_ref())?;
 	let offer = match request.amount_msat {
 		Some(amount_msat) => context.node.bolt12_payment().receive(
 			amount_msat,
@@ -28,6 +34,18 @@ pub(crate) fn handle_bolt12_receive_request(
 			.receive_variable_amount(&request.description, request.expiry_secs)?,
 	};
 
+	// As payments for the offer are only identified once they are received, the metadata is
+	// persisted for the offer and copied to its payments upon receiving them.
+	if let Some(metadata) = request.metadata {
+		write_payment_metadata(
+			&context,
+			OFFER_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			OFFER_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			&offer.id().0.to_lower_hex_string(),
+			&metadata,
+		)?;
+	}
+
 	let response = Bolt12ReceiveResponse { offer: offer.to_string() };
 	Ok(response)
 }
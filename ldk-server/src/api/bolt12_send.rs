@@ -9,37 +9,88 @@
 
 use std::str::FromStr;
 
-use ldk_node::lightning::offers::offer::Offer;
+use ldk_node::lightning::ln::channelmanager::PaymentId;
+use ldk_node::lightning::offers::offer::{Amount, Offer};
 use ldk_server_protos::api::{Bolt12SendRequest, Bolt12SendResponse};
+use ldk_server_protos::endpoints::BOLT12_SEND_PATH;
 
+use crate::api::approvals::hold_for_approval_if_required;
 use crate::api::build_route_parameters_config_from_proto;
 use crate::api::error::LdkServerError;
+use crate::api::payment_metadata::{validate_payment_metadata, write_sent_payment_metadata};
+use crate::auth::ApiKey;
 use crate::service::Context;
 
 pub(crate) fn handle_bolt12_send_request(
 	context: Context, request: Bolt12SendRequest,
 ) -> Result<Bolt12SendResponse, LdkServerError> {
-	let offer =
-		Offer::from_str(request.offer.as_str()).map_err(|_| ldk_node::NodeError::InvalidOffer)?;
+	let offer = parse_offer(&request)?;
+	let amount_msat = payment_amount_msat(&offer, &request);
+	validate_payment_metadata(request.metadata.as_ref())?;
 
-	let route_parameters = build_route_parameters_config_from_proto(request.route_parameters)?;
+	if let Some(pending_approval_id) = hold_for_approval_if_required(
+		&context,
+		BOLT12_SEND_PATH,
+		&request,
+		amount_msat,
+		request.offer.clone(),
+	)? {
+		let response = Bolt12SendResponse {
+			payment_id: String::new(),
+			pending_approval_id: Some(pending_approval_id),
+		};
+		return Ok(response);
+	}
+
+	let payment_id = send_bolt12_payment(&context, &context.api_key, request)?;
 
-	let payment_id = match request.amount_msat {
-		None => context.node.bolt12_payment().send(
-			&offer,
-			request.quantity,
-			request.payer_note,
-			route_parameters,
-		),
-		Some(amount_msat) => context.node.bolt12_payment().send_using_amount(
-			&offer,
-			amount_msat,
-			request.quantity,
-			request.payer_note,
-			route_parameters,
-		),
-	}?;
-
-	let response = Bolt12SendResponse { payment_id: payment_id.to_string() };
+	let response =
+		Bolt12SendResponse { payment_id: payment_id.to_string(), pending_approval_id: None };
 	Ok(response)
 }
+
+/// Sends the payment described by `request`, counting it towards the spending limits of `api_key`.
+pub(crate) fn send_bolt12_payment(
+	context: &Context, api_key: &ApiKey, request: Bolt12SendRequest,
+) -> Result<PaymentId, LdkServerError> {
+	let offer = parse_offer(&request)?;
+	let route_parameters = build_route_parameters_config_from_proto(request.route_parameters)?;
+
+	let amount_msat = payment_amount_msat(&offer, &request);
+	let payment_id = context.spending_tracker.spend(api_key, amount_msat, || {
+		Ok(match request.amount_msat {
+			None => context.node.bolt12_payment().send(
+				&offer,
+				request.quantity,
+				request.payer_note,
+				route_parameters,
+			),
+			Some(amount_msat) => context.node.bolt12_payment().send_using_amount(
+				&offer,
+				amount_msat,
+				request.quantity,
+				request.payer_note,
+				route_parameters,
+			),
+		}?)
+	})?;
+
+	// BOLT12 payments are only identified once they are sent, so their metadata can't be persisted
+	// before sending them.
+	write_sent_payment_metadata(context, &payment_id, request.metadata)?;
+	Ok(payment_id)
+}
+
+fn parse_offer(request: &Bolt12SendRequest) -> Result<Offer, LdkServerError> {
+	Ok(Offer::from_str(request.offer.as_str()).map_err(|_| ldk_node::NodeError::InvalidOffer)?)
+}
+
+fn payment_amount_msat(offer: &Offer, request: &Bolt12SendRequest) -> Option<u64> {
+	let offer_amount_msat = match offer.amount() {
+		Some(Amount::Bitcoin { amount_msats }) => {
+			Some(amount_msats.saturating_mul(request.quantity.unwrap_or(1)))
+		},
+		_ => None,
+	};
+	request.amount_msat.or(offer_amount_msat)
+}
@@ -52,6 +52,9 @@ pub(crate) enum LdkServerErrorCode {
 
 	/// Please refer to [`protos::error::ErrorCode::InternalServerError`].
 	InternalServerError,
+
+	/// Please refer to [`protos::error::ErrorCode::SpendingLimitExceededError`].
+	SpendingLimitExceededError,
 }
 
 impl fmt::Display for LdkServerErrorCode {
@@ -61,6 +64,9 @@ impl fmt::Display for LdkServerErrorCode {
 			LdkServerErrorCode::AuthError => write!(f, "AuthError"),
 			LdkServerErrorCode::LightningError => write!(f, "LightningError"),
 			LdkServerErrorCode::InternalServerError => write!(f, "InternalServerError"),
+			LdkServerErrorCode::SpendingLimitExceededError => {
+				write!(f, "SpendingLimitExceededError")
+			},
 		}
 	}
 }
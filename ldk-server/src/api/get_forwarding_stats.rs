@@ -0,0 +1,168 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::collections::BTreeMap;
+
+use ldk_server_protos::api::{GetForwardingStatsRequest, GetForwardingStatsResponse};
+use ldk_server_protos::types::{
+	ChannelPairForwardingStats, DailyForwardingStats, ForwardedPayment,
+};
+
+use crate::api::current_time;
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::api::list_forwarded_payments::{forwarded_payment_filters, read_forwarded_payment};
+use crate::io::persist::{
+	FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+	FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+};
+use crate::service::Context;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// The maximum time span stats are computed over, as every payment forwarded within it is read.
+const MAX_TIME_SPAN_SECS: u64 = 90 * SECONDS_PER_DAY;
+
+/// The maximum number of channel pairs returned, keeping those with the highest fees earned.
+const MAX_CHANNEL_PAIRS: usize = 100;
+
+/// The number of payments forwarded, the amount forwarded and the fees earned, summed up over a
+/// set of forwarded payments.
+#[derive(Default)]
+struct ForwardingTotals {
+	forwarded_payments_count: u64,
+	outbound_amount_forwarded_msat: u64,
+	total_fee_earned_msat: u64,
+}
+
+impl ForwardingTotals {
+	fn add(&mut self, forwarded_payment: &ForwardedPayment) {
+		self.forwarded_payments_count += 1;
+		self.outbound_amount_forwarded_msat = self
+			.outbound_amount_forwarded_msat
+			.saturating_add(forwarded_payment.outbound_amount_forwarded_msat.unwrap_or(0));
+		self.total_fee_earned_msat = self
+			.total_fee_earned_msat
+			.saturating_add(forwarded_payment.total_fee_earned_msat.unwrap_or(0));
+	}
+}
+
+pub(crate) fn handle_get_forwarding_stats_request(
+	context: Context, request: GetForwardingStatsRequest,
+) -> Result<GetForwardingStatsResponse, LdkServerError> {
+	let max_timestamp = request.max_timestamp.unwrap_or_else(current_time);
+	let min_timestamp =
+		request.min_timestamp.unwrap_or(max_timestamp.saturating_sub(MAX_TIME_SPAN_SECS));
+	if max_timestamp.saturating_sub(min_timestamp) > MAX_TIME_SPAN_SECS {
+		return Err(LdkServerError::new(
+			InvalidRequestError,
+			format!(
+				"Invalid timestamps, must not be more than {} days apart",
+				MAX_TIME_SPAN_SECS / SECONDS_PER_DAY
+			),
+		));
+	}
+	let filters = forwarded_payment_filters(
+		request.prev_channel_id.as_deref(),
+		request.next_channel_id.as_deref(),
+		request.prev_node_id.as_deref(),
+		request.next_node_id.as_deref(),
+		Some(min_timestamp),
+		Some(max_timestamp),
+	);
+
+	let mut totals = ForwardingTotals::default();
+	// Keyed by the channel ids, holding the node ids along with the totals.
+	let mut channel_pairs: BTreeMap<(String, String), (String, String, ForwardingTotals)> =
+		BTreeMap::new();
+	// Keyed by the timestamp of the start of the day.
+	let mut days: BTreeMap<u64, ForwardingTotals> = BTreeMap::new();
+
+	let mut page_token = None;
+	loop {
+		let list_response = context
+			.paginated_kv_store
+			.list_filtered(
+				FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				&filters,
+				page_token,
+			)
+			.map_err(|e| {
+				LdkServerError::new(
+					InternalServerError,
+					format!("Failed to list forwarded payments: {}", e),
+				)
+			})?;
+
+		for key in list_response.keys {
+			let forwarded_payment = read_forwarded_payment(&context, &key)?;
+			totals.add(&forwarded_payment);
+			days.entry(forwarded_payment.timestamp / SECONDS_PER_DAY * SECONDS_PER_DAY)
+				.or_default()
+				.add(&forwarded_payment);
+			let channel_pair = (
+				forwarded_payment.prev_channel_id.clone(),
+				forwarded_payment.next_channel_id.clone(),
+			);
+			channel_pairs
+				.entry(channel_pair)
+				.or_insert_with(|| {
+					(
+						forwarded_payment.prev_node_id.clone(),
+						forwarded_payment.next_node_id.clone(),
+						ForwardingTotals::default(),
+					)
+				})
+				.2
+				.add(&forwarded_payment);
+		}
+
+		match list_response.next_page_token {
+			Some(next_page_token) => page_token = Some(next_page_token),
+			None => break,
+		}
+	}
+
+	let mut channel_pairs = channel_pairs
+		.into_iter()
+		.map(|((prev_channel_id, next_channel_id), (prev_node_id, next_node_id, totals))| {
+			ChannelPairForwardingStats {
+				prev_channel_id,
+				next_channel_id,
+				prev_node_id,
+				next_node_id,
+				forwarded_payments_count: totals.forwarded_payments_count,
+				outbound_amount_forwarded_msat: totals.outbound_amount_forwarded_msat,
+				total_fee_earned_msat: totals.total_fee_earned_msat,
+			}
+		})
+		.collect::<Vec<_>>();
+	// The sort is stable, so channel pairs with equal fees remain ordered by their channel ids.
+	channel_pairs.sort_by(|a, b| b.total_fee_earned_msat.cmp(&a.total_fee_earned_msat));
+	channel_pairs.truncate(MAX_CHANNEL_PAIRS);
+
+	let days = days
+		.into_iter()
+		.map(|(day_start_timestamp, totals)| DailyForwardingStats {
+			day_start_timestamp,
+			forwarded_payments_count: totals.forwarded_payments_count,
+			outbound_amount_forwarded_msat: totals.outbound_amount_forwarded_msat,
+			total_fee_earned_msat: totals.total_fee_earned_msat,
+		})
+		.collect();
+
+	Ok(GetForwardingStatsResponse {
+		forwarded_payments_count: totals.forwarded_payments_count,
+		outbound_amount_forwarded_msat: totals.outbound_amount_forwarded_msat,
+		total_fee_earned_msat: totals.total_fee_earned_msat,
+		channel_pairs,
+		days,
+	})
+}
@@ -13,6 +13,7 @@ use ldk_server_protos::api::{GetPaymentDetailsRequest, GetPaymentDetailsResponse
 
 use crate::api::error::LdkServerError;
 use crate::api::error::LdkServerErrorCode::InvalidRequestError;
+use crate::api::payment_metadata::with_payment_metadata;
 use crate::service::Context;
 use crate::util::proto_adapter::payment_to_proto;
 
@@ -27,9 +28,14 @@ pub(crate) fn handle_get_payment_details_request(
 			)
 		})?;
 
-	let payment_details = context.node.payment(&PaymentId(payment_id_bytes));
+	let payment = match context.node.payment(&PaymentId(payment_id_bytes)) {
+		Some(payment_details) => {
+			Some(with_payment_metadata(&context, payment_to_proto(payment_details))?)
+		},
+		None => None,
+	};
 
-	let response = GetPaymentDetailsResponse { payment: payment_details.map(payment_to_proto) };
+	let response = GetPaymentDetailsResponse { payment };
 
 	Ok(response)
 }
@@ -0,0 +1,86 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use bytes::Bytes;
+use ldk_server_protos::api::{ListEventsRequest, ListEventsResponse};
+use ldk_server_protos::events::EventEnvelope;
+use ldk_server_protos::types::PageToken;
+use prost::Message;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::io::events::outbox::{event_history_key, event_history_time};
+use crate::io::events::EVENT_NAMES;
+use crate::io::persist::paginated_kv_store::ListFilter;
+use crate::io::persist::{
+	EVENTS_PERSISTENCE_PRIMARY_NAMESPACE, EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	EVENT_TYPE_ATTRIBUTE,
+};
+use crate::service::Context;
+
+pub(crate) fn handle_list_events_request(
+	context: Context, request: ListEventsRequest,
+) -> Result<ListEventsResponse, LdkServerError> {
+	let mut event_types = Vec::with_capacity(request.event_types.len());
+	for event_type in &request.event_types {
+		let name = EVENT_NAMES.iter().find(|name| **name == event_type).ok_or_else(|| {
+			LdkServerError::new(InvalidRequestError, format!("Unknown event type: {}", event_type))
+		})?;
+		event_types.push(*name);
+	}
+	let mut filters = Vec::new();
+	if !event_types.is_empty() {
+		filters
+			.push(ListFilter::TextAttributeIn { name: EVENT_TYPE_ATTRIBUTE, values: &event_types });
+	}
+
+	// Events are listed in the order of their sequence number, hence the page starting right after
+	// `since_sequence_number` is the one following its event.
+	let page_token = match (request.page_token, request.since_sequence_number) {
+		(Some(page_token), _) => Some((page_token.token, page_token.index)),
+		(None, Some(since)) => Some((event_history_key(since), event_history_time(since))),
+		(None, None) => None,
+	};
+	let list_response = context
+		.paginated_kv_store
+		.list_filtered(
+			EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&filters,
+			page_token,
+		)
+		.map_err(|e| {
+			LdkServerError::new(InternalServerError, format!("Failed to list events: {}", e))
+		})?;
+
+	let mut events: Vec<EventEnvelope> = Vec::with_capacity(list_response.keys.len());
+	for key in list_response.keys {
+		let event_bytes = context
+			.paginated_kv_store
+			.read(
+				EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				&key,
+			)
+			.map_err(|e| {
+				LdkServerError::new(InternalServerError, format!("Failed to read event: {}", e))
+			})?;
+		let event = EventEnvelope::decode(Bytes::from(event_bytes)).map_err(|e| {
+			LdkServerError::new(InternalServerError, format!("Failed to decode event: {}", e))
+		})?;
+		events.push(event);
+	}
+	let response = ListEventsResponse {
+		events,
+		next_page_token: list_response
+			.next_page_token
+			.map(|(token, index)| PageToken { token, index }),
+	};
+	Ok(response)
+}
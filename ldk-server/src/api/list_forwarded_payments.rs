@@ -14,21 +14,34 @@ use prost::Message;
 
 use crate::api::error::LdkServerError;
 use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::api::list_payments::timestamp_range;
+use crate::io::persist::paginated_kv_store::ListFilter;
 use crate::io::persist::{
 	FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
 	FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	FORWARDED_PAYMENT_NEXT_CHANNEL_ID_ATTRIBUTE, FORWARDED_PAYMENT_NEXT_NODE_ID_ATTRIBUTE,
+	FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE, FORWARDED_PAYMENT_PREV_NODE_ID_ATTRIBUTE,
 };
 use crate::service::Context;
 
 pub(crate) fn handle_list_forwarded_payments_request(
 	context: Context, request: ListForwardedPaymentsRequest,
 ) -> Result<ListForwardedPaymentsResponse, LdkServerError> {
+	let filters = forwarded_payment_filters(
+		request.prev_channel_id.as_deref(),
+		request.next_channel_id.as_deref(),
+		request.prev_node_id.as_deref(),
+		request.next_node_id.as_deref(),
+		request.min_timestamp,
+		request.max_timestamp,
+	);
 	let page_token = request.page_token.map(|p| (p.token, p.index));
 	let list_response = context
 		.paginated_kv_store
-		.list(
+		.list_filtered(
 			FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
 			FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&filters,
 			page_token,
 		)
 		.map_err(|e| {
@@ -41,27 +54,7 @@ pub(crate) fn handle_list_forwarded_payments_request(
 	let mut forwarded_payments: Vec<ForwardedPayment> =
 		Vec::with_capacity(list_response.keys.len());
 	for key in list_response.keys {
-		let forwarded_payment_bytes = context
-			.paginated_kv_store
-			.read(
-				FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
-				FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
-				&key,
-			)
-			.map_err(|e| {
-				LdkServerError::new(
-					InternalServerError,
-					format!("Failed to read forwarded payment data: {}", e),
-				)
-			})?;
-		let forwarded_payment = ForwardedPayment::decode(Bytes::from(forwarded_payment_bytes))
-			.map_err(|e| {
-				LdkServerError::new(
-					InternalServerError,
-					format!("Failed to decode forwarded payment: {}", e),
-				)
-			})?;
-		forwarded_payments.push(forwarded_payment);
+		forwarded_payments.push(read_forwarded_payment(&context, &key)?);
 	}
 	let response = ListForwardedPaymentsResponse {
 		forwarded_payments,
@@ -71,3 +64,50 @@ pub(crate) fn handle_list_forwarded_payments_request(
 	};
 	Ok(response)
 }
+
+/// Returns the [`ListFilter`]s forwarded payments have to match, given the optional channels and
+/// nodes they were forwarded between and the optional bounds of the time they were forwarded at.
+pub(crate) fn forwarded_payment_filters<'a>(
+	prev_channel_id: Option<&'a str>, next_channel_id: Option<&'a str>,
+	prev_node_id: Option<&'a str>, next_node_id: Option<&'a str>, min_timestamp: Option<u64>,
+	max_timestamp: Option<u64>,
+) -> Vec<ListFilter<'a>> {
+	let mut filters: Vec<ListFilter<'a>> = [
+		(FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE, prev_channel_id),
+		(FORWARDED_PAYMENT_NEXT_CHANNEL_ID_ATTRIBUTE, next_channel_id),
+		(FORWARDED_PAYMENT_PREV_NODE_ID_ATTRIBUTE, prev_node_id),
+		(FORWARDED_PAYMENT_NEXT_NODE_ID_ATTRIBUTE, next_node_id),
+	]
+	.into_iter()
+	.filter_map(|(name, value)| value.map(|value| ListFilter::TextAttribute { name, value }))
+	.collect();
+	// Forwarded payments are persisted with the time they were forwarded at as creation time.
+	if let Some(range) = timestamp_range(min_timestamp, max_timestamp) {
+		filters.push(ListFilter::Time(range));
+	}
+	filters
+}
+
+pub(crate) fn read_forwarded_payment(
+	context: &Context, key: &str,
+) -> Result<ForwardedPayment, LdkServerError> {
+	let forwarded_payment_bytes = context
+		.paginated_kv_store
+		.read(
+			FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			key,
+		)
+		.map_err(|e| {
+			LdkServerError::new(
+				InternalServerError,
+				format!("Failed to read forwarded payment data: {}", e),
+			)
+		})?;
+	ForwardedPayment::decode(Bytes::from(forwarded_payment_bytes)).map_err(|e| {
+		LdkServerError::new(
+			InternalServerError,
+			format!("Failed to decode forwarded payment: {}", e),
+		)
+	})
+}
@@ -7,27 +7,38 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
+use std::io;
+use std::ops::RangeInclusive;
+
 use bytes::Bytes;
 use ldk_server_protos::api::{ListPaymentsRequest, ListPaymentsResponse};
-use ldk_server_protos::types::{PageToken, Payment};
+use ldk_server_protos::types::{
+	PageToken, Payment, PaymentDirection, PaymentKindType, PaymentStatus,
+};
 use prost::Message;
 
 use crate::api::error::LdkServerError;
-use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::api::payment_metadata::with_payment_metadata;
+use crate::io::persist::paginated_kv_store::ListFilter;
 use crate::io::persist::{
 	PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE, PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	PAYMENT_DIRECTION_ATTRIBUTE, PAYMENT_KIND_ATTRIBUTE, PAYMENT_LATEST_UPDATE_TIMESTAMP_ATTRIBUTE,
+	PAYMENT_STATUS_ATTRIBUTE,
 };
 use crate::service::Context;
 
 pub(crate) fn handle_list_payments_request(
 	context: Context, request: ListPaymentsRequest,
 ) -> Result<ListPaymentsResponse, LdkServerError> {
+	let filters = list_filters(&request)?;
 	let page_token = request.page_token.map(|p| (p.token, p.index));
 	let list_response = context
 		.paginated_kv_store
-		.list(
+		.list_filtered(
 			PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
 			PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&filters,
 			page_token,
 		)
 		.map_err(|e| {
@@ -36,23 +47,10 @@ pub(crate) fn handle_list_payments_request(
 
 	let mut payments: Vec<Payment> = Vec::with_capacity(list_response.keys.len());
 	for key in list_response.keys {
-		let payment_bytes = context
-			.paginated_kv_store
-			.read(
-				PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
-				PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
-				&key,
-			)
-			.map_err(|e| {
-				LdkServerError::new(
-					InternalServerError,
-					format!("Failed to read payment data: {}", e),
-				)
-			})?;
-		let payment = Payment::decode(Bytes::from(payment_bytes)).map_err(|e| {
-			LdkServerError::new(InternalServerError, format!("Failed to decode payment: {}", e))
-		})?;
-		payments.push(payment);
+		// The payment may have been removed since it was listed.
+		if let Some(payment) = read_payment(&context, &key)? {
+			payments.push(payment);
+		}
 	}
 	let response = ListPaymentsResponse {
 		payments,
@@ -62,3 +60,81 @@ pub(crate) fn handle_list_payments_request(
 	};
 	Ok(response)
 }
+
+/// Reads the payment persisted under the given `key`, along with its metadata, returning `None` if
+/// there is none.
+pub(crate) fn read_payment(
+	context: &Context, key: &str,
+) -> Result<Option<Payment>, LdkServerError> {
+	let payment_bytes = match context.paginated_kv_store.read(
+		PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+		PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+		key,
+	) {
+		Ok(payment_bytes) => payment_bytes,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+		Err(e) => {
+			return Err(LdkServerError::new(
+				InternalServerError,
+				format!("Failed to read payment data: {}", e),
+			))
+		},
+	};
+	let payment = Payment::decode(Bytes::from(payment_bytes)).map_err(|e| {
+		LdkServerError::new(InternalServerError, format!("Failed to decode payment: {}", e))
+	})?;
+	with_payment_metadata(context, payment).map(Some)
+}
+
+/// Returns the [`ListFilter`]s payments have to match to be returned for the given `request`.
+fn list_filters(request: &ListPaymentsRequest) -> Result<Vec<ListFilter<'static>>, LdkServerError> {
+	let mut filters = Vec::new();
+
+	if let Some(status) = request.status {
+		PaymentStatus::from_i32(status).ok_or_else(|| {
+			LdkServerError::new(InvalidRequestError, format!("Invalid payment status: {status}"))
+		})?;
+		filters.push(attribute_equals(PAYMENT_STATUS_ATTRIBUTE, status));
+	}
+	if let Some(direction) = request.direction {
+		PaymentDirection::from_i32(direction).ok_or_else(|| {
+			LdkServerError::new(
+				InvalidRequestError,
+				format!("Invalid payment direction: {direction}"),
+			)
+		})?;
+		filters.push(attribute_equals(PAYMENT_DIRECTION_ATTRIBUTE, direction));
+	}
+	if let Some(kind) = request.kind {
+		PaymentKindType::from_i32(kind).ok_or_else(|| {
+			LdkServerError::new(InvalidRequestError, format!("Invalid payment kind: {kind}"))
+		})?;
+		filters.push(attribute_equals(PAYMENT_KIND_ATTRIBUTE, kind));
+	}
+	if let Some(range) =
+		timestamp_range(request.min_creation_timestamp, request.max_creation_timestamp)
+	{
+		filters.push(ListFilter::Time(range));
+	}
+	if let Some(range) =
+		timestamp_range(request.min_latest_update_timestamp, request.max_latest_update_timestamp)
+	{
+		filters
+			.push(ListFilter::Attribute { name: PAYMENT_LATEST_UPDATE_TIMESTAMP_ATTRIBUTE, range });
+	}
+
+	Ok(filters)
+}
+
+fn attribute_equals(name: &'static str, value: i32) -> ListFilter<'static> {
+	ListFilter::Attribute { name, range: value as i64..=value as i64 }
+}
+
+/// Returns the range between the given inclusive bounds, or `None` if both are unset.
+pub(crate) fn timestamp_range(min: Option<u64>, max: Option<u64>) -> Option<RangeInclusive<i64>> {
+	if min.is_none() && max.is_none() {
+		return None;
+	}
+	let to_i64 = |timestamp: u64| i64::try_from(timestamp).unwrap_or(i64::MAX);
+	Some(min.map_or(i64::MIN, to_i64)..=max.map_or(i64::MAX, to_i64))
+}
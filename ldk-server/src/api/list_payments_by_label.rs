@@ -0,0 +1,58 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use ldk_server_protos::api::{ListPaymentsByLabelRequest, ListPaymentsByLabelResponse};
+use ldk_server_protos::types::{PageToken, Payment};
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::api::list_payments::read_payment;
+use crate::io::persist::paginated_kv_store::ListFilter;
+use crate::io::persist::{
+	PAYMENT_METADATA_LABEL_ATTRIBUTE, PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+	PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+};
+use crate::service::Context;
+
+pub(crate) fn handle_list_payments_by_label_request(
+	context: Context, request: ListPaymentsByLabelRequest,
+) -> Result<ListPaymentsByLabelResponse, LdkServerError> {
+	let page_token = request.page_token.map(|p| (p.token, p.index));
+	let filters = [ListFilter::TextAttribute {
+		name: PAYMENT_METADATA_LABEL_ATTRIBUTE,
+		value: &request.label,
+	}];
+	let list_response = context
+		.paginated_kv_store
+		.list_filtered(
+			PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			&filters,
+			page_token,
+		)
+		.map_err(|e| {
+			LdkServerError::new(InternalServerError, format!("Failed to list payments: {}", e))
+		})?;
+
+	let mut payments: Vec<Payment> = Vec::with_capacity(list_response.keys.len());
+	for key in list_response.keys {
+		// Payments are only persisted once LDK Server handles an event about them, which may not
+		// have happened yet for payments whose metadata was persisted already.
+		if let Some(payment) = read_payment(&context, &key)? {
+			payments.push(payment);
+		}
+	}
+	let response = ListPaymentsByLabelResponse {
+		payments,
+		next_page_token: list_response
+			.next_page_token
+			.map(|(token, index)| PageToken { token, index }),
+	};
+	Ok(response)
+}
@@ -0,0 +1,80 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use ldk_server_protos::api::{ListPaymentsUpdatedSinceRequest, ListPaymentsUpdatedSinceResponse};
+use ldk_server_protos::types::{PageToken, Payment};
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::api::list_payments::read_payment;
+use crate::io::persist::paginated_kv_store::ListFilter;
+use crate::io::persist::{
+	PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE, PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	PAYMENT_LATEST_UPDATE_TIMESTAMP_ATTRIBUTE, PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE,
+};
+use crate::service::Context;
+
+pub(crate) fn handle_list_payments_updated_since_request(
+	context: Context, request: ListPaymentsUpdatedSinceRequest,
+) -> Result<ListPaymentsUpdatedSinceResponse, LdkServerError> {
+	// Payments are listed by the sequence number of their latest update rather than its timestamp,
+	// as updates are persisted in the order of the former only, see `PaymentUpdateSequence`.
+	let updated_since = i64::try_from(request.updated_since).unwrap_or(i64::MAX);
+	let filters = [ListFilter::Attribute {
+		name: PAYMENT_LATEST_UPDATE_TIMESTAMP_ATTRIBUTE,
+		range: updated_since..=i64::MAX,
+	}];
+	let (sequence_numbers, page_token) = match request.page_token {
+		Some(page_token) => (0..=i64::MAX, Some((page_token.token, page_token.index))),
+		// Without a page token, the listing starts right at the first payment updated since
+		// `updated_since`, rather than skipping over all payments updated before.
+		None => match context
+			.paginated_kv_store
+			.min_attribute(
+				PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE,
+				&filters,
+			)
+			.map_err(|e| {
+				LdkServerError::new(InternalServerError, format!("Failed to list payments: {}", e))
+			})? {
+			Some(min_sequence_number) => (min_sequence_number..=i64::MAX, None),
+			None => return Ok(ListPaymentsUpdatedSinceResponse::default()),
+		},
+	};
+	let list_response = context
+		.paginated_kv_store
+		.list_by_attribute(
+			PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE,
+			sequence_numbers,
+			&filters,
+			page_token,
+		)
+		.map_err(|e| {
+			LdkServerError::new(InternalServerError, format!("Failed to list payments: {}", e))
+		})?;
+
+	let mut payments: Vec<Payment> = Vec::with_capacity(list_response.keys.len());
+	for key in list_response.keys {
+		// The payment may have been removed since it was listed.
+		if let Some(payment) = read_payment(&context, &key)? {
+			payments.push(payment);
+		}
+	}
+	let response = ListPaymentsUpdatedSinceResponse {
+		payments,
+		next_page_token: list_response
+			.next_page_token
+			.map(|(token, index)| PageToken { token, index }),
+	};
+	Ok(response)
+}
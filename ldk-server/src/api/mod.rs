@@ -7,6 +7,8 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use ldk_node::config::{ChannelConfig, MaxDustHTLCExposure};
 use ldk_node::lightning::routing::router::RouteParametersConfig;
 use ldk_server_protos::types::channel_config::MaxDustHtlcExposure;
@@ -14,6 +16,8 @@ use ldk_server_protos::types::channel_config::MaxDustHtlcExposure;
 use crate::api::error::LdkServerError;
 use crate::api::error::LdkServerErrorCode::InvalidRequestError;
 
+pub(crate) mod api_keys;
+pub(crate) mod approvals;
 pub(crate) mod bolt11_receive;
 pub(crate) mod bolt11_send;
 pub(crate) mod bolt12_receive;
@@ -24,18 +28,24 @@ pub(crate) mod disconnect_peer;
 pub(crate) mod error;
 pub(crate) mod export_pathfinding_scores;
 pub(crate) mod get_balances;
+pub(crate) mod get_forwarding_stats;
 pub(crate) mod get_node_info;
 pub(crate) mod get_payment_details;
 pub(crate) mod graph_get_channel;
 pub(crate) mod graph_get_node;
 pub(crate) mod graph_list_channels;
 pub(crate) mod graph_list_nodes;
+pub(crate) mod list_audit_log;
 pub(crate) mod list_channels;
+pub(crate) mod list_events;
 pub(crate) mod list_forwarded_payments;
 pub(crate) mod list_payments;
+pub(crate) mod list_payments_by_label;
+pub(crate) mod list_payments_updated_since;
 pub(crate) mod onchain_receive;
 pub(crate) mod onchain_send;
 pub(crate) mod open_channel;
+pub(crate) mod payment_metadata;
 pub(crate) mod sign_message;
 pub(crate) mod splice_channel;
 pub(crate) mod spontaneous_send;
@@ -117,3 +127,8 @@ pub(crate) fn build_route_parameters_config_from_proto(
 		None => Ok(None),
 	}
 }
+
+/// Returns the current time in seconds since the UNIX epoch.
+pub(crate) fn current_time() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
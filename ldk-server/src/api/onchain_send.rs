@@ -9,17 +9,78 @@
 
 use std::str::FromStr;
 
-use ldk_node::bitcoin::{Address, FeeRate};
+use ldk_node::bitcoin::hashes::Hash;
+use ldk_node::bitcoin::{Address, FeeRate, Txid};
+use ldk_node::lightning::ln::channelmanager::PaymentId;
 use ldk_server_protos::api::{OnchainSendRequest, OnchainSendResponse};
+use ldk_server_protos::endpoints::ONCHAIN_SEND_PATH;
 
+use crate::api::approvals::hold_for_approval_if_required;
 use crate::api::error::LdkServerError;
 use crate::api::error::LdkServerErrorCode::InvalidRequestError;
+use crate::api::payment_metadata::{validate_payment_metadata, write_sent_payment_metadata};
+use crate::auth::ApiKey;
 use crate::service::Context;
 
 pub(crate) fn handle_onchain_send_request(
 	context: Context, request: OnchainSendRequest,
 ) -> Result<OnchainSendResponse, LdkServerError> {
-	let address = Address::from_str(&request.address)
+	parse_address(&context, &request)?;
+	let amount_msat = request.amount_sats.map(|sats| sats.saturating_mul(1000));
+	validate_payment_metadata(request.metadata.as_ref())?;
+
+	if let Some(pending_approval_id) = hold_for_approval_if_required(
+		&context,
+		ONCHAIN_SEND_PATH,
+		&request,
+		amount_msat,
+		request.address.clone(),
+	)? {
+		let response = OnchainSendResponse {
+			txid: String::new(),
+			pending_approval_id: Some(pending_approval_id),
+		};
+		return Ok(response);
+	}
+
+	let txid = send_onchain_payment(&context, &context.api_key, request)?;
+
+	let response = OnchainSendResponse { txid: txid.to_string(), pending_approval_id: None };
+	Ok(response)
+}
+
+/// Sends the payment described by `request`, counting it towards the spending limits of `api_key`.
+pub(crate) fn send_onchain_payment(
+	context: &Context, api_key: &ApiKey, request: OnchainSendRequest,
+) -> Result<Txid, LdkServerError> {
+	let address = parse_address(context, &request)?;
+
+	let fee_rate = request.fee_rate_sat_per_vb.and_then(FeeRate::from_sat_per_vb);
+	let amount_msat = request.amount_sats.map(|sats| sats.saturating_mul(1000));
+	let txid = match (request.amount_sats, request.send_all) {
+		(Some(amount_sats), None) => context.spending_tracker.spend(api_key, amount_msat, || {
+			Ok(context.node.onchain_payment().send_to_address(&address, amount_sats, fee_rate)?)
+		}),
+		// Retain existing api behaviour to not retain reserves on `send_all_to_address`.
+		(None, Some(true)) => context.spending_tracker.spend(api_key, None, || {
+			Ok(context.node.onchain_payment().send_all_to_address(&address, false, fee_rate)?)
+		}),
+		_ => Err(LdkServerError::new(
+			InvalidRequestError,
+			"Must specify either `send_all` or `amount_sats`, but not both or neither",
+		)),
+	}?;
+
+	// On-chain payments are identified by their txid, which is only known once they are sent.
+	let payment_id = PaymentId(txid.to_byte_array());
+	write_sent_payment_metadata(context, &payment_id, request.metadata)?;
+	Ok(txid)
+}
+
+fn parse_address(
+	context: &Context, request: &OnchainSendRequest,
+) -> Result<Address, LdkServerError> {
+	Address::from_str(&request.address)
 		.map_err(|_| ldk_node::NodeError::InvalidAddress)?
 		.require_network(context.node.config().network)
 		.map_err(|_| {
@@ -27,24 +88,5 @@ pub(crate) fn handle_onchain_send_request(
 				InvalidRequestError,
 				"Address is not valid for the configured network.".to_string(),
 			)
-		})?;
-
-	let fee_rate = request.fee_rate_sat_per_vb.and_then(FeeRate::from_sat_per_vb);
-	let txid = match (request.amount_sats, request.send_all) {
-		(Some(amount_sats), None) => {
-			context.node.onchain_payment().send_to_address(&address, amount_sats, fee_rate)?
-		},
-		// Retain existing api behaviour to not retain reserves on `send_all_to_address`.
-		(None, Some(true)) => {
-			context.node.onchain_payment().send_all_to_address(&address, false, fee_rate)?
-		},
-		_ => {
-			return Err(LdkServerError::new(
-				InvalidRequestError,
-				"Must specify either `send_all` or `amount_sats`, but not both or neither",
-			))
-		},
-	};
-	let response = OnchainSendResponse { txid: txid.to_string() };
-	Ok(response)
+		})
 }
@@ -0,0 +1,185 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use ldk_node::lightning::ln::channelmanager::PaymentId;
+use ldk_server_protos::types::{Payment, PaymentMetadata};
+use log::error;
+use prost::Message;
+
+use crate::api::current_time;
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::io::persist::paginated_kv_store::WriteOp;
+use crate::io::persist::{
+	payment_metadata_attributes, read_payment_metadata,
+	PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+	PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+};
+use crate::service::Context;
+
+/// The maximum number of labels payment metadata may carry.
+const MAX_LABELS: usize = 16;
+
+/// The maximum length, in bytes, of each of the strings payment metadata carries.
+const MAX_FIELD_LENGTH: usize = 256;
+
+/// Checks that the given `metadata` stays within the limits on the number of labels and the length
+/// of its fields.
+pub(crate) fn validate_payment_metadata(
+	metadata: Option<&PaymentMetadata>,
+) -> Result<(), LdkServerError> {
+	let metadata = match metadata {
+		Some(metadata) => metadata,
+		None => return Ok(()),
+	};
+	if metadata.labels.len() > MAX_LABELS {
+		return Err(LdkServerError::new(
+			InvalidRequestError,
+			format!("Invalid metadata, must not carry more than {MAX_LABELS} labels"),
+		));
+	}
+	let fields =
+		metadata.order_id.iter().chain(&metadata.customer_reference).chain(&metadata.labels);
+	for field in fields {
+		if field.is_empty() || field.len() > MAX_FIELD_LENGTH {
+			return Err(LdkServerError::new(
+				InvalidRequestError,
+				format!(
+					"Invalid metadata, fields must be between 1 and {MAX_FIELD_LENGTH} bytes long"
+				),
+			));
+		}
+	}
+	Ok(())
+}
+
+/// Persists the given `metadata` under the given `key`, replacing any metadata persisted before.
+pub(crate) fn write_payment_metadata(
+	context: &Context, primary_namespace: &str, secondary_namespace: &str, key: &str,
+	metadata: &PaymentMetadata,
+) -> Result<(), LdkServerError> {
+	context
+		.paginated_kv_store
+		.write_batch(&[WriteOp {
+			primary_namespace,
+			secondary_namespace,
+			key,
+			time: current_time() as i64,
+			buf: &metadata.encode_to_vec(),
+			attributes: &payment_metadata_attributes(metadata),
+		}])
+		.map_err(|e| {
+			LdkServerError::new(
+				InternalServerError,
+				format!("Failed to write payment metadata: {}", e),
+			)
+		})
+}
+
+/// Sends a payment via `send`, persisting the given `metadata` for it under the given `payment_id`
+/// beforehand, so that a payment is never sent without its metadata.
+///
+/// If sending the payment fails, the metadata persisted for it before is restored, as the payment
+/// may have been sent before already.
+pub(crate) fn send_with_payment_metadata<T>(
+	context: &Context, payment_id: &PaymentId, metadata: Option<PaymentMetadata>,
+	send: impl FnOnce() -> Result<T, LdkServerError>,
+) -> Result<T, LdkServerError> {
+	let metadata = match metadata {
+		Some(metadata) => metadata,
+		None => return send(),
+	};
+	let key = payment_id.to_string();
+	let previous_metadata = read_payment_metadata(
+		context.paginated_kv_store.as_ref(),
+		PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+		PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+		&key,
+	)
+	.map_err(|e| {
+		LdkServerError::new(InternalServerError, format!("Failed to read payment metadata: {}", e))
+	})?;
+	write_payment_metadata(
+		context,
+		PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+		PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+		&key,
+		&metadata,
+	)?;
+
+	send().inspect_err(|_| {
+		let rolled_back = match &previous_metadata {
+			Some(previous_metadata) => write_payment_metadata(
+				context,
+				PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+				PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+				&key,
+				previous_metadata,
+			),
+			None => context
+				.paginated_kv_store
+				.remove(
+					PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+					PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+					&key,
+				)
+				.map_err(|e| {
+					LdkServerError::new(
+						InternalServerError,
+						format!("Failed to remove payment metadata: {}", e),
+					)
+				}),
+		};
+		if let Err(e) = rolled_back {
+			error!("Failed to roll back metadata of payment {payment_id}: {}", e.message);
+		}
+	})
+}
+
+/// Persists the given `metadata` for the payment with the given `payment_id`, which was just sent.
+///
+/// Only used for payments whose id isn't known before they are sent, see
+/// [`send_with_payment_metadata`] otherwise. As the payment was sent already, the error returned
+/// if the metadata fails to be persisted says so, so that the payment isn't retried.
+pub(crate) fn write_sent_payment_metadata(
+	context: &Context, payment_id: &PaymentId, metadata: Option<PaymentMetadata>,
+) -> Result<(), LdkServerError> {
+	match metadata {
+		Some(metadata) => write_payment_metadata(
+			context,
+			PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			&payment_id.to_string(),
+			&metadata,
+		)
+		.map_err(|e| {
+			LdkServerError::new(
+				e.error_code,
+				format!("Payment {payment_id} was sent, but its metadata wasn't: {}", e.message),
+			)
+		}),
+		None => Ok(()),
+	}
+}
+
+/// Sets the metadata of the given `payment` to the metadata persisted for it, if any.
+pub(crate) fn with_payment_metadata(
+	context: &Context, mut payment: Payment,
+) -> Result<Payment, LdkServerError> {
+	payment.metadata = read_payment_metadata(
+		context.paginated_kv_store.as_ref(),
+		PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+		PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+		&payment.id,
+	)
+	.map_err(|e| {
+		LdkServerError::new(InternalServerError, format!("Failed to read payment metadata: {}", e))
+	})?;
+	Ok(payment)
+}
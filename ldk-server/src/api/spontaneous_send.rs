@@ -9,26 +9,76 @@
 
 use std::str::FromStr;
 
+use ldk_node::bitcoin::hashes::{sha256, Hash};
 use ldk_node::bitcoin::secp256k1::PublicKey;
+use ldk_node::lightning::ln::channelmanager::PaymentId;
+use ldk_node::lightning::types::payment::PaymentPreimage;
 use ldk_server_protos::api::{SpontaneousSendRequest, SpontaneousSendResponse};
+use ldk_server_protos::endpoints::SPONTANEOUS_SEND_PATH;
 
+use crate::api::approvals::hold_for_approval_if_required;
 use crate::api::build_route_parameters_config_from_proto;
 use crate::api::error::LdkServerError;
-use crate::api::error::LdkServerErrorCode::InvalidRequestError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::api::payment_metadata::{send_with_payment_metadata, validate_payment_metadata};
+use crate::auth::ApiKey;
 use crate::service::Context;
 
 pub(crate) fn handle_spontaneous_send_request(
 	context: Context, request: SpontaneousSendRequest,
 ) -> Result<SpontaneousSendResponse, LdkServerError> {
-	let node_id = PublicKey::from_str(&request.node_id).map_err(|_| {
-		LdkServerError::new(InvalidRequestError, "Invalid node_id provided.".to_string())
-	})?;
+	parse_node_id(&request)?;
+	validate_payment_metadata(request.metadata.as_ref())?;
 
-	let route_parameters = build_route_parameters_config_from_proto(request.route_parameters)?;
+	if let Some(pending_approval_id) = hold_for_approval_if_required(
+		&context,
+		SPONTANEOUS_SEND_PATH,
+		&request,
+		Some(request.amount_msat),
+		request.node_id.clone(),
+	)? {
+		let response = SpontaneousSendResponse {
+			payment_id: String::new(),
+			pending_approval_id: Some(pending_approval_id),
+		};
+		return Ok(response);
+	}
 
-	let payment_id =
-		context.node.spontaneous_payment().send(request.amount_msat, node_id, route_parameters)?;
+	let payment_id = send_spontaneous_payment(&context, &context.api_key, request)?;
 
-	let response = SpontaneousSendResponse { payment_id: payment_id.to_string() };
+	let response =
+		SpontaneousSendResponse { payment_id: payment_id.to_string(), pending_approval_id: None };
 	Ok(response)
 }
+
+/// Sends the payment described by `request`, counting it towards the spending limits of `api_key`.
+pub(crate) fn send_spontaneous_payment(
+	context: &Context, api_key: &ApiKey, request: SpontaneousSendRequest,
+) -> Result<PaymentId, LdkServerError> {
+	let node_id = parse_node_id(&request)?;
+	let route_parameters = build_route_parameters_config_from_proto(request.route_parameters)?;
+
+	// Spontaneous payments are identified by their payment hash, so the preimage is generated here
+	// rather than by LDK Node, allowing to persist their metadata before sending them.
+	let mut preimage = [0u8; 32];
+	getrandom::getrandom(&mut preimage).map_err(|e| {
+		LdkServerError::new(InternalServerError, format!("Failed to generate preimage: {}", e))
+	})?;
+	let payment_id = PaymentId(sha256::Hash::hash(&preimage).to_byte_array());
+	send_with_payment_metadata(context, &payment_id, request.metadata, || {
+		context.spending_tracker.spend(api_key, Some(request.amount_msat), || {
+			Ok(context.node.spontaneous_payment().send_with_preimage(
+				request.amount_msat,
+				node_id,
+				PaymentPreimage(preimage),
+				route_parameters,
+			)?)
+		})
+	})
+}
+
+fn parse_node_id(request: &SpontaneousSendRequest) -> Result<PublicKey, LdkServerError> {
+	PublicKey::from_str(&request.node_id).map_err(|_| {
+		LdkServerError::new(InvalidRequestError, "Invalid node_id provided.".to_string())
+	})
+}
@@ -0,0 +1,522 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use bytes::Bytes;
+use hex::DisplayHex;
+use ldk_server_protos::types::{ApiKeyInfo, ApiKeyPermission};
+use log::warn;
+use prost::Message;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{InternalServerError, InvalidRequestError};
+use crate::auth::spending_limits::SpendingLimits;
+use crate::auth::{
+	generate_api_key, is_valid_api_key_name, write_api_key_file, ApiKey, Permission,
+};
+use crate::io::persist::paginated_kv_store::PaginatedKVStore;
+use crate::io::persist::{
+	API_KEYS_PERSISTENCE_PRIMARY_NAMESPACE, API_KEYS_PERSISTENCE_SECONDARY_NAMESPACE,
+};
+
+/// The number of seconds the previous secret of a rotated API key remains valid for, unless
+/// specified otherwise.
+pub(crate) const DEFAULT_ROTATION_GRACE_PERIOD_SECS: u64 = 3600;
+
+/// The persisted representation of an API key created at runtime.
+#[derive(Clone, PartialEq, Message)]
+struct ApiKeyRecord {
+	#[prost(string, tag = "1")]
+	name: String,
+	/// The hex-encoded secret of the key.
+	#[prost(string, tag = "2")]
+	key: String,
+	#[prost(enumeration = "ApiKeyPermission", repeated, tag = "3")]
+	permissions: Vec<i32>,
+	#[prost(uint64, optional, tag = "4")]
+	max_send_amount_msat: Option<u64>,
+	#[prost(uint64, tag = "5")]
+	created_at: u64,
+	/// The hex-encoded secret the key had before it was last rotated.
+	#[prost(string, optional, tag = "6")]
+	previous_key: Option<String>,
+	#[prost(uint64, optional, tag = "7")]
+	previous_key_expires_at: Option<u64>,
+	#[prost(uint64, optional, tag = "8")]
+	revoked_at: Option<u64>,
+	#[prost(string, optional, tag = "9")]
+	client_identity: Option<String>,
+	#[prost(uint64, optional, tag = "10")]
+	daily_budget_msat: Option<u64>,
+}
+
+struct ManagedApiKey {
+	record: ApiKeyRecord,
+	/// The file the secret is stored in, for keys defined by the server's configuration. Such keys
+	/// are not persisted to the [`PaginatedKVStore`] and can't be revoked.
+	key_file: Option<PathBuf>,
+}
+
+impl ManagedApiKey {
+	fn permissions(&self) -> Vec<Permission> {
+		self.record.permissions().map(Permission::from).collect()
+	}
+
+	fn to_api_key(&self, key: &str) -> ApiKey {
+		let spending_limits = SpendingLimits {
+			max_payment_amount_msat: self.record.max_send_amount_msat,
+			daily_budget_msat: self.record.daily_budget_msat,
+		};
+		ApiKey::new(
+			self.record.name.clone(),
+			key.to_string(),
+			self.permissions(),
+			spending_limits,
+			self.record.client_identity.clone(),
+		)
+	}
+}
+
+/// Keeps track of all API keys accepted by the server, allowing keys to be created, revoked and
+/// rotated at runtime.
+pub(crate) struct ApiKeyManager {
+	api_keys: RwLock<BTreeMap<String, ManagedApiKey>>,
+	paginated_kv_store: Arc<dyn PaginatedKVStore>,
+}
+
+impl ApiKeyManager {
+	/// Creates a new manager accepting the given configured keys, along with all keys previously
+	/// created at runtime and persisted in `paginated_kv_store`.
+	///
+	/// `configured_keys` holds each key defined by the server's configuration along with the file
+	/// its secret is stored in.
+	pub(crate) fn new(
+		configured_keys: Vec<(ApiKey, PathBuf)>, paginated_kv_store: Arc<dyn PaginatedKVStore>,
+	) -> io::Result<Self> {
+		let mut api_keys = BTreeMap::new();
+
+		let mut page_token = None;
+		loop {
+			let list_response = paginated_kv_store.list(
+				API_KEYS_PERSISTENCE_PRIMARY_NAMESPACE,
+				API_KEYS_PERSISTENCE_SECONDARY_NAMESPACE,
+				page_token,
+			)?;
+			if list_response.keys.is_empty() {
+				break;
+			}
+			for key in list_response.keys {
+				let record_bytes = paginated_kv_store.read(
+					API_KEYS_PERSISTENCE_PRIMARY_NAMESPACE,
+					API_KEYS_PERSISTENCE_SECONDARY_NAMESPACE,
+					&key,
+				)?;
+				let record = ApiKeyRecord::decode(Bytes::from(record_bytes)).map_err(|e| {
+					io::Error::new(
+						io::ErrorKind::InvalidData,
+						format!("Failed to decode API key '{key}': {e}"),
+					)
+				})?;
+				api_keys.insert(record.name.clone(), ManagedApiKey { record, key_file: None });
+			}
+			page_token = list_response.next_page_token;
+		}
+
+		for (api_key, key_file) in configured_keys {
+			let record = ApiKeyRecord {
+				name: api_key.name.clone(),
+				key: api_key.key,
+				permissions: api_key
+					.permissions
+					.into_iter()
+					.map(|p| ApiKeyPermission::from(p) as i32)
+					.collect(),
+				max_send_amount_msat: api_key.spending_limits.max_payment_amount_msat,
+				created_at: 0,
+				previous_key: None,
+				previous_key_expires_at: None,
+				revoked_at: None,
+				client_identity: api_key.client_identity,
+				daily_budget_msat: api_key.spending_limits.daily_budget_msat,
+			};
+			let managed_key = ManagedApiKey { record, key_file: Some(key_file) };
+			if api_keys.insert(api_key.name.clone(), managed_key).is_some() {
+				warn!(
+					"API key '{}' is defined by the configuration, ignoring the key of the same name created at runtime",
+					api_key.name
+				);
+			}
+		}
+
+		Ok(Self { api_keys: RwLock::new(api_keys), paginated_kv_store })
+	}
+
+	/// Returns all keys requests may currently be authenticated with.
+	///
+	/// This includes the previous secret of recently rotated keys, as long as their grace period
+	/// has not expired at time `now`.
+	pub(crate) fn accepted_keys(&self, now: u64) -> Vec<ApiKey> {
+		let api_keys = self.api_keys.read().unwrap();
+		let mut accepted_keys = Vec::with_capacity(api_keys.len());
+		for managed_key in api_keys.values().filter(|k| k.record.revoked_at.is_none()) {
+			accepted_keys.push(managed_key.to_api_key(&managed_key.record.key));
+			if let (Some(previous_key), Some(expires_at)) =
+				(&managed_key.record.previous_key, managed_key.record.previous_key_expires_at)
+			{
+				if now < expires_at {
+					accepted_keys.push(managed_key.to_api_key(previous_key));
+				}
+			}
+		}
+		accepted_keys
+	}
+
+	/// Returns the key with the given name, unless it doesn't exist or has been revoked.
+	pub(crate) fn get(&self, name: &str) -> Option<ApiKey> {
+		let api_keys = self.api_keys.read().unwrap();
+		api_keys
+			.get(name)
+			.filter(|k| k.record.revoked_at.is_none())
+			.map(|managed_key| managed_key.to_api_key(&managed_key.record.key))
+	}
+
+	/// Returns the details of all known keys, including revoked ones.
+	pub(crate) fn list(&self, now: u64) -> Vec<ApiKeyInfo> {
+		let api_keys = self.api_keys.read().unwrap();
+		api_keys
+			.values()
+			.map(|managed_key| {
+				let record = &managed_key.record;
+				ApiKeyInfo {
+					name: record.name.clone(),
+					permissions: record.permissions.clone(),
+					max_send_amount_msat: record.max_send_amount_msat,
+					configured: managed_key.key_file.is_some(),
+					created_at: managed_key.key_file.is_none().then_some(record.created_at),
+					previous_key_expires_at: record
+						.previous_key_expires_at
+						.filter(|expires_at| now < *expires_at),
+					revoked_at: record.revoked_at,
+					client_identity: record.client_identity.clone(),
+					daily_budget_msat: record.daily_budget_msat,
+				}
+			})
+			.collect()
+	}
+
+	/// Creates and persists a new key, returning its hex-encoded secret.
+	pub(crate) fn create(
+		&self, name: String, permissions: Vec<Permission>, spending_limits: SpendingLimits,
+		client_identity: Option<String>, now: u64,
+	) -> Result<String, LdkServerError> {
+		if !is_valid_api_key_name(&name) {
+			return Err(LdkServerError::new(
+				InvalidRequestError,
+				format!(
+					"Invalid API key name '{name}', must only contain alphanumeric characters, '-' or '_'"
+				),
+			));
+		}
+		if permissions.is_empty() {
+			return Err(LdkServerError::new(
+				InvalidRequestError,
+				"API key must be granted at least one permission",
+			));
+		}
+
+		let mut api_keys = self.api_keys.write().unwrap();
+		if api_keys.contains_key(&name) {
+			return Err(LdkServerError::new(
+				InvalidRequestError,
+				format!("API key '{name}' already exists"),
+			));
+		}
+
+		let key = generate_api_key()
+			.map_err(|e| {
+				LdkServerError::new(InternalServerError, format!("Failed to generate API key: {e}"))
+			})?
+			.to_lower_hex_string();
+		let record = ApiKeyRecord {
+			name: name.clone(),
+			key: key.clone(),
+			permissions: permissions
+				.into_iter()
+				.map(|p| ApiKeyPermission::from(p) as i32)
+				.collect(),
+			max_send_amount_msat: spending_limits.max_payment_amount_msat,
+			created_at: now,
+			previous_key: None,
+			previous_key_expires_at: None,
+			revoked_at: None,
+			client_identity,
+			daily_budget_msat: spending_limits.daily_budget_msat,
+		};
+		self.persist(&record)?;
+
+		api_keys.insert(name, ManagedApiKey { record, key_file: None });
+		Ok(key)
+	}
+
+	/// Revokes the key with the given name, such that requests authenticated with it are rejected.
+	pub(crate) fn revoke(&self, name: &str, now: u64) -> Result<(), LdkServerError> {
+		let mut api_keys = self.api_keys.write().unwrap();
+		let managed_key = api_keys.get_mut(name).ok_or_else(|| {
+			LdkServerError::new(InvalidRequestError, format!("API key '{name}' not found"))
+		})?;
+		if managed_key.key_file.is_some() {
+			return Err(LdkServerError::new(
+				InvalidRequestError,
+				format!(
+					"API key '{name}' is defined by the configuration and can't be revoked at runtime"
+				),
+			));
+		}
+		if managed_key.record.revoked_at.is_some() {
+			return Ok(());
+		}
+
+		let mut record = managed_key.record.clone();
+		record.revoked_at = Some(now);
+		record.previous_key = None;
+		record.previous_key_expires_at = None;
+		self.persist(&record)?;
+
+		managed_key.record = record;
+		Ok(())
+	}
+
+	/// Replaces the secret of the key with the given name, returning the new hex-encoded secret.
+	///
+	/// The previous secret remains valid until `grace_period_secs` after `now`.
+	pub(crate) fn rotate(
+		&self, name: &str, grace_period_secs: u64, now: u64,
+	) -> Result<String, LdkServerError> {
+		let mut api_keys = self.api_keys.write().unwrap();
+		let managed_key = api_keys.get_mut(name).ok_or_else(|| {
+			LdkServerError::new(InvalidRequestError, format!("API key '{name}' not found"))
+		})?;
+		if managed_key.record.revoked_at.is_some() {
+			return Err(LdkServerError::new(
+				InvalidRequestError,
+				format!("API key '{name}' has been revoked"),
+			));
+		}
+
+		let key_bytes = generate_api_key().map_err(|e| {
+			LdkServerError::new(InternalServerError, format!("Failed to generate API key: {e}"))
+		})?;
+
+		let mut record = managed_key.record.clone();
+		let previous_key = std::mem::replace(&mut record.key, key_bytes.to_lower_hex_string());
+		if grace_period_secs > 0 {
+			record.previous_key = Some(previous_key);
+			record.previous_key_expires_at = Some(now.saturating_add(grace_period_secs));
+		} else {
+			record.previous_key = None;
+			record.previous_key_expires_at = None;
+		}
+
+		// The grace period of configured keys is only kept in memory, as their secret is read
+		// from the key file on startup.
+		match &managed_key.key_file {
+			Some(key_file) => write_api_key_file(key_file, &key_bytes).map_err(|e| {
+				LdkServerError::new(
+					InternalServerError,
+					format!("Failed to write API key file: {e}"),
+				)
+			})?,
+			None => self.persist(&record)?,
+		}
+
+		let key = record.key.clone();
+		managed_key.record = record;
+		Ok(key)
+	}
+
+	fn persist(&self, record: &ApiKeyRecord) -> Result<(), LdkServerError> {
+		self.paginated_kv_store
+			.write(
+				API_KEYS_PERSISTENCE_PRIMARY_NAMESPACE,
+				API_KEYS_PERSISTENCE_SECONDARY_NAMESPACE,
+				&record.name,
+				record.created_at as i64,
+				&record.encode_to_vec(),
+			)
+			.map_err(|e| {
+				LdkServerError::new(InternalServerError, format!("Failed to persist API key: {e}"))
+			})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::io::persist::sqlite_store::tests::{create_store, random_storage_path};
+
+	const NOW: u64 = 1_700_000_000;
+
+	fn accepted_key_names(manager: &ApiKeyManager, now: u64) -> Vec<String> {
+		manager.accepted_keys(now).into_iter().map(|k| k.name).collect()
+	}
+
+	#[test]
+	fn test_create_and_revoke() {
+		let storage_path = random_storage_path();
+		let manager = ApiKeyManager::new(vec![], create_store(storage_path.clone())).unwrap();
+
+		let key = manager
+			.create(
+				"pos".to_string(),
+				vec![Permission::Invoice],
+				SpendingLimits::default(),
+				None,
+				NOW,
+			)
+			.unwrap();
+		let accepted_keys = manager.accepted_keys(NOW);
+		assert_eq!(accepted_keys.len(), 1);
+		assert_eq!(accepted_keys[0].key, key);
+		assert!(accepted_keys[0].has_permission(Permission::Invoice));
+
+		// Names must be unique and valid.
+		let err = manager.create(
+			"pos".to_string(),
+			vec![Permission::Read],
+			SpendingLimits::default(),
+			None,
+			NOW,
+		);
+		assert_eq!(err.unwrap_err().error_code, InvalidRequestError);
+		let err = manager.create(
+			"p/os".to_string(),
+			vec![Permission::Read],
+			SpendingLimits::default(),
+			None,
+			NOW,
+		);
+		assert_eq!(err.unwrap_err().error_code, InvalidRequestError);
+		let err = manager.create("empty".to_string(), vec![], SpendingLimits::default(), None, NOW);
+		assert_eq!(err.unwrap_err().error_code, InvalidRequestError);
+
+		manager.revoke("pos", NOW + 1).unwrap();
+		assert!(manager.accepted_keys(NOW + 1).is_empty());
+		assert_eq!(manager.list(NOW + 1)[0].revoked_at, Some(NOW + 1));
+		let err = manager.rotate("pos", 0, NOW + 1);
+		assert_eq!(err.unwrap_err().error_code, InvalidRequestError);
+
+		// Keys and their revocation survive a restart.
+		let manager = ApiKeyManager::new(vec![], create_store(storage_path)).unwrap();
+		let api_keys = manager.list(NOW + 1);
+		assert_eq!(api_keys.len(), 1);
+		assert_eq!(api_keys[0].name, "pos");
+		assert_eq!(api_keys[0].created_at, Some(NOW));
+		assert_eq!(api_keys[0].revoked_at, Some(NOW + 1));
+		assert!(manager.accepted_keys(NOW + 1).is_empty());
+	}
+
+	#[test]
+	fn test_create_with_spending_limits() {
+		let storage_path = random_storage_path();
+		let manager = ApiKeyManager::new(vec![], create_store(storage_path.clone())).unwrap();
+
+		let spending_limits = SpendingLimits {
+			max_payment_amount_msat: Some(10_000),
+			daily_budget_msat: Some(50_000),
+		};
+		manager
+			.create("payouts".to_string(), vec![Permission::Send], spending_limits, None, NOW)
+			.unwrap();
+
+		// Limits are persisted along with the key.
+		let manager = ApiKeyManager::new(vec![], create_store(storage_path)).unwrap();
+		assert_eq!(manager.accepted_keys(NOW)[0].spending_limits, spending_limits);
+		let api_keys = manager.list(NOW);
+		assert_eq!(api_keys[0].max_send_amount_msat, Some(10_000));
+		assert_eq!(api_keys[0].daily_budget_msat, Some(50_000));
+	}
+
+	#[test]
+	fn test_rotate_with_grace_period() {
+		let storage_path = random_storage_path();
+		let manager = ApiKeyManager::new(vec![], create_store(storage_path.clone())).unwrap();
+
+		let old_key = manager
+			.create(
+				"payouts".to_string(),
+				vec![Permission::Send],
+				SpendingLimits::default(),
+				None,
+				NOW,
+			)
+			.unwrap();
+		let new_key = manager.rotate("payouts", 60, NOW).unwrap();
+		assert_ne!(old_key, new_key);
+
+		let accepted_keys: Vec<String> =
+			manager.accepted_keys(NOW + 59).into_iter().map(|k| k.key).collect();
+		assert_eq!(accepted_keys, vec![new_key.clone(), old_key]);
+
+		// The grace period is persisted along with the key.
+		let manager = ApiKeyManager::new(vec![], create_store(storage_path)).unwrap();
+		assert_eq!(accepted_key_names(&manager, NOW + 59), vec!["payouts", "payouts"]);
+
+		let accepted_keys = manager.accepted_keys(NOW + 60);
+		assert_eq!(accepted_keys.len(), 1);
+		assert_eq!(accepted_keys[0].key, new_key);
+
+		// Without a grace period, the previous secret is rejected immediately.
+		let newest_key = manager.rotate("payouts", 0, NOW + 60).unwrap();
+		let accepted_keys = manager.accepted_keys(NOW + 60);
+		assert_eq!(accepted_keys.len(), 1);
+		assert_eq!(accepted_keys[0].key, newest_key);
+	}
+
+	#[test]
+	fn test_configured_keys() {
+		let storage_path = random_storage_path();
+		let key_file = storage_path.join("api_keys").join("pos");
+		let configured_keys = vec![(
+			ApiKey::new(
+				"pos".to_string(),
+				"aa".repeat(32),
+				vec![Permission::Invoice],
+				SpendingLimits::default(),
+				None,
+			),
+			key_file.clone(),
+		)];
+		let manager =
+			ApiKeyManager::new(configured_keys, create_store(storage_path.clone())).unwrap();
+
+		let err = manager.create(
+			"pos".to_string(),
+			vec![Permission::Read],
+			SpendingLimits::default(),
+			None,
+			NOW,
+		);
+		assert_eq!(err.unwrap_err().error_code, InvalidRequestError);
+		let err = manager.revoke("pos", NOW);
+		assert_eq!(err.unwrap_err().error_code, InvalidRequestError);
+
+		let api_keys = manager.list(NOW);
+		assert!(api_keys[0].configured);
+		assert_eq!(api_keys[0].created_at, None);
+
+		// Rotating a configured key replaces its key file.
+		let new_key = manager.rotate("pos", 60, NOW).unwrap();
+		assert_eq!(std::fs::read(&key_file).unwrap().to_lower_hex_string(), new_key);
+		assert_eq!(accepted_key_names(&manager, NOW), vec!["pos", "pos"]);
+	}
+}
@@ -0,0 +1,431 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use hex::DisplayHex;
+use ldk_server_protos::types::PendingApproval;
+use log::error;
+use prost::Message;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{AuthError, InternalServerError, InvalidRequestError};
+use crate::auth::ApiKey;
+use crate::io::persist::paginated_kv_store::{
+	Attribute, AttributeValue, ListFilter, PaginatedKVStore, WriteOp,
+};
+use crate::io::persist::{
+	APPROVALS_PERSISTENCE_PRIMARY_NAMESPACE, APPROVALS_PERSISTENCE_SECONDARY_NAMESPACE,
+	APPROVAL_STATUS_ATTRIBUTE,
+};
+
+/// The [`APPROVAL_STATUS_ATTRIBUTE`] values of pending, approved and rejected payments.
+const PENDING_STATUS: &str = "pending";
+const APPROVED_STATUS: &str = "approved";
+const REJECTED_STATUS: &str = "rejected";
+
+/// The persisted representation of a payment submitted for approval.
+#[derive(Clone, PartialEq, Message)]
+struct ApprovalRecord {
+	#[prost(string, tag = "1")]
+	id: String,
+	#[prost(string, tag = "2")]
+	endpoint: String,
+	/// The serialized request the payment was submitted with.
+	#[prost(bytes = "vec", tag = "3")]
+	request: Vec<u8>,
+	#[prost(uint64, optional, tag = "4")]
+	amount_msat: Option<u64>,
+	#[prost(string, tag = "5")]
+	destination: String,
+	#[prost(string, tag = "6")]
+	submitted_by: String,
+	#[prost(string, optional, tag = "7")]
+	submitter_identity: Option<String>,
+	#[prost(uint64, tag = "8")]
+	created_at: u64,
+	#[prost(string, optional, tag = "9")]
+	reviewed_by: Option<String>,
+	#[prost(uint64, optional, tag = "10")]
+	reviewed_at: Option<u64>,
+	#[prost(bool, tag = "11")]
+	approved: bool,
+}
+
+/// A payment held for approval, as passed to the callback sending it once approved.
+pub(crate) struct PendingPayment<'a> {
+	/// The endpoint the payment was submitted via.
+	pub(crate) endpoint: &'a str,
+	/// The serialized request the payment was submitted with.
+	pub(crate) request: &'a [u8],
+	/// The name of the API key the payment was submitted with.
+	pub(crate) submitted_by: &'a str,
+}
+
+/// Holds outgoing payments exceeding a configured threshold until they are approved by a second
+/// party, i.e., using a different API key than the one they were submitted with.
+///
+/// Payments are persisted to the [`PaginatedKVStore`] and kept across restarts until reviewed.
+/// Reviewed payments remain persisted, but are indexed by their status, so that only pending ones
+/// are loaded.
+pub(crate) struct ApprovalQueue {
+	threshold_msat: Option<u64>,
+	pending: Mutex<BTreeMap<String, ApprovalRecord>>,
+	paginated_kv_store: Arc<dyn PaginatedKVStore>,
+}
+
+impl ApprovalQueue {
+	/// Creates a new queue holding payments above `threshold_msat`, loading all payments still
+	/// pending approval from `paginated_kv_store`.
+	///
+	/// If `threshold_msat` is `None`, no payments require approval.
+	pub(crate) fn new(
+		threshold_msat: Option<u64>, paginated_kv_store: Arc<dyn PaginatedKVStore>,
+	) -> io::Result<Self> {
+		let mut pending = BTreeMap::new();
+
+		let filters =
+			[ListFilter::TextAttribute { name: APPROVAL_STATUS_ATTRIBUTE, value: PENDING_STATUS }];
+		let mut page_token = None;
+		loop {
+			let list_response = paginated_kv_store.list_filtered(
+				APPROVALS_PERSISTENCE_PRIMARY_NAMESPACE,
+				APPROVALS_PERSISTENCE_SECONDARY_NAMESPACE,
+				&filters,
+				page_token,
+			)?;
+			if list_response.keys.is_empty() {
+				break;
+			}
+			for key in list_response.keys {
+				let record_bytes = paginated_kv_store.read(
+					APPROVALS_PERSISTENCE_PRIMARY_NAMESPACE,
+					APPROVALS_PERSISTENCE_SECONDARY_NAMESPACE,
+					&key,
+				)?;
+				let record = ApprovalRecord::decode(Bytes::from(record_bytes)).map_err(|e| {
+					io::Error::new(
+						io::ErrorKind::InvalidData,
+						format!("Failed to decode pending approval '{key}': {e}"),
+					)
+				})?;
+				pending.insert(record.id.clone(), record);
+			}
+			page_token = list_response.next_page_token;
+		}
+
+		Ok(Self { threshold_msat, pending: Mutex::new(pending), paginated_kv_store })
+	}
+
+	/// Returns whether a payment of `amount_msat` has to be approved before it is sent.
+	///
+	/// `amount_msat` is `None` if the amount could not be determined up front, e.g., when sending
+	/// the full on-chain balance, which always requires approval if a threshold is configured.
+	pub(crate) fn requires_approval(&self, amount_msat: Option<u64>) -> bool {
+		match (self.threshold_msat, amount_msat) {
+			(Some(threshold_msat), Some(amount_msat)) => amount_msat > threshold_msat,
+			(Some(_), None) => true,
+			(None, _) => false,
+		}
+	}
+
+	/// Holds a payment submitted via `endpoint` with the serialized `request` until approved,
+	/// returning the id of the pending approval.
+	pub(crate) fn submit(
+		&self, endpoint: &str, request: Vec<u8>, amount_msat: Option<u64>, destination: String,
+		submitter: &ApiKey, submitter_identity: Option<String>, now: u64,
+	) -> Result<String, LdkServerError> {
+		let mut id_bytes = [0u8; 16];
+		getrandom::getrandom(&mut id_bytes).map_err(|e| {
+			LdkServerError::new(InternalServerError, format!("Failed to generate approval id: {e}"))
+		})?;
+		let id = id_bytes.to_lower_hex_string();
+
+		let record = ApprovalRecord {
+			id: id.clone(),
+			endpoint: endpoint.to_string(),
+			request,
+			amount_msat,
+			destination,
+			submitted_by: submitter.name.clone(),
+			submitter_identity,
+			created_at: now,
+			reviewed_by: None,
+			reviewed_at: None,
+			approved: false,
+		};
+		self.persist(&record)?;
+
+		self.pending.lock().unwrap().insert(id.clone(), record);
+		Ok(id)
+	}
+
+	/// Returns all payments pending approval, oldest first.
+	pub(crate) fn list(&self) -> Vec<PendingApproval> {
+		let pending = self.pending.lock().unwrap();
+		let mut pending_approvals: Vec<PendingApproval> = pending
+			.values()
+			.map(|record| PendingApproval {
+				id: record.id.clone(),
+				endpoint: record.endpoint.clone(),
+				amount_msat: record.amount_msat,
+				destination: record.destination.clone(),
+				submitted_by: record.submitted_by.clone(),
+				created_at: record.created_at,
+			})
+			.collect();
+		pending_approvals.sort_by_key(|approval| approval.created_at);
+		pending_approvals
+	}
+
+	/// Approves the pending payment with the given id on behalf of `approver` and sends it via
+	/// `send`.
+	///
+	/// The approval is persisted before the payment is sent, such that it is never sent twice. If
+	/// `send` fails, the payment is pending approval again.
+	pub(crate) fn approve<R>(
+		&self, id: &str, approver: &ApiKey, approver_identity: Option<&str>, now: u64,
+		send: impl FnOnce(PendingPayment) -> Result<R, LdkServerError>,
+	) -> Result<R, LdkServerError> {
+		let mut record = {
+			let mut pending = self.pending.lock().unwrap();
+			let record = pending.get(id).ok_or_else(|| {
+				LdkServerError::new(
+					InvalidRequestError,
+					format!("Pending approval '{id}' not found"),
+				)
+			})?;
+			check_four_eyes(record, approver, approver_identity)?;
+			// Removing the record ensures concurrent requests can't approve it a second time.
+			pending.remove(id).expect("record is present")
+		};
+
+		let pending_record = record.clone();
+		record.reviewed_by = Some(approver.name.clone());
+		record.reviewed_at = Some(now);
+		record.approved = true;
+		if let Err(e) = self.persist(&record) {
+			self.pending.lock().unwrap().insert(pending_record.id.clone(), pending_record);
+			return Err(e);
+		}
+
+		let payment = PendingPayment {
+			endpoint: &record.endpoint,
+			request: &record.request,
+			submitted_by: &record.submitted_by,
+		};
+		match send(payment) {
+			Ok(result) => Ok(result),
+			Err(e) => {
+				// If restoring the record fails, the payment is lost on restart, but never sent
+				// without another approval.
+				if let Err(persist_err) = self.persist(&pending_record) {
+					error!("Failed to restore pending approval '{id}': {persist_err}");
+				}
+				self.pending.lock().unwrap().insert(pending_record.id.clone(), pending_record);
+				Err(e)
+			},
+		}
+	}
+
+	/// Rejects the pending payment with the given id on behalf of `reviewer`, such that it is never
+	/// sent.
+	pub(crate) fn reject(
+		&self, id: &str, reviewer: &ApiKey, now: u64,
+	) -> Result<(), LdkServerError> {
+		let mut pending = self.pending.lock().unwrap();
+		let mut record = pending.get(id).cloned().ok_or_else(|| {
+			LdkServerError::new(InvalidRequestError, format!("Pending approval '{id}' not found"))
+		})?;
+		record.reviewed_by = Some(reviewer.name.clone());
+		record.reviewed_at = Some(now);
+		self.persist(&record)?;
+
+		pending.remove(id);
+		Ok(())
+	}
+
+	fn persist(&self, record: &ApprovalRecord) -> Result<(), LdkServerError> {
+		let status = match (record.reviewed_at, record.approved) {
+			(None, _) => PENDING_STATUS,
+			(Some(_), true) => APPROVED_STATUS,
+			(Some(_), false) => REJECTED_STATUS,
+		};
+		let attributes =
+			[Attribute { name: APPROVAL_STATUS_ATTRIBUTE, value: AttributeValue::Text(status) }];
+		let write = WriteOp {
+			primary_namespace: APPROVALS_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: APPROVALS_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: &record.id,
+			time: record.created_at as i64,
+			buf: &record.encode_to_vec(),
+			attributes: &attributes,
+		};
+		self.paginated_kv_store.write_batch(&[write]).map_err(|e| {
+			LdkServerError::new(
+				InternalServerError,
+				format!("Failed to persist pending approval: {e}"),
+			)
+		})
+	}
+}
+
+fn check_four_eyes(
+	record: &ApprovalRecord, approver: &ApiKey, approver_identity: Option<&str>,
+) -> Result<(), LdkServerError> {
+	if record.submitted_by == approver.name {
+		return Err(LdkServerError::new(
+			AuthError,
+			"Payments must be approved using a different API key than the one they were submitted with",
+		));
+	}
+	if record.submitter_identity.is_some()
+		&& record.submitter_identity.as_deref() == approver_identity
+	{
+		return Err(LdkServerError::new(
+			AuthError,
+			"Payments must be approved using a different client identity than the one they were submitted with",
+		));
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::auth::spending_limits::SpendingLimits;
+	use crate::auth::Permission;
+	use crate::io::persist::sqlite_store::tests::{create_store, random_storage_path};
+
+	const NOW: u64 = 1_700_000_000;
+
+	fn api_key(name: &str) -> ApiKey {
+		ApiKey::new(
+			name.to_string(),
+			"key".to_string(),
+			vec![Permission::Admin],
+			SpendingLimits::default(),
+			None,
+		)
+	}
+
+	#[test]
+	fn test_requires_approval() {
+		let queue = ApprovalQueue::new(Some(10_000), create_store(random_storage_path())).unwrap();
+		assert!(!queue.requires_approval(Some(10_000)));
+		assert!(queue.requires_approval(Some(10_001)));
+		assert!(queue.requires_approval(None));
+
+		let queue = ApprovalQueue::new(None, create_store(random_storage_path())).unwrap();
+		assert!(!queue.requires_approval(Some(u64::MAX)));
+		assert!(!queue.requires_approval(None));
+	}
+
+	#[test]
+	fn test_approve_requires_second_party() {
+		let storage_path = random_storage_path();
+		let queue = ApprovalQueue::new(Some(0), create_store(storage_path.clone())).unwrap();
+		let submitter = api_key("payouts");
+		let approver = api_key("treasury");
+
+		let id = queue
+			.submit(
+				"OnchainSend",
+				vec![1, 2, 3],
+				Some(50_000),
+				"bcrt1q".to_string(),
+				&submitter,
+				Some("payouts-service".to_string()),
+				NOW,
+			)
+			.unwrap();
+		assert_eq!(queue.list()[0].id, id);
+
+		// Neither the same key nor the same client identity may approve the payment.
+		let err = queue.approve(&id, &submitter, None, NOW, |_| Ok(())).unwrap_err();
+		assert_eq!(err.error_code, AuthError);
+		let err =
+			queue.approve(&id, &approver, Some("payouts-service"), NOW, |_| Ok(())).unwrap_err();
+		assert_eq!(err.error_code, AuthError);
+
+		// A failed payment is pending approval again.
+		let err = queue
+			.approve(&id, &approver, Some("treasury"), NOW, |_| {
+				Err::<(), _>(LdkServerError::new(InternalServerError, "failed"))
+			})
+			.unwrap_err();
+		assert_eq!(err.error_code, InternalServerError);
+		assert_eq!(queue.list().len(), 1);
+
+		let request = queue
+			.approve(&id, &approver, Some("treasury"), NOW, |payment| {
+				assert_eq!(payment.endpoint, "OnchainSend");
+				assert_eq!(payment.submitted_by, "payouts");
+				Ok(payment.request.to_vec())
+			})
+			.unwrap();
+		assert_eq!(request, vec![1, 2, 3]);
+		assert!(queue.list().is_empty());
+
+		// Approved payments are not pending again after a restart.
+		let queue = ApprovalQueue::new(Some(0), create_store(storage_path)).unwrap();
+		assert!(queue.list().is_empty());
+		let err = queue.approve(&id, &approver, None, NOW, |_| Ok(())).unwrap_err();
+		assert_eq!(err.error_code, InvalidRequestError);
+	}
+
+	#[test]
+	fn test_reject() {
+		let storage_path = random_storage_path();
+		let queue = ApprovalQueue::new(Some(0), create_store(storage_path.clone())).unwrap();
+		let submitter = api_key("payouts");
+
+		let id = queue
+			.submit("Bolt11Send", vec![], Some(1), "lnbcrt1".to_string(), &submitter, None, NOW)
+			.unwrap();
+		let other_id = queue
+			.submit("Bolt11Send", vec![], Some(2), "lnbcrt2".to_string(), &submitter, None, NOW + 1)
+			.unwrap();
+
+		// Pending approvals are retained across restarts.
+		let queue = ApprovalQueue::new(Some(0), create_store(storage_path.clone())).unwrap();
+		let ids: Vec<String> = queue.list().into_iter().map(|approval| approval.id).collect();
+		assert_eq!(ids, vec![id.clone(), other_id.clone()]);
+
+		queue.reject(&id, &submitter, NOW + 2).unwrap();
+		let err = queue.reject(&id, &submitter, NOW + 2).unwrap_err();
+		assert_eq!(err.error_code, InvalidRequestError);
+
+		let store = create_store(storage_path);
+		let queue = ApprovalQueue::new(Some(0), Arc::clone(&store)).unwrap();
+		let ids: Vec<String> = queue.list().into_iter().map(|approval| approval.id).collect();
+		assert_eq!(ids, vec![other_id.clone()]);
+
+		// Reviewed payments remain persisted, indexed by their status.
+		let list = |status| {
+			let filters =
+				[ListFilter::TextAttribute { name: APPROVAL_STATUS_ATTRIBUTE, value: status }];
+			store
+				.list_filtered(
+					APPROVALS_PERSISTENCE_PRIMARY_NAMESPACE,
+					APPROVALS_PERSISTENCE_SECONDARY_NAMESPACE,
+					&filters,
+					None,
+				)
+				.unwrap()
+				.keys
+		};
+		assert_eq!(list(PENDING_STATUS), vec![other_id]);
+		assert_eq!(list(REJECTED_STATUS), vec![id]);
+		assert!(list(APPROVED_STATUS).is_empty());
+	}
+}
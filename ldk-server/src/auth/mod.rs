@@ -0,0 +1,265 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+pub(crate) mod api_key_manager;
+pub(crate) mod approval_queue;
+pub(crate) mod nonce_cache;
+pub(crate) mod spending_limits;
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::{fs, io};
+
+use ldk_server_protos::endpoints::{
+	BOLT11_RECEIVE_PATH, BOLT11_SEND_PATH, BOLT12_RECEIVE_PATH, BOLT12_SEND_PATH,
+	EXPORT_PATHFINDING_SCORES_PATH, GET_BALANCES_PATH, GET_FORWARDING_STATS_PATH,
+	GET_NODE_INFO_PATH, GET_PAYMENT_DETAILS_PATH, GRAPH_GET_CHANNEL_PATH, GRAPH_GET_NODE_PATH,
+	GRAPH_LIST_CHANNELS_PATH, GRAPH_LIST_NODES_PATH, LIST_CHANNELS_PATH, LIST_EVENTS_PATH,
+	LIST_FORWARDED_PAYMENTS_PATH, LIST_PAYMENTS_BY_LABEL_PATH, LIST_PAYMENTS_PATH,
+	LIST_PAYMENTS_UPDATED_SINCE_PATH, ONCHAIN_RECEIVE_PATH, ONCHAIN_SEND_PATH,
+	SPONTANEOUS_SEND_PATH, SUBSCRIBE_EVENTS_PATH, VERIFY_SIGNATURE_PATH,
+};
+use ldk_server_protos::types::ApiKeyPermission;
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::AuthError;
+use crate::auth::spending_limits::SpendingLimits;
+
+/// Name of the API key generated on first startup, which is always granted [`Permission::Admin`].
+pub(crate) const ADMIN_API_KEY_NAME: &str = "admin";
+
+/// Maximum length of an API key name.
+const MAX_API_KEY_NAME_LEN: usize = 64;
+
+/// A permission that can be granted to an API key.
+///
+/// Every endpoint requires exactly one permission, see [`required_permission`]. A key holding
+/// [`Permission::Admin`] is allowed to call every endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+	/// Read-only access to node, balance, channel, payment and network graph state.
+	Read,
+	/// Allows creating invoices, offers and on-chain addresses to receive funds.
+	Invoice,
+	/// Allows sending on-chain and lightning payments, subject to the configured spending limits.
+	Send,
+	/// Unrestricted access, including channel, peer and signing endpoints.
+	Admin,
+}
+
+impl From<Permission> for ApiKeyPermission {
+	fn from(permission: Permission) -> Self {
+		match permission {
+			Permission::Read => ApiKeyPermission::Read,
+			Permission::Invoice => ApiKeyPermission::Invoice,
+			Permission::Send => ApiKeyPermission::Send,
+			Permission::Admin => ApiKeyPermission::Admin,
+		}
+	}
+}
+
+impl From<ApiKeyPermission> for Permission {
+	fn from(permission: ApiKeyPermission) -> Self {
+		match permission {
+			ApiKeyPermission::Read => Permission::Read,
+			ApiKeyPermission::Invoice => Permission::Invoice,
+			ApiKeyPermission::Send => Permission::Send,
+			ApiKeyPermission::Admin => Permission::Admin,
+		}
+	}
+}
+
+/// Returns the [`Permission`] required to call the endpoint at `path`.
+///
+/// Endpoints not explicitly listed here require [`Permission::Admin`].
+pub(crate) fn required_permission(path: &str) -> Permission {
+	match path {
+		GET_NODE_INFO_PATH
+		| GET_BALANCES_PATH
+		| LIST_CHANNELS_PATH
+		| GET_PAYMENT_DETAILS_PATH
+		| LIST_PAYMENTS_PATH
+		| LIST_PAYMENTS_UPDATED_SINCE_PATH
+		| LIST_PAYMENTS_BY_LABEL_PATH
+		| LIST_FORWARDED_PAYMENTS_PATH
+		| GET_FORWARDING_STATS_PATH
+		| VERIFY_SIGNATURE_PATH
+		| EXPORT_PATHFINDING_SCORES_PATH
+		| GRAPH_LIST_CHANNELS_PATH
+		| GRAPH_GET_CHANNEL_PATH
+		| GRAPH_LIST_NODES_PATH
+		| GRAPH_GET_NODE_PATH
+		| SUBSCRIBE_EVENTS_PATH
+		| LIST_EVENTS_PATH => Permission::Read,
+		ONCHAIN_RECEIVE_PATH | BOLT11_RECEIVE_PATH | BOLT12_RECEIVE_PATH => Permission::Invoice,
+		ONCHAIN_SEND_PATH | BOLT11_SEND_PATH | BOLT12_SEND_PATH | SPONTANEOUS_SEND_PATH => {
+			Permission::Send
+		},
+		_ => Permission::Admin,
+	}
+}
+
+/// Returns whether `name` may be used as the name of an API key.
+///
+/// Names are used as file names and persistence keys, so only a conservative set of characters is
+/// allowed.
+pub(crate) fn is_valid_api_key_name(name: &str) -> bool {
+	!name.is_empty()
+		&& name.len() <= MAX_API_KEY_NAME_LEN
+		&& name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Generates the secret of a new API key, consisting of 32 random bytes.
+pub(crate) fn generate_api_key() -> io::Result<[u8; 32]> {
+	let mut key_bytes = [0u8; 32];
+	getrandom::getrandom(&mut key_bytes).map_err(io::Error::other)?;
+	Ok(key_bytes)
+}
+
+/// Writes the raw bytes of an API key to `path`, replacing any existing key file.
+///
+/// The file is written to a temporary location first and then renamed, so that a crash never
+/// leaves a partially written key behind. The key file is stored with 0400 permissions (read-only
+/// for owner).
+pub(crate) fn write_api_key_file(path: &Path, key_bytes: &[u8]) -> io::Result<()> {
+	// Ensure the parent directory exists
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+
+	let tmp_path = path.with_extension("tmp");
+	if tmp_path.exists() {
+		fs::remove_file(&tmp_path)?;
+	}
+	fs::write(&tmp_path, key_bytes)?;
+
+	// Set permissions to 0400 (read-only for owner)
+	fs::set_permissions(&tmp_path, fs::Permissions::from_mode(0o400))?;
+	fs::rename(&tmp_path, path)
+}
+
+/// A named API key along with the permissions granted to it.
+#[derive(Clone)]
+pub(crate) struct ApiKey {
+	pub(crate) name: String,
+	pub(crate) key: String,
+	pub(crate) permissions: Vec<Permission>,
+	/// The limits on payments sent using this key, in addition to the server-wide limits.
+	pub(crate) spending_limits: SpendingLimits,
+	/// The client certificate identity requests using this key must be made with, if any.
+	pub(crate) client_identity: Option<String>,
+}
+
+impl ApiKey {
+	pub(crate) fn new(
+		name: String, key: String, permissions: Vec<Permission>, spending_limits: SpendingLimits,
+		client_identity: Option<String>,
+	) -> Self {
+		Self { name, key, permissions, spending_limits, client_identity }
+	}
+
+	/// Checks that this key may be used on a connection authenticated with the client certificate
+	/// identity `client_identity`, if any.
+	pub(crate) fn check_client_identity(
+		&self, client_identity: Option<&str>,
+	) -> Result<(), LdkServerError> {
+		match &self.client_identity {
+			Some(required) if client_identity != Some(required.as_str()) => {
+				Err(LdkServerError::new(
+					AuthError,
+					format!(
+						"API key '{}' may only be used with the client certificate of '{required}'",
+						self.name
+					),
+				))
+			},
+			_ => Ok(()),
+		}
+	}
+
+	/// Returns whether this key has been granted the given permission.
+	pub(crate) fn has_permission(&self, permission: Permission) -> bool {
+		self.permissions.iter().any(|p| *p == permission || *p == Permission::Admin)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ldk_server_protos::endpoints::{FORCE_CLOSE_CHANNEL_PATH, OPEN_CHANNEL_PATH};
+
+	use super::*;
+
+	fn api_key(permissions: Vec<Permission>) -> ApiKey {
+		ApiKey::new(
+			"test".to_string(),
+			"key".to_string(),
+			permissions,
+			SpendingLimits::default(),
+			None,
+		)
+	}
+
+	#[test]
+	fn test_required_permission() {
+		assert_eq!(required_permission(GET_NODE_INFO_PATH), Permission::Read);
+		assert_eq!(required_permission(SUBSCRIBE_EVENTS_PATH), Permission::Read);
+		assert_eq!(required_permission(LIST_EVENTS_PATH), Permission::Read);
+		assert_eq!(required_permission(GET_FORWARDING_STATS_PATH), Permission::Read);
+		assert_eq!(required_permission(LIST_PAYMENTS_UPDATED_SINCE_PATH), Permission::Read);
+		assert_eq!(required_permission(LIST_PAYMENTS_BY_LABEL_PATH), Permission::Read);
+		assert_eq!(required_permission(BOLT11_RECEIVE_PATH), Permission::Invoice);
+		assert_eq!(required_permission(ONCHAIN_SEND_PATH), Permission::Send);
+		assert_eq!(required_permission(FORCE_CLOSE_CHANNEL_PATH), Permission::Admin);
+		assert_eq!(required_permission(OPEN_CHANNEL_PATH), Permission::Admin);
+		assert_eq!(required_permission("UnknownEndpoint"), Permission::Admin);
+	}
+
+	#[test]
+	fn test_has_permission() {
+		let invoice_key = api_key(vec![Permission::Invoice]);
+		assert!(invoice_key.has_permission(Permission::Invoice));
+		assert!(!invoice_key.has_permission(Permission::Read));
+		assert!(!invoice_key.has_permission(Permission::Send));
+		assert!(!invoice_key.has_permission(Permission::Admin));
+
+		let admin_key = api_key(vec![Permission::Admin]);
+		assert!(admin_key.has_permission(Permission::Read));
+		assert!(admin_key.has_permission(Permission::Invoice));
+		assert!(admin_key.has_permission(Permission::Send));
+		assert!(admin_key.has_permission(Permission::Admin));
+	}
+
+	#[test]
+	fn test_is_valid_api_key_name() {
+		assert!(is_valid_api_key_name("point-of-sale_1"));
+		assert!(!is_valid_api_key_name(""));
+		assert!(!is_valid_api_key_name("../api_key"));
+		assert!(!is_valid_api_key_name("with space"));
+		assert!(is_valid_api_key_name(&"a".repeat(MAX_API_KEY_NAME_LEN)));
+		assert!(!is_valid_api_key_name(&"a".repeat(MAX_API_KEY_NAME_LEN + 1)));
+	}
+
+	#[test]
+	fn test_check_client_identity() {
+		let unbound_key = api_key(vec![Permission::Read]);
+		assert!(unbound_key.check_client_identity(None).is_ok());
+		assert!(unbound_key.check_client_identity(Some("pos-terminal")).is_ok());
+
+		let mut bound_key = api_key(vec![Permission::Read]);
+		bound_key.client_identity = Some("pos-terminal".to_string());
+		assert!(bound_key.check_client_identity(Some("pos-terminal")).is_ok());
+		assert_eq!(bound_key.check_client_identity(None).unwrap_err().error_code, AuthError);
+		assert_eq!(
+			bound_key.check_client_identity(Some("payouts")).unwrap_err().error_code,
+			AuthError
+		);
+	}
+}
@@ -0,0 +1,85 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+
+use crate::service::AUTH_TIMESTAMP_TOLERANCE_SECS;
+
+// A request is accepted while its timestamp is within `AUTH_TIMESTAMP_TOLERANCE_SECS` of the server
+// time in either direction, so a nonce has to be remembered for twice that duration.
+const NONCE_RETENTION_SECS: u64 = 2 * AUTH_TIMESTAMP_TOLERANCE_SECS;
+
+/// Keeps track of the nonces of recently authenticated requests, so that replayed requests can be
+/// rejected.
+pub(crate) struct NonceCache {
+	inner: Mutex<SeenNonces>,
+}
+
+struct SeenNonces {
+	nonces: HashSet<String>,
+	// Nonces along with the time they can be forgotten at, in insertion order.
+	expiries: VecDeque<(u64, String)>,
+}
+
+impl NonceCache {
+	pub(crate) fn new() -> Self {
+		Self { inner: Mutex::new(SeenNonces { nonces: HashSet::new(), expiries: VecDeque::new() }) }
+	}
+
+	/// Records `nonce` as used at time `now`.
+	///
+	/// Returns `false` if the nonce was already used within the retention window, i.e., if the
+	/// request is a replay and must be rejected.
+	pub(crate) fn insert(&self, nonce: &str, now: u64) -> bool {
+		let mut inner = self.inner.lock().unwrap();
+
+		while inner.expiries.front().is_some_and(|(expiry, _)| *expiry < now) {
+			if let Some((_, expired_nonce)) = inner.expiries.pop_front() {
+				inner.nonces.remove(&expired_nonce);
+			}
+		}
+
+		if !inner.nonces.insert(nonce.to_string()) {
+			return false;
+		}
+		inner.expiries.push_back((now + NONCE_RETENTION_SECS, nonce.to_string()));
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rejects_reused_nonce() {
+		let cache = NonceCache::new();
+		let now = 1_700_000_000;
+
+		assert!(cache.insert("nonce1", now));
+		assert!(cache.insert("nonce2", now));
+		assert!(!cache.insert("nonce1", now));
+		assert!(!cache.insert("nonce1", now + NONCE_RETENTION_SECS));
+	}
+
+	#[test]
+	fn test_forgets_expired_nonces() {
+		let cache = NonceCache::new();
+		let now = 1_700_000_000;
+
+		assert!(cache.insert("nonce1", now));
+		assert!(cache.insert("nonce2", now + 10));
+
+		let later = now + NONCE_RETENTION_SECS + 1;
+		assert!(cache.insert("nonce1", later));
+		assert!(!cache.insert("nonce2", later));
+		assert_eq!(cache.inner.lock().unwrap().expiries.len(), 2);
+	}
+}
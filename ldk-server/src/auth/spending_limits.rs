@@ -0,0 +1,457 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use hex::DisplayHex;
+use log::error;
+use prost::Message;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{
+	InternalServerError, InvalidRequestError, SpendingLimitExceededError,
+};
+use crate::auth::ApiKey;
+use crate::io::persist::paginated_kv_store::{ListFilter, PaginatedKVStore};
+use crate::io::persist::{
+	SPENDING_PERSISTENCE_PRIMARY_NAMESPACE, SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+};
+
+/// The duration of the rolling window daily budgets are enforced over.
+const BUDGET_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// Limits on the value of outgoing payments.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct SpendingLimits {
+	/// The maximum amount of a single payment.
+	pub(crate) max_payment_amount_msat: Option<u64>,
+	/// The maximum total amount of all payments sent within the last 24 hours.
+	pub(crate) daily_budget_msat: Option<u64>,
+}
+
+impl SpendingLimits {
+	fn is_unlimited(&self) -> bool {
+		self.max_payment_amount_msat.is_none() && self.daily_budget_msat.is_none()
+	}
+}
+
+/// The persisted record of a single outgoing payment, counted towards the daily budgets.
+#[derive(Clone, PartialEq, Message)]
+struct SpendingRecord {
+	#[prost(string, tag = "1")]
+	api_key_name: String,
+	#[prost(uint64, tag = "2")]
+	amount_msat: u64,
+	#[prost(uint64, tag = "3")]
+	created_at: u64,
+}
+
+/// A payment counted towards the daily budgets, along with the key its record is persisted under.
+struct Spend {
+	key: String,
+	record: SpendingRecord,
+}
+
+/// Enforces the server-wide and per-API key [`SpendingLimits`] on outgoing payments.
+///
+/// Every successful payment is persisted to the [`PaginatedKVStore`], so that daily budgets are
+/// retained across restarts. Records are removed once they drop out of the budget window.
+pub(crate) struct SpendingTracker {
+	global_limits: SpendingLimits,
+	// Payments sent, or being sent, within the budget window, oldest first.
+	recent_spends: Mutex<VecDeque<Spend>>,
+	paginated_kv_store: Arc<dyn PaginatedKVStore>,
+}
+
+impl SpendingTracker {
+	/// Creates a new tracker enforcing `global_limits`, loading the payments sent within the budget
+	/// window from `paginated_kv_store` and removing the records of any payments sent before.
+	pub(crate) fn new(
+		global_limits: SpendingLimits, paginated_kv_store: Arc<dyn PaginatedKVStore>, now: u64,
+	) -> io::Result<Self> {
+		// Records are persisted with the time they were created at.
+		let window_start = now.saturating_sub(BUDGET_WINDOW_SECS) as i64;
+		let mut recent_spends = VecDeque::new();
+
+		let filters = [ListFilter::Time(window_start + 1..=i64::MAX)];
+		let mut page_token = None;
+		loop {
+			let list_response = paginated_kv_store.list_filtered(
+				SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+				SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+				&filters,
+				page_token,
+			)?;
+			if list_response.keys.is_empty() {
+				break;
+			}
+			for key in list_response.keys {
+				let record_bytes = paginated_kv_store.read(
+					SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+					SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+					&key,
+				)?;
+				let record = SpendingRecord::decode(Bytes::from(record_bytes)).map_err(|e| {
+					io::Error::new(
+						io::ErrorKind::InvalidData,
+						format!("Failed to decode spending record '{key}': {e}"),
+					)
+				})?;
+				// Records are listed newest first.
+				recent_spends.push_front(Spend { key, record });
+			}
+			page_token = list_response.next_page_token;
+		}
+
+		// Records outside the window never count towards the budgets again. As they are removed,
+		// each listing starts over at the newest remaining one.
+		let filters = [ListFilter::Time(i64::MIN..=window_start)];
+		loop {
+			let list_response = paginated_kv_store.list_filtered(
+				SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+				SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+				&filters,
+				None,
+			)?;
+			if list_response.keys.is_empty() {
+				break;
+			}
+			for key in list_response.keys {
+				paginated_kv_store.remove(
+					SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+					SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+					&key,
+				)?;
+			}
+		}
+
+		Ok(Self { global_limits, recent_spends: Mutex::new(recent_spends), paginated_kv_store })
+	}
+
+	/// Checks that a payment of `amount_msat` on behalf of `api_key` stays within the applicable
+	/// per-payment limits, without counting it towards the daily budgets.
+	///
+	/// This allows rejecting payments which could never be sent up front, e.g., before holding them
+	/// for approval. The daily budgets are only enforced once the payment is actually sent.
+	pub(crate) fn check_payment_amount(
+		&self, api_key: &ApiKey, amount_msat: Option<u64>,
+	) -> Result<(), LdkServerError> {
+		let key_limits = api_key.spending_limits;
+		if self.global_limits.is_unlimited() && key_limits.is_unlimited() {
+			return Ok(());
+		}
+
+		let amount_msat = require_amount(amount_msat)?;
+		check_max_payment_amount(&self.global_limits, amount_msat, "the server")?;
+		check_max_payment_amount(&key_limits, amount_msat, &format!("API key '{}'", api_key.name))
+	}
+
+	/// Sends a payment of `amount_msat` on behalf of `api_key` via `send`, if allowed by the
+	/// applicable spending limits.
+	///
+	/// `amount_msat` is `None` if the amount could not be determined up front, e.g., when sending
+	/// the full on-chain balance, which is only allowed if no limits apply.
+	///
+	/// The payment counts towards the budgets from before `send` is called, so that concurrent
+	/// requests can't exceed a budget without having to wait on each other. It stops counting if
+	/// `send` fails.
+	pub(crate) fn spend<R>(
+		&self, api_key: &ApiKey, amount_msat: Option<u64>,
+		send: impl FnOnce() -> Result<R, LdkServerError>,
+	) -> Result<R, LdkServerError> {
+		let key_limits = api_key.spending_limits;
+		if self.global_limits.is_unlimited() && key_limits.is_unlimited() {
+			return send();
+		}
+
+		let amount_msat = require_amount(amount_msat)?;
+		let mut key_bytes = [0u8; 16];
+		getrandom::getrandom(&mut key_bytes).map_err(|e| {
+			LdkServerError::new(
+				InternalServerError,
+				format!("Failed to generate spending record key: {e}"),
+			)
+		})?;
+		let key = key_bytes.to_lower_hex_string();
+
+		let mut recent_spends = self.recent_spends.lock().unwrap();
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+		let window_start = now.saturating_sub(BUDGET_WINDOW_SECS);
+		let mut expired_spends = Vec::new();
+		while recent_spends.front().is_some_and(|spend| spend.record.created_at <= window_start) {
+			expired_spends.extend(recent_spends.pop_front());
+		}
+
+		let spent_msat = recent_spends.iter().map(|spend| spend.record.amount_msat).sum();
+		let key_spent_msat = recent_spends
+			.iter()
+			.filter(|spend| spend.record.api_key_name == api_key.name)
+			.map(|spend| spend.record.amount_msat)
+			.sum();
+		let reserved = check_limits(&self.global_limits, amount_msat, spent_msat, "the server")
+			.and_then(|()| {
+				check_limits(
+					&key_limits,
+					amount_msat,
+					key_spent_msat,
+					&format!("API key '{}'", api_key.name),
+				)
+			});
+		let record =
+			SpendingRecord { api_key_name: api_key.name.clone(), amount_msat, created_at: now };
+		// The amount is reserved, so that it counts towards the budgets while being sent.
+		if reserved.is_ok() {
+			recent_spends.push_back(Spend { key: key.clone(), record: record.clone() });
+		}
+		drop(recent_spends);
+
+		self.remove_expired(expired_spends);
+		reserved?;
+
+		let result = match send() {
+			Ok(result) => result,
+			Err(e) => {
+				// The payment wasn't sent, so it doesn't count towards the budgets after all.
+				self.recent_spends.lock().unwrap().retain(|spend| spend.key != key);
+				return Err(e);
+			},
+		};
+
+		// The payment was already sent, so we only log a failure to persist it, in which case it
+		// is still counted until the next restart.
+		if let Err(e) = self.persist(&key, &record) {
+			error!("Failed to persist spending record: {e}");
+		}
+
+		Ok(result)
+	}
+
+	fn persist(&self, key: &str, record: &SpendingRecord) -> io::Result<()> {
+		self.paginated_kv_store.write(
+			SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+			SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+			key,
+			record.created_at as i64,
+			&record.encode_to_vec(),
+		)
+	}
+
+	/// Removes the records of the payments which dropped out of the budget window.
+	fn remove_expired(&self, expired_spends: Vec<Spend>) {
+		for spend in expired_spends {
+			// Records which fail to be removed are removed on the next restart instead.
+			if let Err(e) = self.paginated_kv_store.remove(
+				SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+				SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+				&spend.key,
+			) {
+				error!("Failed to remove expired spending record: {e}");
+			}
+		}
+	}
+}
+
+fn require_amount(amount_msat: Option<u64>) -> Result<u64, LdkServerError> {
+	amount_msat.ok_or_else(|| {
+		LdkServerError::new(
+			InvalidRequestError,
+			"Spending limits apply, which require an explicit payment amount",
+		)
+	})
+}
+
+fn check_max_payment_amount(
+	limits: &SpendingLimits, amount_msat: u64, subject: &str,
+) -> Result<(), LdkServerError> {
+	if let Some(max_payment_amount_msat) = limits.max_payment_amount_msat {
+		if amount_msat > max_payment_amount_msat {
+			return Err(LdkServerError::new(
+				SpendingLimitExceededError,
+				format!(
+					"Payment amount of {amount_msat}msat exceeds the per-payment limit of {max_payment_amount_msat}msat for {subject}"
+				),
+			));
+		}
+	}
+	Ok(())
+}
+
+fn check_limits(
+	limits: &SpendingLimits, amount_msat: u64, spent_msat: u64, subject: &str,
+) -> Result<(), LdkServerError> {
+	check_max_payment_amount(limits, amount_msat, subject)?;
+	if let Some(daily_budget_msat) = limits.daily_budget_msat {
+		if spent_msat.saturating_add(amount_msat) > daily_budget_msat {
+			return Err(LdkServerError::new(
+				SpendingLimitExceededError,
+				format!(
+					"Payment amount of {amount_msat}msat exceeds the remaining daily budget of {}msat for {subject}",
+					daily_budget_msat.saturating_sub(spent_msat)
+				),
+			));
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::auth::Permission;
+	use crate::io::persist::sqlite_store::tests::{create_store, random_storage_path};
+
+	fn api_key(name: &str, spending_limits: SpendingLimits) -> ApiKey {
+		ApiKey::new(
+			name.to_string(),
+			"key".to_string(),
+			vec![Permission::Send],
+			spending_limits,
+			None,
+		)
+	}
+
+	fn now() -> u64 {
+		SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+	}
+
+	#[test]
+	fn test_max_payment_amount() {
+		let store = create_store(random_storage_path());
+		let global_limits =
+			SpendingLimits { max_payment_amount_msat: Some(50_000), daily_budget_msat: None };
+		let tracker = SpendingTracker::new(global_limits, store, now()).unwrap();
+
+		let key_limits =
+			SpendingLimits { max_payment_amount_msat: Some(10_000), daily_budget_msat: None };
+		let limited_key = api_key("limited", key_limits);
+		let unlimited_key = api_key("unlimited", SpendingLimits::default());
+
+		assert!(tracker.spend(&limited_key, Some(10_000), || Ok(())).is_ok());
+		let err = tracker.spend(&limited_key, Some(10_001), || Ok(())).unwrap_err();
+		assert_eq!(err.error_code, SpendingLimitExceededError);
+
+		// The global limit applies to all keys.
+		assert!(tracker.spend(&unlimited_key, Some(50_000), || Ok(())).is_ok());
+		let err = tracker.spend(&unlimited_key, Some(50_001), || Ok(())).unwrap_err();
+		assert_eq!(err.error_code, SpendingLimitExceededError);
+
+		// Payments of unknown amount are rejected if any limit applies.
+		let err = tracker.spend(&unlimited_key, None, || Ok(())).unwrap_err();
+		assert_eq!(err.error_code, InvalidRequestError);
+	}
+
+	#[test]
+	fn test_daily_budget() {
+		let storage_path = random_storage_path();
+		let store = create_store(storage_path.clone());
+		let global_limits =
+			SpendingLimits { max_payment_amount_msat: None, daily_budget_msat: Some(100_000) };
+		let tracker = SpendingTracker::new(global_limits, store, now()).unwrap();
+
+		let key_limits =
+			SpendingLimits { max_payment_amount_msat: None, daily_budget_msat: Some(30_000) };
+		let payouts_key = api_key("payouts", key_limits);
+		let other_key = api_key("other", SpendingLimits::default());
+
+		assert!(tracker.spend(&payouts_key, Some(20_000), || Ok(())).is_ok());
+		let err = tracker.spend(&payouts_key, Some(10_001), || Ok(())).unwrap_err();
+		assert_eq!(err.error_code, SpendingLimitExceededError);
+
+		// Failed payments don't count towards the budget.
+		let err = tracker
+			.spend(&payouts_key, Some(10_000), || {
+				Err::<(), _>(LdkServerError::new(InternalServerError, "failed"))
+			})
+			.unwrap_err();
+		assert_eq!(err.error_code, InternalServerError);
+		assert!(tracker.spend(&payouts_key, Some(10_000), || Ok(())).is_ok());
+
+		// The global budget is shared by all keys.
+		assert!(tracker.spend(&other_key, Some(70_000), || Ok(())).is_ok());
+		let err = tracker.spend(&other_key, Some(1), || Ok(())).unwrap_err();
+		assert_eq!(err.error_code, SpendingLimitExceededError);
+
+		// Usage is retained across restarts, but only within the budget window.
+		let store = create_store(storage_path.clone());
+		let tracker = SpendingTracker::new(global_limits, store, now()).unwrap();
+		let err = tracker.spend(&other_key, Some(1), || Ok(())).unwrap_err();
+		assert_eq!(err.error_code, SpendingLimitExceededError);
+
+		// Records outside the budget window are removed.
+		let store = create_store(storage_path);
+		let tracker =
+			SpendingTracker::new(global_limits, Arc::clone(&store), now() + BUDGET_WINDOW_SECS + 1)
+				.unwrap();
+		assert!(tracker.recent_spends.lock().unwrap().is_empty());
+		let list_response = store
+			.list(
+				SPENDING_PERSISTENCE_PRIMARY_NAMESPACE,
+				SPENDING_PERSISTENCE_SECONDARY_NAMESPACE,
+				None,
+			)
+			.unwrap();
+		assert!(list_response.keys.is_empty());
+	}
+
+	#[test]
+	fn test_budget_reserved_while_sending() {
+		let store = create_store(random_storage_path());
+		let global_limits =
+			SpendingLimits { max_payment_amount_msat: None, daily_budget_msat: Some(30_000) };
+		let tracker = SpendingTracker::new(global_limits, store, now()).unwrap();
+		let key = api_key("payouts", SpendingLimits::default());
+
+		// Payments count towards the budgets while being sent, unless sending them fails.
+		let err = tracker
+			.spend(&key, Some(30_000), || {
+				assert_eq!(tracker.recent_spends.lock().unwrap().len(), 1);
+				Err::<(), _>(LdkServerError::new(InternalServerError, "failed"))
+			})
+			.unwrap_err();
+		assert_eq!(err.error_code, InternalServerError);
+		assert!(tracker.recent_spends.lock().unwrap().is_empty());
+
+		// Other payments aren't blocked while a payment is being sent.
+		let result = tracker.spend(&key, Some(20_000), || {
+			let err = tracker.spend(&key, Some(10_001), || Ok(())).unwrap_err();
+			assert_eq!(err.error_code, SpendingLimitExceededError);
+			tracker.spend(&key, Some(10_000), || Ok(()))
+		});
+		assert!(result.is_ok());
+		assert_eq!(tracker.recent_spends.lock().unwrap().len(), 2);
+	}
+
+	#[test]
+	fn test_check_payment_amount() {
+		let store = create_store(random_storage_path());
+		let global_limits =
+			SpendingLimits { max_payment_amount_msat: None, daily_budget_msat: Some(10_000) };
+		let tracker = SpendingTracker::new(global_limits, store, now()).unwrap();
+
+		let key_limits =
+			SpendingLimits { max_payment_amount_msat: Some(5_000), daily_budget_msat: None };
+		let limited_key = api_key("limited", key_limits);
+
+		assert!(tracker.check_payment_amount(&limited_key, Some(5_000)).is_ok());
+		let err = tracker.check_payment_amount(&limited_key, Some(5_001)).unwrap_err();
+		assert_eq!(err.error_code, SpendingLimitExceededError);
+		let err = tracker.check_payment_amount(&limited_key, None).unwrap_err();
+		assert_eq!(err.error_code, InvalidRequestError);
+
+		// Checked payments don't count towards the budgets.
+		for _ in 0..3 {
+			assert!(tracker.check_payment_amount(&limited_key, Some(5_000)).is_ok());
+		}
+		assert!(tracker.recent_spends.lock().unwrap().is_empty());
+	}
+}
@@ -0,0 +1,276 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+//! A gRPC transport for the API, serving the `api.LightningNode` service defined in
+//! `ldk-server-protos/src/proto/service.proto`.
+//!
+//! Every RPC maps 1:1 onto the endpoint of the same name, e.g., `/api.LightningNode/GetNodeInfo`
+//! is handled like a request to `/GetNodeInfo`, including authentication via the `x-auth`
+//! metadata entry. The HMAC is computed over the serialized request message, without the gRPC
+//! message framing.
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use hyper::body::{Body, Bytes, Frame, Incoming};
+use hyper::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use hyper::server::conn::http2;
+use hyper::service::Service;
+use hyper::{Request, Response};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use log::error;
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::{
+	AuthError, InternalServerError, InvalidRequestError, LightningError, SpendingLimitExceededError,
+};
+use crate::service::{
+	extract_auth_params, read_body, unary_handler, ContentType, NodeService, RawRequest,
+};
+use crate::util::tls::client_identity_from_cert;
+
+/// The fully-qualified name of the gRPC service.
+const GRPC_SERVICE_NAME: &str = "api.LightningNode";
+
+const APPLICATION_GRPC: &str = "application/grpc";
+
+/// Length of the prefix of every gRPC message: a one-byte compression flag followed by the
+/// big-endian, four-byte message length.
+const MESSAGE_PREFIX_LEN: usize = 5;
+
+/// The gRPC status codes we respond with.
+/// See <https://grpc.github.io/grpc/core/md_doc_statuscodes.html>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GrpcStatus {
+	Ok = 0,
+	InvalidArgument = 3,
+	PermissionDenied = 7,
+	Unimplemented = 12,
+	Internal = 13,
+	Unauthenticated = 16,
+}
+
+impl From<&LdkServerError> for GrpcStatus {
+	fn from(e: &LdkServerError) -> Self {
+		match e.error_code {
+			InvalidRequestError => GrpcStatus::InvalidArgument,
+			AuthError => GrpcStatus::Unauthenticated,
+			LightningError | InternalServerError => GrpcStatus::Internal,
+			SpendingLimitExceededError => GrpcStatus::PermissionDenied,
+		}
+	}
+}
+
+/// Accepts TLS connections on `listener` and serves the gRPC service over HTTP/2 on each of them.
+///
+/// `tls_acceptor` must advertise the `h2` ALPN protocol.
+pub(crate) async fn serve(listener: TcpListener, tls_acceptor: TlsAcceptor, service: NodeService) {
+	loop {
+		let stream = match listener.accept().await {
+			Ok((stream, _)) => stream,
+			Err(e) => {
+				error!("Failed to accept gRPC connection: {e}");
+				continue;
+			},
+		};
+		let acceptor = tls_acceptor.clone();
+		let service = service.clone();
+		tokio::spawn(async move {
+			match acceptor.accept(stream).await {
+				Ok(tls_stream) => {
+					// Only set if client certificates are required, in which case the certificate
+					// has already been verified during the handshake.
+					let client_identity = tls_stream
+						.get_ref()
+						.1
+						.peer_certificates()
+						.and_then(|certs| certs.first())
+						.and_then(client_identity_from_cert);
+					let grpc_service = GrpcService(service.with_client_identity(client_identity));
+					if let Err(err) = http2::Builder::new(TokioExecutor::new())
+						.serve_connection(TokioIo::new(tls_stream), grpc_service)
+						.await
+					{
+						error!("Failed to serve gRPC connection: {err}");
+					}
+				},
+				Err(e) => error!("gRPC TLS handshake failed: {e}"),
+			}
+		});
+	}
+}
+
+#[derive(Clone)]
+struct GrpcService(NodeService);
+
+impl Service<Request<Incoming>> for GrpcService {
+	type Response = Response<UnaryBody>;
+	type Error = hyper::Error;
+	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+	fn call(&self, req: Request<Incoming>) -> Self::Future {
+		let service = self.0.clone();
+		Box::pin(async move {
+			let response = match handle_grpc_request(service, req).await {
+				Ok(message) => UnaryBody::new(Some(frame_message(&message)), GrpcStatus::Ok, ""),
+				Err((status, message)) => UnaryBody::new(None, status, &message),
+			};
+			Ok(Response::builder()
+				.header(CONTENT_TYPE, APPLICATION_GRPC)
+				.body(response)
+				// unwrap safety: body only errors when previous chained calls failed.
+				.unwrap())
+		})
+	}
+}
+
+async fn handle_grpc_request(
+	service: NodeService, req: Request<Incoming>,
+) -> Result<Vec<u8>, (GrpcStatus, String)> {
+	// Methods which aren't served over gRPC, such as the streaming `SubscribeEvents`, are treated
+	// like unknown ones.
+	let path = req.uri().path();
+	let (method, handler) = match path.strip_prefix('/').and_then(|p| p.split_once('/')) {
+		Some((GRPC_SERVICE_NAME, method)) => match unary_handler(method) {
+			Some(handler) => (method.to_string(), handler),
+			None => return Err((GrpcStatus::Unimplemented, format!("Unknown method: {}", path))),
+		},
+		_ => {
+			return Err((GrpcStatus::Unimplemented, format!("Unknown service or method: {}", path)))
+		},
+	};
+	let auth_params = extract_auth_params(&req).map_err(to_grpc_error)?;
+	let body = read_body(req.into_body()).await.map_err(to_grpc_error)?;
+	let message = unframe_message(body)?;
+
+	let request = RawRequest {
+		endpoint: method,
+		auth_params,
+		body: message,
+		content_type: ContentType::Protobuf,
+	};
+	handler(service, request).map_err(to_grpc_error)
+}
+
+fn to_grpc_error(e: LdkServerError) -> (GrpcStatus, String) {
+	(GrpcStatus::from(&e), e.message)
+}
+
+/// Extracts the single message of a unary gRPC request body.
+fn unframe_message(body: Bytes) -> Result<Bytes, (GrpcStatus, String)> {
+	if body.len() < MESSAGE_PREFIX_LEN {
+		return Err((GrpcStatus::InvalidArgument, "Missing gRPC message".to_string()));
+	}
+	if body[0] != 0 {
+		return Err((
+			GrpcStatus::Unimplemented,
+			"Compressed gRPC messages are not supported".to_string(),
+		));
+	}
+	let message_len = u32::from_be_bytes([body[1], body[2], body[3], body[4]]) as usize;
+	if body.len() - MESSAGE_PREFIX_LEN != message_len {
+		return Err((
+			GrpcStatus::InvalidArgument,
+			"Request must contain exactly one gRPC message".to_string(),
+		));
+	}
+	Ok(body.slice(MESSAGE_PREFIX_LEN..))
+}
+
+fn frame_message(message: &[u8]) -> Bytes {
+	let mut framed = Vec::with_capacity(MESSAGE_PREFIX_LEN + message.len());
+	framed.push(0);
+	framed.extend_from_slice(&(message.len() as u32).to_be_bytes());
+	framed.extend_from_slice(message);
+	Bytes::from(framed)
+}
+
+/// Percent-encodes `message` for use as the `grpc-message` trailer, as required by the gRPC
+/// HTTP/2 protocol specification.
+fn percent_encode(message: &str) -> String {
+	let mut encoded = String::with_capacity(message.len());
+	for byte in message.bytes() {
+		if (0x20..=0x7e).contains(&byte) && byte != b'%' {
+			encoded.push(byte as char);
+		} else {
+			encoded.push_str(&format!("%{byte:02X}"));
+		}
+	}
+	encoded
+}
+
+/// The body of a unary gRPC response: at most one message, followed by the status trailers.
+struct UnaryBody {
+	message: Option<Bytes>,
+	trailers: Option<HeaderMap>,
+}
+
+impl UnaryBody {
+	fn new(message: Option<Bytes>, status: GrpcStatus, status_message: &str) -> Self {
+		let mut trailers = HeaderMap::new();
+		trailers.insert("grpc-status", HeaderValue::from(status as u16));
+		if !status_message.is_empty() {
+			// unwrap safety: percent-encoded strings only contain visible ASCII characters.
+			let value = HeaderValue::from_str(&percent_encode(status_message)).unwrap();
+			trailers.insert("grpc-message", value);
+		}
+		Self { message, trailers: Some(trailers) }
+	}
+}
+
+impl Body for UnaryBody {
+	type Data = Bytes;
+	type Error = Infallible;
+
+	fn poll_frame(
+		self: Pin<&mut Self>, _cx: &mut Context<'_>,
+	) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+		let this = self.get_mut();
+		if let Some(message) = this.message.take() {
+			return Poll::Ready(Some(Ok(Frame::data(message))));
+		}
+		Poll::Ready(this.trailers.take().map(|trailers| Ok(Frame::trailers(trailers))))
+	}
+
+	fn is_end_stream(&self) -> bool {
+		self.message.is_none() && self.trailers.is_none()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_message_framing() {
+		let framed = frame_message(b"request");
+		assert_eq!(&framed[..MESSAGE_PREFIX_LEN], &[0, 0, 0, 0, 7]);
+		assert_eq!(unframe_message(framed).unwrap(), Bytes::from_static(b"request"));
+
+		// Empty messages, e.g. of `GetNodeInfoRequest`, are valid.
+		assert_eq!(unframe_message(frame_message(b"")).unwrap(), Bytes::new());
+
+		let truncated = Bytes::from_static(&[0, 0, 0, 0, 7, 1, 2]);
+		assert_eq!(unframe_message(truncated).unwrap_err().0, GrpcStatus::InvalidArgument);
+
+		let compressed = Bytes::from_static(&[1, 0, 0, 0, 1, 1]);
+		assert_eq!(unframe_message(compressed).unwrap_err().0, GrpcStatus::Unimplemented);
+	}
+
+	#[test]
+	fn test_percent_encode() {
+		assert_eq!(percent_encode("Invalid credentials"), "Invalid credentials");
+		assert_eq!(percent_encode("100% done\n"), "100%25 done%0A");
+		assert_eq!(percent_encode("€"), "%E2%82%AC");
+	}
+}
@@ -0,0 +1,298 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::io;
+
+use hex::DisplayHex;
+use ldk_server_protos::api::{
+	ApprovePaymentRequest, Bolt11SendRequest, Bolt12SendRequest, CloseChannelRequest,
+	ConnectPeerRequest, CreateApiKeyRequest, DisconnectPeerRequest, ForceCloseChannelRequest,
+	OnchainSendRequest, OpenChannelRequest, RejectPaymentRequest, RevokeApiKeyRequest,
+	RotateApiKeyRequest, SpliceInRequest, SpliceOutRequest, SpontaneousSendRequest,
+	UpdateChannelConfigRequest,
+};
+use ldk_server_protos::endpoints::{
+	APPROVE_PAYMENT_PATH, BOLT11_SEND_PATH, BOLT12_SEND_PATH, CLOSE_CHANNEL_PATH,
+	CONNECT_PEER_PATH, CREATE_API_KEY_PATH, DISCONNECT_PEER_PATH, FORCE_CLOSE_CHANNEL_PATH,
+	ONCHAIN_SEND_PATH, OPEN_CHANNEL_PATH, REJECT_PAYMENT_PATH, REVOKE_API_KEY_PATH,
+	ROTATE_API_KEY_PATH, SPLICE_IN_PATH, SPLICE_OUT_PATH, SPONTANEOUS_SEND_PATH,
+	UPDATE_CHANNEL_CONFIG_PATH,
+};
+use ldk_server_protos::types::{ApiKeyPermission, AuditLogEntry};
+use prost::Message;
+
+use crate::io::persist::paginated_kv_store::PaginatedKVStore;
+use crate::io::persist::{
+	AUDIT_LOG_PERSISTENCE_PRIMARY_NAMESPACE, AUDIT_LOG_PERSISTENCE_SECONDARY_NAMESPACE,
+};
+
+/// Returns whether calls to the endpoint at `path` change the state of the node or server, and are
+/// hence recorded in the audit log.
+pub(crate) fn is_audited(path: &str) -> bool {
+	matches!(
+		path,
+		ONCHAIN_SEND_PATH
+			| BOLT11_SEND_PATH
+			| BOLT12_SEND_PATH
+			| SPONTANEOUS_SEND_PATH
+			| OPEN_CHANNEL_PATH
+			| SPLICE_IN_PATH
+			| SPLICE_OUT_PATH
+			| CLOSE_CHANNEL_PATH
+			| FORCE_CLOSE_CHANNEL_PATH
+			| UPDATE_CHANNEL_CONFIG_PATH
+			| CONNECT_PEER_PATH
+			| DISCONNECT_PEER_PATH
+			| CREATE_API_KEY_PATH
+			| REVOKE_API_KEY_PATH
+			| ROTATE_API_KEY_PATH
+			| APPROVE_PAYMENT_PATH
+			| REJECT_PAYMENT_PATH
+	)
+}
+
+/// Summarizes the serialized `request` made to the endpoint at `path` for the audit log.
+///
+/// Only fields identifying what the call acted on are included, e.g., destinations, amounts and
+/// channel ids. Private data such as BOLT12 payer notes is never included.
+pub(crate) fn summarize_request(path: &str, request: &[u8]) -> String {
+	let summary = match path {
+		ONCHAIN_SEND_PATH => OnchainSendRequest::decode(request).map(|r| {
+			summarize(&[
+				("address", Some(r.address)),
+				("amount_sats", r.amount_sats.map(|v| v.to_string())),
+				("send_all", r.send_all.map(|v| v.to_string())),
+				("fee_rate_sat_per_vb", r.fee_rate_sat_per_vb.map(|v| v.to_string())),
+			])
+		}),
+		BOLT11_SEND_PATH => Bolt11SendRequest::decode(request).map(|r| {
+			summarize(&[
+				("invoice", Some(r.invoice)),
+				("amount_msat", r.amount_msat.map(|v| v.to_string())),
+			])
+		}),
+		BOLT12_SEND_PATH => Bolt12SendRequest::decode(request).map(|r| {
+			summarize(&[
+				("offer", Some(r.offer)),
+				("amount_msat", r.amount_msat.map(|v| v.to_string())),
+				("quantity", r.quantity.map(|v| v.to_string())),
+			])
+		}),
+		SPONTANEOUS_SEND_PATH => SpontaneousSendRequest::decode(request).map(|r| {
+			summarize(&[
+				("node_id", Some(r.node_id)),
+				("amount_msat", Some(r.amount_msat.to_string())),
+			])
+		}),
+		OPEN_CHANNEL_PATH => OpenChannelRequest::decode(request).map(|r| {
+			summarize(&[
+				("node_pubkey", Some(r.node_pubkey)),
+				("address", Some(r.address)),
+				("channel_amount_sats", Some(r.channel_amount_sats.to_string())),
+				("push_to_counterparty_msat", r.push_to_counterparty_msat.map(|v| v.to_string())),
+				("announce_channel", Some(r.announce_channel.to_string())),
+			])
+		}),
+		SPLICE_IN_PATH => SpliceInRequest::decode(request).map(|r| {
+			summarize(&[
+				("user_channel_id", Some(r.user_channel_id)),
+				("counterparty_node_id", Some(r.counterparty_node_id)),
+				("splice_amount_sats", Some(r.splice_amount_sats.to_string())),
+			])
+		}),
+		SPLICE_OUT_PATH => SpliceOutRequest::decode(request).map(|r| {
+			summarize(&[
+				("user_channel_id", Some(r.user_channel_id)),
+				("counterparty_node_id", Some(r.counterparty_node_id)),
+				("address", r.address),
+				("splice_amount_sats", Some(r.splice_amount_sats.to_string())),
+			])
+		}),
+		CLOSE_CHANNEL_PATH => CloseChannelRequest::decode(request).map(|r| {
+			summarize(&[
+				("user_channel_id", Some(r.user_channel_id)),
+				("counterparty_node_id", Some(r.counterparty_node_id)),
+			])
+		}),
+		FORCE_CLOSE_CHANNEL_PATH => ForceCloseChannelRequest::decode(request).map(|r| {
+			summarize(&[
+				("user_channel_id", Some(r.user_channel_id)),
+				("counterparty_node_id", Some(r.counterparty_node_id)),
+				("force_close_reason", r.force_close_reason),
+			])
+		}),
+		UPDATE_CHANNEL_CONFIG_PATH => UpdateChannelConfigRequest::decode(request).map(|r| {
+			summarize(&[
+				("user_channel_id", Some(r.user_channel_id)),
+				("counterparty_node_id", Some(r.counterparty_node_id)),
+			])
+		}),
+		CONNECT_PEER_PATH => ConnectPeerRequest::decode(request).map(|r| {
+			summarize(&[
+				("node_pubkey", Some(r.node_pubkey)),
+				("address", Some(r.address)),
+				("persist", Some(r.persist.to_string())),
+			])
+		}),
+		DISCONNECT_PEER_PATH => DisconnectPeerRequest::decode(request)
+			.map(|r| summarize(&[("node_pubkey", Some(r.node_pubkey))])),
+		CREATE_API_KEY_PATH => CreateApiKeyRequest::decode(request).map(|r| {
+			let permissions = r
+				.permissions
+				.iter()
+				.map(|p| match ApiKeyPermission::try_from(*p) {
+					Ok(permission) => permission.as_str_name().to_string(),
+					Err(_) => p.to_string(),
+				})
+				.collect::<Vec<_>>()
+				.join("|");
+			summarize(&[
+				("name", Some(r.name)),
+				("permissions", Some(permissions)),
+				("max_send_amount_msat", r.max_send_amount_msat.map(|v| v.to_string())),
+				("daily_budget_msat", r.daily_budget_msat.map(|v| v.to_string())),
+				("client_identity", r.client_identity),
+			])
+		}),
+		REVOKE_API_KEY_PATH => {
+			RevokeApiKeyRequest::decode(request).map(|r| summarize(&[("name", Some(r.name))]))
+		},
+		ROTATE_API_KEY_PATH => RotateApiKeyRequest::decode(request).map(|r| {
+			summarize(&[
+				("name", Some(r.name)),
+				("grace_period_secs", r.grace_period_secs.map(|v| v.to_string())),
+			])
+		}),
+		APPROVE_PAYMENT_PATH => {
+			ApprovePaymentRequest::decode(request).map(|r| summarize(&[("id", Some(r.id))]))
+		},
+		REJECT_PAYMENT_PATH => {
+			RejectPaymentRequest::decode(request).map(|r| summarize(&[("id", Some(r.id))]))
+		},
+		_ => Ok(String::new()),
+	};
+	summary.unwrap_or_else(|e| format!("malformed request: {e}"))
+}
+
+fn summarize(fields: &[(&str, Option<String>)]) -> String {
+	fields
+		.iter()
+		.filter_map(|(name, value)| value.as_ref().map(|value| format!("{name}={value}")))
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Appends `entry` to the audit log in `paginated_kv_store`, assigning it a new unique id.
+pub(crate) fn append_audit_log_entry(
+	paginated_kv_store: &dyn PaginatedKVStore, entry: &mut AuditLogEntry,
+) -> io::Result<()> {
+	let mut id_bytes = [0u8; 16];
+	getrandom::getrandom(&mut id_bytes).map_err(io::Error::other)?;
+	entry.id = id_bytes.to_lower_hex_string();
+	update_audit_log_entry(paginated_kv_store, entry)
+}
+
+/// Replaces the entry in the audit log in `paginated_kv_store` that has the same id as `entry`.
+pub(crate) fn update_audit_log_entry(
+	paginated_kv_store: &dyn PaginatedKVStore, entry: &AuditLogEntry,
+) -> io::Result<()> {
+	paginated_kv_store.write(
+		AUDIT_LOG_PERSISTENCE_PRIMARY_NAMESPACE,
+		AUDIT_LOG_PERSISTENCE_SECONDARY_NAMESPACE,
+		&entry.id,
+		entry.timestamp as i64,
+		&entry.encode_to_vec(),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use bytes::Bytes;
+	use ldk_server_protos::endpoints::{GET_BALANCES_PATH, LIST_AUDIT_LOG_PATH};
+
+	use super::*;
+	use crate::io::persist::sqlite_store::tests::{create_store, random_storage_path};
+
+	#[test]
+	fn test_is_audited() {
+		assert!(is_audited(BOLT11_SEND_PATH));
+		assert!(is_audited(FORCE_CLOSE_CHANNEL_PATH));
+		assert!(is_audited(ROTATE_API_KEY_PATH));
+		assert!(!is_audited(GET_BALANCES_PATH));
+		assert!(!is_audited(LIST_AUDIT_LOG_PATH));
+	}
+
+	#[test]
+	fn test_summarize_request() {
+		let request = Bolt12SendRequest {
+			offer: "lno1qgsq".to_string(),
+			amount_msat: Some(5_000),
+			quantity: None,
+			payer_note: Some("rent for march".to_string()),
+			route_parameters: None,
+			metadata: None,
+		};
+		let summary = summarize_request(BOLT12_SEND_PATH, &request.encode_to_vec());
+		assert_eq!(summary, "offer=lno1qgsq amount_msat=5000");
+
+		let request = CreateApiKeyRequest {
+			name: "payouts".to_string(),
+			permissions: vec![ApiKeyPermission::Read as i32, ApiKeyPermission::Send as i32],
+			max_send_amount_msat: Some(10_000),
+			client_identity: None,
+			daily_budget_msat: None,
+		};
+		let summary = summarize_request(CREATE_API_KEY_PATH, &request.encode_to_vec());
+		assert_eq!(summary, "name=payouts permissions=READ|SEND max_send_amount_msat=10000");
+
+		let summary = summarize_request(CLOSE_CHANNEL_PATH, &[0xff]);
+		assert!(summary.starts_with("malformed request"));
+	}
+
+	#[test]
+	fn test_append_audit_log_entry() {
+		let store = create_store(random_storage_path());
+		let mut entry = AuditLogEntry {
+			id: String::new(),
+			timestamp: 1_700_000_000,
+			api_key_name: "admin".to_string(),
+			client_identity: Some("ops".to_string()),
+			endpoint: DISCONNECT_PEER_PATH.to_string(),
+			request_summary: "node_pubkey=02aa".to_string(),
+			success: false,
+			error: None,
+			completed: false,
+		};
+		append_audit_log_entry(store.as_ref(), &mut entry).unwrap();
+
+		// Completing the entry replaces it rather than appending another one.
+		entry.error = Some("Peer not connected".to_string());
+		entry.completed = true;
+		update_audit_log_entry(store.as_ref(), &entry).unwrap();
+
+		let list_response = store
+			.list(
+				AUDIT_LOG_PERSISTENCE_PRIMARY_NAMESPACE,
+				AUDIT_LOG_PERSISTENCE_SECONDARY_NAMESPACE,
+				None,
+			)
+			.unwrap();
+		assert_eq!(list_response.keys.len(), 1);
+		let stored_bytes = store
+			.read(
+				AUDIT_LOG_PERSISTENCE_PRIMARY_NAMESPACE,
+				AUDIT_LOG_PERSISTENCE_SECONDARY_NAMESPACE,
+				&list_response.keys[0],
+			)
+			.unwrap();
+		let stored = AuditLogEntry::decode(Bytes::from(stored_bytes)).unwrap();
+		assert_eq!(stored.id, list_response.keys[0]);
+		assert_eq!(stored, entry);
+	}
+}
@@ -0,0 +1,79 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use ldk_server_protos::events::EventEnvelope;
+use tokio::sync::broadcast;
+
+/// The number of events buffered per subscriber before it is considered to have fallen behind.
+const EVENT_BUFFER_SIZE: usize = 1024;
+
+/// Hands out events to live subscribers, e.g., clients of the `SubscribeEvents` API.
+///
+/// Events are broadcast by the [`EventOutbox`] as soon as they are queued, i.e., independently of
+/// their publication via the [`EventPublisher`], so that subscribers keep receiving events while a
+/// publisher is unavailable. Every event is broadcast once, in the order of its `sequence_number`.
+/// Events queued while a subscriber isn't connected are not broadcast again, but can be caught up
+/// on via the `ListEvents` API.
+///
+/// [`EventOutbox`]: crate::io::events::outbox::EventOutbox
+/// [`EventPublisher`]: crate::io::events::event_publisher::EventPublisher
+pub(crate) struct EventBroadcaster {
+	sender: broadcast::Sender<EventEnvelope>,
+}
+
+impl EventBroadcaster {
+	pub(crate) fn new() -> Self {
+		let (sender, _) = broadcast::channel(EVENT_BUFFER_SIZE);
+		Self { sender }
+	}
+
+	/// Returns a receiver for all events broadcast from now on.
+	///
+	/// Receivers which fall behind by more than [`EVENT_BUFFER_SIZE`] events miss the oldest ones,
+	/// see [`broadcast::Receiver::recv`].
+	pub(crate) fn subscribe(&self) -> broadcast::Receiver<EventEnvelope> {
+		self.sender.subscribe()
+	}
+
+	/// Hands out `event` to all current subscribers, without waiting on them.
+	pub(crate) fn broadcast(&self, event: EventEnvelope) {
+		// Sending only fails if there are no subscribers, in which case there is nothing to do.
+		let _ = self.sender.send(event);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ldk_server_protos::events::{event_envelope, PaymentReceived};
+
+	use super::*;
+
+	fn payment_received_event() -> EventEnvelope {
+		EventEnvelope {
+			event: Some(event_envelope::Event::PaymentReceived(PaymentReceived { payment: None })),
+			..Default::default()
+		}
+	}
+
+	#[tokio::test]
+	async fn test_broadcast_notifies_subscribers() {
+		let broadcaster = EventBroadcaster::new();
+
+		// Broadcasting without any subscribers succeeds.
+		broadcaster.broadcast(payment_received_event());
+
+		let mut first = broadcaster.subscribe();
+		let mut second = broadcaster.subscribe();
+		broadcaster.broadcast(payment_received_event());
+
+		assert_eq!(first.recv().await.unwrap(), payment_received_event());
+		assert_eq!(second.recv().await.unwrap(), payment_received_event());
+		assert!(first.try_recv().is_err());
+	}
+}
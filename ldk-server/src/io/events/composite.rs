@@ -0,0 +1,166 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ldk_server_protos::events::EventEnvelope;
+use log::warn;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::io::events::event_publisher::EventPublisher;
+
+/// A publisher an event is fanned out to by the [`CompositeEventPublisher`].
+pub(crate) struct EventSink {
+	/// The name of the sink, used for logging.
+	pub(crate) name: String,
+	pub(crate) publisher: Arc<dyn EventPublisher>,
+	/// Whether failing to publish to this sink is only logged, rather than failing the publication
+	/// of the event.
+	pub(crate) best_effort: bool,
+}
+
+/// An [`EventPublisher`] publishing every event to all of the configured [`EventSink`]s.
+///
+/// Events are published to all sinks concurrently. An event is only considered published once all
+/// required sinks published it, while failures of best-effort sinks are only logged. As failed
+/// events are retried as a whole, sinks may receive the same event more than once if another,
+/// required sink failed. If no sinks are configured, events are discarded.
+pub(crate) struct CompositeEventPublisher {
+	sinks: Vec<EventSink>,
+}
+
+impl CompositeEventPublisher {
+	pub(crate) fn new(sinks: Vec<EventSink>) -> Self {
+		Self { sinks }
+	}
+}
+
+#[async_trait]
+impl EventPublisher for CompositeEventPublisher {
+	async fn publish(&self, event: EventEnvelope) -> Result<(), LdkServerError> {
+		let handles = self
+			.sinks
+			.iter()
+			.map(|sink| {
+				let publisher = Arc::clone(&sink.publisher);
+				let event = event.clone();
+				tokio::spawn(async move { publisher.publish(event).await })
+			})
+			.collect::<Vec<_>>();
+
+		let mut result = Ok(());
+		for (sink, handle) in self.sinks.iter().zip(handles) {
+			let sink_result = handle.await.unwrap_or_else(|e| {
+				Err(LdkServerError::new(
+					InternalServerError,
+					format!("Event publisher task failed: {}", e),
+				))
+			});
+			match sink_result {
+				Ok(()) => {},
+				Err(e) if sink.best_effort => {
+					warn!("Failed to publish event to best-effort sink {}: {}", sink.name, e);
+				},
+				Err(e) => {
+					result = Err(LdkServerError::new(
+						e.error_code,
+						format!("Failed to publish event to sink {}: {}", sink.name, e.message),
+					));
+				},
+			}
+		}
+		result
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ldk_server_protos::events::{event_envelope, PaymentReceived};
+	use tokio::sync::mpsc;
+
+	use super::*;
+
+	/// Forwards all events to `sender`, or fails to publish any of them if `sender` is `None`.
+	struct TestEventPublisher {
+		sender: Option<mpsc::UnboundedSender<EventEnvelope>>,
+	}
+
+	#[async_trait]
+	impl EventPublisher for TestEventPublisher {
+		async fn publish(&self, event: EventEnvelope) -> Result<(), LdkServerError> {
+			match &self.sender {
+				Some(sender) => {
+					sender.send(event).unwrap();
+					Ok(())
+				},
+				None => Err(LdkServerError::new(InternalServerError, "Publisher unavailable")),
+			}
+		}
+	}
+
+	fn sink(
+		name: &str, sender: Option<mpsc::UnboundedSender<EventEnvelope>>, best_effort: bool,
+	) -> EventSink {
+		EventSink {
+			name: name.to_string(),
+			publisher: Arc::new(TestEventPublisher { sender }),
+			best_effort,
+		}
+	}
+
+	fn event() -> EventEnvelope {
+		EventEnvelope {
+			event: Some(event_envelope::Event::PaymentReceived(PaymentReceived::default())),
+			sequence_number: 1,
+			..Default::default()
+		}
+	}
+
+	#[tokio::test]
+	async fn test_publish_to_all_sinks() {
+		let (sender_a, mut receiver_a) = mpsc::unbounded_channel();
+		let (sender_b, mut receiver_b) = mpsc::unbounded_channel();
+		let publisher = CompositeEventPublisher::new(vec![
+			sink("a", Some(sender_a), false),
+			sink("b", Some(sender_b), true),
+		]);
+
+		publisher.publish(event()).await.unwrap();
+		assert_eq!(receiver_a.recv().await.unwrap(), event());
+		assert_eq!(receiver_b.recv().await.unwrap(), event());
+
+		// Without any sinks, events are discarded.
+		CompositeEventPublisher::new(Vec::new()).publish(event()).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_sink_failures() {
+		let (sender, mut receiver) = mpsc::unbounded_channel();
+
+		// Failures of best-effort sinks don't affect the publication.
+		let publisher = CompositeEventPublisher::new(vec![
+			sink("required", Some(sender.clone()), false),
+			sink("best-effort", None, true),
+		]);
+		publisher.publish(event()).await.unwrap();
+		assert_eq!(receiver.recv().await.unwrap(), event());
+
+		// Failures of required sinks fail the publication, even if other sinks published the event.
+		let publisher = CompositeEventPublisher::new(vec![
+			sink("best-effort", Some(sender), true),
+			sink("required", None, false),
+		]);
+		let err = publisher.publish(event()).await.unwrap_err();
+		assert_eq!(err.error_code, InternalServerError);
+		assert!(err.message.contains("required"));
+		assert_eq!(receiver.recv().await.unwrap(), event());
+	}
+}
@@ -17,8 +17,8 @@ use crate::api::error::LdkServerError;
 /// Implementors of this trait define how events are sent to various messaging
 /// systems. It provides a consistent, asynchronous interface for event publishing, while allowing
 /// each implementation to manage  its own initialization and configuration, typically sourced from
-/// the `ldk-server.config` file. A no-op implementation is included by default,
-/// with specific implementations enabled via feature flags.
+/// the `ldk-server.config` file. Events are published to all configured implementations, some of
+/// which are enabled via feature flags, and discarded if none is configured.
 ///
 /// Events are represented as [`EventEnvelope`] messages, which are Protocol Buffers
 /// ([protobuf](https://protobuf.dev/)) objects defined in [`ldk_server_protos::events`].
@@ -44,25 +44,10 @@ pub trait EventPublisher: Send + Sync {
 	/// # Errors
 	/// May return an [`LdkServerErrorCode::InternalServerError`] if the event cannot be published,
 	/// such as due to network failures, misconfiguration, or transport-specific issues.
-	/// If event publishing fails, the LDK Server will retry publishing the event indefinitely, which
-	/// may degrade performance until the underlying messaging system is operational again.
+	/// If event publishing fails, the LDK Server will retry publishing the event indefinitely. As
+	/// events are queued durably beforehand, this delays the publication of subsequent events until
+	/// the underlying messaging system is operational again, but not the processing of node events.
 	///
 	/// [`LdkServerErrorCode::InternalServerError`]: crate::api::error::LdkServerErrorCode
 	async fn publish(&self, event: EventEnvelope) -> Result<(), LdkServerError>;
 }
-
-/// A no-op implementation of the [`EventPublisher`] trait.
-#[cfg(not(feature = "events-rabbitmq"))]
-pub(crate) struct NoopEventPublisher;
-
-#[async_trait]
-#[cfg(not(feature = "events-rabbitmq"))]
-impl EventPublisher for NoopEventPublisher {
-	/// Publishes an event to a no-op sink, effectively discarding it.
-	///
-	/// This implementation does nothing and always returns `Ok(())`, serving as a
-	/// default when no messaging system is configured.
-	async fn publish(&self, _event: EventEnvelope) -> Result<(), LdkServerError> {
-		Ok(())
-	}
-}
@@ -0,0 +1,141 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use ldk_server_protos::events::EventEnvelope;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::io::events::event_publisher::EventPublisher;
+use crate::service::ContentType;
+
+/// An [`EventPublisher`] appending every event as a line of JSON to a file.
+///
+/// Every line is the JSON encoding of an [`EventEnvelope`], and is synced to disk before the event
+/// is considered published. Like the log file, the file is reopened on SIGHUP, allowing it to be
+/// rotated by external tools.
+pub(crate) struct FileEventPublisher {
+	file: Arc<Mutex<File>>,
+	path: PathBuf,
+}
+
+impl FileEventPublisher {
+	/// Opens the file at `path` for appending events, creating it and any missing parent
+	/// directories if necessary.
+	pub(crate) fn new(path: &Path) -> Result<Self, io::Error> {
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		let file = open_events_file(path)?;
+		Ok(Self { file: Arc::new(Mutex::new(file)), path: path.to_path_buf() })
+	}
+
+	/// Reopens the file. Called on SIGHUP for file rotation.
+	pub(crate) fn reopen(&self) -> Result<(), io::Error> {
+		let new_file = open_events_file(&self.path)?;
+		match self.file.lock() {
+			Ok(mut file) => {
+				file.sync_all()?;
+				*file = new_file;
+				Ok(())
+			},
+			Err(e) => Err(io::Error::other(format!("Failed to acquire lock: {e}"))),
+		}
+	}
+}
+
+#[async_trait]
+impl EventPublisher for FileEventPublisher {
+	/// Appends the event to the file, returning once it was synced to disk.
+	async fn publish(&self, event: EventEnvelope) -> Result<(), LdkServerError> {
+		// The JSON encoding of our messages doesn't contain any newlines.
+		let mut line = ContentType::Json.encode(&event);
+		line.push(b'\n');
+
+		let file = Arc::clone(&self.file);
+		tokio::task::spawn_blocking(move || append_line(&file, &line))
+			.await
+			.map_err(io::Error::other)
+			.and_then(|res| res)
+			.map_err(|e| {
+				LdkServerError::new(
+					InternalServerError,
+					format!("Failed to write event to file: {}", e),
+				)
+			})
+	}
+}
+
+fn append_line(file: &Mutex<File>, line: &[u8]) -> Result<(), io::Error> {
+	let mut file =
+		file.lock().map_err(|e| io::Error::other(format!("Failed to acquire lock: {e}")))?;
+	file.write_all(line)?;
+	file.sync_data()
+}
+
+fn open_events_file(path: &Path) -> Result<File, io::Error> {
+	OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+	use ldk_server_protos::events::{event_envelope, PaymentReceived};
+	use ldk_server_protos::types::Payment;
+
+	use super::*;
+	use crate::io::persist::sqlite_store::tests::random_storage_path;
+
+	fn event(sequence_number: u64) -> EventEnvelope {
+		EventEnvelope {
+			event: Some(event_envelope::Event::PaymentReceived(PaymentReceived {
+				payment: Some(Payment { id: "payment_id".to_string(), ..Default::default() }),
+			})),
+			sequence_number,
+			..Default::default()
+		}
+	}
+
+	fn read_events(path: &Path) -> Vec<EventEnvelope> {
+		fs::read_to_string(path)
+			.unwrap()
+			.lines()
+			.map(|line| serde_json::from_str(line).unwrap())
+			.collect()
+	}
+
+	#[tokio::test]
+	async fn test_publish_and_reopen() {
+		let path = random_storage_path().join("events.jsonl");
+		let publisher = FileEventPublisher::new(&path).unwrap();
+
+		publisher.publish(event(1)).await.unwrap();
+		publisher.publish(event(2)).await.unwrap();
+		assert_eq!(read_events(&path), vec![event(1), event(2)]);
+
+		// After rotating the file, events are appended to a newly created file.
+		let rotated_path = path.with_extension("jsonl.1");
+		fs::rename(&path, &rotated_path).unwrap();
+		publisher.publish(event(3)).await.unwrap();
+		publisher.reopen().unwrap();
+		publisher.publish(event(4)).await.unwrap();
+
+		assert_eq!(read_events(&rotated_path), vec![event(1), event(2), event(3)]);
+		assert_eq!(read_events(&path), vec![event(4)]);
+
+		// Reopening without rotating keeps appending to the same file.
+		publisher.reopen().unwrap();
+		publisher.publish(event(5)).await.unwrap();
+		assert_eq!(read_events(&path), vec![event(4), event(5)]);
+	}
+}
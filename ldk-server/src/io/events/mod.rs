@@ -7,13 +7,32 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
+pub(crate) mod broadcast;
+pub(crate) mod composite;
 pub(crate) mod event_publisher;
+pub(crate) mod file;
+pub(crate) mod outbox;
 
 #[cfg(feature = "events-rabbitmq")]
 pub(crate) mod rabbitmq;
 
+pub(crate) mod webhook;
+
 use ldk_server_protos::events::event_envelope;
 
+/// The names of all events, see [`get_event_name`].
+pub(crate) const EVENT_NAMES: [&str; 9] = [
+	"PaymentReceived",
+	"PaymentSuccessful",
+	"PaymentFailed",
+	"PaymentForwarded",
+	"ChannelPending",
+	"ChannelReady",
+	"ChannelClosed",
+	"SplicePending",
+	"SpliceFailed",
+];
+
 /// Event variant to event name mapping.
 pub(crate) fn get_event_name(event: &event_envelope::Event) -> &'static str {
 	match event {
@@ -21,5 +40,10 @@ pub(crate) fn get_event_name(event: &event_envelope::Event) -> &'static str {
 		event_envelope::Event::PaymentSuccessful(_) => "PaymentSuccessful",
 		event_envelope::Event::PaymentFailed(_) => "PaymentFailed",
 		event_envelope::Event::PaymentForwarded(_) => "PaymentForwarded",
+		event_envelope::Event::ChannelPending(_) => "ChannelPending",
+		event_envelope::Event::ChannelReady(_) => "ChannelReady",
+		event_envelope::Event::ChannelClosed(_) => "ChannelClosed",
+		event_envelope::Event::SplicePending(_) => "SplicePending",
+		event_envelope::Event::SpliceFailed(_) => "SpliceFailed",
 	}
 }
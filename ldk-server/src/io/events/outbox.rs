@@ -0,0 +1,584 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hex::DisplayHex;
+use ldk_server_protos::events::{event_envelope, EventEnvelope};
+use log::{error, warn};
+use prost::Message;
+use tokio::sync::Notify;
+
+use crate::io::events::broadcast::EventBroadcaster;
+use crate::io::events::event_publisher::EventPublisher;
+use crate::io::events::get_event_name;
+use crate::io::persist::paginated_kv_store::{
+	Attribute, AttributeValue, PaginatedKVStore, WriteOp,
+};
+use crate::io::persist::{
+	EVENTS_PERSISTENCE_PRIMARY_NAMESPACE, EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	EVENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE, EVENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+	EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE, EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+	EVENT_TYPE_ATTRIBUTE,
+};
+
+/// The delay before retrying to publish an event for the first time, doubling with every
+/// subsequent retry.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// The key under which the sequence number of the most recently emitted event is persisted.
+const SEQUENCE_NUMBER_KEY: &str = "sequence_number";
+
+/// A durable queue of the events which are yet to be published.
+///
+/// Events are persisted to the outbox, as well as to the event history retrievable via the
+/// `ListEvents` API, atomically with the state they are about, e.g., the updated payment, see
+/// [`EventOutbox::enqueue`]. A separate task, see [`EventOutbox::run`], then
+/// publishes them in the order they were queued in, retrying failed publications until they
+/// succeed. Hence, an unavailable [`EventPublisher`] only delays the publication of events, rather
+/// than blocking the processing of further events of the node.
+///
+/// Events are only removed from the outbox once published, and are thus published again if LDK
+/// Server stops in between. Such redeliveries carry the same [`EventEnvelope::event_id`] and
+/// [`EventEnvelope::sequence_number`], which are assigned when the event is queued.
+///
+/// Queued events are also handed out to live subscribers via the [`EventBroadcaster`] right away,
+/// rather than once published, so that subscribers don't depend on the [`EventPublisher`].
+pub(crate) struct EventOutbox {
+	store: Arc<dyn PaginatedKVStore>,
+	node_id: String,
+	broadcaster: Arc<EventBroadcaster>,
+	state: Mutex<OutboxState>,
+	notify: Notify,
+}
+
+struct OutboxState {
+	// The time of the most recently queued event. Events are keyed by their time, which is
+	// strictly increasing so that the keys are unique and preserve the order of the events.
+	last_time: i64,
+	// The sequence number of the most recently queued event, persisted alongside every event.
+	last_sequence_number: u64,
+}
+
+impl EventOutbox {
+	/// Constructs an [`EventOutbox`] for the events emitted by the node with the given `node_id`,
+	/// picking up any events which were not published before LDK Server stopped.
+	///
+	/// Events queued from now on are handed out to the subscribers of `broadcaster`.
+	pub(crate) fn new(
+		store: Arc<dyn PaginatedKVStore>, node_id: String, broadcaster: Arc<EventBroadcaster>,
+	) -> io::Result<Self> {
+		// Keys are listed most recent first.
+		let last_time = match store
+			.list(
+				EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+				None,
+			)?
+			.keys
+			.first()
+		{
+			Some(key) => key.parse::<i64>().map_err(|e| {
+				io::Error::new(
+					io::ErrorKind::InvalidData,
+					format!("Invalid event outbox key {key}: {e}"),
+				)
+			})?,
+			None => 0,
+		};
+
+		let last_sequence_number = match store.read(
+			EVENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			EVENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			SEQUENCE_NUMBER_KEY,
+		) {
+			Ok(buf) => u64::from_be_bytes(buf.try_into().map_err(|_| {
+				io::Error::new(
+					io::ErrorKind::InvalidData,
+					"Invalid persisted event sequence number",
+				)
+			})?),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+			Err(e) => return Err(e),
+		};
+
+		let notify = Notify::new();
+		// Makes `run` check for left-over events right away.
+		notify.notify_one();
+
+		let state = OutboxState { last_time, last_sequence_number };
+		Ok(Self { store, node_id, broadcaster, state: Mutex::new(state), notify })
+	}
+
+	/// Queues `event` for publication, persisting it atomically with the given `writes`.
+	///
+	/// Returns the queued [`EventEnvelope`], which carries the next sequence number, a new event id,
+	/// the current time and our node id. Once persisted, the event is broadcast to all current
+	/// subscribers.
+	pub(crate) fn enqueue(
+		&self, event: event_envelope::Event, writes: &[WriteOp<'_>],
+	) -> io::Result<EventEnvelope> {
+		let mut state = self.state.lock().unwrap();
+
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970");
+		let time = (now.as_micros() as i64).max(state.last_time + 1);
+		let sequence_number = state.last_sequence_number + 1;
+
+		let mut event_id = [0u8; 16];
+		getrandom::getrandom(&mut event_id).map_err(io::Error::other)?;
+
+		// The history is filtered by the type of the events via the `ListEvents` API.
+		let history_attributes = [Attribute {
+			name: EVENT_TYPE_ATTRIBUTE,
+			value: AttributeValue::Text(get_event_name(&event)),
+		}];
+		let envelope = EventEnvelope {
+			event: Some(event),
+			sequence_number,
+			event_id: event_id.to_lower_hex_string(),
+			timestamp: now.as_secs(),
+			node_id: self.node_id.clone(),
+		};
+
+		let key = outbox_key(time);
+		let history_key = event_history_key(sequence_number);
+		let buf = envelope.encode_to_vec();
+		let sequence_number_buf = sequence_number.to_be_bytes();
+
+		let mut batch = Vec::with_capacity(writes.len() + 3);
+		batch.push(WriteOp {
+			primary_namespace: EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: &key,
+			time,
+			buf: &buf,
+			attributes: &[],
+		});
+		batch.push(WriteOp {
+			primary_namespace: EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: &history_key,
+			time: event_history_time(sequence_number),
+			buf: &buf,
+			attributes: &history_attributes,
+		});
+		batch.push(WriteOp {
+			primary_namespace: EVENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: EVENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: SEQUENCE_NUMBER_KEY,
+			time: 0,
+			buf: &sequence_number_buf,
+			attributes: &[],
+		});
+		batch.extend_from_slice(writes);
+		self.store.write_batch(&batch)?;
+
+		state.last_time = time;
+		state.last_sequence_number = sequence_number;
+		// Broadcast while holding the lock, so that subscribers receive events in order.
+		self.broadcaster.broadcast(envelope.clone());
+		drop(state);
+
+		self.notify.notify_one();
+		Ok(envelope)
+	}
+
+	/// Publishes the queued events via `publisher`, oldest first, for as long as LDK Server runs.
+	pub(crate) async fn run(self: Arc<Self>, publisher: Arc<dyn EventPublisher>) {
+		loop {
+			self.notify.notified().await;
+
+			loop {
+				let keys = match self.queued_keys() {
+					Ok(keys) => keys,
+					Err(e) => {
+						error!("Failed to list queued events: {e}");
+						tokio::time::sleep(MAX_RETRY_DELAY).await;
+						continue;
+					},
+				};
+				if keys.is_empty() {
+					break;
+				}
+
+				for key in keys {
+					self.publish_with_retries(&key, &*publisher).await;
+				}
+			}
+		}
+	}
+
+	/// Returns the keys of all queued events, oldest first.
+	fn queued_keys(&self) -> io::Result<Vec<String>> {
+		let mut keys = Vec::new();
+		let mut page_token = None;
+		loop {
+			let response = self.store.list(
+				EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+				page_token,
+			)?;
+			keys.extend(response.keys);
+			match response.next_page_token {
+				Some(token) => page_token = Some(token),
+				None => break,
+			}
+		}
+		keys.reverse();
+		Ok(keys)
+	}
+
+	async fn publish_with_retries(&self, key: &str, publisher: &dyn EventPublisher) {
+		let mut attempt = 0;
+		loop {
+			match self.publish(key, publisher).await {
+				Ok(()) => return,
+				Err(e) => {
+					let delay = retry_delay(attempt);
+					warn!("Failed to publish queued event {key}, retrying in {delay:?}: {e}");
+					tokio::time::sleep(delay).await;
+					attempt = attempt.saturating_add(1);
+				},
+			}
+		}
+	}
+
+	async fn publish(&self, key: &str, publisher: &dyn EventPublisher) -> Result<(), String> {
+		let buf = self
+			.store
+			.read(
+				EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+				key,
+			)
+			.map_err(|e| e.to_string())?;
+
+		match EventEnvelope::decode(&buf[..]) {
+			Ok(event) => publisher.publish(event).await.map_err(|e| e.to_string())?,
+			// Retrying won't help, so we drop the event rather than blocking all others behind it.
+			Err(e) => error!("Dropping queued event {key} which failed to decode: {e}"),
+		}
+
+		self.store
+			.remove(
+				EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+				key,
+			)
+			.map_err(|e| e.to_string())
+	}
+}
+
+/// Returns the key of the event queued at `time`, zero-padded so that keys sort like their times.
+fn outbox_key(time: i64) -> String {
+	format!("{time:020}")
+}
+
+/// Returns the key of the event with the given `sequence_number` in the event history, zero-padded
+/// so that keys sort like their sequence numbers.
+pub(crate) fn event_history_key(sequence_number: u64) -> String {
+	format!("{sequence_number:020}")
+}
+
+/// Returns the `time` of the event with the given `sequence_number` in the event history.
+///
+/// As [`PaginatedKVStore::list`] returns keys in descending order of `time`, while the event history
+/// is listed oldest first, this is the negated sequence number. Sequence numbers beyond
+/// [`i64::MAX`], which are never assigned but may be requested, are clamped to it, so that no events
+/// follow them.
+pub(crate) fn event_history_time(sequence_number: u64) -> i64 {
+	-(sequence_number.min(i64::MAX as u64) as i64)
+}
+
+/// Returns the delay before retrying to publish an event which failed to publish `attempt` times
+/// before.
+fn retry_delay(attempt: u32) -> Duration {
+	INITIAL_RETRY_DELAY.saturating_mul(2u32.saturating_pow(attempt)).min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	use async_trait::async_trait;
+	use ldk_server_protos::events::{ChannelReady, PaymentReceived};
+	use ldk_server_protos::types::Payment;
+	use tokio::sync::mpsc;
+
+	use super::*;
+	use crate::api::error::LdkServerError;
+	use crate::api::error::LdkServerErrorCode::InternalServerError;
+	use crate::io::events::composite::{CompositeEventPublisher, EventSink};
+	use crate::io::persist::paginated_kv_store::ListFilter;
+	use crate::io::persist::sqlite_store::tests::{create_store, random_storage_path};
+	use crate::io::persist::{
+		PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE, PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	};
+
+	/// Fails to publish the first `failures` events, forwarding all others to `sender`.
+	struct FlakyEventPublisher {
+		failures: AtomicUsize,
+		sender: mpsc::UnboundedSender<EventEnvelope>,
+	}
+
+	#[async_trait]
+	impl EventPublisher for FlakyEventPublisher {
+		async fn publish(&self, event: EventEnvelope) -> Result<(), LdkServerError> {
+			let remaining = self.failures.load(Ordering::SeqCst);
+			if remaining > 0 {
+				self.failures.store(remaining - 1, Ordering::SeqCst);
+				return Err(LdkServerError::new(InternalServerError, "Publisher unavailable"));
+			}
+			self.sender.send(event).unwrap();
+			Ok(())
+		}
+	}
+
+	const NODE_ID: &str = "02eadbd9e7557375161df8b646776a547c5cbc2e95b3071ec81553f8ec2cea3b8c";
+
+	fn create_outbox(store: Arc<dyn PaginatedKVStore>) -> EventOutbox {
+		EventOutbox::new(store, NODE_ID.to_string(), Arc::new(EventBroadcaster::new())).unwrap()
+	}
+
+	fn payment_received_event(payment_id: &str) -> event_envelope::Event {
+		event_envelope::Event::PaymentReceived(PaymentReceived {
+			payment: Some(Payment { id: payment_id.to_string(), ..Default::default() }),
+		})
+	}
+
+	fn enqueue_payment_received(outbox: &EventOutbox, payment_id: &str) -> EventEnvelope {
+		let payment = Payment { id: payment_id.to_string(), ..Default::default() }.encode_to_vec();
+		let write = WriteOp {
+			primary_namespace: PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: payment_id,
+			time: 0,
+			buf: &payment,
+			attributes: &[],
+		};
+		outbox.enqueue(payment_received_event(payment_id), &[write]).unwrap()
+	}
+
+	#[test]
+	fn test_enqueue_persists_event_with_writes() {
+		let store = create_store(random_storage_path());
+		let outbox = create_outbox(Arc::clone(&store));
+
+		let events: Vec<_> =
+			(0..3).map(|i| enqueue_payment_received(&outbox, &format!("payment_{i}"))).collect();
+
+		let keys = outbox.queued_keys().unwrap();
+		assert_eq!(keys.len(), 3);
+		for (i, key) in keys.iter().enumerate() {
+			let buf = store
+				.read(
+					EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE,
+					EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE,
+					key,
+				)
+				.unwrap();
+			let payment_id = format!("payment_{i}");
+			let event = EventEnvelope::decode(&buf[..]).unwrap();
+			assert_eq!(event, events[i]);
+			assert_eq!(event.event, Some(payment_received_event(&payment_id)));
+			assert_eq!(event.sequence_number, i as u64 + 1);
+			assert_eq!(event.event_id.len(), 32);
+			assert_eq!(event.node_id, NODE_ID);
+			assert!(event.timestamp > 0);
+			assert!(store
+				.read(
+					PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+					PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+					&payment_id
+				)
+				.is_ok());
+		}
+		assert_ne!(events[0].event_id, events[1].event_id);
+
+		// The event history is listed oldest first.
+		let history = store
+			.list(
+				EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				None,
+			)
+			.unwrap();
+		assert_eq!(history.keys, (1..=3).map(event_history_key).collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn test_event_history_by_event_type() {
+		let store = create_store(random_storage_path());
+		let outbox = create_outbox(Arc::clone(&store));
+		enqueue_payment_received(&outbox, "payment_0");
+		outbox.enqueue(event_envelope::Event::ChannelReady(ChannelReady::default()), &[]).unwrap();
+		enqueue_payment_received(&outbox, "payment_1");
+
+		let list_keys = |values: &[&str]| {
+			let filters = [ListFilter::TextAttributeIn { name: EVENT_TYPE_ATTRIBUTE, values }];
+			store
+				.list_filtered(
+					EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+					EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+					&filters,
+					None,
+				)
+				.unwrap()
+				.keys
+		};
+		let history_keys = |sequence_numbers: &[u64]| {
+			sequence_numbers.iter().copied().map(event_history_key).collect::<Vec<_>>()
+		};
+		assert_eq!(list_keys(&["PaymentReceived"]), history_keys(&[1, 3]));
+		assert_eq!(list_keys(&["ChannelReady"]), history_keys(&[2]));
+		assert_eq!(list_keys(&["ChannelReady", "PaymentReceived"]), history_keys(&[1, 2, 3]));
+		assert!(list_keys(&["ChannelClosed"]).is_empty());
+	}
+
+	#[test]
+	fn test_event_history_since_sequence_number() {
+		let store = create_store(random_storage_path());
+		let outbox = create_outbox(Arc::clone(&store));
+		for i in 0..5 {
+			enqueue_payment_received(&outbox, &format!("payment_{i}"));
+		}
+
+		// Listing from the position of an event returns all events following it.
+		let page_token = Some((event_history_key(2), event_history_time(2)));
+		let history = store
+			.list(
+				EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				page_token,
+			)
+			.unwrap();
+		assert_eq!(history.keys, (3..=5).map(event_history_key).collect::<Vec<_>>());
+
+		let page_token = Some((event_history_key(0), event_history_time(0)));
+		let history = store
+			.list(
+				EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				page_token,
+			)
+			.unwrap();
+		assert_eq!(history.keys.len(), 5);
+
+		// No events follow sequence numbers which can't be assigned.
+		for since in [i64::MAX as u64, u64::MAX] {
+			let page_token = Some((event_history_key(since), event_history_time(since)));
+			let history = store
+				.list(
+					EVENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+					EVENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+					page_token,
+				)
+				.unwrap();
+			assert!(history.keys.is_empty());
+		}
+	}
+
+	#[test]
+	fn test_outbox_keys_are_ordered_across_restarts() {
+		let storage_path = random_storage_path();
+		let outbox = create_outbox(create_store(storage_path.clone()));
+		enqueue_payment_received(&outbox, "payment_0");
+		let first_key = outbox.queued_keys().unwrap().remove(0);
+
+		// Pretend the event was queued far in the future, e.g., before the clock was reset.
+		let future_time = i64::MAX / 2;
+		outbox.state.lock().unwrap().last_time = future_time - 1;
+		enqueue_payment_received(&outbox, "payment_1");
+		drop(outbox);
+
+		let outbox = create_outbox(create_store(storage_path));
+		assert_eq!(outbox.state.lock().unwrap().last_time, future_time);
+
+		// Sequence numbers continue where they left off.
+		let event = enqueue_payment_received(&outbox, "payment_2");
+		assert_eq!(event.sequence_number, 3);
+
+		let keys = outbox.queued_keys().unwrap();
+		assert_eq!(keys[0], first_key);
+		assert_eq!(keys[1], outbox_key(future_time));
+		assert_eq!(keys[2], outbox_key(future_time + 1));
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn test_run_publishes_events_in_order_with_retries() {
+		let outbox = Arc::new(create_outbox(create_store(random_storage_path())));
+
+		// Queueing events doesn't depend on the publisher being available.
+		let first = enqueue_payment_received(&outbox, "payment_0");
+		let second = enqueue_payment_received(&outbox, "payment_1");
+
+		let (sender, mut receiver) = mpsc::unbounded_channel();
+		let publisher = Arc::new(FlakyEventPublisher { failures: AtomicUsize::new(3), sender });
+		tokio::spawn(Arc::clone(&outbox).run(publisher));
+
+		assert_eq!(receiver.recv().await.unwrap(), first);
+		assert_eq!(receiver.recv().await.unwrap(), second);
+
+		let third = enqueue_payment_received(&outbox, "payment_2");
+		assert_eq!(receiver.recv().await.unwrap(), third);
+
+		// Published events are removed from the outbox.
+		tokio::task::yield_now().await;
+		assert!(outbox.queued_keys().unwrap().is_empty());
+	}
+
+	struct FailingEventPublisher;
+
+	#[async_trait]
+	impl EventPublisher for FailingEventPublisher {
+		async fn publish(&self, _event: EventEnvelope) -> Result<(), LdkServerError> {
+			Err(LdkServerError::new(InternalServerError, "Publisher unavailable"))
+		}
+	}
+
+	#[tokio::test(start_paused = true)]
+	async fn test_broadcast_despite_failing_required_sink() {
+		let store = create_store(random_storage_path());
+		let broadcaster = Arc::new(EventBroadcaster::new());
+		let mut receiver = broadcaster.subscribe();
+		let outbox = Arc::new(
+			EventOutbox::new(store, NODE_ID.to_string(), Arc::clone(&broadcaster)).unwrap(),
+		);
+		let publisher = Arc::new(CompositeEventPublisher::new(vec![EventSink {
+			name: "webhook".to_string(),
+			publisher: Arc::new(FailingEventPublisher),
+			best_effort: false,
+		}]));
+		tokio::spawn(Arc::clone(&outbox).run(publisher));
+
+		// Subscribers receive every event once queued, even though the first event is still being
+		// retried and holds back the publication of all later ones.
+		let first = enqueue_payment_received(&outbox, "payment_0");
+		assert_eq!(receiver.recv().await.unwrap(), first);
+		tokio::time::sleep(Duration::from_secs(10)).await;
+		let second = enqueue_payment_received(&outbox, "payment_1");
+		let third = enqueue_payment_received(&outbox, "payment_2");
+		assert_eq!(receiver.recv().await.unwrap(), second);
+		assert_eq!(receiver.recv().await.unwrap(), third);
+		assert!(receiver.try_recv().is_err());
+		assert_eq!(outbox.queued_keys().unwrap().len(), 3);
+	}
+
+	#[test]
+	fn test_retry_delay() {
+		assert_eq!(retry_delay(0), Duration::from_secs(1));
+		assert_eq!(retry_delay(2), Duration::from_secs(4));
+		assert_eq!(retry_delay(10), MAX_RETRY_DELAY);
+		assert_eq!(retry_delay(u32::MAX), MAX_RETRY_DELAY);
+	}
+}
@@ -0,0 +1,171 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use ldk_node::bitcoin::hashes::hmac::{Hmac, HmacEngine};
+use ldk_node::bitcoin::hashes::{sha256, Hash, HashEngine};
+use ldk_server_protos::events::EventEnvelope;
+use log::warn;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Client;
+
+use crate::api::error::LdkServerError;
+use crate::api::error::LdkServerErrorCode::InternalServerError;
+use crate::io::events::event_publisher::EventPublisher;
+use crate::service::ContentType;
+use crate::util::config::{WebhookConfig, WebhookFormat};
+
+/// The header carrying the signature of a delivery, formatted as `HMAC <timestamp>:<hmac_hex>`.
+///
+/// The HMAC is computed like the one of API requests, i.e., as
+/// `HMAC-SHA256(secret, timestamp_bytes || body)`, where `timestamp_bytes` is the big-endian,
+/// eight-byte encoding of the UNIX timestamp in seconds.
+const SIGNATURE_HEADER: &str = "X-LDK-Server-Signature";
+
+/// The number of times a failed delivery is retried, unless configured otherwise.
+const DEFAULT_MAX_RETRIES: u32 = 5;
+
+/// The delay before the first retry, doubling with every subsequent one.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An [`EventPublisher`] delivering every event via HTTP POST to the configured webhook URLs.
+///
+/// Deliveries are signed with the configured secret, see [`SIGNATURE_HEADER`], and are considered
+/// successful once a URL responds with a `2xx` status code. Failed deliveries are retried with
+/// exponential backoff. An event is only published once it was delivered to all URLs, which means
+/// URLs may receive the same event more than once if another one was unavailable.
+pub(crate) struct WebhookEventPublisher {
+	client: Client,
+	config: WebhookConfig,
+	content_type: ContentType,
+}
+
+impl WebhookEventPublisher {
+	pub(crate) fn new(config: WebhookConfig) -> Result<Self, LdkServerError> {
+		let client = Client::builder().timeout(REQUEST_TIMEOUT).build().map_err(|e| {
+			LdkServerError::new(
+				InternalServerError,
+				format!("Failed to build webhook HTTP client: {}", e),
+			)
+		})?;
+		let content_type = match config.format {
+			WebhookFormat::Protobuf => ContentType::Protobuf,
+			WebhookFormat::Json => ContentType::Json,
+		};
+		Ok(Self { client, config, content_type })
+	}
+
+	async fn deliver(&self, url: &str, body: &[u8]) -> Result<(), String> {
+		let timestamp =
+			SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970").as_secs();
+
+		let response = self
+			.client
+			.post(url)
+			.header(CONTENT_TYPE, self.content_type.as_str())
+			.header(SIGNATURE_HEADER, sign(&self.config.secret, timestamp, body))
+			.body(body.to_vec())
+			.send()
+			.await
+			.map_err(|e| e.to_string())?;
+
+		if response.status().is_success() {
+			Ok(())
+		} else {
+			Err(format!("Received status {}", response.status()))
+		}
+	}
+
+	async fn deliver_with_retries(&self, url: &str, body: &[u8]) -> Result<(), LdkServerError> {
+		let max_retries = self.config.max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+		let mut attempt = 0;
+		loop {
+			match self.deliver(url, body).await {
+				Ok(()) => return Ok(()),
+				Err(e) if attempt < max_retries => {
+					let delay = retry_delay(attempt);
+					warn!("Failed to deliver event to webhook {url}, retrying in {delay:?}: {e}");
+					tokio::time::sleep(delay).await;
+					attempt += 1;
+				},
+				Err(e) => {
+					return Err(LdkServerError::new(
+						InternalServerError,
+						format!("Failed to deliver event to webhook {url}: {e}"),
+					))
+				},
+			}
+		}
+	}
+}
+
+#[async_trait]
+impl EventPublisher for WebhookEventPublisher {
+	/// Delivers an event to all configured webhook URLs.
+	///
+	/// Returns an error if any of the URLs still failed to accept the event after all retries.
+	async fn publish(&self, event: EventEnvelope) -> Result<(), LdkServerError> {
+		let body = self.content_type.encode(&event);
+		for url in &self.config.urls {
+			self.deliver_with_retries(url, &body).await?;
+		}
+		Ok(())
+	}
+}
+
+/// Computes the value of the [`SIGNATURE_HEADER`] of a delivery of `body` at `timestamp`.
+fn sign(secret: &str, timestamp: u64, body: &[u8]) -> String {
+	let mut hmac_engine: HmacEngine<sha256::Hash> = HmacEngine::new(secret.as_bytes());
+	hmac_engine.input(&timestamp.to_be_bytes());
+	hmac_engine.input(body);
+	let hmac = Hmac::<sha256::Hash>::from_engine(hmac_engine);
+	format!("HMAC {timestamp}:{hmac}")
+}
+
+/// Returns the delay before retrying a delivery that failed `attempt` times before.
+fn retry_delay(attempt: u32) -> Duration {
+	INITIAL_RETRY_DELAY.saturating_mul(2u32.saturating_pow(attempt)).min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_sign() {
+		let body = b"event";
+		let signature = sign("secret", 1_700_000_000, body);
+
+		let (timestamp, hmac_hex) =
+			signature.strip_prefix("HMAC ").and_then(|s| s.split_once(':')).unwrap();
+		assert_eq!(timestamp, "1700000000");
+		assert_eq!(hmac_hex.len(), 64);
+
+		// The signature covers the secret, the timestamp and the body.
+		assert_eq!(sign("secret", 1_700_000_000, body), signature);
+		assert_ne!(sign("other secret", 1_700_000_000, body), signature);
+		assert_ne!(sign("secret", 1_700_000_001, body), signature);
+		assert_ne!(sign("secret", 1_700_000_000, b"other event"), signature);
+	}
+
+	#[test]
+	fn test_retry_delay() {
+		assert_eq!(retry_delay(0), Duration::from_millis(500));
+		assert_eq!(retry_delay(1), Duration::from_secs(1));
+		assert_eq!(retry_delay(3), Duration::from_secs(4));
+		assert_eq!(retry_delay(10), MAX_RETRY_DELAY);
+		assert_eq!(retry_delay(u32::MAX), MAX_RETRY_DELAY);
+	}
+}
@@ -8,12 +8,526 @@
 // licenses.
 
 pub(crate) mod paginated_kv_store;
+#[cfg(feature = "storage-postgres")]
+pub(crate) mod postgres_store;
 pub(crate) mod sqlite_store;
 
+use std::collections::BTreeSet;
+use std::io;
+
+use bytes::Bytes;
+use ldk_node::bitcoin::hashes::{sha256, Hash};
+use ldk_server_protos::types::payment_kind::Kind;
+use ldk_server_protos::types::{ForwardedPayment, Payment, PaymentKindType, PaymentMetadata};
+use prost::Message;
+
+use crate::io::persist::paginated_kv_store::{
+	Attribute, AttributeValue, PaginatedKVStore, WriteOp,
+};
+
 /// The forwarded payments will be persisted under this prefix.
 pub(crate) const FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE: &str = "forwarded_payments";
 pub(crate) const FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
 
+/// The [`ForwardedPaymentMarker`] of the last forwarded payment will be persisted under this prefix
+/// and key.
+pub(crate) const FORWARDED_PAYMENT_MARKER_PERSISTENCE_PRIMARY_NAMESPACE: &str =
+	"forwarded_payment_marker";
+pub(crate) const FORWARDED_PAYMENT_MARKER_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+pub(crate) const FORWARDED_PAYMENT_MARKER_PERSISTENCE_KEY: &str = "last_forwarded_payment";
+
+/// The names of the [`Attribute`]s forwarded payments are persisted with, see
+/// [`forwarded_payment_attributes`].
+pub(crate) const FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE: &str = "prev_channel_id";
+pub(crate) const FORWARDED_PAYMENT_NEXT_CHANNEL_ID_ATTRIBUTE: &str = "next_channel_id";
+pub(crate) const FORWARDED_PAYMENT_PREV_NODE_ID_ATTRIBUTE: &str = "prev_node_id";
+pub(crate) const FORWARDED_PAYMENT_NEXT_NODE_ID_ATTRIBUTE: &str = "next_node_id";
+
 /// The payments will be persisted under this prefix.
 pub(crate) const PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE: &str = "payments";
 pub(crate) const PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The names of the [`Attribute`]s payments are persisted with, see [`payment_attributes`].
+pub(crate) const PAYMENT_STATUS_ATTRIBUTE: &str = "status";
+pub(crate) const PAYMENT_DIRECTION_ATTRIBUTE: &str = "direction";
+pub(crate) const PAYMENT_KIND_ATTRIBUTE: &str = "kind";
+pub(crate) const PAYMENT_LATEST_UPDATE_TIMESTAMP_ATTRIBUTE: &str = "latest_update_timestamp";
+pub(crate) const PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE: &str = "update_sequence_number";
+
+/// The sequence number of the most recent payment update will be persisted under this prefix and
+/// key, see [`PaymentUpdateSequence`].
+pub(crate) const PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_PRIMARY_NAMESPACE: &str =
+	"payment_update_sequence";
+pub(crate) const PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+pub(crate) const PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_KEY: &str = "last_update_sequence_number";
+
+/// The metadata supplied for payments will be persisted under this prefix, keyed by payment id.
+pub(crate) const PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE: &str = "payment_metadata";
+pub(crate) const PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The name of the [`Attribute`]s payment metadata is persisted with, see
+/// [`payment_metadata_attributes`].
+pub(crate) const PAYMENT_METADATA_LABEL_ATTRIBUTE: &str = "label";
+
+/// The metadata supplied for offers will be persisted under this prefix, keyed by offer id. It is
+/// copied to the metadata of every payment received for the offer.
+pub(crate) const OFFER_METADATA_PERSISTENCE_PRIMARY_NAMESPACE: &str = "offer_metadata";
+pub(crate) const OFFER_METADATA_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The API keys created at runtime will be persisted under this prefix.
+pub(crate) const API_KEYS_PERSISTENCE_PRIMARY_NAMESPACE: &str = "api_keys";
+pub(crate) const API_KEYS_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The payments counted towards the daily spending budgets will be persisted under this prefix.
+pub(crate) const SPENDING_PERSISTENCE_PRIMARY_NAMESPACE: &str = "spending";
+pub(crate) const SPENDING_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The payments submitted for approval will be persisted under this prefix.
+pub(crate) const APPROVALS_PERSISTENCE_PRIMARY_NAMESPACE: &str = "approvals";
+pub(crate) const APPROVALS_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The name of the [`AttributeValue::Text`] attribute approvals are persisted with, holding whether
+/// they are still pending, or were approved or rejected, so that pending ones can be listed without
+/// reading all reviewed ones.
+pub(crate) const APPROVAL_STATUS_ATTRIBUTE: &str = "status";
+
+/// The audit log of state-changing API calls will be persisted under this prefix.
+pub(crate) const AUDIT_LOG_PERSISTENCE_PRIMARY_NAMESPACE: &str = "audit_log";
+pub(crate) const AUDIT_LOG_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The events which are yet to be published will be persisted under this prefix.
+pub(crate) const EVENT_OUTBOX_PERSISTENCE_PRIMARY_NAMESPACE: &str = "event_outbox";
+pub(crate) const EVENT_OUTBOX_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The metadata of emitted events, e.g., the sequence number of the most recent one, will be
+/// persisted under this prefix.
+pub(crate) const EVENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE: &str = "event_metadata";
+pub(crate) const EVENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The history of all emitted events will be persisted under this prefix.
+pub(crate) const EVENTS_PERSISTENCE_PRIMARY_NAMESPACE: &str = "events";
+pub(crate) const EVENTS_PERSISTENCE_SECONDARY_NAMESPACE: &str = "";
+
+/// The name of the [`Attribute`] events are persisted with in the event history, holding their
+/// name, see [`get_event_name`].
+///
+/// [`get_event_name`]: crate::io::events::get_event_name
+pub(crate) const EVENT_TYPE_ATTRIBUTE: &str = "event_type";
+
+/// Returns the [`Attribute`]s a payment is persisted with, allowing payments to be filtered by
+/// their status, direction, kind and the time of their latest update, and to be listed in the order
+/// they were updated in by the given `update_sequence_number`, see [`PaymentUpdateSequence`].
+pub(crate) fn payment_attributes(
+	payment: &Payment, update_sequence_number: u64,
+) -> Vec<Attribute<'static>> {
+	let integer_attribute = |name, value| Attribute { name, value: AttributeValue::Integer(value) };
+	let mut attributes = vec![
+		integer_attribute(PAYMENT_STATUS_ATTRIBUTE, payment.status as i64),
+		integer_attribute(PAYMENT_DIRECTION_ATTRIBUTE, payment.direction as i64),
+		integer_attribute(
+			PAYMENT_LATEST_UPDATE_TIMESTAMP_ATTRIBUTE,
+			payment.latest_update_timestamp as i64,
+		),
+		integer_attribute(PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE, update_sequence_number as i64),
+	];
+	if let Some(kind) = payment.kind.as_ref().and_then(|k| k.kind.as_ref()) {
+		let kind_type = match kind {
+			Kind::Onchain(_) => PaymentKindType::Onchain,
+			Kind::Bolt11(_) => PaymentKindType::Bolt11,
+			Kind::Bolt11Jit(_) => PaymentKindType::Bolt11Jit,
+			Kind::Bolt12Offer(_) => PaymentKindType::Bolt12Offer,
+			Kind::Bolt12Refund(_) => PaymentKindType::Bolt12Refund,
+			Kind::Spontaneous(_) => PaymentKindType::Spontaneous,
+		};
+		attributes.push(integer_attribute(PAYMENT_KIND_ATTRIBUTE, kind_type as i64));
+	}
+	attributes
+}
+
+/// Assigns payment updates their local sequence numbers, in the order they are persisted in.
+///
+/// A payment's `latest_update_timestamp` is set by LDK before we get to persist the update, so
+/// updates may be persisted out of the order of their timestamps. Payments are therefore persisted
+/// with the sequence number of their latest update, which is assigned by the event loop right before
+/// persisting it, along with the sequence number itself. Clients syncing payments by their sequence
+/// numbers thus never miss an update persisted after they synced.
+pub(crate) struct PaymentUpdateSequence {
+	last: u64,
+}
+
+impl PaymentUpdateSequence {
+	/// Loads the sequence number of the last payment update persisted before the restart.
+	pub(crate) fn load(store: &dyn PaginatedKVStore) -> io::Result<Self> {
+		let last = match store.read(
+			PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_SECONDARY_NAMESPACE,
+			PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_KEY,
+		) {
+			Ok(buf) => u64::from_be_bytes(buf.try_into().map_err(|_| {
+				io::Error::new(
+					io::ErrorKind::InvalidData,
+					"Invalid persisted payment update sequence number",
+				)
+			})?),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+			Err(e) => return Err(e),
+		};
+		Ok(Self { last })
+	}
+
+	/// Returns the sequence number to persist the next payment update with.
+	pub(crate) fn next(&self) -> u64 {
+		self.last + 1
+	}
+
+	/// Records that a payment update was persisted with `sequence_number`.
+	pub(crate) fn persisted(&mut self, sequence_number: u64) {
+		self.last = sequence_number;
+	}
+}
+
+/// Returns the write persisting `sequence_number_buf`, the big-endian bytes of the sequence number
+/// a payment update is persisted with, as the last one assigned, see [`PaymentUpdateSequence`].
+pub(crate) fn payment_update_sequence_write(sequence_number_buf: &[u8]) -> WriteOp<'_> {
+	WriteOp {
+		primary_namespace: PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_PRIMARY_NAMESPACE,
+		secondary_namespace: PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_SECONDARY_NAMESPACE,
+		key: PAYMENT_UPDATE_SEQUENCE_PERSISTENCE_KEY,
+		time: 0,
+		buf: sequence_number_buf,
+		attributes: &[],
+	}
+}
+
+/// Returns the [`Attribute`]s payment metadata is persisted with, allowing payments to be looked up
+/// by their labels.
+pub(crate) fn payment_metadata_attributes(metadata: &PaymentMetadata) -> Vec<Attribute<'_>> {
+	// Attributes have to be unique, so each label is only persisted once.
+	let labels: BTreeSet<&str> = metadata.labels.iter().map(String::as_str).collect();
+	labels
+		.into_iter()
+		.map(|label| Attribute {
+			name: PAYMENT_METADATA_LABEL_ATTRIBUTE,
+			value: AttributeValue::Text(label),
+		})
+		.collect()
+}
+
+/// Reads the metadata persisted under the given `key`, returning `None` if there is none.
+pub(crate) fn read_payment_metadata(
+	store: &dyn PaginatedKVStore, primary_namespace: &str, secondary_namespace: &str, key: &str,
+) -> Result<Option<PaymentMetadata>, io::Error> {
+	match store.read(primary_namespace, secondary_namespace, key) {
+		Ok(bytes) => PaymentMetadata::decode(Bytes::from(bytes))
+			.map(Some)
+			.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+		Err(e) => Err(e),
+	}
+}
+
+/// Returns the key of the forwarded payment with the given local `sequence_number`, zero-padded so
+/// that keys sort like their sequence numbers.
+///
+/// LDK doesn't provide an identifier for forwarded payments, so they are numbered in the order they
+/// are persisted in, see [`ForwardedPaymentSequence`].
+pub(crate) fn forwarded_payment_key(sequence_number: u64) -> String {
+	format!("{sequence_number:020}")
+}
+
+/// Returns the hash of the contents of a forwarded payment, excluding the time it was forwarded at,
+/// which differs if its `PaymentForwarded` event is replayed.
+pub(crate) fn forwarded_payment_hash(forwarded_payment: &ForwardedPayment) -> String {
+	let forwarded_payment = ForwardedPayment { timestamp: 0, ..forwarded_payment.clone() };
+	sha256::Hash::hash(&forwarded_payment.encode_to_vec()).to_string()
+}
+
+/// Identifies the last forwarded payment persisted, and is persisted along with it.
+#[derive(Clone, PartialEq, Message)]
+pub(crate) struct ForwardedPaymentMarker {
+	/// The hash of the forwarded payment, see [`forwarded_payment_hash`].
+	#[prost(string, tag = "1")]
+	pub(crate) hash: String,
+	/// The local sequence number of the forwarded payment, see [`forwarded_payment_key`].
+	#[prost(uint64, tag = "2")]
+	pub(crate) sequence_number: u64,
+}
+
+/// Assigns forwarded payments their local sequence numbers, recognizing a `PaymentForwarded` event
+/// replayed after a restart.
+///
+/// LDK replays an event if we stopped after persisting the forwarded payment it is about, but before
+/// marking the event as handled. As events are handled in order, only the first event handled after
+/// a restart may be such a replay, in which case it has the same hash as the last forwarded payment
+/// persisted. Any other forwards of the same amount and fee between the same channels are persisted
+/// as separate forwarded payments.
+///
+/// A distinct forward which is identical to the last one, and happens to be the first event after a
+/// restart, is indistinguishable from a replay though, as LDK doesn't persist whether an event was
+/// handled atomically with our store.
+pub(crate) struct ForwardedPaymentSequence {
+	last: Option<ForwardedPaymentMarker>,
+	// Whether no event was handled since the restart yet.
+	restarted: bool,
+}
+
+impl ForwardedPaymentSequence {
+	/// Loads the marker of the last forwarded payment persisted before the restart.
+	pub(crate) fn load(store: &dyn PaginatedKVStore) -> io::Result<Self> {
+		let last = match store.read(
+			FORWARDED_PAYMENT_MARKER_PERSISTENCE_PRIMARY_NAMESPACE,
+			FORWARDED_PAYMENT_MARKER_PERSISTENCE_SECONDARY_NAMESPACE,
+			FORWARDED_PAYMENT_MARKER_PERSISTENCE_KEY,
+		) {
+			Ok(bytes) => Some(
+				ForwardedPaymentMarker::decode(Bytes::from(bytes))
+					.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+			),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+			Err(e) => return Err(e),
+		};
+		Ok(Self { last, restarted: true })
+	}
+
+	/// Returns the marker to persist along with `forwarded_payment`, or `None` if it is a replay of
+	/// the last forwarded payment persisted before the restart, which must not be persisted again.
+	pub(crate) fn next(
+		&mut self, forwarded_payment: &ForwardedPayment,
+	) -> Option<ForwardedPaymentMarker> {
+		let hash = forwarded_payment_hash(forwarded_payment);
+		let restarted = std::mem::replace(&mut self.restarted, false);
+		match &self.last {
+			Some(last) if restarted && last.hash == hash => None,
+			last => {
+				let sequence_number = last.as_ref().map_or(0, |last| last.sequence_number + 1);
+				Some(ForwardedPaymentMarker { hash, sequence_number })
+			},
+		}
+	}
+
+	/// Records that an event other than `PaymentForwarded` was received, after which no replay is
+	/// expected anymore.
+	pub(crate) fn other_event_received(&mut self) {
+		self.restarted = false;
+	}
+
+	/// Records that a forwarded payment was persisted along with `marker`.
+	pub(crate) fn persisted(&mut self, marker: ForwardedPaymentMarker) {
+		self.last = Some(marker);
+	}
+}
+
+/// Returns the [`Attribute`]s a forwarded payment is persisted with, allowing forwarded payments to
+/// be filtered by the channels and nodes they were forwarded between.
+pub(crate) fn forwarded_payment_attributes(
+	forwarded_payment: &ForwardedPayment,
+) -> [Attribute<'_>; 4] {
+	let text_attribute = |name, value| Attribute { name, value: AttributeValue::Text(value) };
+	[
+		text_attribute(
+			FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE,
+			forwarded_payment.prev_channel_id.as_str(),
+		),
+		text_attribute(
+			FORWARDED_PAYMENT_NEXT_CHANNEL_ID_ATTRIBUTE,
+			forwarded_payment.next_channel_id.as_str(),
+		),
+		text_attribute(
+			FORWARDED_PAYMENT_PREV_NODE_ID_ATTRIBUTE,
+			forwarded_payment.prev_node_id.as_str(),
+		),
+		text_attribute(
+			FORWARDED_PAYMENT_NEXT_NODE_ID_ATTRIBUTE,
+			forwarded_payment.next_node_id.as_str(),
+		),
+	]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::io::persist::sqlite_store::tests::{create_store, random_storage_path};
+
+	fn forwarded_payment(timestamp: u64) -> ForwardedPayment {
+		ForwardedPayment {
+			prev_channel_id: "prev_channel_id".to_string(),
+			next_channel_id: "next_channel_id".to_string(),
+			total_fee_earned_msat: Some(1_000),
+			outbound_amount_forwarded_msat: Some(100_000),
+			timestamp,
+			..Default::default()
+		}
+	}
+
+	/// Persists `forwarded_payment` the way the event loop does, unless it is a replay.
+	fn persist_forwarded_payment(
+		store: &dyn PaginatedKVStore, sequence: &mut ForwardedPaymentSequence,
+		forwarded_payment: &ForwardedPayment,
+	) -> bool {
+		let marker = match sequence.next(forwarded_payment) {
+			Some(marker) => marker,
+			None => return false,
+		};
+		let key = forwarded_payment_key(marker.sequence_number);
+		let forwarded_payment_bytes = forwarded_payment.encode_to_vec();
+		let marker_bytes = marker.encode_to_vec();
+		let writes = [
+			WriteOp {
+				primary_namespace: FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				secondary_namespace: FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				key: &key,
+				time: forwarded_payment.timestamp as i64,
+				buf: &forwarded_payment_bytes,
+				attributes: &forwarded_payment_attributes(forwarded_payment),
+			},
+			WriteOp {
+				primary_namespace: FORWARDED_PAYMENT_MARKER_PERSISTENCE_PRIMARY_NAMESPACE,
+				secondary_namespace: FORWARDED_PAYMENT_MARKER_PERSISTENCE_SECONDARY_NAMESPACE,
+				key: FORWARDED_PAYMENT_MARKER_PERSISTENCE_KEY,
+				time: 0,
+				buf: &marker_bytes,
+				attributes: &[],
+			},
+		];
+		store.write_batch(&writes).unwrap();
+		sequence.persisted(marker);
+		true
+	}
+
+	fn list_forwarded_payment_keys(store: &dyn PaginatedKVStore) -> Vec<String> {
+		store
+			.list(
+				FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				None,
+			)
+			.unwrap()
+			.keys
+	}
+
+	#[test]
+	fn test_forwarded_payment_hash() {
+		let hash = forwarded_payment_hash(&forwarded_payment(42));
+		assert_eq!(hash.len(), 64);
+
+		// The hash doesn't depend on the time the payment was forwarded at.
+		assert_eq!(forwarded_payment_hash(&forwarded_payment(43)), hash);
+
+		let other =
+			ForwardedPayment { total_fee_earned_msat: Some(1_001), ..forwarded_payment(42) };
+		assert_ne!(forwarded_payment_hash(&other), hash);
+	}
+
+	#[test]
+	fn test_forwarded_payment_sequence() {
+		let storage_path = random_storage_path();
+		let store = create_store(storage_path.clone());
+		let mut sequence = ForwardedPaymentSequence::load(store.as_ref()).unwrap();
+
+		// Distinct forwards of the same amount and fee between the same channels are all persisted.
+		assert!(persist_forwarded_payment(store.as_ref(), &mut sequence, &forwarded_payment(42)));
+		assert!(persist_forwarded_payment(store.as_ref(), &mut sequence, &forwarded_payment(43)));
+		assert_eq!(
+			list_forwarded_payment_keys(store.as_ref()),
+			vec![forwarded_payment_key(1), forwarded_payment_key(0)]
+		);
+
+		// After a restart, a replay of the last forwarded payment is skipped, but only as the first
+		// forwarded payment handled.
+		let mut sequence = ForwardedPaymentSequence::load(store.as_ref()).unwrap();
+		assert!(!persist_forwarded_payment(store.as_ref(), &mut sequence, &forwarded_payment(44)));
+		assert!(persist_forwarded_payment(store.as_ref(), &mut sequence, &forwarded_payment(45)));
+		assert_eq!(list_forwarded_payment_keys(store.as_ref()).len(), 3);
+
+		// Other forwarded payments handled first after a restart are persisted.
+		let mut sequence = ForwardedPaymentSequence::load(store.as_ref()).unwrap();
+		let other =
+			ForwardedPayment { total_fee_earned_msat: Some(1_001), ..forwarded_payment(46) };
+		assert!(persist_forwarded_payment(store.as_ref(), &mut sequence, &other));
+		assert_eq!(list_forwarded_payment_keys(store.as_ref())[0], forwarded_payment_key(3));
+
+		// Neither are identical ones handled after any other event.
+		let mut sequence = ForwardedPaymentSequence::load(store.as_ref()).unwrap();
+		sequence.other_event_received();
+		assert!(persist_forwarded_payment(store.as_ref(), &mut sequence, &other));
+		assert_eq!(list_forwarded_payment_keys(store.as_ref())[0], forwarded_payment_key(4));
+	}
+
+	/// Persists `payment` the way the event loop does, under the next update sequence number.
+	fn persist_payment_update(
+		store: &dyn PaginatedKVStore, sequence: &mut PaymentUpdateSequence, payment: &Payment,
+	) {
+		let update_sequence_number = sequence.next();
+		let writes = [
+			WriteOp {
+				primary_namespace: PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				secondary_namespace: PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				key: &payment.id,
+				time: 0,
+				buf: &payment.encode_to_vec(),
+				attributes: &payment_attributes(payment, update_sequence_number),
+			},
+			payment_update_sequence_write(&update_sequence_number.to_be_bytes()),
+		];
+		store.write_batch(&writes).unwrap();
+		sequence.persisted(update_sequence_number);
+	}
+
+	#[test]
+	fn test_payment_update_sequence() {
+		let storage_path = random_storage_path();
+		let store = create_store(storage_path.clone());
+		let mut sequence = PaymentUpdateSequence::load(store.as_ref()).unwrap();
+		let list = |page_token| {
+			store
+				.list_by_attribute(
+					PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+					PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+					PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE,
+					0..=i64::MAX,
+					&[],
+					page_token,
+				)
+				.unwrap()
+		};
+		let payment = |id: &str, latest_update_timestamp| Payment {
+			id: id.to_string(),
+			latest_update_timestamp,
+			..Default::default()
+		};
+
+		// Payments are listed in the order their updates were persisted in, regardless of their
+		// latest update timestamps.
+		persist_payment_update(store.as_ref(), &mut sequence, &payment("b", 20));
+		persist_payment_update(store.as_ref(), &mut sequence, &payment("a", 10));
+		let response = list(None);
+		assert_eq!(response.keys, vec!["b", "a"]);
+
+		// An update persisted after syncing is returned when resuming, even if it carries an older
+		// timestamp than the last payment synced, also after a restart.
+		let mut sequence = PaymentUpdateSequence::load(store.as_ref()).unwrap();
+		assert_eq!(sequence.next(), 3);
+		persist_payment_update(store.as_ref(), &mut sequence, &payment("b", 15));
+		let response = list(response.next_page_token);
+		assert_eq!(response.keys, vec!["b"]);
+		assert_eq!(response.next_page_token, Some(("b".to_string(), 3)));
+		assert!(list(response.next_page_token).keys.is_empty());
+	}
+
+	#[test]
+	fn test_payment_metadata_attributes() {
+		let metadata = PaymentMetadata {
+			order_id: Some("order_id".to_string()),
+			labels: vec!["b".to_string(), "a".to_string(), "b".to_string()],
+			..Default::default()
+		};
+		let labels = payment_metadata_attributes(&metadata)
+			.into_iter()
+			.map(|attribute| {
+				assert_eq!(attribute.name, PAYMENT_METADATA_LABEL_ATTRIBUTE);
+				attribute.value
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(labels, vec![AttributeValue::Text("a"), AttributeValue::Text("b")]);
+	}
+}
@@ -8,6 +8,7 @@
 // licenses.
 
 use std::io;
+use std::ops::RangeInclusive;
 
 /// Provides an interface that allows storage and retrieval of persisted values that are associated
 /// with given keys, with support for pagination with time-based ordering.
@@ -53,11 +54,25 @@ pub trait PaginatedKVStore: Send + Sync {
 	/// `time` value and use it for ordering in the `list` method.
 	///
 	/// Will create the given `primary_namespace` and `secondary_namespace` if not already present
-	/// in the store.
+	/// in the store. Any [`Attribute`]s previously persisted for the `key` are removed.
 	fn write(
 		&self, primary_namespace: &str, secondary_namespace: &str, key: &str, time: i64, buf: &[u8],
 	) -> Result<(), io::Error>;
 
+	/// Persists all of the given [`WriteOp`]s atomically, i.e., either all or none of them are
+	/// persisted.
+	///
+	/// Each write behaves as if it were persisted via [`PaginatedKVStore::write`].
+	fn write_batch(&self, writes: &[WriteOp<'_>]) -> Result<(), io::Error>;
+
+	/// Removes any data and [`Attribute`]s that had previously been persisted under the given
+	/// `key`.
+	///
+	/// Returns successfully if no data was stored under the given `key`.
+	fn remove(
+		&self, primary_namespace: &str, secondary_namespace: &str, key: &str,
+	) -> Result<(), io::Error>;
+
 	/// Returns a paginated list of keys that are stored under the given `secondary_namespace` in
 	/// `primary_namespace`, ordered in descending order of `time`.
 	///
@@ -78,6 +93,92 @@ pub trait PaginatedKVStore: Send + Sync {
 		&self, primary_namespace: &str, secondary_namespace: &str,
 		next_page_token: Option<(String, i64)>,
 	) -> Result<ListResponse, io::Error>;
+
+	/// Returns a paginated list of the keys stored under the given `secondary_namespace` in
+	/// `primary_namespace` which match all of the given `filters`.
+	///
+	/// Keys are ordered and paginated as in [`PaginatedKVStore::list`]. As filtering is done by the
+	/// store, pages only contain matching keys, and an empty list is returned if no more keys match.
+	fn list_filtered(
+		&self, primary_namespace: &str, secondary_namespace: &str, filters: &[ListFilter<'_>],
+		next_page_token: Option<(String, i64)>,
+	) -> Result<ListResponse, io::Error>;
+
+	/// Returns a paginated list of the keys stored under the given `secondary_namespace` in
+	/// `primary_namespace` with an [`AttributeValue::Integer`] attribute of the given `name` with a
+	/// value within `range` which match all of the given `filters`, ordered in ascending order of
+	/// that value and then by key.
+	///
+	/// Unlike for [`PaginatedKVStore::list`], the `next_page_token` holds the attribute value of the
+	/// last returned key instead of its `time`. As keys are returned again once their attribute value
+	/// increases beyond it, this allows to follow changes of keys, e.g., by the time they were last
+	/// updated at. Keys with multiple matching attributes of the given `name` are returned once per
+	/// value.
+	fn list_by_attribute(
+		&self, primary_namespace: &str, secondary_namespace: &str, name: &str,
+		range: RangeInclusive<i64>, filters: &[ListFilter<'_>],
+		next_page_token: Option<(String, i64)>,
+	) -> Result<ListResponse, io::Error>;
+
+	/// Returns the lowest value of the [`AttributeValue::Integer`] attributes of the given `name` of
+	/// the keys stored under the given `secondary_namespace` in `primary_namespace` which match all
+	/// of the given `filters`, or `None` if there is no such attribute.
+	///
+	/// This allows to start [`PaginatedKVStore::list_by_attribute`] right at the first matching key
+	/// rather than skipping over all keys before it.
+	fn min_attribute(
+		&self, primary_namespace: &str, secondary_namespace: &str, name: &str,
+		filters: &[ListFilter<'_>],
+	) -> Result<Option<i64>, io::Error>;
+}
+
+/// A single write persisted as part of a [`PaginatedKVStore::write_batch`].
+#[derive(Clone, Copy)]
+pub struct WriteOp<'a> {
+	pub primary_namespace: &'a str,
+	pub secondary_namespace: &'a str,
+	pub key: &'a str,
+	pub time: i64,
+	pub buf: &'a [u8],
+	/// The attributes the `key` can be filtered by in [`PaginatedKVStore::list_filtered`],
+	/// replacing any previously persisted ones.
+	pub attributes: &'a [Attribute<'a>],
+}
+
+/// A named value persisted along with a key, allowing to filter keys by it without having to read
+/// and decode their data.
+///
+/// A key may have multiple attributes of the same name, e.g., to attach multiple labels to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Attribute<'a> {
+	pub name: &'a str,
+	pub value: AttributeValue<'a>,
+}
+
+/// The value of an [`Attribute`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeValue<'a> {
+	/// An integer value, which keys can be filtered by ranges of.
+	Integer(i64),
+	/// A text value, which keys can be filtered by exact matches of.
+	Text(&'a str),
+}
+
+/// A condition keys have to satisfy to be returned by [`PaginatedKVStore::list_filtered`] and
+/// [`PaginatedKVStore::list_by_attribute`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListFilter<'a> {
+	/// Matches keys which were written with a `time` within the given range.
+	Time(RangeInclusive<i64>),
+	/// Matches keys with any [`AttributeValue::Integer`] attribute of the given `name` with a value
+	/// within `range`.
+	Attribute { name: &'a str, range: RangeInclusive<i64> },
+	/// Matches keys with any [`AttributeValue::Text`] attribute of the given `name` equal to
+	/// `value`.
+	TextAttribute { name: &'a str, value: &'a str },
+	/// Matches keys with any [`AttributeValue::Text`] attribute of the given `name` equal to one of
+	/// `values`.
+	TextAttributeIn { name: &'a str, values: &'a [&'a str] },
 }
 
 /// Represents the response from a paginated `list` operation.
@@ -85,7 +186,7 @@ pub trait PaginatedKVStore: Send + Sync {
 /// Contains the list of keys and an optional `next_page_token` that can be used to retrieve the
 /// next set of keys.
 pub struct ListResponse {
-	/// A vector of keys, ordered in descending order of `time`.
+	/// A vector of keys, ordered as documented by the respective `list` method.
 	pub keys: Vec<String>,
 
 	///  A token that can be used to retrieve the next set of keys.
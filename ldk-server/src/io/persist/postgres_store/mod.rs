@@ -0,0 +1,587 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+use std::io;
+use std::ops::RangeInclusive;
+use std::sync::Mutex;
+
+use ldk_node::lightning::types::string::PrintableString;
+use postgres::types::ToSql;
+use postgres::{Client, GenericClient, NoTls};
+
+use crate::io::persist::paginated_kv_store::{
+	AttributeValue, ListFilter, ListResponse, PaginatedKVStore, WriteOp,
+};
+use crate::io::utils::check_namespace_key_validity;
+
+/// The default table in which we store all the paginated data.
+pub const DEFAULT_PAGINATED_KV_TABLE_NAME: &str = "ldk_paginated_data";
+
+// The maximum number of keys retrieved per page in paginated list operation.
+const LIST_KEYS_MAX_PAGE_SIZE: i64 = 100;
+
+/// A [`PaginatedKVStore`] backed by a PostgreSQL database.
+///
+/// Data is stored in the same layout as in the [`SqliteStore`], and listed in the same order. In
+/// particular, keys are compared bytewise (using the `"C"` collation) regardless of the collation
+/// the database was created with.
+///
+/// As the [`PaginatedKVStore`] interface is synchronous, queries block the calling thread. When
+/// called from within a Tokio runtime, this requires a multi-threaded runtime, see
+/// [`tokio::task::block_in_place`].
+///
+/// [`SqliteStore`]: crate::io::persist::sqlite_store::SqliteStore
+pub struct PostgresStore {
+	client: Mutex<Client>,
+	paginated_kv_table_name: String,
+	attributes_table_name: String,
+	text_attributes_table_name: String,
+}
+
+impl PostgresStore {
+	/// Constructs a new [`PostgresStore`], connecting to the database at `connection_string`.
+	///
+	/// The `connection_string` is either a `postgresql://` URL or a `key=value` string, see
+	/// [`postgres::Config`]. If not already existing, the given `paginated_kv_table_name` (or
+	/// [`DEFAULT_PAGINATED_KV_TABLE_NAME`] if set to `None`) will be created, along with the table
+	/// of the attributes keys are persisted with.
+	pub fn new(
+		connection_string: &str, paginated_kv_table_name: Option<String>,
+	) -> io::Result<Self> {
+		let paginated_kv_table_name =
+			paginated_kv_table_name.unwrap_or(DEFAULT_PAGINATED_KV_TABLE_NAME.to_string());
+
+		let mut client = blocking(|| Client::connect(connection_string, NoTls)).map_err(|e| {
+			let msg = format!("Failed to connect to PostgreSQL database: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		let create_paginated_kv_table_sql = format!(
+			"CREATE TABLE IF NOT EXISTS {} (
+			primary_namespace TEXT NOT NULL,
+			secondary_namespace TEXT DEFAULT '' NOT NULL,
+			key TEXT COLLATE \"C\" NOT NULL CHECK (key <> ''),
+			creation_time BIGINT NOT NULL,
+			value BYTEA, PRIMARY KEY ( primary_namespace, secondary_namespace, key )
+			);",
+			paginated_kv_table_name
+		);
+
+		blocking(|| client.batch_execute(&create_paginated_kv_table_sql)).map_err(|e| {
+			let msg = format!("Failed to create table {}: {}", paginated_kv_table_name, e);
+			io::Error::other(msg)
+		})?;
+
+		let index_creation_time_sql = format!(
+			"CREATE INDEX IF NOT EXISTS {0}_creation_time_idx ON {0} (creation_time);",
+			paginated_kv_table_name
+		);
+
+		blocking(|| client.batch_execute(&index_creation_time_sql)).map_err(|e| {
+			let msg = format!(
+				"Failed to create index on creation_time, table {}: {}",
+				paginated_kv_table_name, e
+			);
+			io::Error::other(msg)
+		})?;
+
+		// Integer and text attributes are kept in separate tables, so that their values are typed.
+		let attributes_table_name = format!("{}_attributes", paginated_kv_table_name);
+		let text_attributes_table_name = format!("{}_text_attributes", paginated_kv_table_name);
+		for (table_name, value_type) in
+			[(&attributes_table_name, "BIGINT"), (&text_attributes_table_name, "TEXT")]
+		{
+			let create_attributes_table_sql = format!(
+				"CREATE TABLE IF NOT EXISTS {0} (
+				primary_namespace TEXT NOT NULL,
+				secondary_namespace TEXT DEFAULT '' NOT NULL,
+				key TEXT COLLATE \"C\" NOT NULL,
+				name TEXT NOT NULL,
+				value {1} NOT NULL,
+				PRIMARY KEY ( primary_namespace, secondary_namespace, key, name, value )
+				);
+				CREATE INDEX IF NOT EXISTS {0}_value_idx ON {0} (primary_namespace, secondary_namespace, name, value);",
+				table_name, value_type
+			);
+
+			blocking(|| client.batch_execute(&create_attributes_table_sql)).map_err(|e| {
+				let msg = format!("Failed to create table {}: {}", table_name, e);
+				io::Error::other(msg)
+			})?;
+		}
+
+		Ok(Self {
+			client: Mutex::new(client),
+			paginated_kv_table_name,
+			attributes_table_name,
+			text_attributes_table_name,
+		})
+	}
+
+	fn write_internal<C: GenericClient>(
+		&self, client: &mut C, write: &WriteOp<'_>,
+	) -> io::Result<()> {
+		let WriteOp { primary_namespace, secondary_namespace, key, time, buf, attributes } = *write;
+
+		let sql = format!(
+			"INSERT INTO {} (primary_namespace, secondary_namespace, key, creation_time, value)
+			VALUES ($1, $2, $3, $4, $5)
+			ON CONFLICT (primary_namespace, secondary_namespace, key)
+			DO UPDATE SET value = excluded.value;",
+			self.paginated_kv_table_name
+		);
+
+		client
+			.execute(sql.as_str(), &[&primary_namespace, &secondary_namespace, &key, &time, &buf])
+			.and_then(|_| {
+				self.delete_attributes(client, primary_namespace, secondary_namespace, key)
+			})
+			.and_then(|_| {
+				for attribute in attributes {
+					let (table_name, value): (_, &(dyn ToSql + Sync)) = match &attribute.value {
+						AttributeValue::Integer(value) => (&self.attributes_table_name, value),
+						AttributeValue::Text(value) => (&self.text_attributes_table_name, value),
+					};
+					let insert_attribute_sql = format!(
+						"INSERT INTO {} (primary_namespace, secondary_namespace, key, name, value)
+						VALUES ($1, $2, $3, $4, $5)
+						ON CONFLICT DO NOTHING;",
+						table_name
+					);
+					client.execute(
+						insert_attribute_sql.as_str(),
+						&[&primary_namespace, &secondary_namespace, &key, &attribute.name, value],
+					)?;
+				}
+				Ok(())
+			})
+			.map_err(|e| {
+				let msg = format!(
+					"Failed to write to key {}/{}/{}: {}",
+					PrintableString(primary_namespace),
+					PrintableString(secondary_namespace),
+					PrintableString(key),
+					e
+				);
+				io::Error::other(msg)
+			})
+	}
+
+	fn delete_attributes<C: GenericClient>(
+		&self, client: &mut C, primary_namespace: &str, secondary_namespace: &str, key: &str,
+	) -> Result<(), postgres::Error> {
+		for table_name in [&self.attributes_table_name, &self.text_attributes_table_name] {
+			let sql = format!(
+				"DELETE FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 AND key=$3;",
+				table_name
+			);
+			client.execute(sql.as_str(), &[&primary_namespace, &secondary_namespace, &key])?;
+		}
+		Ok(())
+	}
+
+	/// Adds the condition restricting a query to the keys which match `filter` to `sql`, and its
+	/// parameters to `params`.
+	///
+	/// The query's first two parameters are expected to be the primary and secondary namespace.
+	fn push_filter_condition<'a>(
+		&self, sql: &mut String, params: &mut Vec<&'a (dyn ToSql + Sync)>,
+		filter: &'a ListFilter<'_>,
+	) {
+		match filter {
+			ListFilter::Time(range) => {
+				sql.push_str(&format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 \
+					AND creation_time BETWEEN ${} AND ${} )",
+					self.paginated_kv_table_name,
+					params.len() + 1,
+					params.len() + 2
+				));
+				params.push(range.start());
+				params.push(range.end());
+			},
+			ListFilter::Attribute { name, range } => {
+				params.push(name);
+				sql.push_str(&format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 \
+					AND name=${} AND value BETWEEN ${} AND ${} )",
+					self.attributes_table_name,
+					params.len(),
+					params.len() + 1,
+					params.len() + 2
+				));
+				params.push(range.start());
+				params.push(range.end());
+			},
+			ListFilter::TextAttribute { name, value } => {
+				params.push(name);
+				params.push(value);
+				sql.push_str(&format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 \
+					AND name=${} AND value=${} )",
+					self.text_attributes_table_name,
+					params.len() - 1,
+					params.len()
+				));
+			},
+			ListFilter::TextAttributeIn { name, values } => {
+				params.push(name);
+				params.push(values);
+				sql.push_str(&format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 \
+					AND name=${} AND value = ANY(${}) )",
+					self.text_attributes_table_name,
+					params.len() - 1,
+					params.len()
+				));
+			},
+		}
+	}
+}
+
+impl PaginatedKVStore for PostgresStore {
+	fn read(
+		&self, primary_namespace: &str, secondary_namespace: &str, key: &str,
+	) -> io::Result<Vec<u8>> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, Some(key), "read")?;
+
+		let mut client = self.client.lock().unwrap();
+		let sql = format!(
+			"SELECT value FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 AND key=$3;",
+			self.paginated_kv_table_name
+		);
+
+		let row = blocking(|| {
+			client.query_opt(sql.as_str(), &[&primary_namespace, &secondary_namespace, &key])
+		})
+		.map_err(|e| {
+			let msg = format!(
+				"Failed to read from key {}/{}/{}: {}",
+				PrintableString(primary_namespace),
+				PrintableString(secondary_namespace),
+				PrintableString(key),
+				e
+			);
+			io::Error::other(msg)
+		})?;
+
+		match row {
+			Some(row) => Ok(row.get(0)),
+			None => {
+				let msg = format!(
+					"Failed to read as key could not be found: {}/{}/{}",
+					PrintableString(primary_namespace),
+					PrintableString(secondary_namespace),
+					PrintableString(key)
+				);
+				Err(io::Error::new(io::ErrorKind::NotFound, msg))
+			},
+		}
+	}
+
+	fn write(
+		&self, primary_namespace: &str, secondary_namespace: &str, key: &str, time: i64, buf: &[u8],
+	) -> io::Result<()> {
+		self.write_batch(&[WriteOp {
+			primary_namespace,
+			secondary_namespace,
+			key,
+			time,
+			buf,
+			attributes: &[],
+		}])
+	}
+
+	fn write_batch(&self, writes: &[WriteOp<'_>]) -> io::Result<()> {
+		for write in writes {
+			check_namespace_key_validity(
+				write.primary_namespace,
+				write.secondary_namespace,
+				Some(write.key),
+				"write",
+			)?;
+		}
+
+		let mut client = self.client.lock().unwrap();
+
+		blocking(|| {
+			let mut tx = client.transaction().map_err(|e| {
+				let msg = format!("Failed to start transaction: {}", e);
+				io::Error::other(msg)
+			})?;
+
+			for write in writes {
+				self.write_internal(&mut tx, write)?;
+			}
+
+			// Dropping the transaction without committing it rolls back any of the writes.
+			tx.commit().map_err(|e| {
+				let msg = format!("Failed to commit transaction: {}", e);
+				io::Error::other(msg)
+			})
+		})
+	}
+
+	fn remove(
+		&self, primary_namespace: &str, secondary_namespace: &str, key: &str,
+	) -> io::Result<()> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, Some(key), "remove")?;
+
+		let mut client = self.client.lock().unwrap();
+		let sql = format!(
+			"DELETE FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 AND key=$3;",
+			self.paginated_kv_table_name
+		);
+
+		blocking(|| {
+			let mut tx = client.transaction().map_err(|e| {
+				let msg = format!("Failed to start transaction: {}", e);
+				io::Error::other(msg)
+			})?;
+
+			tx.execute(sql.as_str(), &[&primary_namespace, &secondary_namespace, &key])
+				.and_then(|_| {
+					self.delete_attributes(&mut tx, primary_namespace, secondary_namespace, key)
+				})
+				.map_err(|e| {
+					let msg = format!(
+						"Failed to delete key {}/{}/{}: {}",
+						PrintableString(primary_namespace),
+						PrintableString(secondary_namespace),
+						PrintableString(key),
+						e
+					);
+					io::Error::other(msg)
+				})?;
+
+			tx.commit().map_err(|e| {
+				let msg = format!("Failed to commit transaction: {}", e);
+				io::Error::other(msg)
+			})
+		})
+	}
+
+	fn list(
+		&self, primary_namespace: &str, secondary_namespace: &str,
+		page_token: Option<(String, i64)>,
+	) -> io::Result<ListResponse> {
+		self.list_filtered(primary_namespace, secondary_namespace, &[], page_token)
+	}
+
+	fn list_filtered(
+		&self, primary_namespace: &str, secondary_namespace: &str, filters: &[ListFilter<'_>],
+		page_token: Option<(String, i64)>,
+	) -> io::Result<ListResponse> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, None, "list")?;
+
+		let (key_token, creation_time_token) = page_token.unwrap_or(("".to_string(), i64::MAX));
+
+		let mut sql = format!(
+			"SELECT key, creation_time FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 \
+			AND ( creation_time < $3 OR (creation_time = $3 AND key > $4) )",
+			self.paginated_kv_table_name
+		);
+		let mut params: Vec<&(dyn ToSql + Sync)> =
+			vec![&primary_namespace, &secondary_namespace, &creation_time_token, &key_token];
+
+		for filter in filters {
+			match filter {
+				ListFilter::Time(range) => {
+					sql.push_str(&format!(
+						" AND creation_time BETWEEN ${} AND ${}",
+						params.len() + 1,
+						params.len() + 2
+					));
+					params.push(range.start());
+					params.push(range.end());
+				},
+				_ => self.push_filter_condition(&mut sql, &mut params, filter),
+			}
+		}
+
+		params.push(&LIST_KEYS_MAX_PAGE_SIZE);
+		sql.push_str(&format!(" ORDER BY creation_time DESC, key ASC LIMIT ${}", params.len()));
+
+		let mut client = self.client.lock().unwrap();
+		let rows = blocking(|| client.query(sql.as_str(), &params)).map_err(|e| {
+			let msg = format!("Failed to retrieve queried rows: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		let next_page_token = rows.last().map(|row| (row.get(0), row.get(1)));
+		let keys = rows.iter().map(|row| row.get(0)).collect();
+
+		Ok(ListResponse { keys, next_page_token })
+	}
+
+	fn list_by_attribute(
+		&self, primary_namespace: &str, secondary_namespace: &str, name: &str,
+		range: RangeInclusive<i64>, filters: &[ListFilter<'_>], page_token: Option<(String, i64)>,
+	) -> io::Result<ListResponse> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, None, "list")?;
+
+		let (key_token, value_token) = page_token.unwrap_or(("".to_string(), i64::MIN));
+
+		let mut sql = format!(
+			"SELECT key, value FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 \
+			AND name=$3 AND value BETWEEN $4 AND $5 \
+			AND ( value > $6 OR (value = $6 AND key > $7) )",
+			self.attributes_table_name
+		);
+		let mut params: Vec<&(dyn ToSql + Sync)> = vec![
+			&primary_namespace,
+			&secondary_namespace,
+			&name,
+			range.start(),
+			range.end(),
+			&value_token,
+			&key_token,
+		];
+		for filter in filters {
+			self.push_filter_condition(&mut sql, &mut params, filter);
+		}
+
+		params.push(&LIST_KEYS_MAX_PAGE_SIZE);
+		sql.push_str(&format!(" ORDER BY value ASC, key ASC LIMIT ${}", params.len()));
+
+		let mut client = self.client.lock().unwrap();
+		let rows = blocking(|| client.query(sql.as_str(), &params)).map_err(|e| {
+			let msg = format!("Failed to retrieve queried rows: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		let next_page_token = rows.last().map(|row| (row.get(0), row.get(1)));
+		let keys = rows.iter().map(|row| row.get(0)).collect();
+
+		Ok(ListResponse { keys, next_page_token })
+	}
+
+	fn min_attribute(
+		&self, primary_namespace: &str, secondary_namespace: &str, name: &str,
+		filters: &[ListFilter<'_>],
+	) -> io::Result<Option<i64>> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, None, "list")?;
+
+		let mut sql = format!(
+			"SELECT MIN(value) FROM {} WHERE primary_namespace=$1 AND secondary_namespace=$2 AND name=$3",
+			self.attributes_table_name
+		);
+		let mut params: Vec<&(dyn ToSql + Sync)> =
+			vec![&primary_namespace, &secondary_namespace, &name];
+		for filter in filters {
+			self.push_filter_condition(&mut sql, &mut params, filter);
+		}
+
+		let mut client = self.client.lock().unwrap();
+		let row = blocking(|| client.query_one(sql.as_str(), &params)).map_err(|e| {
+			let msg = format!("Failed to retrieve queried rows: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		Ok(row.get(0))
+	}
+}
+
+/// Runs the blocking database operation `f`.
+///
+/// The synchronous [`Client`] drives its own runtime, which may not be started from within another
+/// runtime's context, so if called from within one, the context is left for the duration of `f`.
+fn blocking<R>(f: impl FnOnce() -> R) -> R {
+	match tokio::runtime::Handle::try_current() {
+		Ok(_) => tokio::task::block_in_place(f),
+		Err(_) => f(),
+	}
+}
+
+#[cfg(test)]
+#[cfg(feature = "integration-tests-storage-postgres")]
+mod integration_tests_storage_postgres {
+	use hex::DisplayHex;
+
+	use super::*;
+	use crate::io::persist::sqlite_store::tests::{
+		do_list_by_attribute, do_list_filtered, do_read_write_remove_list_persist,
+	};
+
+	const CONNECTION_STRING: &str = "host=localhost user=postgres password=postgres";
+
+	/// Creates a store using a new table, so that tests don't interfere with each other.
+	fn create_store() -> PostgresStore {
+		let mut bytes = [0u8; 8];
+		getrandom::getrandom(&mut bytes).expect("Failed to generate random bytes");
+		let table_name = format!("test_table_{}", bytes.to_lower_hex_string());
+		PostgresStore::new(CONNECTION_STRING, Some(table_name)).unwrap()
+	}
+
+	#[test]
+	fn read_write_remove_list_persist() {
+		let store = create_store();
+		do_read_write_remove_list_persist(&store);
+	}
+
+	#[test]
+	fn list_filtered() {
+		let store = create_store();
+		do_list_filtered(&store);
+	}
+
+	#[test]
+	fn list_by_attribute() {
+		let store = create_store();
+		do_list_by_attribute(&store);
+	}
+
+	#[test]
+	fn list_ordering_and_pagination() {
+		let store = create_store();
+		let data = [42u8; 32];
+
+		// Keys with equal times are ordered bytewise, independently of the database's collation.
+		for (key, time) in [("b", 1), ("B", 1), ("a", 1), ("c", 2), ("_", 0)] {
+			store.write("testspace", "", key, time, &data).unwrap();
+		}
+
+		let list_response = store.list("testspace", "", None).unwrap();
+		assert_eq!(list_response.keys, vec!["c", "B", "a", "b", "_"]);
+		assert_eq!(list_response.next_page_token, Some(("_".to_string(), 0)));
+
+		let list_response = store.list("testspace", "", Some(("B".to_string(), 1))).unwrap();
+		assert_eq!(list_response.keys, vec!["a", "b", "_"]);
+
+		let list_response = store.list("testspace", "", Some(("_".to_string(), 0))).unwrap();
+		assert!(list_response.keys.is_empty());
+		assert_eq!(list_response.next_page_token, None);
+	}
+
+	#[test]
+	fn write_batch_and_remove() {
+		let store = create_store();
+		let data = [42u8; 32];
+		let write_op = |primary_namespace, key| WriteOp {
+			primary_namespace,
+			secondary_namespace: "",
+			key,
+			time: 0,
+			buf: &data,
+			attributes: &[],
+		};
+
+		store.write_batch(&[write_op("first", "key_0"), write_op("second", "key_1")]).unwrap();
+		assert_eq!(store.read("first", "", "key_0").unwrap(), data);
+		assert_eq!(store.read("second", "", "key_1").unwrap(), data);
+
+		store.remove("first", "", "key_0").unwrap();
+		let err = store.read("first", "", "key_0").unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::NotFound);
+		assert!(store.list("first", "", None).unwrap().keys.is_empty());
+
+		// Removing a key which doesn't exist succeeds.
+		store.remove("first", "", "key_0").unwrap();
+	}
+}
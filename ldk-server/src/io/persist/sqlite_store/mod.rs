@@ -7,14 +7,28 @@
 // You may not use this file except in accordance with one or both of these
 // licenses.
 
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::{fs, io};
 
+use ldk_node::bitcoin::hashes::{sha256, Hash};
 use ldk_node::lightning::types::string::PrintableString;
-use rusqlite::{named_params, Connection};
+use ldk_server_protos::events::{event_envelope, EventEnvelope};
+use ldk_server_protos::types::payment_kind::Kind;
+use ldk_server_protos::types::{ForwardedPayment, Payment, PaymentKindType};
+use prost::Message;
+use rusqlite::types::{Type, Value};
+use rusqlite::{named_params, Connection, ToSql, Transaction};
 
-use crate::io::persist::paginated_kv_store::{ListResponse, PaginatedKVStore};
+use crate::io::persist::paginated_kv_store::{
+	Attribute, AttributeValue, ListFilter, ListResponse, PaginatedKVStore, WriteOp,
+};
+use crate::io::persist::{
+	FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+	FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE, PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+	PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+};
 use crate::io::utils::check_namespace_key_validity;
 
 /// The default database file name.
@@ -23,8 +37,25 @@ pub const DEFAULT_SQLITE_DB_FILE_NAME: &str = "ldk_server_data.sqlite";
 /// The default table in which we store all the paginated data.
 pub const DEFAULT_PAGINATED_KV_TABLE_NAME: &str = "ldk_paginated_data";
 
-// The current SQLite `user_version`, which we can use if we'd ever need to do a schema migration.
-const SCHEMA_USER_VERSION: u16 = 1;
+/// A migration of the database schema from one SQLite `user_version` to the next.
+///
+/// Migrations are run within a transaction and are given the name of the paginated KV table.
+type Migration = fn(&Transaction<'_>, &str) -> rusqlite::Result<()>;
+
+/// The migrations of the database schema, in order. The migration at index `i` migrates databases
+/// from `user_version` `i + 1` to `i + 2`.
+///
+/// Migrations must never be changed or removed once released, as they may not have been applied to
+/// all databases yet. Instead, new ones are appended. For the same reason, migrations must not call
+/// into code which may change later on, such as the derivation of the attributes keys are persisted
+/// with, but carry their own copy of it.
+const MIGRATIONS: &[Migration] = &[
+	add_attributes_table,
+	add_text_attributes_table,
+	deduplicate_forwarded_payments,
+	add_payment_update_sequence_numbers,
+	add_event_type_attributes,
+];
 
 // The maximum number of keys retrieved per page in paginated list operation.
 const LIST_KEYS_MAX_PAGE_SIZE: i32 = 100;
@@ -59,64 +90,13 @@ impl SqliteStore {
 		let mut db_file_path = data_dir;
 		db_file_path.push(db_file_name);
 
-		let connection = Connection::open(db_file_path.clone()).map_err(|e| {
+		let mut connection = Connection::open(db_file_path.clone()).map_err(|e| {
 			let msg =
 				format!("Failed to open/create database file {}: {}", db_file_path.display(), e);
 			io::Error::other(msg)
 		})?;
 
-		let sql = "SELECT user_version FROM pragma_user_version".to_string();
-		let version_res: u16 = connection.query_row(&sql, [], |row| row.get(0)).unwrap();
-
-		if version_res == 0 {
-			// New database, set our SCHEMA_USER_VERSION and continue
-			connection
-				.pragma(
-					Some(rusqlite::DatabaseName::Main),
-					"user_version",
-					SCHEMA_USER_VERSION,
-					|_| Ok(()),
-				)
-				.map_err(|e| {
-					let msg = format!("Failed to set PRAGMA user_version: {}", e);
-					io::Error::other(msg)
-				})?;
-		} else if version_res > SCHEMA_USER_VERSION {
-			let msg = format!(
-				"Failed to open database: incompatible schema version {}. Expected: {}",
-				version_res, SCHEMA_USER_VERSION
-			);
-			return Err(io::Error::other(msg));
-		}
-
-		let create_paginated_kv_table_sql = format!(
-			"CREATE TABLE IF NOT EXISTS {} (
-			primary_namespace TEXT NOT NULL,
-			secondary_namespace TEXT DEFAULT \"\" NOT NULL,
-			key TEXT NOT NULL CHECK (key <> ''),
-			creation_time INTEGER NOT NULL,
-			value BLOB, PRIMARY KEY ( primary_namespace, secondary_namespace, key )
-			);",
-			paginated_kv_table_name
-		);
-
-		connection.execute(&create_paginated_kv_table_sql, []).map_err(|e| {
-			let msg = format!("Failed to create table {}: {}", paginated_kv_table_name, e);
-			io::Error::other(msg)
-		})?;
-
-		let index_creation_time_sql = format!(
-			"CREATE INDEX IF NOT EXISTS idx_creation_time ON {} (creation_time);",
-			paginated_kv_table_name
-		);
-
-		connection.execute(&index_creation_time_sql, []).map_err(|e| {
-			let msg = format!(
-				"Failed to create index on creation_time, table {}: {}",
-				paginated_kv_table_name, e
-			);
-			io::Error::other(msg)
-		})?;
+		migrate(&mut connection, &paginated_kv_table_name, MIGRATIONS)?;
 
 		let connection = Arc::new(Mutex::new(connection));
 		Ok(Self { connection, paginated_kv_table_name })
@@ -169,6 +149,653 @@ impl SqliteStore {
 			})?;
 		Ok(res)
 	}
+
+	fn write_internal(&self, conn: &Connection, write: &WriteOp<'_>) -> io::Result<()> {
+		let WriteOp { primary_namespace, secondary_namespace, key, time, buf, attributes } = *write;
+
+		let sql = format!(
+			"INSERT INTO {} (primary_namespace, secondary_namespace, key, creation_time, value)
+         VALUES (:primary_namespace, :secondary_namespace, :key, :creation_time, :value)
+         ON CONFLICT(primary_namespace, secondary_namespace, key)
+         DO UPDATE SET value = excluded.value;",
+			self.paginated_kv_table_name
+		);
+
+		let mut stmt = conn.prepare_cached(&sql).map_err(|e| {
+			let msg = format!("Failed to prepare statement: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		stmt.execute(named_params! {
+			":primary_namespace": primary_namespace,
+			":secondary_namespace": secondary_namespace,
+			":key": key,
+			":creation_time": time,
+			":value": buf,
+		})
+		.and_then(|_| {
+			delete_attributes(
+				conn,
+				&self.paginated_kv_table_name,
+				primary_namespace,
+				secondary_namespace,
+				key,
+			)
+		})
+		.and_then(|_| {
+			insert_attributes(
+				conn,
+				&self.paginated_kv_table_name,
+				primary_namespace,
+				secondary_namespace,
+				key,
+				attributes,
+			)
+		})
+		.map_err(|e| {
+			let msg = format!(
+				"Failed to write to key {}/{}/{}: {}",
+				PrintableString(primary_namespace),
+				PrintableString(secondary_namespace),
+				PrintableString(key),
+				e
+			);
+			io::Error::other(msg)
+		})
+	}
+
+	/// Returns the condition restricting a query to the keys which match `filter`, the `i`th filter
+	/// of the query, and adds its parameters to `filter_params`.
+	fn filter_condition(
+		&self, i: usize, filter: &ListFilter<'_>, filter_params: &mut Vec<(String, Value)>,
+	) -> String {
+		match filter {
+			ListFilter::Time(range) => {
+				filter_params.push((format!(":min_{i}"), Value::Integer(*range.start())));
+				filter_params.push((format!(":max_{i}"), Value::Integer(*range.end())));
+				format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=:primary_namespace \
+					AND secondary_namespace=:secondary_namespace AND creation_time BETWEEN :min_{i} AND :max_{i} )",
+					self.paginated_kv_table_name
+				)
+			},
+			ListFilter::Attribute { name, range } => {
+				filter_params.push((format!(":name_{i}"), Value::Text(name.to_string())));
+				filter_params.push((format!(":min_{i}"), Value::Integer(*range.start())));
+				filter_params.push((format!(":max_{i}"), Value::Integer(*range.end())));
+				format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=:primary_namespace \
+					AND secondary_namespace=:secondary_namespace AND name=:name_{i} AND value BETWEEN :min_{i} AND :max_{i} )",
+					attributes_table_name(&self.paginated_kv_table_name)
+				)
+			},
+			ListFilter::TextAttribute { name, value } => {
+				filter_params.push((format!(":name_{i}"), Value::Text(name.to_string())));
+				filter_params.push((format!(":value_{i}"), Value::Text(value.to_string())));
+				format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=:primary_namespace \
+					AND secondary_namespace=:secondary_namespace AND name=:name_{i} AND value=:value_{i} )",
+					text_attributes_table_name(&self.paginated_kv_table_name)
+				)
+			},
+			ListFilter::TextAttributeIn { name, values } => {
+				filter_params.push((format!(":name_{i}"), Value::Text(name.to_string())));
+				for (j, value) in values.iter().enumerate() {
+					filter_params.push((format!(":value_{i}_{j}"), Value::Text(value.to_string())));
+				}
+				let value_params = (0..values.len())
+					.map(|j| format!(":value_{i}_{j}"))
+					.collect::<Vec<_>>()
+					.join(", ");
+				format!(
+					" AND key IN ( SELECT key FROM {} WHERE primary_namespace=:primary_namespace \
+					AND secondary_namespace=:secondary_namespace AND name=:name_{i} AND value IN ( {} ) )",
+					text_attributes_table_name(&self.paginated_kv_table_name),
+					value_params
+				)
+			},
+		}
+	}
+}
+
+/// The table holding the [`AttributeValue::Integer`] attributes of the paginated KV table.
+fn attributes_table_name(paginated_kv_table_name: &str) -> String {
+	format!("{}_attributes", paginated_kv_table_name)
+}
+
+/// The table holding the [`AttributeValue::Text`] attributes of the paginated KV table.
+fn text_attributes_table_name(paginated_kv_table_name: &str) -> String {
+	format!("{}_text_attributes", paginated_kv_table_name)
+}
+
+fn delete_attributes(
+	conn: &Connection, paginated_kv_table_name: &str, primary_namespace: &str,
+	secondary_namespace: &str, key: &str,
+) -> rusqlite::Result<()> {
+	for table_name in [
+		attributes_table_name(paginated_kv_table_name),
+		text_attributes_table_name(paginated_kv_table_name),
+	] {
+		let sql = format!(
+			"DELETE FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace AND key=:key;",
+			table_name
+		);
+		conn.prepare_cached(&sql)?.execute(named_params! {
+			":primary_namespace": primary_namespace,
+			":secondary_namespace": secondary_namespace,
+			":key": key,
+		})?;
+	}
+	Ok(())
+}
+
+fn insert_attributes(
+	conn: &Connection, paginated_kv_table_name: &str, primary_namespace: &str,
+	secondary_namespace: &str, key: &str, attributes: &[Attribute<'_>],
+) -> rusqlite::Result<()> {
+	for attribute in attributes {
+		let (table_name, value): (_, &dyn ToSql) = match &attribute.value {
+			AttributeValue::Integer(value) => {
+				(attributes_table_name(paginated_kv_table_name), value)
+			},
+			AttributeValue::Text(value) => {
+				(text_attributes_table_name(paginated_kv_table_name), value)
+			},
+		};
+		let sql = format!(
+			"INSERT OR IGNORE INTO {} (primary_namespace, secondary_namespace, key, name, value)
+         VALUES (:primary_namespace, :secondary_namespace, :key, :name, :value);",
+			table_name
+		);
+		conn.prepare_cached(&sql)?.execute(named_params! {
+			":primary_namespace": primary_namespace,
+			":secondary_namespace": secondary_namespace,
+			":key": key,
+			":name": attribute.name,
+			":value": value,
+		})?;
+	}
+	Ok(())
+}
+
+fn user_version(connection: &Connection) -> io::Result<u16> {
+	connection
+		.query_row("SELECT user_version FROM pragma_user_version", [], |row| row.get(0))
+		.map_err(|e| {
+			let msg = format!("Failed to read PRAGMA user_version: {}", e);
+			io::Error::other(msg)
+		})
+}
+
+/// Creates the schema of `user_version` 1, which all [`MIGRATIONS`] build upon.
+fn create_initial_schema(
+	connection: &mut Connection, paginated_kv_table_name: &str,
+) -> io::Result<()> {
+	let tx = connection.transaction().map_err(|e| {
+		let msg = format!("Failed to start transaction: {}", e);
+		io::Error::other(msg)
+	})?;
+
+	let create_paginated_kv_table_sql = format!(
+		"CREATE TABLE IF NOT EXISTS {} (
+		primary_namespace TEXT NOT NULL,
+		secondary_namespace TEXT DEFAULT \"\" NOT NULL,
+		key TEXT NOT NULL CHECK (key <> ''),
+		creation_time INTEGER NOT NULL,
+		value BLOB, PRIMARY KEY ( primary_namespace, secondary_namespace, key )
+		);",
+		paginated_kv_table_name
+	);
+
+	tx.execute(&create_paginated_kv_table_sql, []).map_err(|e| {
+		let msg = format!("Failed to create table {}: {}", paginated_kv_table_name, e);
+		io::Error::other(msg)
+	})?;
+
+	let index_creation_time_sql = format!(
+		"CREATE INDEX IF NOT EXISTS idx_creation_time ON {} (creation_time);",
+		paginated_kv_table_name
+	);
+
+	tx.execute(&index_creation_time_sql, []).map_err(|e| {
+		let msg = format!(
+			"Failed to create index on creation_time, table {}: {}",
+			paginated_kv_table_name, e
+		);
+		io::Error::other(msg)
+	})?;
+
+	tx.pragma_update(None, "user_version", 1u16).map_err(|e| {
+		let msg = format!("Failed to set PRAGMA user_version: {}", e);
+		io::Error::other(msg)
+	})?;
+
+	tx.commit().map_err(|e| {
+		let msg = format!("Failed to commit transaction: {}", e);
+		io::Error::other(msg)
+	})
+}
+
+/// Adds the table of the [`Attribute`]s keys are persisted with, indexing the existing payments by
+/// their status, direction, latest update timestamp and kind.
+///
+/// Must never be changed once released, see [`MIGRATIONS`].
+fn add_attributes_table(
+	tx: &Transaction<'_>, paginated_kv_table_name: &str,
+) -> rusqlite::Result<()> {
+	let attributes_table_name = attributes_table_name(paginated_kv_table_name);
+	tx.execute_batch(&format!(
+		"CREATE TABLE {0} (
+		primary_namespace TEXT NOT NULL,
+		secondary_namespace TEXT DEFAULT \"\" NOT NULL,
+		key TEXT NOT NULL,
+		name TEXT NOT NULL,
+		value INTEGER NOT NULL,
+		PRIMARY KEY ( primary_namespace, secondary_namespace, key, name, value )
+		);
+		CREATE INDEX idx_{0}_value ON {0} (primary_namespace, secondary_namespace, name, value);",
+		attributes_table_name
+	))?;
+
+	let sql = format!(
+		"SELECT key, value FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace;",
+		paginated_kv_table_name
+	);
+	let mut stmt = tx.prepare(&sql)?;
+	let mut rows = stmt.query(named_params! {
+		":primary_namespace": PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+		":secondary_namespace": PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	})?;
+	while let Some(row) = rows.next()? {
+		let key: String = row.get(0)?;
+		let value: Vec<u8> = row.get(1)?;
+		let payment = Payment::decode(&*value)
+			.map_err(|e| rusqlite::Error::FromSqlConversionFailure(1, Type::Blob, Box::new(e)))?;
+
+		let integer_attribute =
+			|name, value| Attribute { name, value: AttributeValue::Integer(value) };
+		let mut attributes = vec![
+			integer_attribute("status", payment.status as i64),
+			integer_attribute("direction", payment.direction as i64),
+			integer_attribute("latest_update_timestamp", payment.latest_update_timestamp as i64),
+		];
+		if let Some(kind) = payment.kind.as_ref().and_then(|k| k.kind.as_ref()) {
+			let kind_type = match kind {
+				Kind::Onchain(_) => PaymentKindType::Onchain,
+				Kind::Bolt11(_) => PaymentKindType::Bolt11,
+				Kind::Bolt11Jit(_) => PaymentKindType::Bolt11Jit,
+				Kind::Bolt12Offer(_) => PaymentKindType::Bolt12Offer,
+				Kind::Bolt12Refund(_) => PaymentKindType::Bolt12Refund,
+				Kind::Spontaneous(_) => PaymentKindType::Spontaneous,
+			};
+			attributes.push(integer_attribute("kind", kind_type as i64));
+		}
+		insert_attributes(
+			tx,
+			paginated_kv_table_name,
+			PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&key,
+			&attributes,
+		)?;
+	}
+	Ok(())
+}
+
+/// Adds the table of the [`AttributeValue::Text`] attributes keys are persisted with, indexing the
+/// existing forwarded payments by the channels and nodes they were forwarded between.
+///
+/// As forwarded payments were persisted without their [`ForwardedPayment::timestamp`] before, it is
+/// set to the time they were first persisted at.
+///
+/// Must never be changed once released, see [`MIGRATIONS`].
+fn add_text_attributes_table(
+	tx: &Transaction<'_>, paginated_kv_table_name: &str,
+) -> rusqlite::Result<()> {
+	tx.execute_batch(&format!(
+		"CREATE TABLE {0} (
+		primary_namespace TEXT NOT NULL,
+		secondary_namespace TEXT DEFAULT \"\" NOT NULL,
+		key TEXT NOT NULL,
+		name TEXT NOT NULL,
+		value TEXT NOT NULL,
+		PRIMARY KEY ( primary_namespace, secondary_namespace, key, name, value )
+		);
+		CREATE INDEX idx_{0}_value ON {0} (primary_namespace, secondary_namespace, name, value);",
+		text_attributes_table_name(paginated_kv_table_name)
+	))?;
+
+	let select_sql = format!(
+		"SELECT key, creation_time, value FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace;",
+		paginated_kv_table_name
+	);
+	let update_sql = format!(
+		"UPDATE {} SET value=:value WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace AND key=:key;",
+		paginated_kv_table_name
+	);
+	// Collect all rows first, as they are updated while being processed.
+	let rows = tx
+		.prepare(&select_sql)?
+		.query_map(
+			named_params! {
+				":primary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				":secondary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			},
+			|row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, Vec<u8>>(2)?)),
+		)?
+		.collect::<rusqlite::Result<Vec<_>>>()?;
+	let mut update_stmt = tx.prepare(&update_sql)?;
+	for (key, creation_time, value) in rows {
+		let mut forwarded_payment = ForwardedPayment::decode(&*value)
+			.map_err(|e| rusqlite::Error::FromSqlConversionFailure(2, Type::Blob, Box::new(e)))?;
+		if forwarded_payment.timestamp == 0 {
+			forwarded_payment.timestamp = creation_time as u64;
+			update_stmt.execute(named_params! {
+				":primary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				":secondary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				":key": key,
+				":value": forwarded_payment.encode_to_vec(),
+			})?;
+		}
+		insert_attributes(
+			tx,
+			paginated_kv_table_name,
+			FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&key,
+			&v3_forwarded_payment_attributes(&forwarded_payment),
+		)?;
+	}
+	Ok(())
+}
+
+/// The text attributes forwarded payments are persisted with as of `user_version` 3.
+///
+/// Used by migrations, so must never be changed once released either.
+fn v3_forwarded_payment_attributes(forwarded_payment: &ForwardedPayment) -> [Attribute<'_>; 4] {
+	let text_attribute = |name, value| Attribute { name, value: AttributeValue::Text(value) };
+	[
+		text_attribute("prev_channel_id", forwarded_payment.prev_channel_id.as_str()),
+		text_attribute("next_channel_id", forwarded_payment.next_channel_id.as_str()),
+		text_attribute("prev_node_id", forwarded_payment.prev_node_id.as_str()),
+		text_attribute("next_node_id", forwarded_payment.next_node_id.as_str()),
+	]
+}
+
+/// Re-keys the existing forwarded payments by their local sequence number, in the order they were
+/// persisted in, which they were persisted under a random key before. Also persists the marker of
+/// the last forwarded payment, which replays of its `PaymentForwarded` event are recognized by.
+///
+/// Forwarded payments persisted under a random key were duplicated whenever their `PaymentForwarded`
+/// event was replayed after a restart. As the replay is the first event handled after the restart,
+/// such a duplicate was persisted right after the original, but possibly long after it, depending on
+/// how long LDK Server was stopped. Duplicates are thus dropped if they were persisted right after a
+/// forwarded payment of the same contents, however much later. Any other forwarded payments are
+/// kept, even if they share their contents with others.
+///
+/// Must never be changed once released, see [`MIGRATIONS`].
+fn deduplicate_forwarded_payments(
+	tx: &Transaction<'_>, paginated_kv_table_name: &str,
+) -> rusqlite::Result<()> {
+	/// The marker of the last forwarded payment as of `user_version` 4.
+	#[derive(Clone, PartialEq, Message)]
+	struct ForwardedPaymentMarker {
+		#[prost(string, tag = "1")]
+		hash: String,
+		#[prost(uint64, tag = "2")]
+		sequence_number: u64,
+	}
+
+	let select_sql = format!(
+		"SELECT key, value FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace ORDER BY creation_time ASC, key ASC;",
+		paginated_kv_table_name
+	);
+	let update_sql = format!(
+		"UPDATE {} SET key=:new_key WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace AND key=:key;",
+		paginated_kv_table_name
+	);
+	let delete_sql = format!(
+		"DELETE FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace AND key=:key;",
+		paginated_kv_table_name
+	);
+	let insert_sql = format!(
+		"INSERT INTO {} (primary_namespace, secondary_namespace, key, creation_time, value) VALUES (:primary_namespace, :secondary_namespace, :key, 0, :value);",
+		paginated_kv_table_name
+	);
+	// Collect all rows first, as they are updated while being processed.
+	let rows = tx
+		.prepare(&select_sql)?
+		.query_map(
+			named_params! {
+				":primary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				":secondary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			},
+			|row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)),
+		)?
+		.collect::<rusqlite::Result<Vec<_>>>()?;
+
+	// The hash of the previous forwarded payment, and the last marker.
+	let mut previous: Option<String> = None;
+	let mut marker: Option<ForwardedPaymentMarker> = None;
+	for (key, value) in rows {
+		let forwarded_payment = ForwardedPayment::decode(&*value)
+			.map_err(|e| rusqlite::Error::FromSqlConversionFailure(1, Type::Blob, Box::new(e)))?;
+		let hash = sha256::Hash::hash(
+			&ForwardedPayment { timestamp: 0, ..forwarded_payment.clone() }.encode_to_vec(),
+		)
+		.to_string();
+
+		delete_attributes(
+			tx,
+			paginated_kv_table_name,
+			FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&key,
+		)?;
+		let is_replay = previous.as_ref() == Some(&hash);
+		previous = Some(hash.clone());
+		if is_replay {
+			tx.execute(
+				&delete_sql,
+				named_params! {
+					":primary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+					":secondary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+					":key": key,
+				},
+			)?;
+			continue;
+		}
+
+		let sequence_number = marker.as_ref().map_or(0, |marker| marker.sequence_number + 1);
+		let new_key = format!("{sequence_number:020}");
+		tx.execute(
+			&update_sql,
+			named_params! {
+				":primary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				":secondary_namespace": FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				":key": key,
+				":new_key": new_key,
+			},
+		)?;
+		insert_attributes(
+			tx,
+			paginated_kv_table_name,
+			FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&new_key,
+			&v3_forwarded_payment_attributes(&forwarded_payment),
+		)?;
+		marker = Some(ForwardedPaymentMarker { hash, sequence_number });
+	}
+
+	if let Some(marker) = marker {
+		tx.execute(
+			&insert_sql,
+			named_params! {
+				":primary_namespace": "forwarded_payment_marker",
+				":secondary_namespace": "",
+				":key": "last_forwarded_payment",
+				":value": marker.encode_to_vec(),
+			},
+		)?;
+	}
+	Ok(())
+}
+
+/// Indexes the existing payments by the sequence number of their latest update, numbering them in
+/// the order of their latest update timestamp, and persists the last sequence number assigned.
+///
+/// Must never be changed once released, see [`MIGRATIONS`].
+fn add_payment_update_sequence_numbers(
+	tx: &Transaction<'_>, paginated_kv_table_name: &str,
+) -> rusqlite::Result<()> {
+	let select_sql = format!(
+		"SELECT key FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace AND name=:name ORDER BY value ASC, key ASC;",
+		attributes_table_name(paginated_kv_table_name)
+	);
+	let insert_sql = format!(
+		"INSERT INTO {} (primary_namespace, secondary_namespace, key, creation_time, value) VALUES (:primary_namespace, :secondary_namespace, :key, 0, :value);",
+		paginated_kv_table_name
+	);
+	let keys = tx
+		.prepare(&select_sql)?
+		.query_map(
+			named_params! {
+				":primary_namespace": PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				":secondary_namespace": PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				":name": "latest_update_timestamp",
+			},
+			|row| row.get::<_, String>(0),
+		)?
+		.collect::<rusqlite::Result<Vec<_>>>()?;
+
+	let mut update_sequence_number = 0u64;
+	for key in keys {
+		update_sequence_number += 1;
+		insert_attributes(
+			tx,
+			paginated_kv_table_name,
+			PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			&key,
+			&[Attribute {
+				name: "update_sequence_number",
+				value: AttributeValue::Integer(update_sequence_number as i64),
+			}],
+		)?;
+	}
+
+	if update_sequence_number > 0 {
+		tx.execute(
+			&insert_sql,
+			named_params! {
+				":primary_namespace": "payment_update_sequence",
+				":secondary_namespace": "",
+				":key": "last_update_sequence_number",
+				":value": update_sequence_number.to_be_bytes(),
+			},
+		)?;
+	}
+	Ok(())
+}
+
+/// Indexes the existing event history by the type of its events.
+///
+/// Must never be changed once released, see [`MIGRATIONS`].
+fn add_event_type_attributes(
+	tx: &Transaction<'_>, paginated_kv_table_name: &str,
+) -> rusqlite::Result<()> {
+	let select_sql = format!(
+		"SELECT key, value FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace;",
+		paginated_kv_table_name
+	);
+	let rows = tx
+		.prepare(&select_sql)?
+		.query_map(
+			named_params! { ":primary_namespace": "events", ":secondary_namespace": "" },
+			|row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)),
+		)?
+		.collect::<rusqlite::Result<Vec<_>>>()?;
+
+	for (key, value) in rows {
+		let envelope = EventEnvelope::decode(&*value)
+			.map_err(|e| rusqlite::Error::FromSqlConversionFailure(1, Type::Blob, Box::new(e)))?;
+		let event_type = match envelope.event {
+			Some(event_envelope::Event::PaymentReceived(_)) => "PaymentReceived",
+			Some(event_envelope::Event::PaymentSuccessful(_)) => "PaymentSuccessful",
+			Some(event_envelope::Event::PaymentFailed(_)) => "PaymentFailed",
+			Some(event_envelope::Event::PaymentForwarded(_)) => "PaymentForwarded",
+			Some(event_envelope::Event::ChannelPending(_)) => "ChannelPending",
+			Some(event_envelope::Event::ChannelReady(_)) => "ChannelReady",
+			Some(event_envelope::Event::ChannelClosed(_)) => "ChannelClosed",
+			Some(event_envelope::Event::SplicePending(_)) => "SplicePending",
+			Some(event_envelope::Event::SpliceFailed(_)) => "SpliceFailed",
+			None => continue,
+		};
+		insert_attributes(
+			tx,
+			paginated_kv_table_name,
+			"events",
+			"",
+			&key,
+			&[Attribute { name: "event_type", value: AttributeValue::Text(event_type) }],
+		)?;
+	}
+	Ok(())
+}
+
+/// Migrates the database from its current `user_version` to the one after all of `migrations`.
+///
+/// New databases are initialized with the schema of `user_version` 1 first. Every migration is
+/// applied in its own transaction, along with the update of the `user_version`, so that a failed
+/// migration leaves the database at the previous version.
+fn migrate(
+	connection: &mut Connection, paginated_kv_table_name: &str, migrations: &[Migration],
+) -> io::Result<()> {
+	let latest_version = migrations.len() as u16 + 1;
+	let mut version = user_version(connection)?;
+	if version == 0 {
+		create_initial_schema(connection, paginated_kv_table_name)?;
+		version = 1;
+	}
+
+	if version > latest_version {
+		let msg = format!(
+			"Failed to open database: incompatible schema version {}. Expected: {}",
+			version, latest_version
+		);
+		return Err(io::Error::other(msg));
+	}
+
+	for (from_version, migration) in (1..).zip(migrations).skip(version as usize - 1) {
+		let to_version: u16 = from_version + 1;
+
+		let tx = connection.transaction().map_err(|e| {
+			let msg = format!("Failed to start transaction: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		migration(&tx, paginated_kv_table_name).map_err(|e| {
+			let msg = format!(
+				"Failed to migrate database from version {} to {}: {}",
+				from_version, to_version, e
+			);
+			io::Error::other(msg)
+		})?;
+
+		tx.pragma_update(None, "user_version", to_version).map_err(|e| {
+			let msg = format!("Failed to set PRAGMA user_version: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		// Dropping the transaction without committing it rolls back the migration.
+		tx.commit().map_err(|e| {
+			let msg = format!("Failed to commit transaction: {}", e);
+			io::Error::other(msg)
+		})?;
+	}
+
+	Ok(())
 }
 
 impl PaginatedKVStore for SqliteStore {
@@ -186,19 +813,60 @@ impl PaginatedKVStore for SqliteStore {
 	fn write(
 		&self, primary_namespace: &str, secondary_namespace: &str, key: &str, time: i64, buf: &[u8],
 	) -> io::Result<()> {
-		check_namespace_key_validity(primary_namespace, secondary_namespace, Some(key), "write")?;
+		self.write_batch(&[WriteOp {
+			primary_namespace,
+			secondary_namespace,
+			key,
+			time,
+			buf,
+			attributes: &[],
+		}])
+	}
 
-		let locked_conn = self.connection.lock().unwrap();
+	fn write_batch(&self, writes: &[WriteOp<'_>]) -> io::Result<()> {
+		for write in writes {
+			check_namespace_key_validity(
+				write.primary_namespace,
+				write.secondary_namespace,
+				Some(write.key),
+				"write",
+			)?;
+		}
 
-		let sql = format!(
-			"INSERT INTO {} (primary_namespace, secondary_namespace, key, creation_time, value)
-         VALUES (:primary_namespace, :secondary_namespace, :key, :creation_time, :value)
-         ON CONFLICT(primary_namespace, secondary_namespace, key)
-         DO UPDATE SET value = excluded.value;",
-			self.paginated_kv_table_name
-		);
+		let mut locked_conn = self.connection.lock().unwrap();
 
-		let mut stmt = locked_conn.prepare_cached(&sql).map_err(|e| {
+		let tx = locked_conn.transaction().map_err(|e| {
+			let msg = format!("Failed to start transaction: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		for write in writes {
+			self.write_internal(&tx, write)?;
+		}
+
+		// Dropping the transaction without committing it rolls back any of the writes.
+		tx.commit().map_err(|e| {
+			let msg = format!("Failed to commit transaction: {}", e);
+			io::Error::other(msg)
+		})
+	}
+
+	fn remove(
+		&self, primary_namespace: &str, secondary_namespace: &str, key: &str,
+	) -> io::Result<()> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, Some(key), "remove")?;
+
+		let mut locked_conn = self.connection.lock().unwrap();
+
+		let tx = locked_conn.transaction().map_err(|e| {
+			let msg = format!("Failed to start transaction: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		let sql = format!("DELETE FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace AND key=:key;",
+			self.paginated_kv_table_name);
+
+		let mut stmt = tx.prepare_cached(&sql).map_err(|e| {
 			let msg = format!("Failed to prepare statement: {}", e);
 			io::Error::other(msg)
 		})?;
@@ -207,37 +875,68 @@ impl PaginatedKVStore for SqliteStore {
 			":primary_namespace": primary_namespace,
 			":secondary_namespace": secondary_namespace,
 			":key": key,
-			":creation_time": time,
-			":value": buf,
 		})
-		.map(|_| ())
+		.and_then(|_| {
+			delete_attributes(
+				&tx,
+				&self.paginated_kv_table_name,
+				primary_namespace,
+				secondary_namespace,
+				key,
+			)
+		})
 		.map_err(|e| {
 			let msg = format!(
-				"Failed to write to key {}/{}/{}: {}",
+				"Failed to delete key {}/{}/{}: {}",
 				PrintableString(primary_namespace),
 				PrintableString(secondary_namespace),
 				PrintableString(key),
 				e
 			);
 			io::Error::other(msg)
+		})?;
+		drop(stmt);
+
+		tx.commit().map_err(|e| {
+			let msg = format!("Failed to commit transaction: {}", e);
+			io::Error::other(msg)
 		})
 	}
 
 	fn list(
 		&self, primary_namespace: &str, secondary_namespace: &str,
 		page_token: Option<(String, i64)>,
+	) -> io::Result<ListResponse> {
+		self.list_filtered(primary_namespace, secondary_namespace, &[], page_token)
+	}
+
+	fn list_filtered(
+		&self, primary_namespace: &str, secondary_namespace: &str, filters: &[ListFilter<'_>],
+		page_token: Option<(String, i64)>,
 	) -> io::Result<ListResponse> {
 		check_namespace_key_validity(primary_namespace, secondary_namespace, None, "list")?;
 
 		let locked_conn = self.connection.lock().unwrap();
 
-		let sql = format!(
+		let mut sql = format!(
 			"SELECT key, creation_time FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace \
-			AND ( creation_time < :creation_time_token OR (creation_time = :creation_time_token AND key > :key_token) ) \
-			ORDER BY creation_time DESC, key ASC LIMIT :page_size",
+			AND ( creation_time < :creation_time_token OR (creation_time = :creation_time_token AND key > :key_token) )",
 			self.paginated_kv_table_name
 		);
 
+		let mut filter_params: Vec<(String, Value)> = Vec::new();
+		for (i, filter) in filters.iter().enumerate() {
+			match filter {
+				ListFilter::Time(range) => {
+					sql.push_str(&format!(" AND creation_time BETWEEN :min_{i} AND :max_{i}"));
+					filter_params.push((format!(":min_{i}"), Value::Integer(*range.start())));
+					filter_params.push((format!(":max_{i}"), Value::Integer(*range.end())));
+				},
+				_ => sql.push_str(&self.filter_condition(i, filter, &mut filter_params)),
+			}
+		}
+		sql.push_str(" ORDER BY creation_time DESC, key ASC LIMIT :page_size");
+
 		let mut stmt = locked_conn.prepare_cached(&sql).map_err(|e| {
 			let msg = format!("Failed to prepare statement: {}", e);
 			io::Error::other(msg)
@@ -246,21 +945,22 @@ impl PaginatedKVStore for SqliteStore {
 		let mut keys: Vec<String> = Vec::new();
 		let page_token = page_token.unwrap_or(("".to_string(), i64::MAX));
 
+		let mut params: Vec<(&str, &dyn ToSql)> = vec![
+			(":primary_namespace", &primary_namespace),
+			(":secondary_namespace", &secondary_namespace),
+			(":key_token", &page_token.0),
+			(":creation_time_token", &page_token.1),
+			(":page_size", &LIST_KEYS_MAX_PAGE_SIZE),
+		];
+		params
+			.extend(filter_params.iter().map(|(name, value)| (name.as_str(), value as &dyn ToSql)));
+
 		let rows_iter = stmt
-			.query_map(
-				named_params! {
-						":primary_namespace": primary_namespace,
-						":secondary_namespace": secondary_namespace,
-						":key_token": page_token.0,
-						":creation_time_token": page_token.1,
-						":page_size": LIST_KEYS_MAX_PAGE_SIZE,
-				},
-				|row| {
-					let key: String = row.get(0)?;
-					let creation_time: i64 = row.get(1)?;
-					Ok((key, creation_time))
-				},
-			)
+			.query_map(params.as_slice(), |row| {
+				let key: String = row.get(0)?;
+				let creation_time: i64 = row.get(1)?;
+				Ok((key, creation_time))
+			})
 			.map_err(|e| {
 				let msg = format!("Failed to retrieve queried rows: {}", e);
 				io::Error::other(msg)
@@ -285,16 +985,119 @@ impl PaginatedKVStore for SqliteStore {
 
 		Ok(ListResponse { keys, next_page_token })
 	}
+
+	fn list_by_attribute(
+		&self, primary_namespace: &str, secondary_namespace: &str, name: &str,
+		range: RangeInclusive<i64>, filters: &[ListFilter<'_>], page_token: Option<(String, i64)>,
+	) -> io::Result<ListResponse> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, None, "list")?;
+
+		let locked_conn = self.connection.lock().unwrap();
+
+		let mut sql = format!(
+			"SELECT key, value FROM {} WHERE primary_namespace=:primary_namespace AND secondary_namespace=:secondary_namespace \
+			AND name=:name AND value BETWEEN :min AND :max \
+			AND ( value > :value_token OR (value = :value_token AND key > :key_token) )",
+			attributes_table_name(&self.paginated_kv_table_name)
+		);
+		let mut filter_params: Vec<(String, Value)> = Vec::new();
+		for (i, filter) in filters.iter().enumerate() {
+			sql.push_str(&self.filter_condition(i, filter, &mut filter_params));
+		}
+		sql.push_str(" ORDER BY value ASC, key ASC LIMIT :page_size");
+
+		let mut stmt = locked_conn.prepare_cached(&sql).map_err(|e| {
+			let msg = format!("Failed to prepare statement: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		let (key_token, value_token) = page_token.unwrap_or(("".to_string(), i64::MIN));
+		let mut params: Vec<(&str, &dyn ToSql)> = vec![
+			(":primary_namespace", &primary_namespace),
+			(":secondary_namespace", &secondary_namespace),
+			(":name", &name),
+			(":min", range.start()),
+			(":max", range.end()),
+			(":key_token", &key_token),
+			(":value_token", &value_token),
+			(":page_size", &LIST_KEYS_MAX_PAGE_SIZE),
+		];
+		params
+			.extend(filter_params.iter().map(|(name, value)| (name.as_str(), value as &dyn ToSql)));
+
+		let rows = stmt
+			.query_map(params.as_slice(), |row| {
+				Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+			})
+			.and_then(|rows| rows.collect::<rusqlite::Result<Vec<_>>>())
+			.map_err(|e| {
+				let msg = format!("Failed to retrieve queried rows: {}", e);
+				io::Error::other(msg)
+			})?;
+
+		let next_page_token = rows.last().cloned();
+		let keys = rows.into_iter().map(|(key, _)| key).collect();
+
+		Ok(ListResponse { keys, next_page_token })
+	}
+
+	fn min_attribute(
+		&self, primary_namespace: &str, secondary_namespace: &str, name: &str,
+		filters: &[ListFilter<'_>],
+	) -> io::Result<Option<i64>> {
+		check_namespace_key_validity(primary_namespace, secondary_namespace, None, "list")?;
+
+		let locked_conn = self.connection.lock().unwrap();
+
+		let mut sql = format!(
+			"SELECT MIN(value) FROM {} WHERE primary_namespace=:primary_namespace \
+			AND secondary_namespace=:secondary_namespace AND name=:name",
+			attributes_table_name(&self.paginated_kv_table_name)
+		);
+		let mut filter_params: Vec<(String, Value)> = Vec::new();
+		for (i, filter) in filters.iter().enumerate() {
+			sql.push_str(&self.filter_condition(i, filter, &mut filter_params));
+		}
+
+		let mut stmt = locked_conn.prepare_cached(&sql).map_err(|e| {
+			let msg = format!("Failed to prepare statement: {}", e);
+			io::Error::other(msg)
+		})?;
+
+		let mut params: Vec<(&str, &dyn ToSql)> = vec![
+			(":primary_namespace", &primary_namespace),
+			(":secondary_namespace", &secondary_namespace),
+			(":name", &name),
+		];
+		params
+			.extend(filter_params.iter().map(|(name, value)| (name.as_str(), value as &dyn ToSql)));
+
+		stmt.query_row(params.as_slice(), |row| row.get::<_, Option<i64>>(0)).map_err(|e| {
+			let msg = format!("Failed to retrieve queried rows: {}", e);
+			io::Error::other(msg)
+		})
+	}
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 	use std::panic::RefUnwindSafe;
+	use std::path::Path;
 
 	use hex::DisplayHex;
 	use ldk_node::lightning::util::persist::KVSTORE_NAMESPACE_KEY_MAX_LEN;
+	use ldk_server_protos::types::{
+		payment_kind, PaymentKind, PaymentKindType, PaymentStatus, Spontaneous,
+	};
+	use rusqlite::params;
 
 	use super::*;
+	use crate::io::persist::{
+		forwarded_payment_key, ForwardedPaymentSequence, PaymentUpdateSequence,
+		EVENT_TYPE_ATTRIBUTE, FORWARDED_PAYMENT_NEXT_NODE_ID_ATTRIBUTE,
+		FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE, PAYMENT_KIND_ATTRIBUTE,
+		PAYMENT_STATUS_ATTRIBUTE, PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE,
+	};
 
 	#[test]
 	fn read_write_remove_list_persist() {
@@ -309,6 +1112,336 @@ mod tests {
 		do_read_write_remove_list_persist(&store);
 	}
 
+	#[test]
+	fn write_batch_and_remove() {
+		let mut temp_path = random_storage_path();
+		temp_path.push("write_batch_and_remove");
+		let store = SqliteStore::new(
+			temp_path,
+			Some("test_db".to_string()),
+			Some("test_table".to_string()),
+		)
+		.unwrap();
+
+		let data = [42u8; 32];
+		let write_op = |primary_namespace, key| WriteOp {
+			primary_namespace,
+			secondary_namespace: "",
+			key,
+			time: 0,
+			buf: &data,
+			attributes: &[],
+		};
+
+		store.write_batch(&[write_op("first", "key_0"), write_op("second", "key_1")]).unwrap();
+		assert_eq!(store.read("first", "", "key_0").unwrap(), data);
+		assert_eq!(store.read("second", "", "key_1").unwrap(), data);
+
+		store.remove("first", "", "key_0").unwrap();
+		let err = store.read("first", "", "key_0").unwrap_err();
+		assert_eq!(err.kind(), io::ErrorKind::NotFound);
+		assert!(store.list("first", "", None).unwrap().keys.is_empty());
+
+		// Removing a key which doesn't exist succeeds.
+		store.remove("first", "", "key_0").unwrap();
+	}
+
+	#[test]
+	fn list_filtered() {
+		let mut temp_path = random_storage_path();
+		temp_path.push("list_filtered");
+		let store = SqliteStore::new(
+			temp_path,
+			Some("test_db".to_string()),
+			Some("test_table".to_string()),
+		)
+		.unwrap();
+		do_list_filtered(&store);
+	}
+
+	#[test]
+	fn list_by_attribute() {
+		let mut temp_path = random_storage_path();
+		temp_path.push("list_by_attribute");
+		let store = SqliteStore::new(
+			temp_path,
+			Some("test_db".to_string()),
+			Some("test_table".to_string()),
+		)
+		.unwrap();
+		do_list_by_attribute(&store);
+	}
+
+	fn v1_payment() -> Payment {
+		Payment {
+			id: "payment_id".to_string(),
+			kind: Some(PaymentKind {
+				kind: Some(payment_kind::Kind::Spontaneous(Spontaneous::default())),
+			}),
+			status: PaymentStatus::Succeeded.into(),
+			latest_update_timestamp: 42,
+			..Default::default()
+		}
+	}
+
+	fn v1_forwarded_payment() -> ForwardedPayment {
+		ForwardedPayment {
+			prev_channel_id: "prev_channel_id".to_string(),
+			next_channel_id: "next_channel_id".to_string(),
+			prev_node_id: "prev_node_id".to_string(),
+			next_node_id: "next_node_id".to_string(),
+			..Default::default()
+		}
+	}
+
+	fn v1_event() -> EventEnvelope {
+		EventEnvelope {
+			event: Some(event_envelope::Event::ChannelReady(Default::default())),
+			sequence_number: 1,
+			..Default::default()
+		}
+	}
+
+	/// Creates a database as created by the first release, i.e., of `user_version` 1, holding a
+	/// single payment, a single event and a forwarded payment, which was persisted twice as its
+	/// event was replayed, and forwarded again an hour later.
+	fn create_v1_database(db_file_path: &Path) -> Connection {
+		fs::create_dir_all(db_file_path.parent().unwrap()).unwrap();
+		let connection = Connection::open(db_file_path).unwrap();
+		connection
+			.execute_batch(
+				"PRAGMA user_version = 1;
+				CREATE TABLE test_table (
+				primary_namespace TEXT NOT NULL,
+				secondary_namespace TEXT DEFAULT \"\" NOT NULL,
+				key TEXT NOT NULL CHECK (key <> ''),
+				creation_time INTEGER NOT NULL,
+				value BLOB, PRIMARY KEY ( primary_namespace, secondary_namespace, key )
+				);
+				CREATE INDEX idx_creation_time ON test_table (creation_time);",
+			)
+			.unwrap();
+		connection
+			.execute(
+				"INSERT INTO test_table VALUES ('payments', '', 'payment_id', 42, ?1);",
+				[v1_payment().encode_to_vec()],
+			)
+			.unwrap();
+		connection
+			.execute(
+				"INSERT INTO test_table VALUES ('events', '', '00000000000000000001', -1, ?1);",
+				[v1_event().encode_to_vec()],
+			)
+			.unwrap();
+		let other_forwarded_payment =
+			ForwardedPayment { total_fee_earned_msat: Some(1_000), ..v1_forwarded_payment() };
+		for (key, creation_time, forwarded_payment) in [
+			("forwarded_payment_id", 43, v1_forwarded_payment()),
+			("replayed_payment_id", 44, v1_forwarded_payment()),
+			("replayed_after_downtime_payment_id", 86_443, v1_forwarded_payment()),
+			("other_payment_id", 90_000, other_forwarded_payment),
+			("repeated_payment_id", 93_600, v1_forwarded_payment()),
+		] {
+			connection
+				.execute(
+					"INSERT INTO test_table VALUES ('forwarded_payments', '', ?1, ?2, ?3);",
+					params![key, creation_time, forwarded_payment.encode_to_vec()],
+				)
+				.unwrap();
+		}
+		connection
+	}
+
+	fn add_label_column(tx: &Transaction<'_>, table_name: &str) -> rusqlite::Result<()> {
+		tx.execute_batch(&format!("ALTER TABLE {} ADD COLUMN label TEXT;", table_name))
+	}
+
+	fn create_labels_table(tx: &Transaction<'_>, _table_name: &str) -> rusqlite::Result<()> {
+		tx.execute_batch("CREATE TABLE labels (label TEXT PRIMARY KEY);")
+	}
+
+	fn fail_after_creating_table(tx: &Transaction<'_>, _table_name: &str) -> rusqlite::Result<()> {
+		tx.execute_batch("CREATE TABLE partial (id INTEGER);")?;
+		tx.execute_batch("INVALID SQL;")
+	}
+
+	fn table_exists(connection: &Connection, table_name: &str) -> bool {
+		connection
+			.query_row(
+				"SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+				[table_name],
+				|row| row.get::<_, u32>(0),
+			)
+			.unwrap() == 1
+	}
+
+	#[test]
+	fn migrate_v1_database() {
+		let db_file_path = random_storage_path().join("test_db");
+		let mut connection = create_v1_database(&db_file_path);
+
+		// Migrating to the current schema keeps all data.
+		migrate(&mut connection, "test_table", MIGRATIONS).unwrap();
+		assert_eq!(user_version(&connection).unwrap(), MIGRATIONS.len() as u16 + 1);
+		drop(connection);
+
+		let store = SqliteStore::new(
+			db_file_path.parent().unwrap().to_path_buf(),
+			Some("test_db".to_string()),
+			Some("test_table".to_string()),
+		)
+		.unwrap();
+		assert_eq!(store.read("payments", "", "payment_id").unwrap(), v1_payment().encode_to_vec());
+		let list_response = store.list("payments", "", None).unwrap();
+		assert_eq!(list_response.keys, vec!["payment_id"]);
+		assert_eq!(list_response.next_page_token, Some(("payment_id".to_string(), 42)));
+
+		// Existing payments are indexed by their attributes.
+		let status = PaymentStatus::Succeeded as i64;
+		let kind = PaymentKindType::Spontaneous as i64;
+		let filters = [
+			ListFilter::Attribute { name: PAYMENT_STATUS_ATTRIBUTE, range: status..=status },
+			ListFilter::Attribute { name: PAYMENT_KIND_ATTRIBUTE, range: kind..=kind },
+		];
+		let list_response = store.list_filtered("payments", "", &filters, None).unwrap();
+		assert_eq!(list_response.keys, vec!["payment_id"]);
+		let pending = PaymentStatus::Pending as i64;
+		let filters =
+			[ListFilter::Attribute { name: PAYMENT_STATUS_ATTRIBUTE, range: pending..=pending }];
+		assert!(store.list_filtered("payments", "", &filters, None).unwrap().keys.is_empty());
+
+		// Existing payments are numbered by their latest update, and later updates continue from
+		// the last sequence number.
+		let list_response = store
+			.list_by_attribute(
+				"payments",
+				"",
+				PAYMENT_UPDATE_SEQUENCE_NUMBER_ATTRIBUTE,
+				0..=i64::MAX,
+				&[],
+				None,
+			)
+			.unwrap();
+		assert_eq!(list_response.keys, vec!["payment_id"]);
+		assert_eq!(list_response.next_page_token, Some(("payment_id".to_string(), 1)));
+		assert_eq!(PaymentUpdateSequence::load(&store).unwrap().next(), 2);
+
+		// Existing forwarded payments are deduplicated, only dropping the replays persisted right
+		// after the original, however much later, and re-keyed. They are timestamped with their
+		// creation time, and indexed by the channels and nodes they were forwarded between.
+		let forwarded_payment_keys =
+			vec![forwarded_payment_key(2), forwarded_payment_key(1), forwarded_payment_key(0)];
+		let list_response = store.list("forwarded_payments", "", None).unwrap();
+		assert_eq!(list_response.keys, forwarded_payment_keys);
+		for (sequence_number, timestamp, total_fee_earned_msat) in
+			[(0, 43, None), (1, 90_000, Some(1_000)), (2, 93_600, None)]
+		{
+			let key = forwarded_payment_key(sequence_number);
+			let forwarded_payment_bytes = store.read("forwarded_payments", "", &key).unwrap();
+			let forwarded_payment = ForwardedPayment::decode(&*forwarded_payment_bytes).unwrap();
+			let expected =
+				ForwardedPayment { timestamp, total_fee_earned_msat, ..v1_forwarded_payment() };
+			assert_eq!(forwarded_payment, expected);
+		}
+
+		// A replay of the last forwarded payment is recognized after the migration.
+		let mut forwarded_payment_sequence = ForwardedPaymentSequence::load(&store).unwrap();
+		assert!(forwarded_payment_sequence.next(&v1_forwarded_payment()).is_none());
+		let marker = forwarded_payment_sequence.next(&v1_forwarded_payment()).unwrap();
+		assert_eq!(marker.sequence_number, 3);
+
+		let filters = [
+			ListFilter::TextAttribute {
+				name: FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE,
+				value: "prev_channel_id",
+			},
+			ListFilter::TextAttribute {
+				name: FORWARDED_PAYMENT_NEXT_NODE_ID_ATTRIBUTE,
+				value: "next_node_id",
+			},
+		];
+		let list_response = store.list_filtered("forwarded_payments", "", &filters, None).unwrap();
+		assert_eq!(list_response.keys, forwarded_payment_keys);
+		let filters = [ListFilter::TextAttribute {
+			name: FORWARDED_PAYMENT_PREV_CHANNEL_ID_ATTRIBUTE,
+			value: "next_channel_id",
+		}];
+		assert!(store
+			.list_filtered("forwarded_payments", "", &filters, None)
+			.unwrap()
+			.keys
+			.is_empty());
+
+		// Existing events are indexed by their type.
+		let filter = |values: &'static [&'static str]| ListFilter::TextAttributeIn {
+			name: EVENT_TYPE_ATTRIBUTE,
+			values,
+		};
+		let list_response =
+			store.list_filtered("events", "", &[filter(&["ChannelReady"])], None).unwrap();
+		assert_eq!(list_response.keys, vec!["00000000000000000001"]);
+		assert!(store
+			.list_filtered("events", "", &[filter(&["ChannelClosed"])], None)
+			.unwrap()
+			.keys
+			.is_empty());
+	}
+
+	#[test]
+	fn migrations_are_applied_in_order() {
+		let db_file_path = random_storage_path().join("test_db");
+		let mut connection = create_v1_database(&db_file_path);
+
+		migrate(&mut connection, "test_table", &[add_label_column]).unwrap();
+		assert_eq!(user_version(&connection).unwrap(), 2);
+		let label: Option<String> = connection
+			.query_row("SELECT label FROM test_table WHERE key = 'payment_id'", [], |row| {
+				row.get(0)
+			})
+			.unwrap();
+		assert_eq!(label, None);
+
+		// Only migrations which weren't applied yet are run.
+		migrate(&mut connection, "test_table", &[add_label_column, create_labels_table]).unwrap();
+		assert_eq!(user_version(&connection).unwrap(), 3);
+		assert!(table_exists(&connection, "labels"));
+
+		// Migrating an up-to-date database is a no-op.
+		migrate(&mut connection, "test_table", &[add_label_column, create_labels_table]).unwrap();
+		assert_eq!(user_version(&connection).unwrap(), 3);
+
+		// Databases of newer versions are refused.
+		assert!(migrate(&mut connection, "test_table", &[add_label_column]).is_err());
+	}
+
+	#[test]
+	fn failed_migration_is_rolled_back() {
+		let db_file_path = random_storage_path().join("test_db");
+		let mut connection = create_v1_database(&db_file_path);
+
+		let migrations: &[Migration] = &[add_label_column, fail_after_creating_table];
+		assert!(migrate(&mut connection, "test_table", migrations).is_err());
+
+		// Migrations preceding the failed one stay applied.
+		assert_eq!(user_version(&connection).unwrap(), 2);
+		assert!(!table_exists(&connection, "partial"));
+
+		// Once fixed, migrating resumes from the failed migration.
+		migrate(&mut connection, "test_table", &[add_label_column, create_labels_table]).unwrap();
+		assert_eq!(user_version(&connection).unwrap(), 3);
+		assert!(table_exists(&connection, "labels"));
+	}
+
+	#[test]
+	fn new_database_is_created_at_latest_version() {
+		let temp_path = random_storage_path();
+		SqliteStore::new(temp_path.clone(), Some("test_db".to_string()), None).unwrap();
+
+		let connection = Connection::open(temp_path.join("test_db")).unwrap();
+		assert_eq!(user_version(&connection).unwrap(), MIGRATIONS.len() as u16 + 1);
+		assert!(table_exists(&connection, DEFAULT_PAGINATED_KV_TABLE_NAME));
+	}
+
 	pub(crate) fn random_storage_path() -> PathBuf {
 		let mut temp_path = std::env::temp_dir();
 		let mut bytes = [0u8; 8];
@@ -318,6 +1451,227 @@ mod tests {
 		temp_path
 	}
 
+	pub(crate) fn create_store(storage_path: PathBuf) -> Arc<dyn PaginatedKVStore> {
+		Arc::new(SqliteStore::new(storage_path, None, None).unwrap())
+	}
+
+	pub(crate) fn do_list_filtered<K: PaginatedKVStore>(kv_store: &K) {
+		let data = [42u8; 32];
+		let write = |key: &str, time: i64, attributes: &[Attribute<'_>]| {
+			let write_op = WriteOp {
+				primary_namespace: "testspace",
+				secondary_namespace: "",
+				key,
+				time,
+				buf: &data,
+				attributes,
+			};
+			kv_store.write_batch(&[write_op]).unwrap();
+		};
+		let list_keys = |filters: &[ListFilter<'_>]| -> Vec<String> {
+			kv_store.list_filtered("testspace", "", filters, None).unwrap().keys
+		};
+		let attribute = |name, value| Attribute { name, value: AttributeValue::Integer(value) };
+		let text_attribute = |name, value| Attribute { name, value: AttributeValue::Text(value) };
+		let equals = |name, value| ListFilter::Attribute { name, range: value..=value };
+
+		write(
+			"a",
+			1,
+			&[attribute("status", 0), attribute("kind", 1), text_attribute("peer", "alice")],
+		);
+		write(
+			"b",
+			2,
+			&[attribute("status", 1), attribute("kind", 1), text_attribute("peer", "bob")],
+		);
+		write(
+			"c",
+			3,
+			&[
+				attribute("status", 1),
+				attribute("kind", 2),
+				attribute("tag", 7),
+				attribute("tag", 8),
+				text_attribute("peer", "alice"),
+			],
+		);
+		// Attributes are scoped to their namespace.
+		kv_store
+			.write_batch(&[WriteOp {
+				primary_namespace: "otherspace",
+				secondary_namespace: "",
+				key: "a",
+				time: 4,
+				buf: &data,
+				attributes: &[attribute("status", 1)],
+			}])
+			.unwrap();
+
+		assert_eq!(list_keys(&[]), vec!["c", "b", "a"]);
+		assert_eq!(list_keys(&[equals("status", 1)]), vec!["c", "b"]);
+		assert_eq!(list_keys(&[equals("status", 1), equals("kind", 1)]), vec!["b"]);
+		assert_eq!(list_keys(&[ListFilter::Time(1..=2)]), vec!["b", "a"]);
+		assert_eq!(
+			list_keys(&[
+				ListFilter::Time(2..=i64::MAX),
+				ListFilter::Attribute { name: "kind", range: 0..=1 }
+			]),
+			vec!["b"]
+		);
+		assert_eq!(list_keys(&[equals("tag", 8)]), vec!["c"]);
+		assert!(list_keys(&[equals("status", 2)]).is_empty());
+		assert!(list_keys(&[equals("unknown", 1)]).is_empty());
+
+		// Text attributes are matched exactly, and independently of integer attributes.
+		let text_equals = |name, value| ListFilter::TextAttribute { name, value };
+		assert_eq!(list_keys(&[text_equals("peer", "alice")]), vec!["c", "a"]);
+		assert_eq!(list_keys(&[text_equals("peer", "alice"), equals("status", 1)]), vec!["c"]);
+		assert!(list_keys(&[text_equals("peer", "ALICE")]).is_empty());
+		assert!(list_keys(&[text_equals("status", "1")]).is_empty());
+		assert!(list_keys(&[equals("peer", 0)]).is_empty());
+		let text_in =
+			|name, values: &'static [&'static str]| ListFilter::TextAttributeIn { name, values };
+		assert_eq!(list_keys(&[text_in("peer", &["alice", "bob"])]), vec!["c", "b", "a"]);
+		assert_eq!(list_keys(&[text_in("peer", &["bob", "carol"])]), vec!["b"]);
+		assert!(list_keys(&[text_in("peer", &[])]).is_empty());
+
+		// Writing a key replaces its attributes, and removing it removes them.
+		write("b", 2, &[attribute("status", 0)]);
+		assert_eq!(list_keys(&[equals("status", 1)]), vec!["c"]);
+		assert_eq!(list_keys(&[equals("status", 0)]), vec!["b", "a"]);
+		kv_store.write("testspace", "", "c", 3, &data).unwrap();
+		assert!(list_keys(&[equals("tag", 7)]).is_empty());
+		kv_store.remove("testspace", "", "a").unwrap();
+		write("a", 1, &[]);
+		assert_eq!(list_keys(&[equals("status", 0)]), vec!["b"]);
+
+		// Pages only contain matching keys.
+		for i in 0..150 {
+			write(&format!("key_{:03}", i), 10, &[attribute("status", (i % 2) as i64 + 2)]);
+		}
+		let response =
+			kv_store.list_filtered("testspace", "", &[equals("status", 3)], None).unwrap();
+		assert_eq!(
+			response.keys,
+			(1..150).step_by(2).map(|i| format!("key_{:03}", i)).collect::<Vec<_>>()
+		);
+		let response = kv_store
+			.list_filtered(
+				"testspace",
+				"",
+				&[equals("status", 2)],
+				Some(("key_098".to_string(), 10)),
+			)
+			.unwrap();
+		assert_eq!(
+			response.keys,
+			(100..150).step_by(2).map(|i| format!("key_{:03}", i)).collect::<Vec<_>>()
+		);
+		assert_eq!(response.next_page_token, Some(("key_148".to_string(), 10)));
+	}
+
+	pub(crate) fn do_list_by_attribute<K: PaginatedKVStore>(kv_store: &K) {
+		let data = [42u8; 32];
+		let write = |primary_namespace, key: &str, time: i64, updated: i64| {
+			let attributes =
+				[Attribute { name: "updated", value: AttributeValue::Integer(updated) }];
+			let write_op = WriteOp {
+				primary_namespace,
+				secondary_namespace: "",
+				key,
+				time,
+				buf: &data,
+				attributes: &attributes,
+			};
+			kv_store.write_batch(&[write_op]).unwrap();
+		};
+		let list = |range, page_token| {
+			kv_store.list_by_attribute("testspace", "", "updated", range, &[], page_token).unwrap()
+		};
+
+		write("testspace", "a", 1, 20);
+		write("testspace", "b", 2, 10);
+		write("testspace", "c", 3, 10);
+		// Keys without the attribute, or in other namespaces, aren't returned.
+		kv_store.write("testspace", "", "d", 4, &data).unwrap();
+		write("otherspace", "e", 5, 10);
+
+		// Keys are ordered by their attribute value and then by key, independently of their `time`.
+		let response = list(i64::MIN..=i64::MAX, None);
+		assert_eq!(response.keys, vec!["b", "c", "a"]);
+		assert_eq!(response.next_page_token, Some(("a".to_string(), 20)));
+		assert_eq!(list(15..=i64::MAX, None).keys, vec!["a"]);
+		assert_eq!(list(i64::MIN..=10, None).keys, vec!["b", "c"]);
+		assert_eq!(list(i64::MIN..=i64::MAX, Some(("b".to_string(), 10))).keys, vec!["c", "a"]);
+
+		// Resuming from the last token only returns keys whose attribute value increased beyond it.
+		let page_token = response.next_page_token;
+		let response = list(i64::MIN..=i64::MAX, page_token.clone());
+		assert!(response.keys.is_empty());
+		assert_eq!(response.next_page_token, None);
+		write("testspace", "b", 2, 30);
+		assert_eq!(list(i64::MIN..=i64::MAX, page_token).keys, vec!["b"]);
+
+		// Pages are limited in size.
+		for i in 0..150 {
+			write("testspace", &format!("key_{:03}", i), 0, 100);
+		}
+		let response = list(100..=100, None);
+		assert_eq!(response.keys.len(), 100);
+		assert_eq!(response.next_page_token, Some(("key_099".to_string(), 100)));
+		let response = list(100..=100, response.next_page_token);
+		assert_eq!(response.keys, (100..150).map(|i| format!("key_{:03}", i)).collect::<Vec<_>>());
+
+		// Keys are filtered by the given filters, which also allow to look up the lowest attribute
+		// value of the matching keys to start listing from.
+		let write_created = |key, updated: i64, created: i64| {
+			let attributes = [
+				Attribute { name: "updated", value: AttributeValue::Integer(updated) },
+				Attribute { name: "created", value: AttributeValue::Integer(created) },
+			];
+			let write_op = WriteOp {
+				primary_namespace: "filteredspace",
+				secondary_namespace: "",
+				key,
+				time: created,
+				buf: &data,
+				attributes: &attributes,
+			};
+			kv_store.write_batch(&[write_op]).unwrap();
+		};
+		write_created("f", 300, 30);
+		write_created("g", 200, 20);
+		write_created("h", 100, 10);
+		for filters in [
+			[ListFilter::Attribute { name: "created", range: 20..=i64::MAX }],
+			[ListFilter::Time(20..=i64::MAX)],
+		] {
+			let min = kv_store.min_attribute("filteredspace", "", "updated", &filters).unwrap();
+			assert_eq!(min, Some(200));
+			let list_filtered = |page_token| {
+				kv_store
+					.list_by_attribute(
+						"filteredspace",
+						"",
+						"updated",
+						200..=i64::MAX,
+						&filters,
+						page_token,
+					)
+					.unwrap()
+			};
+			let response = list_filtered(None);
+			assert_eq!(response.keys, vec!["g", "f"]);
+			assert_eq!(response.next_page_token, Some(("f".to_string(), 300)));
+			assert!(list_filtered(response.next_page_token).keys.is_empty());
+		}
+		let filters = [ListFilter::Attribute { name: "created", range: 40..=i64::MAX }];
+		assert_eq!(kv_store.min_attribute("filteredspace", "", "updated", &filters).unwrap(), None);
+		assert_eq!(kv_store.min_attribute("filteredspace", "", "updated", &[]).unwrap(), Some(100));
+		assert_eq!(kv_store.min_attribute("otherspace", "", "created", &[]).unwrap(), None);
+	}
+
 	pub(crate) fn do_read_write_remove_list_persist<K: PaginatedKVStore + RefUnwindSafe>(
 		kv_store: &K,
 	) {
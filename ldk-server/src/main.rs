@@ -8,12 +8,15 @@
 // licenses.
 
 mod api;
+mod auth;
+#[cfg(feature = "grpc")]
+mod grpc;
 mod io;
 mod service;
+mod sse;
 mod util;
 
 use std::fs;
-use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -28,32 +31,57 @@ use ldk_node::entropy::NodeEntropy;
 use ldk_node::lightning::ln::channelmanager::PaymentId;
 use ldk_node::{Builder, Event, Node};
 use ldk_server_protos::events;
-use ldk_server_protos::events::{event_envelope, EventEnvelope};
-use ldk_server_protos::types::Payment;
-use log::{debug, error, info};
+use ldk_server_protos::events::event_envelope;
+use ldk_server_protos::types::payment_kind::Kind;
+use ldk_server_protos::types::{ForwardedPayment, Payment, PaymentDirection, PaymentMetadata};
+use log::{debug, error, info, warn};
 use prost::Message;
 use tokio::net::TcpListener;
 use tokio::select;
 use tokio::signal::unix::SignalKind;
 
+use crate::auth::api_key_manager::ApiKeyManager;
+use crate::auth::approval_queue::ApprovalQueue;
+use crate::auth::nonce_cache::NonceCache;
+use crate::auth::spending_limits::{SpendingLimits, SpendingTracker};
+use crate::auth::{generate_api_key, write_api_key_file, ApiKey, Permission, ADMIN_API_KEY_NAME};
+use crate::io::events::broadcast::EventBroadcaster;
+use crate::io::events::composite::{CompositeEventPublisher, EventSink};
 use crate::io::events::event_publisher::EventPublisher;
+use crate::io::events::file::FileEventPublisher;
 use crate::io::events::get_event_name;
+use crate::io::events::outbox::EventOutbox;
 #[cfg(feature = "events-rabbitmq")]
 use crate::io::events::rabbitmq::{RabbitMqConfig, RabbitMqEventPublisher};
-use crate::io::persist::paginated_kv_store::PaginatedKVStore;
+use crate::io::events::webhook::WebhookEventPublisher;
+use crate::io::persist::paginated_kv_store::{PaginatedKVStore, WriteOp};
+#[cfg(feature = "storage-postgres")]
+use crate::io::persist::postgres_store::PostgresStore;
 use crate::io::persist::sqlite_store::SqliteStore;
 use crate::io::persist::{
+	forwarded_payment_attributes, forwarded_payment_hash, forwarded_payment_key,
+	payment_attributes, payment_metadata_attributes, payment_update_sequence_write,
+	read_payment_metadata, ForwardedPaymentSequence, PaymentUpdateSequence,
 	FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
-	FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE, PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
-	PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE, FORWARDED_PAYMENT_MARKER_PERSISTENCE_KEY,
+	FORWARDED_PAYMENT_MARKER_PERSISTENCE_PRIMARY_NAMESPACE,
+	FORWARDED_PAYMENT_MARKER_PERSISTENCE_SECONDARY_NAMESPACE,
+	OFFER_METADATA_PERSISTENCE_PRIMARY_NAMESPACE, OFFER_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+	PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE, PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+	PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+	PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
 };
 use crate::service::NodeService;
 use crate::util::config::{load_config, ArgsConfig, ChainSource};
 use crate::util::logger::ServerLogger;
-use crate::util::proto_adapter::{forwarded_payment_to_proto, payment_to_proto};
-use crate::util::tls::get_or_generate_tls_config;
+use crate::util::proto_adapter::{
+	channel_closed_to_proto, channel_pending_to_proto, channel_ready_to_proto,
+	forwarded_payment_to_proto, payment_to_proto, splice_failed_to_proto, splice_pending_to_proto,
+};
+use crate::util::tls::{client_identity_from_cert, get_or_generate_tls_config};
 
 const API_KEY_FILE: &str = "api_key";
+const API_KEYS_DIR: &str = "api_keys";
 
 pub fn get_default_data_dir() -> Option<PathBuf> {
 	#[cfg(target_os = "macos")]
@@ -128,7 +156,8 @@ fn main() {
 		},
 	};
 
-	let api_key = match load_or_generate_api_key(&network_dir) {
+	let api_key_path = network_dir.join(API_KEY_FILE);
+	let api_key = match load_or_generate_api_key(&api_key_path) {
 		Ok(key) => key,
 		Err(e) => {
 			eprintln!("Failed to load or generate API key: {e}");
@@ -136,6 +165,56 @@ fn main() {
 		},
 	};
 
+	let mut configured_api_keys = vec![(
+		ApiKey::new(
+			ADMIN_API_KEY_NAME.to_string(),
+			api_key,
+			vec![Permission::Admin],
+			SpendingLimits::default(),
+			None,
+		),
+		api_key_path,
+	)];
+	for api_key_config in config_file.api_keys {
+		let api_key_path = network_dir.join(API_KEYS_DIR).join(&api_key_config.name);
+		let key = match load_or_generate_api_key(&api_key_path) {
+			Ok(key) => key,
+			Err(e) => {
+				eprintln!("Failed to load or generate API key '{}': {e}", api_key_config.name);
+				std::process::exit(-1);
+			},
+		};
+		let spending_limits = SpendingLimits {
+			max_payment_amount_msat: api_key_config
+				.max_send_amount_sats
+				.map(|sats| sats.saturating_mul(1000)),
+			daily_budget_msat: api_key_config
+				.daily_budget_sats
+				.map(|sats| sats.saturating_mul(1000)),
+		};
+		configured_api_keys.push((
+			ApiKey::new(
+				api_key_config.name,
+				key,
+				api_key_config.permissions,
+				spending_limits,
+				api_key_config.client_identity,
+			),
+			api_key_path,
+		));
+	}
+	let nonce_cache = Arc::new(NonceCache::new());
+	let global_spending_limits = SpendingLimits {
+		max_payment_amount_msat: config_file
+			.spending_limits
+			.max_payment_amount_sats
+			.map(|sats| sats.saturating_mul(1000)),
+		daily_budget_msat: config_file
+			.spending_limits
+			.daily_budget_sats
+			.map(|sats| sats.saturating_mul(1000)),
+	};
+
 	ldk_node_config.storage_dir_path = network_dir.to_str().unwrap().to_string();
 	ldk_node_config.listening_addresses = config_file.listening_addrs;
 	ldk_node_config.announcement_addresses = config_file.announcement_addrs;
@@ -196,26 +275,148 @@ fn main() {
 		},
 	};
 
-	let paginated_store: Arc<dyn PaginatedKVStore> =
-		Arc::new(match SqliteStore::new(network_dir.clone(), None, None) {
-			Ok(store) => store,
+	#[cfg(feature = "storage-postgres")]
+	let postgres_store = config_file.postgres.map(|postgres| -> Arc<dyn PaginatedKVStore> {
+		match PostgresStore::new(&postgres.connection_string, None) {
+			Ok(store) => Arc::new(store),
+			Err(e) => {
+				error!("Failed to create PostgresStore: {e:?}");
+				std::process::exit(-1);
+			},
+		}
+	});
+
+	#[cfg(not(feature = "storage-postgres"))]
+	let postgres_store = None;
+
+	let paginated_store: Arc<dyn PaginatedKVStore> = match postgres_store {
+		Some(store) => store,
+		None => match SqliteStore::new(network_dir.clone(), None, None) {
+			Ok(store) => Arc::new(store),
 			Err(e) => {
 				error!("Failed to create SqliteStore: {e:?}");
 				std::process::exit(-1);
 			},
-		});
+		},
+	};
 
-	#[cfg(not(feature = "events-rabbitmq"))]
-	let event_publisher: Arc<dyn EventPublisher> =
-		Arc::new(crate::io::events::event_publisher::NoopEventPublisher);
+	let api_keys = match ApiKeyManager::new(configured_api_keys, Arc::clone(&paginated_store)) {
+		Ok(api_keys) => Arc::new(api_keys),
+		Err(e) => {
+			error!("Failed to load API keys: {e}");
+			std::process::exit(-1);
+		},
+	};
+
+	let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970").as_secs();
+	let spending_tracker =
+		match SpendingTracker::new(global_spending_limits, Arc::clone(&paginated_store), now) {
+			Ok(spending_tracker) => Arc::new(spending_tracker),
+			Err(e) => {
+				error!("Failed to load spending history: {e}");
+				std::process::exit(-1);
+			},
+		};
+
+	let approval_threshold_msat =
+		config_file.approvals.threshold_sats.map(|sats| sats.saturating_mul(1000));
+	let approval_queue =
+		match ApprovalQueue::new(approval_threshold_msat, Arc::clone(&paginated_store)) {
+			Ok(approval_queue) => Arc::new(approval_queue),
+			Err(e) => {
+				error!("Failed to load pending approvals: {e}");
+				std::process::exit(-1);
+			},
+		};
+
+	let mut event_sinks = Vec::new();
+	if let Some(webhook) = config_file.webhook {
+		let best_effort = webhook.best_effort;
+		match WebhookEventPublisher::new(webhook) {
+			Ok(publisher) => event_sinks.push(EventSink {
+				name: "webhook".to_string(),
+				publisher: Arc::new(publisher),
+				best_effort,
+			}),
+			Err(e) => {
+				error!("Failed to set up webhook event publisher: {e}");
+				std::process::exit(-1);
+			},
+		}
+	}
+
+	// Kept around to reopen the file on SIGHUP.
+	let mut file_event_publisher = None;
+	if let Some(event_file) = config_file.event_file {
+		match FileEventPublisher::new(Path::new(&event_file.path)) {
+			Ok(publisher) => {
+				let publisher = Arc::new(publisher);
+				file_event_publisher = Some(Arc::clone(&publisher));
+				event_sinks.push(EventSink {
+					name: "event_file".to_string(),
+					publisher,
+					best_effort: event_file.best_effort,
+				});
+			},
+			Err(e) => {
+				error!("Failed to open event file {}: {e}", event_file.path);
+				std::process::exit(-1);
+			},
+		}
+	}
 
 	#[cfg(feature = "events-rabbitmq")]
-	let event_publisher: Arc<dyn EventPublisher> = {
+	{
 		let rabbitmq_config = RabbitMqConfig {
 			connection_string: config_file.rabbitmq_connection_string,
 			exchange_name: config_file.rabbitmq_exchange_name,
+			exchange_type: config_file.rabbitmq_exchange_type,
+		};
+		event_sinks.push(EventSink {
+			name: "rabbitmq".to_string(),
+			publisher: Arc::new(RabbitMqEventPublisher::new(rabbitmq_config)),
+			best_effort: config_file.rabbitmq_best_effort,
+		});
+	}
+
+	// Publishes every event to all configured sinks.
+	let event_publisher: Arc<dyn EventPublisher> =
+		Arc::new(CompositeEventPublisher::new(event_sinks));
+
+	// Hands out events to clients of the `SubscribeEvents` API as soon as they are queued, rather
+	// than once published to all sinks.
+	let event_broadcaster = Arc::new(EventBroadcaster::new());
+
+	// Events are queued in the outbox while being handled, and published by a separate task, so that
+	// an unavailable event publisher doesn't block the handling of further events.
+	let event_outbox = match EventOutbox::new(
+		Arc::clone(&paginated_store),
+		node.node_id().to_string(),
+		Arc::clone(&event_broadcaster),
+	) {
+		Ok(event_outbox) => Arc::new(event_outbox),
+		Err(e) => {
+			error!("Failed to load event outbox: {e}");
+			std::process::exit(-1);
+		},
+	};
+	runtime.spawn(Arc::clone(&event_outbox).run(event_publisher));
+
+	let mut forwarded_payment_sequence =
+		match ForwardedPaymentSequence::load(paginated_store.as_ref()) {
+			Ok(forwarded_payment_sequence) => forwarded_payment_sequence,
+			Err(e) => {
+				error!("Failed to load last forwarded payment: {e}");
+				std::process::exit(-1);
+			},
 		};
-		Arc::new(RabbitMqEventPublisher::new(rabbitmq_config))
+
+	let mut payment_update_sequence = match PaymentUpdateSequence::load(paginated_store.as_ref()) {
+		Ok(payment_update_sequence) => payment_update_sequence,
+		Err(e) => {
+			error!("Failed to load last payment update sequence number: {e}");
+			std::process::exit(-1);
+		},
 	};
 
 	info!("Starting up...");
@@ -239,7 +440,7 @@ fn main() {
 	}
 
 	runtime.block_on(async {
-		// Register SIGHUP handler for log rotation
+		// Register SIGHUP handler for log and event file rotation
 		let mut sighup_stream = match tokio::signal::unix::signal(SignalKind::hangup()) {
 			Ok(stream) => stream,
 			Err(e) => {
@@ -270,30 +471,84 @@ fn main() {
 				std::process::exit(-1);
 			}
 		};
+		#[cfg(feature = "grpc")]
+		if let Some(grpc_service_addr) = config_file.grpc_service_addr {
+			let grpc_svc_listener = TcpListener::bind(grpc_service_addr)
+				.await
+				.expect("Failed to bind gRPC listening port");
+			// gRPC requires HTTP/2, which clients negotiate via ALPN.
+			let mut grpc_server_config = server_config.clone();
+			grpc_server_config.alpn_protocols = vec![b"h2".to_vec()];
+			let grpc_tls_acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(grpc_server_config));
+			let node_service = NodeService::new(
+				Arc::clone(&node),
+				Arc::clone(&paginated_store),
+				Arc::clone(&api_keys),
+				Arc::clone(&nonce_cache),
+				Arc::clone(&spending_tracker),
+				Arc::clone(&approval_queue),
+				Arc::clone(&event_broadcaster),
+				None,
+			);
+			runtime.spawn(grpc::serve(grpc_svc_listener, grpc_tls_acceptor, node_service));
+			info!("TLS enabled for gRPC service on {grpc_service_addr}");
+		}
+		#[cfg(not(feature = "grpc"))]
+		if config_file.grpc_service_addr.is_some() {
+			error!("`grpc_service_address` is set, but LDK Server was built without the `grpc` feature.");
+			std::process::exit(-1);
+		}
+
 		let tls_acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(server_config));
 		info!("TLS enabled for REST service on {}", config_file.rest_service_addr);
 
 		loop {
 			select! {
 				event = event_node.next_event_async() => {
+					// Only the first event after a restart may be a replayed `PaymentForwarded` event.
+					if !matches!(event, Event::PaymentForwarded { .. }) {
+						forwarded_payment_sequence.other_event_received();
+					}
 					match event {
-						Event::ChannelPending { channel_id, counterparty_node_id, .. } => {
+						Event::ChannelPending { channel_id, user_channel_id, counterparty_node_id, funding_txo, .. } => {
 							info!(
 								"CHANNEL_PENDING: {} from counterparty {}",
 								channel_id, counterparty_node_id
 							);
-							if let Err(e) = event_node.event_handled() {
-								error!("Failed to mark event as handled: {e}");
-							}
+							let channel_pending = channel_pending_to_proto(channel_id, user_channel_id, counterparty_node_id, funding_txo);
+							enqueue_event(event_envelope::Event::ChannelPending(channel_pending), &event_node, &event_outbox);
 						},
-						Event::ChannelReady { channel_id, counterparty_node_id, .. } => {
+						Event::ChannelReady { channel_id, user_channel_id, counterparty_node_id, .. } => {
 							info!(
 								"CHANNEL_READY: {} from counterparty {:?}",
 								channel_id, counterparty_node_id
 							);
-							if let Err(e) = event_node.event_handled() {
-								error!("Failed to mark event as handled: {e}");
-							}
+							let channel_ready = channel_ready_to_proto(channel_id, user_channel_id, counterparty_node_id);
+							enqueue_event(event_envelope::Event::ChannelReady(channel_ready), &event_node, &event_outbox);
+						},
+						Event::ChannelClosed { channel_id, user_channel_id, counterparty_node_id, reason, .. } => {
+							info!(
+								"CHANNEL_CLOSED: {} from counterparty {:?}, reason: {:?}",
+								channel_id, counterparty_node_id, reason
+							);
+							let channel_closed = channel_closed_to_proto(channel_id, user_channel_id, counterparty_node_id, reason);
+							enqueue_event(event_envelope::Event::ChannelClosed(channel_closed), &event_node, &event_outbox);
+						},
+						Event::SplicePending { channel_id, user_channel_id, counterparty_node_id, new_funding_txo, .. } => {
+							info!(
+								"SPLICE_PENDING: {} from counterparty {}, new funding txo {}",
+								channel_id, counterparty_node_id, new_funding_txo
+							);
+							let splice_pending = splice_pending_to_proto(channel_id, user_channel_id, counterparty_node_id, new_funding_txo);
+							enqueue_event(event_envelope::Event::SplicePending(splice_pending), &event_node, &event_outbox);
+						},
+						Event::SpliceFailed { channel_id, user_channel_id, counterparty_node_id, abandoned_funding_txo, .. } => {
+							info!(
+								"SPLICE_FAILED: {} from counterparty {}",
+								channel_id, counterparty_node_id
+							);
+							let splice_failed = splice_failed_to_proto(channel_id, user_channel_id, counterparty_node_id, abandoned_funding_txo);
+							enqueue_event(event_envelope::Event::SpliceFailed(splice_failed), &event_node, &event_outbox);
 						},
 						Event::PaymentReceived { payment_id, payment_hash, amount_msat, .. } => {
 							info!(
@@ -302,40 +557,43 @@ fn main() {
 							);
 							let payment_id = payment_id.expect("PaymentId expected for ldk-server >=0.1");
 
-							publish_event_and_upsert_payment(&payment_id,
+							enqueue_event_and_upsert_payment(&payment_id,
 								|payment_ref| event_envelope::Event::PaymentReceived(events::PaymentReceived {
 									payment: Some(payment_ref.clone()),
 								}),
+								&mut payment_update_sequence,
 								&event_node,
-								Arc::clone(&event_publisher),
-								Arc::clone(&paginated_store)).await;
+								paginated_store.as_ref(),
+								&event_outbox);
 						},
 						Event::PaymentSuccessful {payment_id, ..} => {
 							let payment_id = payment_id.expect("PaymentId expected for ldk-server >=0.1");
 
-							publish_event_and_upsert_payment(&payment_id,
+							enqueue_event_and_upsert_payment(&payment_id,
 								|payment_ref| event_envelope::Event::PaymentSuccessful(events::PaymentSuccessful {
 									payment: Some(payment_ref.clone()),
 								}),
+								&mut payment_update_sequence,
 								&event_node,
-								Arc::clone(&event_publisher),
-								Arc::clone(&paginated_store)).await;
+								paginated_store.as_ref(),
+								&event_outbox);
 						},
 						Event::PaymentFailed {payment_id, ..} => {
 							let payment_id = payment_id.expect("PaymentId expected for ldk-server >=0.1");
 
-							publish_event_and_upsert_payment(&payment_id,
+							enqueue_event_and_upsert_payment(&payment_id,
 								|payment_ref| event_envelope::Event::PaymentFailed(events::PaymentFailed {
 									payment: Some(payment_ref.clone()),
 								}),
+								&mut payment_update_sequence,
 								&event_node,
-								Arc::clone(&event_publisher),
-								Arc::clone(&paginated_store)).await;
+								paginated_store.as_ref(),
+								&event_outbox);
 						},
 						Event::PaymentClaimable {payment_id, ..} => {
 							if let Some(payment_details) = event_node.payment(&payment_id) {
 								let payment = payment_to_proto(payment_details);
-								upsert_payment_details(&event_node, Arc::clone(&paginated_store), &payment);
+								upsert_payment_details(&event_node, Arc::clone(&paginated_store), &payment, &mut payment_update_sequence);
 							} else {
 								error!("Unable to find payment with paymentId: {payment_id}");
 							}
@@ -357,6 +615,8 @@ fn main() {
 								outbound_amount_forwarded_msat.unwrap_or(0), total_fee_earned_msat.unwrap_or(0), prev_channel_id, next_channel_id
 							);
 
+							let forwarded_payment_creation_time = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970").as_secs() as i64;
+
 							let forwarded_payment = forwarded_payment_to_proto(
 								prev_channel_id,
 								next_channel_id,
@@ -367,43 +627,11 @@ fn main() {
 								total_fee_earned_msat,
 								skimmed_fee_msat,
 								claim_from_onchain_tx,
-								outbound_amount_forwarded_msat
+								outbound_amount_forwarded_msat,
+								forwarded_payment_creation_time as u64
 							);
 
-							// We don't expose this payment-id to the user, it is a temporary measure to generate
-							// some unique identifiers until we have forwarded-payment-id available in ldk.
-							// Currently, this is the expected user handling behaviour for forwarded payments.
-							let mut forwarded_payment_id = [0u8; 32];
-							getrandom::getrandom(&mut forwarded_payment_id).expect("Failed to generate random bytes");
-
-							let forwarded_payment_creation_time = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970").as_secs() as i64;
-
-							match event_publisher.publish(EventEnvelope {
-									event: Some(event_envelope::Event::PaymentForwarded(events::PaymentForwarded {
-											forwarded_payment: Some(forwarded_payment.clone()),
-									})),
-							}).await {
-								Ok(_) => {},
-								Err(e) => {
-									error!("Failed to publish 'PaymentForwarded' event: {}", e);
-									continue;
-								}
-							};
-
-							match paginated_store.write(FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
-								&forwarded_payment_id.to_lower_hex_string(),
-								forwarded_payment_creation_time,
-								&forwarded_payment.encode_to_vec(),
-							) {
-								Ok(_) => {
-									if let Err(e) = event_node.event_handled() {
-										error!("Failed to mark event as handled: {e}");
-									}
-								}
-								Err(e) => {
-										error!("Failed to write forwarded payment to persistence: {}", e);
-								}
-							}
+							enqueue_event_and_insert_forwarded_payment(forwarded_payment, &mut forwarded_payment_sequence, &event_node, &event_outbox);
 						},
 						_ => {
 							if let Err(e) = event_node.event_handled() {
@@ -415,11 +643,26 @@ fn main() {
 				res = rest_svc_listener.accept() => {
 					match res {
 						Ok((stream, _)) => {
-							let node_service = NodeService::new(Arc::clone(&node), Arc::clone(&paginated_store), api_key.clone());
+							let node = Arc::clone(&node);
+							let paginated_store = Arc::clone(&paginated_store);
+							let api_keys = Arc::clone(&api_keys);
+							let nonce_cache = Arc::clone(&nonce_cache);
+							let spending_tracker = Arc::clone(&spending_tracker);
+							let approval_queue = Arc::clone(&approval_queue);
+							let event_broadcaster = Arc::clone(&event_broadcaster);
 							let acceptor = tls_acceptor.clone();
 							runtime.spawn(async move {
 								match acceptor.accept(stream).await {
 									Ok(tls_stream) => {
+										// Only set if client certificates are required, in which case the
+										// certificate has already been verified during the handshake.
+										let client_identity = tls_stream
+											.get_ref()
+											.1
+											.peer_certificates()
+											.and_then(|certs| certs.first())
+											.and_then(client_identity_from_cert);
+										let node_service = NodeService::new(node, paginated_store, api_keys, nonce_cache, spending_tracker, approval_queue, event_broadcaster, client_identity);
 										let io_stream = TokioIo::new(tls_stream);
 										if let Err(err) = http1::Builder::new().serve_connection(io_stream, node_service).await {
 											error!("Failed to serve TLS connection: {err}");
@@ -440,6 +683,11 @@ fn main() {
 					if let Err(e) = logger.reopen() {
 						error!("Failed to reopen log file on SIGHUP: {e}");
 					}
+					if let Some(file_event_publisher) = &file_event_publisher {
+						if let Err(e) = file_event_publisher.reopen() {
+							error!("Failed to reopen event file on SIGHUP: {e}");
+						}
+					}
 				}
 				_ = sigterm_stream.recv() => {
 					info!("Received SIGTERM, shutting down..");
@@ -453,44 +701,197 @@ fn main() {
 	info!("Shutdown complete..");
 }
 
-async fn publish_event_and_upsert_payment(
+/// Queues the event for publication.
+fn enqueue_event(event: event_envelope::Event, event_node: &Node, event_outbox: &EventOutbox) {
+	let event_name = get_event_name(&event);
+	match event_outbox.enqueue(event, &[]) {
+		Ok(_) => {
+			if let Err(e) = event_node.event_handled() {
+				error!("Failed to mark event as handled: {e}");
+			}
+		},
+		Err(e) => {
+			error!("Failed to persist '{event_name}' event: {e}");
+		},
+	}
+}
+
+/// Queues the `PaymentForwarded` event for publication, atomically with inserting the forwarded
+/// payment it is about.
+///
+/// If the event is replayed as we stopped after persisting the forwarded payment but before marking
+/// the event as handled, neither the event nor the forwarded payment are persisted again, see
+/// [`ForwardedPaymentSequence`].
+fn enqueue_event_and_insert_forwarded_payment(
+	forwarded_payment: ForwardedPayment, forwarded_payment_sequence: &mut ForwardedPaymentSequence,
+	event_node: &Node, event_outbox: &EventOutbox,
+) {
+	let marker = match forwarded_payment_sequence.next(&forwarded_payment) {
+		Some(marker) => marker,
+		None => {
+			// A distinct but identical forward can't be told apart from a replay, so leave a trace.
+			warn!(
+				"Skipping forwarded payment {} from channel {} to {}, as it is identical to the last one persisted before the restart and thus considered a replay",
+				forwarded_payment_hash(&forwarded_payment),
+				forwarded_payment.prev_channel_id,
+				forwarded_payment.next_channel_id
+			);
+			if let Err(e) = event_node.event_handled() {
+				error!("Failed to mark event as handled: {e}");
+			}
+			return;
+		},
+	};
+
+	let forwarded_payment_key = forwarded_payment_key(marker.sequence_number);
+	let forwarded_payment_bytes = forwarded_payment.encode_to_vec();
+	let attributes = forwarded_payment_attributes(&forwarded_payment);
+	let marker_bytes = marker.encode_to_vec();
+	let writes = [
+		WriteOp {
+			primary_namespace: FORWARDED_PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: FORWARDED_PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: &forwarded_payment_key,
+			time: forwarded_payment.timestamp as i64,
+			buf: &forwarded_payment_bytes,
+			attributes: &attributes,
+		},
+		WriteOp {
+			primary_namespace: FORWARDED_PAYMENT_MARKER_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: FORWARDED_PAYMENT_MARKER_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: FORWARDED_PAYMENT_MARKER_PERSISTENCE_KEY,
+			time: 0,
+			buf: &marker_bytes,
+			attributes: &[],
+		},
+	];
+	let event = event_envelope::Event::PaymentForwarded(events::PaymentForwarded {
+		forwarded_payment: Some(forwarded_payment.clone()),
+	});
+
+	match event_outbox.enqueue(event, &writes) {
+		Ok(_) => {
+			forwarded_payment_sequence.persisted(marker);
+			if let Err(e) = event_node.event_handled() {
+				error!("Failed to mark event as handled: {e}");
+			}
+		},
+		Err(e) => {
+			error!("Failed to persist 'PaymentForwarded' event and forwarded payment: {e}");
+		},
+	}
+}
+
+/// Queues the event for publication, atomically with upserting the payment it is about under the
+/// next [`PaymentUpdateSequence`] number.
+///
+/// If the payment was received for an offer created with metadata, the metadata is copied to the
+/// payment along the way.
+fn enqueue_event_and_upsert_payment(
 	payment_id: &PaymentId, payment_to_event: fn(&Payment) -> event_envelope::Event,
-	event_node: &Node, event_publisher: Arc<dyn EventPublisher>,
-	paginated_store: Arc<dyn PaginatedKVStore>,
+	payment_update_sequence: &mut PaymentUpdateSequence, event_node: &Node,
+	paginated_store: &dyn PaginatedKVStore, event_outbox: &EventOutbox,
 ) {
 	if let Some(payment_details) = event_node.payment(payment_id) {
 		let payment = payment_to_proto(payment_details);
-
-		let event = payment_to_event(&payment);
-		let event_name = get_event_name(&event);
-		match event_publisher.publish(EventEnvelope { event: Some(event) }).await {
-			Ok(_) => {},
+		let offer_metadata = match received_offer_metadata(&payment, paginated_store) {
+			Ok(offer_metadata) => offer_metadata,
 			Err(e) => {
-				error!("Failed to publish '{event_name}' event, : {e}");
+				error!("Failed to read offer metadata for payment {payment_id}: {e}");
 				return;
 			},
 		};
 
-		upsert_payment_details(event_node, Arc::clone(&paginated_store), &payment);
+		let event = payment_to_event(&payment);
+		let event_name = get_event_name(&event);
+		let time =
+			SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970").as_secs()
+				as i64;
+		let payment_bytes = payment.encode_to_vec();
+		let update_sequence_number = payment_update_sequence.next();
+		let update_sequence_number_buf = update_sequence_number.to_be_bytes();
+		let attributes = payment_attributes(&payment, update_sequence_number);
+		let mut writes = vec![
+			WriteOp {
+				primary_namespace: PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+				secondary_namespace: PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+				key: &payment.id,
+				time,
+				buf: &payment_bytes,
+				attributes: &attributes,
+			},
+			payment_update_sequence_write(&update_sequence_number_buf),
+		];
+		let metadata_bytes;
+		let metadata_attributes;
+		if let Some(metadata) = &offer_metadata {
+			metadata_bytes = metadata.encode_to_vec();
+			metadata_attributes = payment_metadata_attributes(metadata);
+			writes.push(WriteOp {
+				primary_namespace: PAYMENT_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+				secondary_namespace: PAYMENT_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+				key: &payment.id,
+				time,
+				buf: &metadata_bytes,
+				attributes: &metadata_attributes,
+			});
+		}
+
+		match event_outbox.enqueue(event, &writes) {
+			Ok(_) => {
+				payment_update_sequence.persisted(update_sequence_number);
+				if let Err(e) = event_node.event_handled() {
+					error!("Failed to mark event as handled: {e}");
+				}
+			},
+			Err(e) => {
+				error!("Failed to persist '{event_name}' event and payment: {e}");
+			},
+		}
 	} else {
 		error!("Unable to find payment with paymentId: {payment_id}");
 	}
 }
 
+/// Returns the metadata of the offer the given payment was received for, if any.
+fn received_offer_metadata(
+	payment: &Payment, paginated_store: &dyn PaginatedKVStore,
+) -> Result<Option<PaymentMetadata>, std::io::Error> {
+	if payment.direction != PaymentDirection::Inbound as i32 {
+		return Ok(None);
+	}
+	match payment.kind.as_ref().and_then(|k| k.kind.as_ref()) {
+		Some(Kind::Bolt12Offer(bolt12_offer)) => read_payment_metadata(
+			paginated_store,
+			OFFER_METADATA_PERSISTENCE_PRIMARY_NAMESPACE,
+			OFFER_METADATA_PERSISTENCE_SECONDARY_NAMESPACE,
+			&bolt12_offer.offer_id,
+		),
+		_ => Ok(None),
+	}
+}
+
 fn upsert_payment_details(
 	event_node: &Node, paginated_store: Arc<dyn PaginatedKVStore>, payment: &Payment,
+	payment_update_sequence: &mut PaymentUpdateSequence,
 ) {
 	let time =
 		SystemTime::now().duration_since(UNIX_EPOCH).expect("Time must be > 1970").as_secs() as i64;
-
-	match paginated_store.write(
-		PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
-		PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
-		&payment.id,
-		time,
-		&payment.encode_to_vec(),
-	) {
+	let update_sequence_number = payment_update_sequence.next();
+
+	match paginated_store.write_batch(&[
+		WriteOp {
+			primary_namespace: PAYMENTS_PERSISTENCE_PRIMARY_NAMESPACE,
+			secondary_namespace: PAYMENTS_PERSISTENCE_SECONDARY_NAMESPACE,
+			key: &payment.id,
+			time,
+			buf: &payment.encode_to_vec(),
+			attributes: &payment_attributes(payment, update_sequence_number),
+		},
+		payment_update_sequence_write(&update_sequence_number.to_be_bytes()),
+	]) {
 		Ok(_) => {
+			payment_update_sequence.persisted(update_sequence_number);
 			if let Err(e) = event_node.event_handled() {
 				error!("Failed to mark event as handled: {e}");
 			}
@@ -501,28 +902,16 @@ fn upsert_payment_details(
 	}
 }
 
-/// Loads the API key from a file, or generates a new one if it doesn't exist.
+/// Loads the API key from the given file, or generates a new one if it doesn't exist.
 /// The API key file is stored with 0400 permissions (read-only for owner).
-fn load_or_generate_api_key(storage_dir: &Path) -> std::io::Result<String> {
-	let api_key_path = storage_dir.join(API_KEY_FILE);
-
+fn load_or_generate_api_key(api_key_path: &Path) -> std::io::Result<String> {
 	if api_key_path.exists() {
-		let key_bytes = fs::read(&api_key_path)?;
+		let key_bytes = fs::read(api_key_path)?;
 		Ok(key_bytes.to_lower_hex_string())
 	} else {
-		// Ensure the storage directory exists
-		fs::create_dir_all(storage_dir)?;
-
 		// Generate a 32-byte random API key
-		let mut key_bytes = [0u8; 32];
-		getrandom::getrandom(&mut key_bytes).map_err(std::io::Error::other)?;
-
-		// Write the raw bytes to the file
-		fs::write(&api_key_path, key_bytes)?;
-
-		// Set permissions to 0400 (read-only for owner)
-		let permissions = fs::Permissions::from_mode(0o400);
-		fs::set_permissions(&api_key_path, permissions)?;
+		let key_bytes = generate_api_key()?;
+		write_api_key_file(api_key_path, &key_bytes)?;
 
 		debug!("Generated new API key at {}", api_key_path.display());
 		Ok(key_bytes.to_lower_hex_string())
@@ -11,24 +11,42 @@ use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 
-use http_body_util::{BodyExt, Full, Limited};
+use http_body_util::{BodyExt, Either, Full, Limited};
 use hyper::body::{Bytes, Incoming};
+use hyper::header::{CACHE_CONTROL, CONTENT_TYPE};
 use hyper::service::Service;
 use hyper::{Request, Response, StatusCode};
 use ldk_node::bitcoin::hashes::hmac::{Hmac, HmacEngine};
 use ldk_node::bitcoin::hashes::{sha256, Hash, HashEngine};
 use ldk_node::Node;
+use ldk_server_protos::api::SubscribeEventsRequest;
 use ldk_server_protos::endpoints::{
-	BOLT11_RECEIVE_PATH, BOLT11_SEND_PATH, BOLT12_RECEIVE_PATH, BOLT12_SEND_PATH,
-	CLOSE_CHANNEL_PATH, CONNECT_PEER_PATH, DISCONNECT_PEER_PATH, EXPORT_PATHFINDING_SCORES_PATH,
-	FORCE_CLOSE_CHANNEL_PATH, GET_BALANCES_PATH, GET_NODE_INFO_PATH, GET_PAYMENT_DETAILS_PATH,
+	APPROVE_PAYMENT_PATH, BOLT11_RECEIVE_PATH, BOLT11_SEND_PATH, BOLT12_RECEIVE_PATH,
+	BOLT12_SEND_PATH, CLOSE_CHANNEL_PATH, CONNECT_PEER_PATH, CREATE_API_KEY_PATH,
+	DISCONNECT_PEER_PATH, EXPORT_PATHFINDING_SCORES_PATH, FORCE_CLOSE_CHANNEL_PATH,
+	GET_BALANCES_PATH, GET_FORWARDING_STATS_PATH, GET_NODE_INFO_PATH, GET_PAYMENT_DETAILS_PATH,
 	GRAPH_GET_CHANNEL_PATH, GRAPH_GET_NODE_PATH, GRAPH_LIST_CHANNELS_PATH, GRAPH_LIST_NODES_PATH,
-	LIST_CHANNELS_PATH, LIST_FORWARDED_PAYMENTS_PATH, LIST_PAYMENTS_PATH, ONCHAIN_RECEIVE_PATH,
-	ONCHAIN_SEND_PATH, OPEN_CHANNEL_PATH, SIGN_MESSAGE_PATH, SPLICE_IN_PATH, SPLICE_OUT_PATH,
-	SPONTANEOUS_SEND_PATH, UPDATE_CHANNEL_CONFIG_PATH, VERIFY_SIGNATURE_PATH,
+	LIST_API_KEYS_PATH, LIST_AUDIT_LOG_PATH, LIST_CHANNELS_PATH, LIST_EVENTS_PATH,
+	LIST_FORWARDED_PAYMENTS_PATH, LIST_PAYMENTS_BY_LABEL_PATH, LIST_PAYMENTS_PATH,
+	LIST_PAYMENTS_UPDATED_SINCE_PATH, LIST_PENDING_APPROVALS_PATH, ONCHAIN_RECEIVE_PATH,
+	ONCHAIN_SEND_PATH, OPEN_CHANNEL_PATH, REJECT_PAYMENT_PATH, REVOKE_API_KEY_PATH,
+	ROTATE_API_KEY_PATH, SIGN_MESSAGE_PATH, SPLICE_IN_PATH, SPLICE_OUT_PATH, SPONTANEOUS_SEND_PATH,
+	SUBSCRIBE_EVENTS_PATH, UPDATE_CHANNEL_CONFIG_PATH, VERIFY_SIGNATURE_PATH,
 };
+use ldk_server_protos::types::AuditLogEntry;
+use log::error;
 use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
+use crate::api::api_keys::{
+	handle_create_api_key_request, handle_list_api_keys_request, handle_revoke_api_key_request,
+	handle_rotate_api_key_request,
+};
+use crate::api::approvals::{
+	handle_approve_payment_request, handle_list_pending_approvals_request,
+	handle_reject_payment_request,
+};
 use crate::api::bolt11_receive::handle_bolt11_receive_request;
 use crate::api::bolt11_send::handle_bolt11_send_request;
 use crate::api::bolt12_receive::handle_bolt12_receive_request;
@@ -37,18 +55,23 @@ use crate::api::close_channel::{handle_close_channel_request, handle_force_close
 use crate::api::connect_peer::handle_connect_peer;
 use crate::api::disconnect_peer::handle_disconnect_peer;
 use crate::api::error::LdkServerError;
-use crate::api::error::LdkServerErrorCode::{AuthError, InvalidRequestError};
+use crate::api::error::LdkServerErrorCode::{AuthError, InternalServerError, InvalidRequestError};
 use crate::api::export_pathfinding_scores::handle_export_pathfinding_scores_request;
 use crate::api::get_balances::handle_get_balances_request;
+use crate::api::get_forwarding_stats::handle_get_forwarding_stats_request;
 use crate::api::get_node_info::handle_get_node_info_request;
 use crate::api::get_payment_details::handle_get_payment_details_request;
 use crate::api::graph_get_channel::handle_graph_get_channel_request;
 use crate::api::graph_get_node::handle_graph_get_node_request;
 use crate::api::graph_list_channels::handle_graph_list_channels_request;
 use crate::api::graph_list_nodes::handle_graph_list_nodes_request;
+use crate::api::list_audit_log::handle_list_audit_log_request;
 use crate::api::list_channels::handle_list_channels_request;
+use crate::api::list_events::handle_list_events_request;
 use crate::api::list_forwarded_payments::handle_list_forwarded_payments_request;
 use crate::api::list_payments::handle_list_payments_request;
+use crate::api::list_payments_by_label::handle_list_payments_by_label_request;
+use crate::api::list_payments_updated_since::handle_list_payments_updated_since_request;
 use crate::api::onchain_receive::handle_onchain_receive_request;
 use crate::api::onchain_send::handle_onchain_send_request;
 use crate::api::open_channel::handle_open_channel;
@@ -57,9 +80,22 @@ use crate::api::splice_channel::{handle_splice_in_request, handle_splice_out_req
 use crate::api::spontaneous_send::handle_spontaneous_send_request;
 use crate::api::update_channel_config::handle_update_channel_config_request;
 use crate::api::verify_signature::handle_verify_signature_request;
+use crate::auth::api_key_manager::ApiKeyManager;
+use crate::auth::approval_queue::ApprovalQueue;
+use crate::auth::nonce_cache::NonceCache;
+use crate::auth::spending_limits::SpendingTracker;
+use crate::auth::{required_permission, ApiKey};
+use crate::io::audit_log::{
+	append_audit_log_entry, is_audited, summarize_request, update_audit_log_entry,
+};
+use crate::io::events::broadcast::EventBroadcaster;
 use crate::io::persist::paginated_kv_store::PaginatedKVStore;
+use crate::sse::{EventStreamBody, TEXT_EVENT_STREAM};
 use crate::util::proto_adapter::to_error_response;
 
+const APPLICATION_OCTET_STREAM: &str = "application/octet-stream";
+const APPLICATION_JSON: &str = "application/json";
+
 // Maximum request body size: 10 MB
 // This prevents memory exhaustion from large requests
 const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
@@ -68,59 +104,148 @@ const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
 pub struct NodeService {
 	node: Arc<Node>,
 	paginated_kv_store: Arc<dyn PaginatedKVStore>,
-	api_key: String,
+	api_keys: Arc<ApiKeyManager>,
+	nonce_cache: Arc<NonceCache>,
+	spending_tracker: Arc<SpendingTracker>,
+	approval_queue: Arc<ApprovalQueue>,
+	event_broadcaster: Arc<EventBroadcaster>,
+	client_identity: Option<String>,
 }
 
 impl NodeService {
 	pub(crate) fn new(
-		node: Arc<Node>, paginated_kv_store: Arc<dyn PaginatedKVStore>, api_key: String,
+		node: Arc<Node>, paginated_kv_store: Arc<dyn PaginatedKVStore>,
+		api_keys: Arc<ApiKeyManager>, nonce_cache: Arc<NonceCache>,
+		spending_tracker: Arc<SpendingTracker>, approval_queue: Arc<ApprovalQueue>,
+		event_broadcaster: Arc<EventBroadcaster>, client_identity: Option<String>,
 	) -> Self {
-		Self { node, paginated_kv_store, api_key }
+		Self {
+			node,
+			paginated_kv_store,
+			api_keys,
+			nonce_cache,
+			spending_tracker,
+			approval_queue,
+			event_broadcaster,
+			client_identity,
+		}
+	}
+
+	/// Returns this service for a connection established with the client certificate identity
+	/// `client_identity`, if any.
+	#[cfg(feature = "grpc")]
+	pub(crate) fn with_client_identity(mut self, client_identity: Option<String>) -> Self {
+		self.client_identity = client_identity;
+		self
 	}
 }
 
 // Maximum allowed time difference between client timestamp and server time (1 minute)
-const AUTH_TIMESTAMP_TOLERANCE_SECS: u64 = 60;
+pub(crate) const AUTH_TIMESTAMP_TOLERANCE_SECS: u64 = 60;
+
+// Length of the hex-encoded, 16-byte random nonce included in every request.
+const AUTH_NONCE_HEX_LEN: usize = 32;
 
 #[derive(Debug, Clone)]
 pub(crate) struct AuthParams {
 	timestamp: u64,
+	nonce: String,
 	hmac_hex: String,
 }
 
+/// The encoding of request and response bodies, negotiated via the `Content-Type` header.
+///
+/// Requests are decoded as protobuf unless they are sent with `Content-Type: application/json`, in
+/// which case the canonical proto3 JSON mapping of the `api.proto` messages is accepted and returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ContentType {
+	Protobuf,
+	Json,
+}
+
+impl ContentType {
+	fn from_request<B>(req: &Request<B>) -> Self {
+		let mime_type = req
+			.headers()
+			.get(CONTENT_TYPE)
+			.and_then(|v| v.to_str().ok())
+			.and_then(|v| v.split(';').next())
+			.map(str::trim);
+		match mime_type {
+			Some(mime_type) if mime_type.eq_ignore_ascii_case(APPLICATION_JSON) => Self::Json,
+			_ => Self::Protobuf,
+		}
+	}
+
+	pub(crate) fn as_str(&self) -> &'static str {
+		match self {
+			Self::Protobuf => APPLICATION_OCTET_STREAM,
+			Self::Json => APPLICATION_JSON,
+		}
+	}
+
+	fn decode<T: Message + Default + DeserializeOwned>(
+		&self, bytes: Bytes,
+	) -> Result<T, LdkServerError> {
+		match self {
+			Self::Protobuf => T::decode(bytes)
+				.map_err(|_| LdkServerError::new(InvalidRequestError, "Malformed request.")),
+			Self::Json => serde_json::from_slice(&bytes).map_err(|e| {
+				LdkServerError::new(InvalidRequestError, format!("Malformed request: {e}"))
+			}),
+		}
+	}
+
+	pub(crate) fn encode<M: Message + Serialize>(&self, message: &M) -> Vec<u8> {
+		match self {
+			Self::Protobuf => message.encode_to_vec(),
+			// unwrap safety: serializing to JSON only fails for enum fields holding values unknown to
+			// the `.proto` definitions, which we never set.
+			Self::Json => serde_json::to_vec(message).unwrap(),
+		}
+	}
+}
+
 /// Extracts authentication parameters from request headers.
-/// Returns (timestamp, hmac_hex) if valid format, or error.
-fn extract_auth_params<B>(req: &Request<B>) -> Result<AuthParams, LdkServerError> {
+/// Returns (timestamp, nonce, hmac_hex) if valid format, or error.
+pub(crate) fn extract_auth_params<B>(req: &Request<B>) -> Result<AuthParams, LdkServerError> {
 	let auth_header = req
 		.headers()
 		.get("X-Auth")
 		.and_then(|v| v.to_str().ok())
 		.ok_or_else(|| LdkServerError::new(AuthError, "Missing X-Auth header"))?;
 
-	// Format: "HMAC <timestamp>:<hmac_hex>"
+	// Format: "HMAC <timestamp>:<nonce>:<hmac_hex>"
 	let auth_data = auth_header
 		.strip_prefix("HMAC ")
 		.ok_or_else(|| LdkServerError::new(AuthError, "Invalid X-Auth header format"))?;
 
-	let (timestamp_str, hmac_hex) = auth_data
-		.split_once(':')
-		.ok_or_else(|| LdkServerError::new(AuthError, "Invalid X-Auth header format"))?;
+	let mut parts = auth_data.splitn(3, ':');
+	let (timestamp_str, nonce, hmac_hex) = match (parts.next(), parts.next(), parts.next()) {
+		(Some(timestamp_str), Some(nonce), Some(hmac_hex)) => (timestamp_str, nonce, hmac_hex),
+		_ => return Err(LdkServerError::new(AuthError, "Invalid X-Auth header format")),
+	};
 
 	let timestamp = timestamp_str
 		.parse::<u64>()
 		.map_err(|_| LdkServerError::new(AuthError, "Invalid timestamp in X-Auth header"))?;
 
+	// validate nonce is valid hex
+	if nonce.len() != AUTH_NONCE_HEX_LEN || !nonce.chars().all(|c| c.is_ascii_hexdigit()) {
+		return Err(LdkServerError::new(AuthError, "Invalid nonce in X-Auth header"));
+	}
+
 	// validate hmac_hex is valid hex
 	if hmac_hex.len() != 64 || !hmac_hex.chars().all(|c| c.is_ascii_hexdigit()) {
 		return Err(LdkServerError::new(AuthError, "Invalid HMAC in X-Auth header"));
 	}
 
-	Ok(AuthParams { timestamp, hmac_hex: hmac_hex.to_string() })
+	Ok(AuthParams { timestamp, nonce: nonce.to_string(), hmac_hex: hmac_hex.to_string() })
 }
 
 /// Validates the HMAC authentication after the request body has been read.
 fn validate_hmac_auth(
-	timestamp: u64, provided_hmac_hex: &str, body: &[u8], api_key: &str,
+	timestamp: u64, nonce: &str, provided_hmac_hex: &str, body: &[u8], api_key: &str,
 ) -> Result<(), LdkServerError> {
 	// Validate timestamp is within acceptable window
 	let now = std::time::SystemTime::now()
@@ -133,9 +258,10 @@ fn validate_hmac_auth(
 		return Err(LdkServerError::new(AuthError, "Request timestamp expired"));
 	}
 
-	// Compute expected HMAC: HMAC-SHA256(api_key, timestamp_bytes || body)
+	// Compute expected HMAC: HMAC-SHA256(api_key, timestamp_bytes || nonce || body)
 	let mut hmac_engine: HmacEngine<sha256::Hash> = HmacEngine::new(api_key.as_bytes());
 	hmac_engine.input(&timestamp.to_be_bytes());
+	hmac_engine.input(nonce.as_bytes());
 	hmac_engine.input(body);
 	let expected_hmac = Hmac::<sha256::Hash>::from_engine(hmac_engine);
 
@@ -148,309 +274,406 @@ fn validate_hmac_auth(
 	Ok(())
 }
 
+/// Finds the API key the request was signed with, validating the HMAC against each known key.
+fn authenticate(
+	auth_params: &AuthParams, body: &[u8], api_keys: &[ApiKey],
+) -> Result<ApiKey, LdkServerError> {
+	let mut last_error = LdkServerError::new(AuthError, "Invalid credentials");
+	for api_key in api_keys {
+		match validate_hmac_auth(
+			auth_params.timestamp,
+			&auth_params.nonce,
+			&auth_params.hmac_hex,
+			body,
+			&api_key.key,
+		) {
+			Ok(()) => return Ok(api_key.clone()),
+			Err(e) => last_error = e,
+		}
+	}
+	Err(last_error)
+}
+
+/// A request as received by any of the transports, before it is authenticated and decoded.
+///
+/// Transports are only responsible for reading requests and writing back responses, such that
+/// all of them share the same authentication, authorization and handlers.
+pub(crate) struct RawRequest {
+	/// The name of the API called, e.g. `GetNodeInfo`.
+	pub(crate) endpoint: String,
+	pub(crate) auth_params: AuthParams,
+	pub(crate) body: Bytes,
+	pub(crate) content_type: ContentType,
+}
+
 pub(crate) struct Context {
 	pub(crate) node: Arc<Node>,
 	pub(crate) paginated_kv_store: Arc<dyn PaginatedKVStore>,
+	/// The API key the current request was authenticated with.
+	pub(crate) api_key: ApiKey,
+	pub(crate) api_keys: Arc<ApiKeyManager>,
+	pub(crate) spending_tracker: Arc<SpendingTracker>,
+	pub(crate) approval_queue: Arc<ApprovalQueue>,
+	/// The identity of the client certificate the connection was established with, if client
+	/// certificates are required.
+	pub(crate) client_identity: Option<String>,
 }
 
+/// The body of a REST response: either a single message, or a stream of events.
+type ResponseBody = Either<Full<Bytes>, EventStreamBody>;
+
 impl Service<Request<Incoming>> for NodeService {
-	type Response = Response<Full<Bytes>>;
+	type Response = Response<ResponseBody>;
 	type Error = hyper::Error;
 	type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
 
 	fn call(&self, req: Request<Incoming>) -> Self::Future {
+		let content_type = ContentType::from_request(&req);
+
 		// Extract auth params from headers (validation happens after body is read)
 		let auth_params = match extract_auth_params(&req) {
 			Ok(params) => params,
 			Err(e) => {
-				let (error_response, status_code) = to_error_response(e);
-				return Box::pin(async move {
-					Ok(Response::builder()
-						.status(status_code)
-						.body(Full::new(Bytes::from(error_response.encode_to_vec())))
-						// unwrap safety: body only errors when previous chained calls failed.
-						.unwrap())
-				});
+				let response = build_error_response(e, content_type).map(Either::Left);
+				return Box::pin(async move { Ok(response) });
 			},
 		};
 
-		let context = Context {
-			node: Arc::clone(&self.node),
-			paginated_kv_store: Arc::clone(&self.paginated_kv_store),
-		};
-		let api_key = self.api_key.clone();
-
-		// Exclude '/' from path pattern matching.
-		match &req.uri().path()[1..] {
-			GET_NODE_INFO_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_get_node_info_request,
-			)),
-			GET_BALANCES_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_get_balances_request,
-			)),
-			ONCHAIN_RECEIVE_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_onchain_receive_request,
-			)),
-			ONCHAIN_SEND_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_onchain_send_request,
-			)),
-			BOLT11_RECEIVE_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_bolt11_receive_request,
-			)),
-			BOLT11_SEND_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_bolt11_send_request,
-			)),
-			BOLT12_RECEIVE_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_bolt12_receive_request,
-			)),
-			BOLT12_SEND_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_bolt12_send_request,
-			)),
-			OPEN_CHANNEL_PATH => {
-				Box::pin(handle_request(context, req, auth_params, api_key, handle_open_channel))
-			},
-			SPLICE_IN_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_splice_in_request,
-			)),
-			SPLICE_OUT_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_splice_out_request,
-			)),
-			CLOSE_CHANNEL_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_close_channel_request,
-			)),
-			FORCE_CLOSE_CHANNEL_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_force_close_channel_request,
-			)),
-			LIST_CHANNELS_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_list_channels_request,
-			)),
-			UPDATE_CHANNEL_CONFIG_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_update_channel_config_request,
-			)),
-			GET_PAYMENT_DETAILS_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_get_payment_details_request,
-			)),
-			LIST_PAYMENTS_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_list_payments_request,
-			)),
-			LIST_FORWARDED_PAYMENTS_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_list_forwarded_payments_request,
-			)),
-			CONNECT_PEER_PATH => {
-				Box::pin(handle_request(context, req, auth_params, api_key, handle_connect_peer))
-			},
-			DISCONNECT_PEER_PATH => {
-				Box::pin(handle_request(context, req, auth_params, api_key, handle_disconnect_peer))
-			},
-			SPONTANEOUS_SEND_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_spontaneous_send_request,
-			)),
-			SIGN_MESSAGE_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_sign_message_request,
-			)),
-			VERIFY_SIGNATURE_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_verify_signature_request,
-			)),
-			EXPORT_PATHFINDING_SCORES_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_export_pathfinding_scores_request,
-			)),
-			GRAPH_LIST_CHANNELS_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_graph_list_channels_request,
-			)),
-			GRAPH_GET_CHANNEL_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_graph_get_channel_request,
-			)),
-			GRAPH_LIST_NODES_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_graph_list_nodes_request,
-			)),
-			GRAPH_GET_NODE_PATH => Box::pin(handle_request(
-				context,
-				req,
-				auth_params,
-				api_key,
-				handle_graph_get_node_request,
-			)),
-			path => {
-				let error = format!("Unknown request: {}", path).into_bytes();
-				Box::pin(async {
-					Ok(Response::builder()
-						.status(StatusCode::BAD_REQUEST)
-						.body(Full::new(Bytes::from(error)))
+		let service = self.clone();
+		Box::pin(async move {
+			// Exclude '/' from path.
+			let endpoint = req.uri().path()[1..].to_string();
+			let body = match read_body(req.into_body()).await {
+				Ok(body) => body,
+				Err(e) => return Ok(build_error_response(e, content_type).map(Either::Left)),
+			};
+
+			let request = RawRequest { endpoint, auth_params, body, content_type };
+			if request.endpoint == SUBSCRIBE_EVENTS_PATH {
+				return match service.subscribe_events(request) {
+					Ok(events) => Ok(Response::builder()
+						.header(CONTENT_TYPE, TEXT_EVENT_STREAM)
+						.header(CACHE_CONTROL, "no-cache")
+						.body(Either::Right(events))
 						// unwrap safety: body only errors when previous chained calls failed.
-						.unwrap())
-				})
-			},
-		}
+						.unwrap()),
+					Err(e) => Ok(build_error_response(e, content_type).map(Either::Left)),
+				};
+			}
+
+			match service.dispatch(request) {
+				Ok(response) => Ok(Response::builder()
+					.header(CONTENT_TYPE, content_type.as_str())
+					.body(Either::Left(Full::new(Bytes::from(response))))
+					// unwrap safety: body only errors when previous chained calls failed.
+					.unwrap()),
+				Err(e) => Ok(build_error_response(e, content_type).map(Either::Left)),
+			}
+		})
 	}
 }
 
-async fn handle_request<
-	T: Message + Default,
-	R: Message,
-	F: Fn(Context, T) -> Result<R, LdkServerError>,
->(
-	context: Context, request: Request<Incoming>, auth_params: AuthParams, api_key: String,
-	handler: F,
-) -> Result<<NodeService as Service<Request<Incoming>>>::Response, hyper::Error> {
-	// Limit the size of the request body to prevent abuse
-	let limited_body = Limited::new(request.into_body(), MAX_BODY_SIZE);
-	let bytes = match limited_body.collect().await {
-		Ok(collected) => collected.to_bytes(),
-		Err(_) => {
-			let (error_response, status_code) = to_error_response(LdkServerError::new(
+impl NodeService {
+	/// Authenticates `request` and passes it to the handler of its endpoint, returning the encoded
+	/// response.
+	pub(crate) fn dispatch(self, request: RawRequest) -> Result<Vec<u8>, LdkServerError> {
+		match unary_handler(&request.endpoint) {
+			Some(handler) => handler(self, request),
+			None => Err(LdkServerError::new(
 				InvalidRequestError,
-				"Request body too large or failed to read.",
+				format!("Unknown request: {}", request.endpoint),
+			)),
+		}
+	}
+
+	/// Authenticates `request` and checks that the API key it was signed with may call its
+	/// endpoint.
+	fn authorize(&self, request: &RawRequest, now: u64) -> Result<ApiKey, LdkServerError> {
+		let api_key = self.authenticate(request, now)?;
+		self.check_authorization(&api_key, &request.endpoint)?;
+		Ok(api_key)
+	}
+
+	/// Finds the API key `request` was signed with, rejecting replayed requests.
+	fn authenticate(&self, request: &RawRequest, now: u64) -> Result<ApiKey, LdkServerError> {
+		let auth_params = &request.auth_params;
+
+		// Validate HMAC authentication with the raw request body, regardless of its content type.
+		let api_key = authenticate(auth_params, &request.body, &self.api_keys.accepted_keys(now))?;
+
+		// Reject replayed requests. This is only checked after successful authentication, so that
+		// unauthenticated requests can't fill up the nonce cache.
+		if !self.nonce_cache.insert(&auth_params.nonce, now) {
+			return Err(LdkServerError::new(AuthError, "Request nonce has already been used"));
+		}
+		Ok(api_key)
+	}
+
+	/// Checks that `api_key` may call `endpoint` over the current connection.
+	fn check_authorization(&self, api_key: &ApiKey, endpoint: &str) -> Result<(), LdkServerError> {
+		api_key.check_client_identity(self.client_identity.as_deref())?;
+
+		if !api_key.has_permission(required_permission(endpoint)) {
+			return Err(LdkServerError::new(
+				AuthError,
+				format!("API key '{}' is not permitted to call this endpoint", api_key.name),
 			));
-			return Ok(Response::builder()
-				.status(status_code)
-				.body(Full::new(Bytes::from(error_response.encode_to_vec())))
-				// unwrap safety: body only errors when previous chained calls failed.
-				.unwrap());
+		}
+		Ok(())
+	}
+
+	/// Authenticates a `SubscribeEvents` request and starts streaming events to the client.
+	fn subscribe_events(&self, request: RawRequest) -> Result<EventStreamBody, LdkServerError> {
+		let now = std::time::SystemTime::now()
+			.duration_since(std::time::UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs();
+		self.authorize(&request, now)?;
+
+		let content_type = request.content_type;
+		let _: SubscribeEventsRequest = content_type.decode(request.body)?;
+		Ok(EventStreamBody::new(self.event_broadcaster.subscribe(), content_type))
+	}
+}
+
+/// Reads a request body of at most [`MAX_BODY_SIZE`] bytes.
+pub(crate) async fn read_body(body: Incoming) -> Result<Bytes, LdkServerError> {
+	// Limit the size of the request body to prevent abuse
+	Limited::new(body, MAX_BODY_SIZE).collect().await.map(|collected| collected.to_bytes()).map_err(
+		|_| LdkServerError::new(InvalidRequestError, "Request body too large or failed to read."),
+	)
+}
+
+/// Handles a unary request, i.e., one answered by a single response, see [`unary_handler`].
+type UnaryHandler = fn(NodeService, RawRequest) -> Result<Vec<u8>, LdkServerError>;
+
+/// Returns the handler of the unary endpoint at `path`, or `None` if there is no such endpoint.
+///
+/// Streaming endpoints, i.e., `SubscribeEvents`, are served separately.
+pub(crate) fn unary_handler(path: &str) -> Option<UnaryHandler> {
+	let handler: UnaryHandler = match path {
+		GET_NODE_INFO_PATH => {
+			|service, request| handle_request(service, request, handle_get_node_info_request)
+		},
+		GET_BALANCES_PATH => {
+			|service, request| handle_request(service, request, handle_get_balances_request)
+		},
+		ONCHAIN_RECEIVE_PATH => {
+			|service, request| handle_request(service, request, handle_onchain_receive_request)
+		},
+		ONCHAIN_SEND_PATH => {
+			|service, request| handle_request(service, request, handle_onchain_send_request)
+		},
+		BOLT11_RECEIVE_PATH => {
+			|service, request| handle_request(service, request, handle_bolt11_receive_request)
+		},
+		BOLT11_SEND_PATH => {
+			|service, request| handle_request(service, request, handle_bolt11_send_request)
+		},
+		BOLT12_RECEIVE_PATH => {
+			|service, request| handle_request(service, request, handle_bolt12_receive_request)
+		},
+		BOLT12_SEND_PATH => {
+			|service, request| handle_request(service, request, handle_bolt12_send_request)
 		},
+		OPEN_CHANNEL_PATH => {
+			|service, request| handle_request(service, request, handle_open_channel)
+		},
+		SPLICE_IN_PATH => {
+			|service, request| handle_request(service, request, handle_splice_in_request)
+		},
+		SPLICE_OUT_PATH => {
+			|service, request| handle_request(service, request, handle_splice_out_request)
+		},
+		CLOSE_CHANNEL_PATH => {
+			|service, request| handle_request(service, request, handle_close_channel_request)
+		},
+		FORCE_CLOSE_CHANNEL_PATH => {
+			|service, request| handle_request(service, request, handle_force_close_channel_request)
+		},
+		LIST_CHANNELS_PATH => {
+			|service, request| handle_request(service, request, handle_list_channels_request)
+		},
+		UPDATE_CHANNEL_CONFIG_PATH => |service, request| {
+			handle_request(service, request, handle_update_channel_config_request)
+		},
+		GET_PAYMENT_DETAILS_PATH => {
+			|service, request| handle_request(service, request, handle_get_payment_details_request)
+		},
+		LIST_PAYMENTS_PATH => {
+			|service, request| handle_request(service, request, handle_list_payments_request)
+		},
+		LIST_PAYMENTS_UPDATED_SINCE_PATH => |service, request| {
+			handle_request(service, request, handle_list_payments_updated_since_request)
+		},
+		LIST_PAYMENTS_BY_LABEL_PATH => |service, request| {
+			handle_request(service, request, handle_list_payments_by_label_request)
+		},
+		LIST_FORWARDED_PAYMENTS_PATH => |service, request| {
+			handle_request(service, request, handle_list_forwarded_payments_request)
+		},
+		GET_FORWARDING_STATS_PATH => {
+			|service, request| handle_request(service, request, handle_get_forwarding_stats_request)
+		},
+		CONNECT_PEER_PATH => {
+			|service, request| handle_request(service, request, handle_connect_peer)
+		},
+		DISCONNECT_PEER_PATH => {
+			|service, request| handle_request(service, request, handle_disconnect_peer)
+		},
+		SPONTANEOUS_SEND_PATH => {
+			|service, request| handle_request(service, request, handle_spontaneous_send_request)
+		},
+		SIGN_MESSAGE_PATH => {
+			|service, request| handle_request(service, request, handle_sign_message_request)
+		},
+		VERIFY_SIGNATURE_PATH => {
+			|service, request| handle_request(service, request, handle_verify_signature_request)
+		},
+		EXPORT_PATHFINDING_SCORES_PATH => |service, request| {
+			handle_request(service, request, handle_export_pathfinding_scores_request)
+		},
+		GRAPH_LIST_CHANNELS_PATH => {
+			|service, request| handle_request(service, request, handle_graph_list_channels_request)
+		},
+		GRAPH_GET_CHANNEL_PATH => {
+			|service, request| handle_request(service, request, handle_graph_get_channel_request)
+		},
+		GRAPH_LIST_NODES_PATH => {
+			|service, request| handle_request(service, request, handle_graph_list_nodes_request)
+		},
+		GRAPH_GET_NODE_PATH => {
+			|service, request| handle_request(service, request, handle_graph_get_node_request)
+		},
+		CREATE_API_KEY_PATH => {
+			|service, request| handle_request(service, request, handle_create_api_key_request)
+		},
+		LIST_API_KEYS_PATH => {
+			|service, request| handle_request(service, request, handle_list_api_keys_request)
+		},
+		REVOKE_API_KEY_PATH => {
+			|service, request| handle_request(service, request, handle_revoke_api_key_request)
+		},
+		ROTATE_API_KEY_PATH => {
+			|service, request| handle_request(service, request, handle_rotate_api_key_request)
+		},
+		LIST_PENDING_APPROVALS_PATH => |service, request| {
+			handle_request(service, request, handle_list_pending_approvals_request)
+		},
+		APPROVE_PAYMENT_PATH => {
+			|service, request| handle_request(service, request, handle_approve_payment_request)
+		},
+		REJECT_PAYMENT_PATH => {
+			|service, request| handle_request(service, request, handle_reject_payment_request)
+		},
+		LIST_AUDIT_LOG_PATH => {
+			|service, request| handle_request(service, request, handle_list_audit_log_request)
+		},
+		LIST_EVENTS_PATH => {
+			|service, request| handle_request(service, request, handle_list_events_request)
+		},
+		_ => return None,
 	};
+	Some(handler)
+}
 
-	// Validate HMAC authentication with the request body
-	if let Err(e) =
-		validate_hmac_auth(auth_params.timestamp, &auth_params.hmac_hex, &bytes, &api_key)
-	{
-		let (error_response, status_code) = to_error_response(e);
-		return Ok(Response::builder()
-			.status(status_code)
-			.body(Full::new(Bytes::from(error_response.encode_to_vec())))
-			// unwrap safety: body only errors when previous chained calls failed.
-			.unwrap());
-	}
-
-	match T::decode(bytes) {
-		Ok(request) => match handler(context, request) {
-			Ok(response) => Ok(Response::builder()
-				.body(Full::new(Bytes::from(response.encode_to_vec())))
-				// unwrap safety: body only errors when previous chained calls failed.
-				.unwrap()),
-			Err(e) => {
-				let (error_response, status_code) = to_error_response(e);
-				Ok(Response::builder()
-					.status(status_code)
-					.body(Full::new(Bytes::from(error_response.encode_to_vec())))
-					// unwrap safety: body only errors when previous chained calls failed.
-					.unwrap())
-			},
-		},
-		Err(_) => {
-			let (error_response, status_code) =
-				to_error_response(LdkServerError::new(InvalidRequestError, "Malformed request."));
-			Ok(Response::builder()
-				.status(status_code)
-				.body(Full::new(Bytes::from(error_response.encode_to_vec())))
-				// unwrap safety: body only errors when previous chained calls failed.
-				.unwrap())
+fn handle_request<
+	T: Message + Default + DeserializeOwned,
+	R: Message + Serialize,
+	F: Fn(Context, T) -> Result<R, LdkServerError>,
+>(
+	service: NodeService, request: RawRequest, handler: F,
+) -> Result<Vec<u8>, LdkServerError> {
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap_or_default()
+		.as_secs();
+	// Unauthenticated requests are never recorded, so that they can't fill up the audit log.
+	let api_key = service.authenticate(&request, now)?;
+
+	let RawRequest { endpoint, body: bytes, content_type, .. } = request;
+	let authorization = service.check_authorization(&api_key, &endpoint);
+	let request = content_type.decode::<T>(bytes);
+
+	let mut audit_log_entry = is_audited(&endpoint).then(|| AuditLogEntry {
+		id: String::new(),
+		timestamp: now,
+		api_key_name: api_key.name.clone(),
+		client_identity: service.client_identity.clone(),
+		request_summary: match &request {
+			// Summarize the protobuf encoding, so that entries look the same for JSON requests.
+			Ok(request) => summarize_request(&endpoint, &request.encode_to_vec()),
+			Err(e) => e.message.clone(),
 		},
+		endpoint,
+		success: false,
+		error: None,
+		completed: false,
+	});
+	let paginated_kv_store = Arc::clone(&service.paginated_kv_store);
+	// The call is recorded before it is processed, so that it is audited even if the server stops
+	// while processing it. Calls which can't be recorded are never processed.
+	if let Some(entry) = audit_log_entry.as_mut() {
+		append_audit_log_entry(&*paginated_kv_store, entry).map_err(|e| {
+			LdkServerError::new(
+				InternalServerError,
+				format!("Failed to append audit log entry: {e}"),
+			)
+		})?;
+	}
+
+	let context = Context {
+		node: service.node,
+		paginated_kv_store: service.paginated_kv_store,
+		api_key,
+		api_keys: service.api_keys,
+		spending_tracker: service.spending_tracker,
+		approval_queue: service.approval_queue,
+		client_identity: service.client_identity,
+	};
+
+	let result = authorization.and(request).and_then(|request| handler(context, request));
+
+	if let Some(mut entry) = audit_log_entry {
+		entry.completed = true;
+		entry.success = result.is_ok();
+		entry.error = result.as_ref().err().map(|e| e.message.clone());
+		// The call was already processed, so we only log a failure to record its result.
+		if let Err(e) = update_audit_log_entry(&*paginated_kv_store, &entry) {
+			error!("Failed to update audit log entry: {e}");
+		}
 	}
+
+	result.map(|response| content_type.encode(&response))
+}
+
+fn build_error_response(e: LdkServerError, content_type: ContentType) -> Response<Full<Bytes>> {
+	let (error_response, status_code) = to_error_response(e);
+	Response::builder()
+		.status(status_code)
+		.header(CONTENT_TYPE, content_type.as_str())
+		.body(Full::new(Bytes::from(content_type.encode(&error_response))))
+		// unwrap safety: body only errors when previous chained calls failed.
+		.unwrap()
 }
 
 #[cfg(test)]
 mod tests {
+	use ldk_server_protos::api::{
+		CreateApiKeyRequest, ListPaymentsRequest, OpenChannelRequest, SignMessageRequest,
+	};
+	use ldk_server_protos::types::{ApiKeyPermission, PaymentDirection, PaymentStatus};
+
 	use super::*;
+	use crate::auth::spending_limits::SpendingLimits;
+	use crate::auth::Permission;
+
+	const NONCE: &str = "0123456789abcdef0123456789abcdef";
 
-	fn compute_hmac(api_key: &str, timestamp: u64, body: &[u8]) -> String {
+	fn compute_hmac(api_key: &str, timestamp: u64, nonce: &str, body: &[u8]) -> String {
 		let mut hmac_engine: HmacEngine<sha256::Hash> = HmacEngine::new(api_key.as_bytes());
 		hmac_engine.input(&timestamp.to_be_bytes());
+		hmac_engine.input(nonce.as_bytes());
 		hmac_engine.input(body);
 		Hmac::<sha256::Hash>::from_engine(hmac_engine).to_string()
 	}
@@ -468,14 +691,15 @@ mod tests {
 		let timestamp =
 			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
 		let hmac = "8f5a33c2c68fb253899a588308fd13dcaf162d2788966a1fb6cc3aa2e0c51a93";
-		let auth_header = format!("HMAC {timestamp}:{hmac}");
+		let auth_header = format!("HMAC {timestamp}:{NONCE}:{hmac}");
 
 		let req = create_test_request(Some(auth_header));
 
 		let result = extract_auth_params(&req);
 		assert!(result.is_ok());
-		let AuthParams { timestamp: ts, hmac_hex } = result.unwrap();
+		let AuthParams { timestamp: ts, nonce, hmac_hex } = result.unwrap();
 		assert_eq!(ts, timestamp);
+		assert_eq!(nonce, NONCE);
 		assert_eq!(hmac_hex, hmac);
 	}
 
@@ -498,15 +722,33 @@ mod tests {
 		assert_eq!(result.unwrap_err().error_code, AuthError);
 	}
 
+	#[test]
+	fn test_extract_auth_params_missing_nonce() {
+		// Legacy format without a nonce
+		let hmac = "8f5a33c2c68fb253899a588308fd13dcaf162d2788966a1fb6cc3aa2e0c51a93";
+		let req = create_test_request(Some(format!("HMAC 12345:{hmac}")));
+
+		let result = extract_auth_params(&req);
+		assert!(result.is_err());
+		assert_eq!(result.unwrap_err().error_code, AuthError);
+
+		// Nonce of invalid length
+		let req = create_test_request(Some(format!("HMAC 12345:abcdef:{hmac}")));
+
+		let result = extract_auth_params(&req);
+		assert!(result.is_err());
+		assert_eq!(result.unwrap_err().error_code, AuthError);
+	}
+
 	#[test]
 	fn test_validate_hmac_auth_success() {
 		let api_key = "test_api_key".to_string();
 		let body = b"test request body";
 		let timestamp =
 			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
-		let hmac = compute_hmac(&api_key, timestamp, body);
+		let hmac = compute_hmac(&api_key, timestamp, NONCE, body);
 
-		let result = validate_hmac_auth(timestamp, &hmac, body, &api_key);
+		let result = validate_hmac_auth(timestamp, NONCE, &hmac, body, &api_key);
 		assert!(result.is_ok());
 	}
 
@@ -517,9 +759,9 @@ mod tests {
 		let timestamp =
 			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
 		// Compute HMAC with wrong key
-		let hmac = compute_hmac("wrong_key", timestamp, body);
+		let hmac = compute_hmac("wrong_key", timestamp, NONCE, body);
 
-		let result = validate_hmac_auth(timestamp, &hmac, body, &api_key);
+		let result = validate_hmac_auth(timestamp, NONCE, &hmac, body, &api_key);
 		assert!(result.is_err());
 		assert_eq!(result.unwrap_err().error_code, AuthError);
 	}
@@ -532,9 +774,9 @@ mod tests {
 		let timestamp =
 			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
 				- 600;
-		let hmac = compute_hmac(&api_key, timestamp, body);
+		let hmac = compute_hmac(&api_key, timestamp, NONCE, body);
 
-		let result = validate_hmac_auth(timestamp, &hmac, body, &api_key);
+		let result = validate_hmac_auth(timestamp, NONCE, &hmac, body, &api_key);
 		assert!(result.is_err());
 		assert_eq!(result.unwrap_err().error_code, AuthError);
 	}
@@ -547,11 +789,157 @@ mod tests {
 		let timestamp =
 			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
 		// Compute HMAC with original body
-		let hmac = compute_hmac(&api_key, timestamp, original_body);
+		let hmac = compute_hmac(&api_key, timestamp, NONCE, original_body);
 
 		// Try to validate with tampered body
-		let result = validate_hmac_auth(timestamp, &hmac, tampered_body, &api_key);
+		let result = validate_hmac_auth(timestamp, NONCE, &hmac, tampered_body, &api_key);
+		assert!(result.is_err());
+		assert_eq!(result.unwrap_err().error_code, AuthError);
+	}
+
+	#[test]
+	fn test_validate_hmac_auth_tampered_nonce() {
+		let api_key = "test_api_key".to_string();
+		let body = b"test request body";
+		let timestamp =
+			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+		let hmac = compute_hmac(&api_key, timestamp, NONCE, body);
+
+		// Replaying the request with a fresh nonce must not pass validation
+		let fresh_nonce = "fedcba9876543210fedcba9876543210";
+		let result = validate_hmac_auth(timestamp, fresh_nonce, &hmac, body, &api_key);
 		assert!(result.is_err());
 		assert_eq!(result.unwrap_err().error_code, AuthError);
 	}
+
+	#[test]
+	fn test_authenticate_selects_matching_key() {
+		let api_keys = vec![
+			ApiKey::new(
+				"admin".to_string(),
+				"admin_key".to_string(),
+				vec![Permission::Admin],
+				SpendingLimits::default(),
+				None,
+			),
+			ApiKey::new(
+				"pos".to_string(),
+				"pos_key".to_string(),
+				vec![Permission::Invoice],
+				SpendingLimits::default(),
+				None,
+			),
+		];
+		let body = b"test request body";
+		let timestamp =
+			std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+
+		let auth_params = AuthParams {
+			timestamp,
+			nonce: NONCE.to_string(),
+			hmac_hex: compute_hmac("pos_key", timestamp, NONCE, body),
+		};
+		let api_key = authenticate(&auth_params, body, &api_keys).unwrap();
+		assert_eq!(api_key.name, "pos");
+		assert!(!api_key.has_permission(Permission::Send));
+
+		let auth_params = AuthParams {
+			timestamp,
+			nonce: NONCE.to_string(),
+			hmac_hex: compute_hmac("unknown_key", timestamp, NONCE, body),
+		};
+		let result = authenticate(&auth_params, body, &api_keys);
+		assert_eq!(result.unwrap_err().error_code, AuthError);
+	}
+
+	#[test]
+	fn test_unary_handler() {
+		assert!(unary_handler(GET_NODE_INFO_PATH).is_some());
+		assert!(unary_handler(LIST_EVENTS_PATH).is_some());
+		// The event stream isn't answered by a single response.
+		assert!(unary_handler(SUBSCRIBE_EVENTS_PATH).is_none());
+		assert!(unary_handler("UnknownMethod").is_none());
+	}
+
+	#[test]
+	fn test_content_type_from_request() {
+		let req = Request::builder()
+			.header(CONTENT_TYPE, "application/json; charset=utf-8")
+			.body(())
+			.unwrap();
+		assert_eq!(ContentType::from_request(&req), ContentType::Json);
+
+		let req =
+			Request::builder().header(CONTENT_TYPE, APPLICATION_OCTET_STREAM).body(()).unwrap();
+		assert_eq!(ContentType::from_request(&req), ContentType::Protobuf);
+
+		// Requests without a content type are treated as protobuf for backwards compatibility.
+		assert_eq!(ContentType::from_request(&create_test_request(None)), ContentType::Protobuf);
+	}
+
+	#[test]
+	fn test_json_request_decoding() {
+		let body = br#"{"name": "pos", "permissions": ["READ", "INVOICE"]}"#;
+		let request: CreateApiKeyRequest =
+			ContentType::Json.decode(Bytes::from_static(body)).unwrap();
+		assert_eq!(request.name, "pos");
+		assert_eq!(
+			request.permissions,
+			vec![ApiKeyPermission::Read as i32, ApiKeyPermission::Invoice as i32]
+		);
+		assert_eq!(request.max_send_amount_msat, None);
+
+		// The JSON encoding of a message decodes to the same message.
+		let encoded = ContentType::Json.encode(&request);
+		let decoded: CreateApiKeyRequest = ContentType::Json.decode(Bytes::from(encoded)).unwrap();
+		assert_eq!(decoded, request);
+
+		// Omitted fields take their default value.
+		let body = br#"{"node_pubkey": "02aa", "address": "127.0.0.1:9735", "channel_amount_sats": 100000}"#;
+		let request: OpenChannelRequest =
+			ContentType::Json.decode(Bytes::from_static(body)).unwrap();
+		assert_eq!(request.channel_amount_sats, 100_000);
+		assert!(!request.announce_channel);
+
+		let body = br#"{"name": "pos", "permissions": ["SUPERUSER"]}"#;
+		let result = ContentType::Json.decode::<CreateApiKeyRequest>(Bytes::from_static(body));
+		assert_eq!(result.unwrap_err().error_code, InvalidRequestError);
+	}
+
+	#[test]
+	fn test_canonical_json_mapping() {
+		// Bytes fields are base64 strings.
+		let request = SignMessageRequest { message: Bytes::from_static(b"hi") };
+		let encoded = ContentType::Json.encode(&request);
+		let json: serde_json::Value = serde_json::from_slice(&encoded).unwrap();
+		assert_eq!(json, serde_json::json!({ "message": "aGk=" }));
+		let decoded: SignMessageRequest = ContentType::Json.decode(Bytes::from(encoded)).unwrap();
+		assert_eq!(decoded, request);
+
+		// Field names are lowerCamelCase, 64-bit integers are strings and enums are the names of
+		// their values.
+		let request = ListPaymentsRequest {
+			status: Some(PaymentStatus::Succeeded as i32),
+			direction: Some(PaymentDirection::Outbound as i32),
+			min_creation_timestamp: Some(1_000),
+			..Default::default()
+		};
+		let encoded = ContentType::Json.encode(&request);
+		let json: serde_json::Value = serde_json::from_slice(&encoded).unwrap();
+		assert_eq!(json["status"], "SUCCEEDED");
+		assert_eq!(json["direction"], "OUTBOUND");
+		assert_eq!(json["minCreationTimestamp"], "1000");
+		assert_eq!(json["kind"], serde_json::Value::Null);
+		let decoded: ListPaymentsRequest = ContentType::Json.decode(Bytes::from(encoded)).unwrap();
+		assert_eq!(decoded, request);
+
+		// Both lowerCamelCase and snake_case names are accepted, as are 64-bit integers given as
+		// numbers.
+		let body = br#"{"nodePubkey": "02aa", "channel_amount_sats": 100000, "pushToCounterpartyMsat": "5"}"#;
+		let request: OpenChannelRequest =
+			ContentType::Json.decode(Bytes::from_static(body)).unwrap();
+		assert_eq!(request.node_pubkey, "02aa");
+		assert_eq!(request.channel_amount_sats, 100_000);
+		assert_eq!(request.push_to_counterparty_msat, Some(5));
+	}
 }
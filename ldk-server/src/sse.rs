@@ -0,0 +1,140 @@
+// This file is Copyright its original authors, visible in version control
+// history.
+//
+// This file is licensed under the Apache License, Version 2.0 <LICENSE-APACHE
+// or http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your option.
+// You may not use this file except in accordance with one or both of these
+// licenses.
+
+//! Streams events to clients of the `SubscribeEvents` API as
+//! [server-sent events](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+
+use std::convert::Infallible;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use base64::Engine;
+use hyper::body::{Body, Bytes, Frame};
+use ldk_server_protos::events::EventEnvelope;
+use log::warn;
+use prost::Message;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, mpsc};
+
+use crate::io::events::get_event_name;
+use crate::service::ContentType;
+
+pub(crate) const TEXT_EVENT_STREAM: &str = "text/event-stream";
+
+/// Interval at which a comment is sent on idle streams, so that intermediaries don't consider the
+/// connection dead.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+const KEEP_ALIVE_COMMENT: &[u8] = b": keep-alive\n\n";
+
+/// The body of a `SubscribeEvents` response, yielding one server-sent event per published event.
+pub(crate) struct EventStreamBody {
+	receiver: mpsc::Receiver<Bytes>,
+}
+
+impl EventStreamBody {
+	/// Starts streaming the events received on `events`, encoded as `content_type`.
+	///
+	/// The stream ends if the subscriber falls behind, as it would otherwise silently miss events.
+	pub(crate) fn new(
+		mut events: broadcast::Receiver<EventEnvelope>, content_type: ContentType,
+	) -> Self {
+		let (sender, receiver) = mpsc::channel(1);
+		tokio::spawn(async move {
+			let mut keep_alive = tokio::time::interval(KEEP_ALIVE_INTERVAL);
+			loop {
+				let message = tokio::select! {
+					event = events.recv() => match event {
+						Ok(event) => match encode_event(&event, content_type) {
+							Some(message) => message,
+							None => continue,
+						},
+						Err(RecvError::Lagged(missed)) => {
+							warn!("Closing event stream of subscriber which missed {missed} events");
+							break;
+						},
+						Err(RecvError::Closed) => break,
+					},
+					_ = keep_alive.tick() => Bytes::from_static(KEEP_ALIVE_COMMENT),
+					// The client disconnected.
+					_ = sender.closed() => break,
+				};
+				if sender.send(message).await.is_err() {
+					break;
+				}
+			}
+		});
+		Self { receiver }
+	}
+}
+
+impl Body for EventStreamBody {
+	type Data = Bytes;
+	type Error = Infallible;
+
+	fn poll_frame(
+		self: Pin<&mut Self>, cx: &mut Context<'_>,
+	) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+		self.get_mut().receiver.poll_recv(cx).map(|message| message.map(|m| Ok(Frame::data(m))))
+	}
+}
+
+/// Encodes `event` as a server-sent event named after its variant.
+///
+/// The event data is the base64-encoded protobuf serialization of the [`EventEnvelope`], or its
+/// JSON encoding if the subscription was requested with JSON. Returns `None` for envelopes without
+/// an event.
+fn encode_event(event: &EventEnvelope, content_type: ContentType) -> Option<Bytes> {
+	let event_name = get_event_name(event.event.as_ref()?);
+	let data = match content_type {
+		ContentType::Protobuf => {
+			base64::engine::general_purpose::STANDARD.encode(event.encode_to_vec())
+		},
+		// unwrap safety: the JSON encoding of our messages is valid UTF-8 without any newlines.
+		ContentType::Json => String::from_utf8(content_type.encode(event)).unwrap(),
+	};
+	Some(Bytes::from(format!("event: {event_name}\ndata: {data}\n\n")))
+}
+
+#[cfg(test)]
+mod tests {
+	use ldk_server_protos::events::{event_envelope, PaymentReceived};
+	use ldk_server_protos::types::Payment;
+
+	use super::*;
+
+	#[test]
+	fn test_encode_event() {
+		let event = EventEnvelope {
+			event: Some(event_envelope::Event::PaymentReceived(PaymentReceived {
+				payment: Some(Payment { id: "payment_id".to_string(), ..Default::default() }),
+			})),
+			sequence_number: 1,
+			..Default::default()
+		};
+
+		let encoded = encode_event(&event, ContentType::Protobuf).unwrap();
+		let encoded = std::str::from_utf8(&encoded).unwrap();
+		let data = encoded
+			.strip_prefix("event: PaymentReceived\ndata: ")
+			.and_then(|rest| rest.strip_suffix("\n\n"))
+			.unwrap();
+		let decoded = base64::engine::general_purpose::STANDARD.decode(data).unwrap();
+		assert_eq!(EventEnvelope::decode(&decoded[..]).unwrap(), event);
+
+		let encoded = encode_event(&event, ContentType::Json).unwrap();
+		let encoded = std::str::from_utf8(&encoded).unwrap();
+		assert!(encoded.starts_with("event: PaymentReceived\ndata: {"));
+		assert!(encoded.contains("\"id\":\"payment_id\""));
+		assert_eq!(encoded.matches('\n').count(), 3);
+
+		assert!(encode_event(&EventEnvelope::default(), ContentType::Protobuf).is_none());
+	}
+}
@@ -12,8 +12,10 @@ use hex::prelude::*;
 use hyper::StatusCode;
 use ldk_node::bitcoin::hashes::sha256;
 use ldk_node::bitcoin::secp256k1::PublicKey;
+use ldk_node::bitcoin::OutPoint as BitcoinOutPoint;
 use ldk_node::config::{ChannelConfig, MaxDustHTLCExposure};
 use ldk_node::lightning::chain::channelmonitor::BalanceSource;
+use ldk_node::lightning::events::ClosureReason;
 use ldk_node::lightning::ln::types::ChannelId;
 use ldk_node::lightning::routing::gossip::{
 	ChannelInfo, ChannelUpdateInfo, NodeAnnouncementInfo, NodeInfo, RoutingFees,
@@ -24,6 +26,9 @@ use ldk_node::payment::{
 };
 use ldk_node::{ChannelDetails, LightningBalance, PendingSweepBalance, UserChannelId};
 use ldk_server_protos::error::{ErrorCode, ErrorResponse};
+use ldk_server_protos::events::{
+	ChannelClosed, ChannelPending, ChannelReady, SpliceFailed, SplicePending,
+};
 use ldk_server_protos::types::confirmation_status::Status::{Confirmed, Unconfirmed};
 use ldk_server_protos::types::lightning_balance::BalanceType::{
 	ClaimableAwaitingConfirmations, ClaimableOnChannelClose, ContentiousClaimable,
@@ -41,16 +46,14 @@ use ldk_server_protos::types::{
 
 use crate::api::error::LdkServerError;
 use crate::api::error::LdkServerErrorCode::{
-	AuthError, InternalServerError, InvalidRequestError, LightningError,
+	AuthError, InternalServerError, InvalidRequestError, LightningError, SpendingLimitExceededError,
 };
 
 pub(crate) fn channel_to_proto(channel: ChannelDetails) -> Channel {
 	Channel {
 		channel_id: channel.channel_id.0.to_lower_hex_string(),
 		counterparty_node_id: channel.counterparty_node_id.to_string(),
-		funding_txo: channel
-			.funding_txo
-			.map(|o| OutPoint { txid: o.txid.to_string(), vout: o.vout }),
+		funding_txo: channel.funding_txo.map(outpoint_to_proto),
 		user_channel_id: channel.user_channel_id.0.to_string(),
 		unspendable_punishment_reserve: channel.unspendable_punishment_reserve,
 		channel_value_sats: channel.channel_value_sats,
@@ -137,6 +140,7 @@ pub(crate) fn payment_to_proto(payment: PaymentDetails) -> Payment {
 			PaymentStatus::Failed => ldk_server_protos::types::PaymentStatus::Failed.into(),
 		},
 		latest_update_timestamp,
+		metadata: None,
 	}
 }
 
@@ -406,7 +410,7 @@ pub(crate) fn forwarded_payment_to_proto(
 	prev_user_channel_id: Option<UserChannelId>, next_user_channel_id: Option<UserChannelId>,
 	prev_node_id: Option<PublicKey>, next_node_id: Option<PublicKey>,
 	total_fee_earned_msat: Option<u64>, skimmed_fee_msat: Option<u64>, claim_from_onchain_tx: bool,
-	outbound_amount_forwarded_msat: Option<u64>,
+	outbound_amount_forwarded_msat: Option<u64>, timestamp: u64,
 ) -> ForwardedPayment {
 	ForwardedPayment {
 		prev_channel_id: prev_channel_id.to_string(),
@@ -422,6 +426,82 @@ pub(crate) fn forwarded_payment_to_proto(
 		skimmed_fee_msat,
 		claim_from_onchain_tx,
 		outbound_amount_forwarded_msat,
+		timestamp,
+	}
+}
+
+pub(crate) fn outpoint_to_proto(outpoint: BitcoinOutPoint) -> OutPoint {
+	OutPoint { txid: outpoint.txid.to_string(), vout: outpoint.vout }
+}
+
+pub(crate) fn channel_pending_to_proto(
+	channel_id: ChannelId, user_channel_id: UserChannelId, counterparty_node_id: PublicKey,
+	funding_txo: BitcoinOutPoint,
+) -> ChannelPending {
+	ChannelPending {
+		channel_id: channel_id.0.to_lower_hex_string(),
+		user_channel_id: user_channel_id.0.to_string(),
+		counterparty_node_id: counterparty_node_id.to_string(),
+		funding_txo: Some(outpoint_to_proto(funding_txo)),
+	}
+}
+
+pub(crate) fn channel_ready_to_proto(
+	channel_id: ChannelId, user_channel_id: UserChannelId, counterparty_node_id: Option<PublicKey>,
+) -> ChannelReady {
+	ChannelReady {
+		channel_id: channel_id.0.to_lower_hex_string(),
+		user_channel_id: user_channel_id.0.to_string(),
+		counterparty_node_id: counterparty_node_id.map(|n| n.to_string()),
+	}
+}
+
+pub(crate) fn channel_closed_to_proto(
+	channel_id: ChannelId, user_channel_id: UserChannelId, counterparty_node_id: Option<PublicKey>,
+	reason: Option<ClosureReason>,
+) -> ChannelClosed {
+	ChannelClosed {
+		channel_id: channel_id.0.to_lower_hex_string(),
+		user_channel_id: user_channel_id.0.to_string(),
+		counterparty_node_id: counterparty_node_id.map(|n| n.to_string()),
+		force_closed: reason.as_ref().is_some_and(is_force_closure),
+		reason: reason.map(|r| r.to_string()),
+	}
+}
+
+/// Returns whether the channel was closed by broadcasting a commitment transaction, by either us or
+/// the counterparty.
+fn is_force_closure(reason: &ClosureReason) -> bool {
+	matches!(
+		reason,
+		ClosureReason::CounterpartyForceClosed { .. }
+			| ClosureReason::HolderForceClosed { .. }
+			| ClosureReason::CommitmentTxConfirmed { .. }
+			| ClosureReason::HTLCsTimedOut { .. }
+	)
+}
+
+pub(crate) fn splice_pending_to_proto(
+	channel_id: ChannelId, user_channel_id: UserChannelId, counterparty_node_id: PublicKey,
+	new_funding_txo: BitcoinOutPoint,
+) -> SplicePending {
+	SplicePending {
+		channel_id: channel_id.0.to_lower_hex_string(),
+		user_channel_id: user_channel_id.0.to_string(),
+		counterparty_node_id: counterparty_node_id.to_string(),
+		new_funding_txo: Some(outpoint_to_proto(new_funding_txo)),
+	}
+}
+
+pub(crate) fn splice_failed_to_proto(
+	channel_id: ChannelId, user_channel_id: UserChannelId, counterparty_node_id: PublicKey,
+	abandoned_funding_txo: Option<BitcoinOutPoint>,
+) -> SpliceFailed {
+	SpliceFailed {
+		channel_id: channel_id.0.to_lower_hex_string(),
+		user_channel_id: user_channel_id.0.to_string(),
+		counterparty_node_id: counterparty_node_id.to_string(),
+		abandoned_funding_txo: abandoned_funding_txo.map(outpoint_to_proto),
 	}
 }
 
@@ -516,6 +596,7 @@ pub(crate) fn to_error_response(ldk_error: LdkServerError) -> (ErrorResponse, St
 		AuthError => ErrorCode::AuthError,
 		LightningError => ErrorCode::LightningError,
 		InternalServerError => ErrorCode::InternalServerError,
+		SpendingLimitExceededError => ErrorCode::SpendingLimitExceededError,
 	} as i32;
 
 	let status = match ldk_error.error_code {
@@ -523,6 +604,7 @@ pub(crate) fn to_error_response(ldk_error: LdkServerError) -> (ErrorResponse, St
 		AuthError => StatusCode::UNAUTHORIZED,
 		LightningError => StatusCode::INTERNAL_SERVER_ERROR,
 		InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
+		SpendingLimitExceededError => StatusCode::FORBIDDEN,
 	};
 
 	let error_response = ErrorResponse { message: ldk_error.message, error_code };
@@ -9,12 +9,14 @@
 
 use std::fs;
 use std::net::IpAddr;
+use std::sync::Arc;
 
 use base64::Engine;
 use ring::rand::SystemRandom;
 use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING};
 use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
-use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::server::WebPkiClientVerifier;
+use tokio_rustls::rustls::{RootCertStore, ServerConfig};
 
 use crate::util::config::TlsConfig;
 
@@ -40,12 +42,16 @@ const TAG_BIT_STRING: u8 = 0x03;
 const TAG_OCTET_STRING: u8 = 0x04;
 const TAG_OID: u8 = 0x06;
 const TAG_UTF8_STRING: u8 = 0x0C;
+const TAG_PRINTABLE_STRING: u8 = 0x13;
+const TAG_IA5_STRING: u8 = 0x16;
 const TAG_UTC_TIME: u8 = 0x17;
 const TAG_SEQUENCE: u8 = 0x30;
 const TAG_SET: u8 = 0x31;
 
 /// Gets or generates TLS configuration. If custom paths are provided, uses those.
 /// Otherwise, generates a self-signed certificate in the storage directory.
+///
+/// If a client CA is configured, clients are required to present a certificate signed by it.
 pub fn get_or_generate_tls_config(
 	tls_config: Option<TlsConfig>, storage_dir: &str,
 ) -> Result<ServerConfig, String> {
@@ -55,7 +61,7 @@ pub fn get_or_generate_tls_config(
 		if !fs::exists(&cert_path).unwrap_or(false) || !fs::exists(&key_path).unwrap_or(false) {
 			generate_self_signed_cert(&cert_path, &key_path, &config.hosts)?;
 		}
-		load_tls_config(&cert_path, &key_path)
+		load_tls_config(&cert_path, &key_path, config.client_ca_path.as_deref())
 	} else {
 		// Check if we already have generated certs, if we don't, generate new ones
 		let cert_path = format!("{storage_dir}/tls.crt");
@@ -64,10 +70,49 @@ pub fn get_or_generate_tls_config(
 			generate_self_signed_cert(&cert_path, &key_path, &[])?;
 		}
 
-		load_tls_config(&cert_path, &key_path)
+		load_tls_config(&cert_path, &key_path, None)
 	}
 }
 
+/// Returns the identity of a client, i.e., the common name of its certificate's subject.
+///
+/// Returns `None` if the certificate can't be parsed or its subject has no common name.
+pub(crate) fn client_identity_from_cert(cert: &CertificateDer<'_>) -> Option<String> {
+	// Certificate ::= SEQUENCE { tbsCertificate, signatureAlgorithm, signatureValue }
+	let (_, certificate, _) = der_read(cert.as_ref(), TAG_SEQUENCE)?;
+	let (_, mut tbs_cert, _) = der_read(certificate, TAG_SEQUENCE)?;
+
+	// Skip the optional version, the serial number, the signature algorithm, the issuer and the
+	// validity to get to the subject, see `build_tbs_certificate`.
+	if tbs_cert.first() == Some(&0xA0) {
+		tbs_cert = der_read_any(tbs_cert)?.2;
+	}
+	for _ in 0..4 {
+		tbs_cert = der_read_any(tbs_cert)?.2;
+	}
+	let (_, mut rdns, _) = der_read(tbs_cert, TAG_SEQUENCE)?;
+
+	// Name ::= SEQUENCE OF SET OF SEQUENCE { type OID, value ANY }
+	while !rdns.is_empty() {
+		let (_, mut attributes, rest) = der_read(rdns, TAG_SET)?;
+		rdns = rest;
+		while !attributes.is_empty() {
+			let (_, attribute, rest) = der_read(attributes, TAG_SEQUENCE)?;
+			attributes = rest;
+			let (_, oid, value) = der_read(attribute, TAG_OID)?;
+			if oid != OID_COMMON_NAME {
+				continue;
+			}
+			let (tag, value, _) = der_read_any(value)?;
+			if !matches!(tag, TAG_UTF8_STRING | TAG_PRINTABLE_STRING | TAG_IA5_STRING) {
+				return None;
+			}
+			return String::from_utf8(value.to_vec()).ok();
+		}
+	}
+	None
+}
+
 /// Parses a PEM-encoded certificate file and returns the DER-encoded certificates.
 fn parse_pem_certs(pem_data: &str) -> Result<Vec<CertificateDer<'static>>, String> {
 	let mut certs = Vec::new();
@@ -392,8 +437,40 @@ fn der_context_implicit(tag_num: u8, content: &[u8]) -> Vec<u8> {
 	der_tag_length_value(0x80 | tag_num, content)
 }
 
+// DER decoding helpers
+
+/// Reads a single DER element, returning its tag, its value and the remaining input.
+fn der_read_any(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+	let (&tag, rest) = input.split_first()?;
+	let (&first_len_byte, rest) = rest.split_first()?;
+
+	// See `der_tag_length_value` for the length encoding rules.
+	let (len, rest) = if first_len_byte < 0x80 {
+		(first_len_byte as usize, rest)
+	} else {
+		let num_len_bytes = (first_len_byte & 0x7F) as usize;
+		if num_len_bytes == 0 || num_len_bytes > 3 || rest.len() < num_len_bytes {
+			return None;
+		}
+		let len = rest[..num_len_bytes].iter().fold(0usize, |acc, b| (acc << 8) | *b as usize);
+		(len, &rest[num_len_bytes..])
+	};
+
+	if rest.len() < len {
+		return None;
+	}
+	Some((tag, &rest[..len], &rest[len..]))
+}
+
+/// Reads a single DER element, which is required to have the given tag.
+fn der_read(input: &[u8], expected_tag: u8) -> Option<(u8, &[u8], &[u8])> {
+	der_read_any(input).filter(|(tag, _, _)| *tag == expected_tag)
+}
+
 /// Loads TLS configuration from provided paths.
-fn load_tls_config(cert_path: &str, key_path: &str) -> Result<ServerConfig, String> {
+fn load_tls_config(
+	cert_path: &str, key_path: &str, client_ca_path: Option<&str>,
+) -> Result<ServerConfig, String> {
 	let cert_pem = fs::read_to_string(cert_path)
 		.map_err(|e| format!("Failed to read TLS certificate file '{cert_path}': {e}"))?;
 	let key_pem = fs::read_to_string(key_path)
@@ -407,12 +484,39 @@ fn load_tls_config(cert_path: &str, key_path: &str) -> Result<ServerConfig, Stri
 
 	let key = parse_pem_private_key(&key_pem)?;
 
-	ServerConfig::builder()
-		.with_no_client_auth()
+	let builder = match client_ca_path {
+		Some(client_ca_path) => {
+			let client_verifier =
+				WebPkiClientVerifier::builder(Arc::new(load_client_ca_roots(client_ca_path)?))
+					.build()
+					.map_err(|e| format!("Failed to build TLS client verifier: {e}"))?;
+			ServerConfig::builder().with_client_cert_verifier(client_verifier)
+		},
+		None => ServerConfig::builder().with_no_client_auth(),
+	};
+
+	builder
 		.with_single_cert(certs, key)
 		.map_err(|e| format!("Failed to build TLS server config: {e}"))
 }
 
+/// Loads the CA certificates client certificates are verified against.
+fn load_client_ca_roots(client_ca_path: &str) -> Result<RootCertStore, String> {
+	let ca_pem = fs::read_to_string(client_ca_path)
+		.map_err(|e| format!("Failed to read TLS client CA file '{client_ca_path}': {e}"))?;
+	let ca_certs = parse_pem_certs(&ca_pem)?;
+
+	if ca_certs.is_empty() {
+		return Err("No certificates found in TLS client CA file".to_string());
+	}
+
+	let mut roots = RootCertStore::empty();
+	for ca_cert in ca_certs {
+		roots.add(ca_cert).map_err(|e| format!("Invalid TLS client CA certificate: {e}"))?;
+	}
+	Ok(roots)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -451,6 +555,25 @@ mod tests {
 		assert!(matches!(key, PrivateKeyDer::Pkcs8(_)));
 	}
 
+	#[test]
+	fn test_client_identity_from_cert() {
+		let rng = SystemRandom::new();
+		let pkcs8_doc =
+			EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+		let key_pair =
+			EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8_doc.as_ref(), &rng)
+				.unwrap();
+		let cert_der = build_self_signed_cert(&key_pair, &["localhost".to_string()], &rng).unwrap();
+
+		let identity = client_identity_from_cert(&CertificateDer::from(cert_der.clone()));
+		assert_eq!(identity.as_deref(), Some(ISSUER_NAME));
+
+		// Truncated certificates are rejected
+		let truncated = CertificateDer::from(cert_der[..cert_der.len() / 2].to_vec());
+		assert_eq!(client_identity_from_cert(&truncated), None);
+		assert_eq!(client_identity_from_cert(&CertificateDer::from(vec![])), None);
+	}
+
 	#[test]
 	fn test_parse_pem_private_key_invalid() {
 		let result = parse_pem_private_key("");
@@ -482,9 +605,22 @@ mod tests {
 		assert!(key_path.exists());
 
 		// Load config
-		let res = load_tls_config(cert_path.to_str().unwrap(), key_path.to_str().unwrap());
+		let res = load_tls_config(cert_path.to_str().unwrap(), key_path.to_str().unwrap(), None);
+		assert!(res.is_ok());
+
+		// Load config requiring client certificates, using the generated certificate as CA
+		let res = load_tls_config(
+			cert_path.to_str().unwrap(),
+			key_path.to_str().unwrap(),
+			Some(cert_path.to_str().unwrap()),
+		);
 		assert!(res.is_ok());
 
+		// The client identity is taken from the certificate's subject
+		let cert_pem = fs::read_to_string(&cert_path).unwrap();
+		let certs = parse_pem_certs(&cert_pem).unwrap();
+		assert_eq!(client_identity_from_cert(&certs[0]).as_deref(), Some(ISSUER_NAME));
+
 		// Clean up
 		let _ = fs::remove_file(&cert_path);
 		let _ = fs::remove_file(&key_path);